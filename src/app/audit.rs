@@ -0,0 +1,159 @@
+//! Audit trail of actions taken from this board (`A` to view)
+//!
+//! The event log shows what agents did; this records what the operator did
+//! to them: permission answers, question answers and sent input, whether
+//! from a keybinding, the auto-accept scope or the control socket. Entries
+//! live in a ring buffer and, with `--audit-log`, are also appended to a
+//! file as JSON lines so a long session can be reviewed afterwards.
+//!
+//! Rehoboam has no kill action; stopping an agent happens in its pane.
+
+use color_eyre::eyre::{Result, WrapErr};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Entries kept in memory; the oldest is evicted first
+pub const MAX_AUDIT_ENTRIES: usize = 300;
+
+/// What the operator did
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Approve,
+    Reject,
+    /// Approved by the read-only auto-accept scope
+    AutoApprove,
+    Answer,
+    Input,
+}
+
+impl AuditAction {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Approve => "approve",
+            Self::Reject => "reject",
+            Self::AutoApprove => "auto-approve",
+            Self::Answer => "answer",
+            Self::Input => "input",
+        }
+    }
+}
+
+/// One recorded action
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEntry {
+    /// Unix seconds
+    pub timestamp: i64,
+    pub action: AuditAction,
+    pub pane_id: String,
+    pub project: String,
+    /// Tool, option label or input text
+    pub detail: String,
+    /// False when the keypress or send failed
+    pub ok: bool,
+}
+
+/// Ring buffer of operator actions, optionally mirrored to disk
+#[derive(Debug, Default)]
+pub struct AuditLog {
+    entries: VecDeque<AuditEntry>,
+    file: Option<File>,
+}
+
+impl AuditLog {
+    /// Also append every entry to `path`
+    pub fn open_file(&mut self, path: &Path) -> Result<()> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open audit log {}", path.display()))?;
+        self.file = Some(file);
+        Ok(())
+    }
+
+    /// Record an action against `pane_id`, stamped with the current time
+    pub fn record(
+        &mut self,
+        action: AuditAction,
+        pane_id: &str,
+        project: &str,
+        detail: impl Into<String>,
+        ok: bool,
+    ) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.push(AuditEntry {
+            timestamp,
+            action,
+            pane_id: pane_id.to_string(),
+            project: project.to_string(),
+            detail: detail.into(),
+            ok,
+        });
+    }
+
+    fn push(&mut self, entry: AuditEntry) {
+        if let Some(file) = self.file.as_mut() {
+            let written = serde_json::to_string(&entry)
+                .map_err(std::io::Error::other)
+                .and_then(|line| file.write_all(format!("{line}\n").as_bytes()));
+            if let Err(e) = written {
+                tracing::warn!(error = %e, "Failed to append to audit log");
+            }
+        }
+        if self.entries.len() == MAX_AUDIT_ENTRIES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Entries, newest first
+    pub fn newest_first(&self) -> impl Iterator<Item = &AuditEntry> {
+        self.entries.iter().rev()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_buffer_evicts_oldest_and_mirrors_to_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let mut log = AuditLog::default();
+        log.open_file(&path).unwrap();
+
+        for i in 0..MAX_AUDIT_ENTRIES + 2 {
+            log.record(AuditAction::Input, &format!("%{i}"), "p", "yes", true);
+        }
+        assert_eq!(log.len(), MAX_AUDIT_ENTRIES);
+        assert_eq!(
+            log.newest_first().next().unwrap().pane_id,
+            format!("%{}", MAX_AUDIT_ENTRIES + 1)
+        );
+        assert_eq!(log.newest_first().last().unwrap().pane_id, "%2");
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(content.lines().count(), MAX_AUDIT_ENTRIES + 2);
+        let first: serde_json::Value =
+            serde_json::from_str(content.lines().next().unwrap()).unwrap();
+        assert_eq!(first["action"], "input");
+        assert_eq!(first["pane_id"], "%0");
+    }
+}
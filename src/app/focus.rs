@@ -0,0 +1,107 @@
+//! Board selection saved across modals
+//!
+//! Opening a modal pushes the selected agent; closing it pops that entry and
+//! selects the agent again, so `j`/`k` pressed under the help overlay or an
+//! agent that took focus while a dialog was up doesn't leave the board
+//! somewhere else. The stack follows how many modals are open after each
+//! key, so `Esc` closing only the innermost one pops only its entry.
+//!
+//! The selection is not restored when the closing key chose an agent itself
+//! (a palette or history jump, loading a workspace), or when the saved agent
+//! has left the board. Search, playback and the panels that show the selected
+//! agent (detail, transcript, pane output) are not modals here: moving the
+//! selection is how they are used.
+
+use super::{App, InputMode};
+
+impl App {
+    /// Modals currently open, innermost last counted
+    fn modal_depth(&self) -> usize {
+        let overlays = [
+            self.show_help,
+            self.show_task_board,
+            self.show_audit_log,
+            self.show_sources,
+            self.show_failures,
+        ];
+        let dialog = !matches!(
+            self.input_mode,
+            InputMode::Normal | InputMode::Search | InputMode::Playback
+        );
+        overlays.into_iter().filter(|open| *open).count() + usize::from(dialog)
+    }
+
+    /// Push or pop saved selections after a key; `before` is the selection
+    /// the key started from
+    pub(super) fn track_modal_focus(&mut self, before: Option<String>) {
+        let depth = self.modal_depth();
+        let mut restore = None;
+        while self.focus_stack.len() > depth {
+            restore = self.focus_stack.pop();
+        }
+        while self.focus_stack.len() < depth {
+            self.focus_stack.push(self.state.selected_pane_id.clone());
+        }
+
+        let Some(saved) = restore else {
+            return;
+        };
+        let chose_agent = self.state.selected_pane_id != before;
+        let still_here = saved
+            .as_ref()
+            .is_none_or(|pane_id| self.state.agents.contains_key(pane_id));
+        if !chose_agent && still_here && saved != self.state.selected_pane_id {
+            tracing::debug!(pane_id = ?saved, "Restoring selection after modal");
+            self.state.selected_pane_id = saved;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RehoboamConfig;
+    use crate::state::Agent;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_closing_modals_restores_selection() {
+        let mut config = RehoboamConfig::default();
+        config.persistence.enabled = false;
+        let mut app = App::new(false, None, &config);
+        for pane in ["%1", "%2", "%3"] {
+            let agent = Agent::new(pane.to_string(), "proj".to_string());
+            app.state.agents.insert(pane.to_string(), agent);
+        }
+        app.state.selected_pane_id = Some("%1".to_string());
+
+        // Help, then the palette over it; j under help moves the board
+        press(&mut app, KeyCode::Char('?'));
+        press(&mut app, KeyCode::Char('j'));
+        let under_help = app.state.selected_pane_id.clone();
+        assert_ne!(under_help.as_deref(), Some("%1"));
+        press(&mut app, KeyCode::Char(':'));
+        assert_eq!(app.focus_stack.len(), 2);
+        app.state.selected_pane_id = Some("%3".to_string());
+
+        // Esc closes the innermost modal only
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.show_help);
+        assert_eq!(app.state.selected_pane_id, under_help);
+        press(&mut app, KeyCode::Esc);
+        assert!(app.focus_stack.is_empty());
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%1"));
+
+        // A saved agent that left the board is not brought back
+        press(&mut app, KeyCode::Char('?'));
+        press(&mut app, KeyCode::Char('j'));
+        app.state.agents.remove("%1");
+        press(&mut app, KeyCode::Esc);
+        assert_ne!(app.state.selected_pane_id.as_deref(), Some("%1"));
+    }
+}
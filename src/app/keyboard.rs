@@ -5,31 +5,85 @@
 //! # Keyboard Layout
 //!
 //! **Uppercase = Views** (open overlays), **lowercase = actions** (do things).
+//! These are the defaults: every Normal-mode key except `Esc`, `1`-`9`,
+//! `Ctrl+C` and `Ctrl+P` can be remapped under `[keymap]` (see
+//! [`super::keymap`]).
 //!
 //! ## Navigation
 //! - `j`/`↓` - Move to next agent
 //! - `k`/`↑` - Move to previous agent
+//! - `Tab` - Jump to next agent needing attention (highest priority first)
 //! - `Enter` - Jump to selected agent's tmux pane
-//! - `/` - Enter search mode
+//! - `/` - Fuzzy search: narrows and ranks the list as you type, `Enter` jumps
+//!   to the best match, `Esc` clears it
 //!
 //! ## Views (uppercase)
+//! - `M` - Toggle the minimap: one status-colored cell per agent in place of
+//!   the cards, for large fleets (`h`/`l` move a cell, `j`/`k` a row)
 //! - `T` - Toggle task board overlay
 //! - `P` - Toggle plan viewer
 //! - `S` - Toggle stats dashboard
 //! - `L` - Toggle history log
 //! - `D` - Toggle debug viewer
 //! - `I` - Toggle insights report
+//! - `C` - Toggle checkpoint timeline (selected sprite agent)
+//! - `A` - Toggle audit trail (approvals, answers and input sent from here)
+//! - `N` - Toggle connections (events delivered and last report per local
+//!   socket and sprite; a connected sprite that went quiet reads "silent")
+//! - `E` - Toggle recent errors (unresolved tool failures, grouped by message)
+//! - `V` - Full-screen event log (last `[ui] event_log_size` hook events);
+//!   `/` filters by `pane:`, `event:` or `source:`, bare words fuzzy-match
+//! - `O` - Toggle transcript tail (selected agent's last messages, read from
+//!   its `.jsonl`, following selection)
+//! - `W` - Toggle pane output (selected agent's tmux pane with scrollback and
+//!   colors; `PgUp`/`PgDn` page back and pause capture, `Home`/`End` jump)
 //! - `?`/`H` - Toggle help
 //!
 //! ## Actions (lowercase)
-//! - `s` - Open spawn dialog
+//! - `s` - Open spawn dialog (a prompt of `@FILE` spawns one agent per entry
+//!   in FILE, see [`super::spawn_batch`])
+//! - `a` - Toggle attention-only filter (composes with search)
+//! - `v` - Toggle compact cards (one line per agent)
+//! - `f` - Cycle display freeze: live → frozen → frozen and silent
+//! - `g` - Cycle card grouping: team → model → permission mode
+//! - `z` - Collapse/expand the selected agent's team (`j`/`k` skip collapsed teams)
+//! - `p` - Cycle permission auto-accept: off → read-only tools
+//! - `n` - Cycle do not disturb: follow `[notify] quiet_hours` → silent → always
+//!   notify (overrides the schedule until cycled back)
+//! - `i` - Inspect selected agent (detail overlay)
+//! - `Space` - Mark/unmark selected agent for bulk input
+//! - `*` - Pin/unpin selected agent to the top of its team (kept across restarts)
+//! - `X` - Sweep: remove gone agents and agents idle past `sweep_idle_secs`
+//!   from the board after a y/N confirmation (tmux panes are left alone)
+//! - `Y` - Approve pending permission prompts in bulk: all of them, or only
+//!   those for one tool (`Write`, `Bash`, ...) picked from a menu
+//! - `c` - Send input to marked agents (or the selected agent if none marked)
+//! - `e` - Edit the selected agent's note (shown on its card, kept across
+//!   status changes and restarts; saving an empty note clears it)
+//! - `w` - Workspaces: `1`-`9` load a saved layout (view, filters, grouping,
+//!   collapsed teams, pins), `s` saves the current one under a name (see
+//!   [`super::workspace`])
+//! - `1`-`9` - Answer the selected agent's pending question with that option
+//! - `y` - Copy the selected agent's pane id, transcript path, cwd or session id
+//! - `o` - Open the selected agent's cwd with `[ui] open_command` (detached)
+//!
+//! In read-only mirror mode (`--connect`), `Enter`, `s`, `c`, `p`, `o`, `W`, `Y` and
+//! `1`-`9` are ignored since they would act on local tmux rather than the mirrored host.
 //!
 //! ## Application
-//! - `q` - Quit application
-//! - `Esc` - Close current overlay or quit
+//! - `:`/`Ctrl+P` - Command palette: type to filter every action (with its
+//!   keys), `Enter` runs it; greyed-out entries don't apply right now
+//! - `q` - Quit application (asks y/N first while agents are working or
+//!   compacting, unless `[ui] confirm_quit = false`; `Ctrl+C` never asks)
+//! - `Esc` - Close current overlay, clear search or marks, or quit
 //! - `Ctrl+C` - Force quit
 
-use super::{navigation, spawn, App, InputMode};
+use super::keymap::Action;
+use super::{
+    navigation, operations, spawn, spawn_batch, App, AuditAction, AutoAccept, DoNotDisturb,
+    InputMode, InputPurpose,
+};
+use crate::state::AgentFilter;
 use crossterm::event::{KeyCode, KeyModifiers};
 
 impl App {
@@ -41,7 +95,13 @@ impl App {
             return;
         }
 
-        // Route to mode-specific handlers
+        let selected = self.state.selected_pane_id.clone();
+        self.dispatch_key(key);
+        self.track_modal_focus(selected);
+    }
+
+    /// Route a key to the handler for the current input mode
+    fn dispatch_key(&mut self, key: crossterm::event::KeyEvent) {
         match self.input_mode {
             InputMode::Normal => self.handle_key_normal(key),
             InputMode::Spawn => self.handle_key_spawn(key),
@@ -51,54 +111,400 @@ impl App {
             InputMode::HistoryViewer => self.handle_key_history_viewer(key),
             InputMode::DebugViewer => self.handle_key_debug_viewer(key),
             InputMode::InsightsViewer => self.handle_key_insights_viewer(key),
+            InputMode::Input => self.handle_key_input(key),
+            InputMode::CheckpointViewer => self.handle_key_checkpoint_viewer(key),
+            InputMode::CopyMenu => self.handle_key_copy_menu(key),
+            InputMode::SweepConfirm => self.handle_key_sweep_confirm(key),
+            InputMode::BulkApprove => self.handle_key_bulk_approve(key),
+            InputMode::WorkspaceMenu => self.handle_key_workspace_menu(key),
+            InputMode::QuitConfirm => self.handle_key_quit_confirm(key),
+            InputMode::Palette => self.handle_key_palette(key),
+            InputMode::EventLog => self.handle_key_event_log(key),
+            InputMode::Playback => self.handle_key_playback(key),
         }
     }
 
     /// Handle keyboard input in Normal mode
+    ///
+    /// `Esc` and `1`-`9` are fixed; every other key goes through the keymap.
     fn handle_key_normal(&mut self, key: crossterm::event::KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('p') {
+            self.open_palette();
+            return;
+        }
+        if let Some(action) = self.keymap.action(key.code) {
+            self.run_action(action);
+            return;
+        }
         match key.code {
-            // Quit (but Esc first closes overlays)
-            KeyCode::Char('q') => {
-                self.should_quit = true;
-            }
             // Esc cascade: close overlays in priority order
             // Note: Stats/History/Debug/Insights/Plan viewers use dedicated InputModes
             // and handle their own Esc — they never reach this Normal mode handler.
-            // Only help and task_board stay in Normal mode, so only they need handling here.
+            // Only help, task_board, agent detail, the audit trail, connections, recent
+            // errors, the transcript tail and pane output stay in Normal mode, so only
+            // they need handling here.
             KeyCode::Esc => {
                 if self.show_help {
                     self.show_help = false;
+                } else if self.show_agent_detail {
+                    self.show_agent_detail = false;
                 } else if self.show_task_board {
                     self.show_task_board = false;
+                } else if self.show_audit_log {
+                    self.show_audit_log = false;
+                } else if self.show_sources {
+                    self.show_sources = false;
+                } else if self.show_failures {
+                    self.show_failures = false;
+                } else if self.show_transcript {
+                    self.show_transcript = false;
+                    self.transcript = None;
+                } else if self.show_pane_output {
+                    self.show_pane_output = false;
+                    self.pane_output = None;
+                } else if !self.search_query.is_empty() {
+                    self.search_query.clear();
+                } else if !self.state.selected_agents.is_empty() {
+                    self.state.selected_agents.clear();
                 } else {
-                    self.should_quit = true;
+                    self.request_quit();
                 }
             }
-            // Agent navigation (flat across all teams)
-            KeyCode::Char('j') | KeyCode::Down => {
-                self.state.next_agent();
+
+            // Minimap: step one cell left or right
+            KeyCode::Char('h') | KeyCode::Left if self.show_minimap => {
+                let filter = AgentFilter {
+                    attention_only: self.attention_only,
+                    query: &self.search_query,
+                };
+                self.state.offset_selection(&filter, -1);
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                self.state.prev_agent();
+            KeyCode::Char('l') | KeyCode::Right if self.show_minimap => {
+                let filter = AgentFilter {
+                    attention_only: self.attention_only,
+                    query: &self.search_query,
+                };
+                self.state.offset_selection(&filter, 1);
             }
-            // Jump to agent
-            KeyCode::Enter => {
-                navigation::jump_to_selected(&self.state);
+
+            // Pane output: page through the scrollback (capture pauses while
+            // scrolled back), Home/End jump to the oldest/newest line
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End
+                if self.show_pane_output =>
+            {
+                let page = self.output_page as isize;
+                if let Some(output) = self.pane_output.as_mut() {
+                    match key.code {
+                        KeyCode::PageUp => output.scroll_by(page),
+                        KeyCode::PageDown => output.scroll_by(-page),
+                        KeyCode::Home => output.scroll_by(isize::MAX),
+                        _ => output.scroll = 0,
+                    }
+                }
+            }
+
+            // Answer the selected agent's AskUserQuestion
+            KeyCode::Char(c @ '1'..='9') if !self.read_only => {
+                let Some(agent) = self.state.selected_agent() else {
+                    return;
+                };
+                let choice = (c as usize) - ('0' as usize);
+                let Some(label) = agent.pending_options.get(choice - 1).cloned() else {
+                    return;
+                };
+                let pane_id = agent.pane_id.clone();
+                let result = operations::answer_question(
+                    &self.state,
+                    &pane_id,
+                    choice,
+                    self.sprites_client.as_ref(),
+                    self.event_tx.as_ref(),
+                );
+                self.audit(AuditAction::Answer, &pane_id, &label, result.is_ok());
+                match result {
+                    Ok(()) => self.set_status_message(format!("answered {pane_id}: {label}")),
+                    Err(e) => {
+                        tracing::warn!(pane_id = %pane_id, error = %e, "Failed to answer question");
+                        self.set_status_message(format!("failed to answer {pane_id}"));
+                    }
+                }
+            }
+
+            _ => {}
+        }
+    }
+
+    /// Perform a Normal-mode action (from the keymap or the palette)
+    pub(super) fn run_action(&mut self, action: Action) {
+        match action {
+            Action::Quit => self.request_quit(),
+            Action::Palette => self.open_palette(),
+            // Agent navigation (flat across all teams, honoring the view filter)
+            // (a whole grid row at a time in the minimap)
+            Action::NextAgent => {
+                let filter = AgentFilter {
+                    attention_only: self.attention_only,
+                    query: &self.search_query,
+                };
+                if self.show_minimap {
+                    let row = self.minimap_columns as isize;
+                    self.state.offset_selection(&filter, row);
+                } else {
+                    self.state.next_agent_filtered(&filter);
+                }
+            }
+            Action::PrevAgent => {
+                let filter = AgentFilter {
+                    attention_only: self.attention_only,
+                    query: &self.search_query,
+                };
+                if self.show_minimap {
+                    let row = self.minimap_columns as isize;
+                    self.state.offset_selection(&filter, -row);
+                } else {
+                    self.state.prev_agent_filtered(&filter);
+                }
+            }
+            // Jump to next agent needing attention (priority order, wraps)
+            Action::NextAttention => {
+                let filter = AgentFilter {
+                    attention_only: self.attention_only,
+                    query: &self.search_query,
+                };
+                if !self.state.next_attention_agent(&filter) {
+                    self.set_status_message("No agents need attention");
+                }
+            }
+            // Toggle attention-only filter
+            Action::AttentionFilter => {
+                self.attention_only = !self.attention_only;
+                // Keep selection on a visible agent
+                let filter = self.agent_filter();
+                let hidden = self
+                    .state
+                    .selected_agent()
+                    .is_some_and(|a| !filter.matches(a));
+                if hidden {
+                    self.state.selected_pane_id = None;
+                }
+                tracing::debug!(
+                    attention_only = self.attention_only,
+                    "Toggled attention filter"
+                );
+            }
+            // Toggle card density
+            Action::Density => {
+                self.density = self.density.toggled();
+                tracing::debug!(density = ?self.density, "Toggled card density");
+            }
+            Action::Freeze => self.cycle_freeze(),
+            // Cycle what the cards are grouped under
+            Action::GroupBy => {
+                self.state.group_by = self.state.group_by.next();
+                let label = self.state.group_by.label();
+                self.set_status_message(format!("grouped by {label}"));
+            }
+            // Cycle the do-not-disturb override of quiet hours
+            Action::DoNotDisturb => {
+                self.do_not_disturb = self.do_not_disturb.next();
+                let message = match self.do_not_disturb {
+                    DoNotDisturb::Schedule if self.quiet_hours.is_none() => "notifications on",
+                    DoNotDisturb::Schedule => "notifications follow quiet hours",
+                    DoNotDisturb::On => "do not disturb: notifications off",
+                    DoNotDisturb::Off => "notifications on, quiet hours ignored",
+                };
+                self.set_status_message(message);
+            }
+            // Cycle the permission auto-accept scope (acts on local panes)
+            Action::AutoAccept if self.read_only => {
+                tracing::debug!("Ignoring auto-accept in read-only mirror mode");
+            }
+            Action::AutoAccept => {
+                self.auto_accept = self.auto_accept.next();
+                let scope = match self.auto_accept {
+                    AutoAccept::Off => "auto-accept off",
+                    AutoAccept::ReadOnly => "auto-accept: read-only tools",
+                };
+                self.set_status_message(scope);
+            }
+            // Collapse/expand the selected agent's team (or model/mode group)
+            Action::CollapseTeam => {
+                let Some(team) = self
+                    .state
+                    .selected_agent()
+                    .map(|agent| self.state.display_group(agent).to_string())
+                else {
+                    self.set_status_message("No agent selected");
+                    return;
+                };
+                self.state.toggle_team_collapsed(&team);
+            }
+            // Jump to agent (panes live on the remote host when mirroring)
+            Action::Jump => {
+                if self.read_only {
+                    tracing::debug!("Ignoring jump in read-only mirror mode");
+                } else {
+                    navigation::jump_to_selected(&self.state, &self.terminal);
+                }
             }
             // Toggle help
-            KeyCode::Char('?' | 'H') => {
+            Action::Help => {
                 self.show_help = !self.show_help;
             }
+            // Swap the card view for the minimap grid
+            Action::Minimap => {
+                self.show_minimap = !self.show_minimap;
+                tracing::debug!(show_minimap = self.show_minimap, "Toggled minimap");
+            }
             // Toggle task board
-            KeyCode::Char('T') => {
+            Action::TaskBoard => {
                 self.show_task_board = !self.show_task_board;
                 tracing::debug!(show_task_board = self.show_task_board, "Toggled task board");
             }
+            // Toggle audit trail
+            Action::AuditLog => {
+                self.show_audit_log = !self.show_audit_log;
+                tracing::debug!(show_audit_log = self.show_audit_log, "Toggled audit trail");
+            }
+            // Toggle connections panel (events per socket and sprite)
+            Action::Sources => {
+                self.show_sources = !self.show_sources;
+                tracing::debug!(show_sources = self.show_sources, "Toggled connections");
+            }
+            // Toggle recent errors
+            Action::Failures => {
+                self.show_failures = !self.show_failures;
+                tracing::debug!(show_failures = self.show_failures, "Toggled recent errors");
+            }
+            // Full-screen event log, opened unfiltered at the newest event
+            Action::EventLog => {
+                self.event_log = super::EventLogState::default();
+                self.input_mode = InputMode::EventLog;
+                tracing::debug!("Opened event log");
+            }
+            // Toggle agent detail (tool history, latency, context)
+            Action::AgentDetail => {
+                self.show_agent_detail = !self.show_agent_detail;
+                tracing::debug!(
+                    show_agent_detail = self.show_agent_detail,
+                    "Toggled agent detail"
+                );
+            }
+            // Toggle transcript tail (selected agent's .jsonl)
+            Action::Transcript => {
+                self.show_transcript = !self.show_transcript;
+                if self.show_transcript {
+                    self.refresh_transcript();
+                } else {
+                    self.transcript = None;
+                }
+                tracing::debug!(
+                    show_transcript = self.show_transcript,
+                    "Toggled transcript tail"
+                );
+            }
+            // Toggle pane output (captured from local tmux, so not when mirroring)
+            Action::PaneOutput => {
+                if self.read_only {
+                    return;
+                }
+                self.show_pane_output = !self.show_pane_output;
+                if self.show_pane_output {
+                    self.refresh_pane_output();
+                } else {
+                    self.pane_output = None;
+                }
+                tracing::debug!(
+                    show_pane_output = self.show_pane_output,
+                    "Toggled pane output"
+                );
+            }
+
+            // === Bulk input ===
+            Action::Mark => {
+                if let Some(pane_id) = self.state.selected_pane_id.clone() {
+                    if !self.state.selected_agents.remove(&pane_id) {
+                        self.state.selected_agents.insert(pane_id);
+                    }
+                }
+            }
+            Action::Pin => {
+                if let Some(pane_id) = self.state.selected_pane_id.clone() {
+                    let pinned = self.state.toggle_pinned(&pane_id);
+                    tracing::debug!(pane_id = %pane_id, pinned, "Toggled pin");
+                } else {
+                    self.set_status_message("No agent selected");
+                }
+            }
+            // Bulk cleanup: confirm before removing gone/idle agents
+            Action::Sweep => {
+                self.sweep_pending = self.state.sweep_candidates();
+                if self.sweep_pending.is_empty() {
+                    self.set_status_message("Nothing to sweep");
+                } else {
+                    self.input_mode = InputMode::SweepConfirm;
+                }
+            }
+            Action::ApprovePending if self.read_only => {
+                tracing::debug!("Ignoring bulk approve in read-only mirror mode");
+            }
+            Action::ApprovePending => {
+                if self.state.pending_permissions(None).is_empty() {
+                    self.set_status_message("No permission prompts pending");
+                } else {
+                    self.bulk_approve_tools = self.state.pending_permission_tools();
+                    self.input_mode = InputMode::BulkApprove;
+                }
+            }
+            Action::Input if self.read_only => {
+                tracing::debug!("Ignoring input in read-only mirror mode");
+            }
+            Action::Input => {
+                if self.input_targets().is_empty() {
+                    self.set_status_message("No agent selected");
+                } else {
+                    self.input_buffer.clear();
+                    self.input_mode = InputMode::Input;
+                    tracing::debug!("Entering input mode");
+                }
+            }
+
+            Action::Note => match self.state.selected_agent() {
+                Some(agent) => {
+                    self.input_purpose = InputPurpose::Note(agent.pane_id.clone());
+                    self.input_buffer.clear();
+                    self.input_buffer
+                        .insert_str(agent.note.as_deref().unwrap_or_default());
+                    self.input_mode = InputMode::Input;
+                }
+                None => self.set_status_message("No agent selected"),
+            },
+
+            Action::Workspaces => self.input_mode = InputMode::WorkspaceMenu,
+
+            // Copy agent details to the clipboard
+            Action::Copy => {
+                if self.state.selected_agent().is_some() {
+                    self.input_mode = InputMode::CopyMenu;
+                } else {
+                    self.set_status_message("No agent selected");
+                }
+            }
+
+            // Open the selected agent's directory in an editor
+            Action::OpenDir if self.read_only => {
+                tracing::debug!("Ignoring open in read-only mirror mode");
+            }
+            Action::OpenDir => self.open_selected_dir(),
 
             // === Agent spawning ===
-            KeyCode::Char('s') => {
+            Action::Spawn if self.read_only => {
+                tracing::debug!("Ignoring spawn in read-only mirror mode");
+            }
+            Action::Spawn => {
                 self.input_mode = InputMode::Spawn;
                 self.spawn_state = spawn::SpawnState::default();
+                self.spawn_state.load_recents();
                 if let Ok(cwd) = std::env::current_dir() {
                     self.spawn_state.project_path = cwd.display().to_string();
                 }
@@ -106,7 +512,7 @@ impl App {
             }
 
             // Plan viewer
-            KeyCode::Char('P') => {
+            Action::PlanViewer => {
                 if self.show_plan_viewer {
                     self.show_plan_viewer = false;
                     self.input_mode = InputMode::Normal;
@@ -124,7 +530,7 @@ impl App {
             }
 
             // Stats dashboard
-            KeyCode::Char('S') => {
+            Action::Stats => {
                 if self.show_stats_viewer {
                     self.show_stats_viewer = false;
                     self.input_mode = InputMode::Normal;
@@ -137,7 +543,7 @@ impl App {
             }
 
             // History timeline
-            KeyCode::Char('L') => {
+            Action::History => {
                 if self.show_history_viewer {
                     self.show_history_viewer = false;
                     self.input_mode = InputMode::Normal;
@@ -152,7 +558,7 @@ impl App {
             }
 
             // Debug log viewer
-            KeyCode::Char('D') => {
+            Action::Debug => {
                 if self.show_debug_viewer {
                     self.show_debug_viewer = false;
                     self.input_mode = InputMode::Normal;
@@ -167,7 +573,7 @@ impl App {
             }
 
             // Insights report
-            KeyCode::Char('I') => {
+            Action::Insights => {
                 if self.show_insights_viewer {
                     self.show_insights_viewer = false;
                     self.input_mode = InputMode::Normal;
@@ -181,14 +587,32 @@ impl App {
                 }
             }
 
+            // Sprite checkpoint timeline
+            Action::Checkpoints => {
+                let sprite_id = self
+                    .state
+                    .selected_agent()
+                    .and_then(|a| a.sprite_id.clone());
+                if let Some(sprite_id) = sprite_id {
+                    self.checkpoint_viewer = super::CheckpointViewerState {
+                        sprite_id,
+                        ..Default::default()
+                    };
+                    self.show_checkpoint_viewer = true;
+                    self.input_mode = InputMode::CheckpointViewer;
+                    self.fetch_checkpoints();
+                    tracing::debug!("Opened checkpoint timeline");
+                } else {
+                    self.set_status_message("Select a sprite agent to view checkpoints");
+                }
+            }
+
             // Agent search
-            KeyCode::Char('/') => {
+            Action::Search => {
                 self.input_mode = InputMode::Search;
                 self.search_query.clear();
                 tracing::debug!("Entering search mode");
             }
-
-            _ => {}
         }
     }
 
@@ -200,6 +624,20 @@ impl App {
                 self.spawn_state = spawn::SpawnState::default();
                 tracing::debug!("Cancelled spawn mode");
             }
+            // Up/Down in the project field cycle recent projects (if any)
+            KeyCode::Down | KeyCode::Up
+                if self.spawn_state.active_field == 0 && !self.spawn_state.recents.is_empty() =>
+            {
+                let forward = key.code == KeyCode::Down;
+                if !self.spawn_state.cycle_recent(forward) {
+                    // No recents for this mode: behave like Tab/BackTab
+                    self.spawn_state.active_field = if forward {
+                        1
+                    } else {
+                        spawn::SPAWN_FIELD_COUNT - 1
+                    };
+                }
+            }
             KeyCode::Tab | KeyCode::Down => {
                 self.spawn_state.active_field =
                     (self.spawn_state.active_field + 1) % spawn::SPAWN_FIELD_COUNT;
@@ -219,11 +657,22 @@ impl App {
                     ) {
                         Ok(()) => {
                             self.spawn_state.validation_error = None;
-                            spawn::spawn_agent(
-                                &self.spawn_state,
-                                self.sprites_client.as_ref(),
-                                &mut self.state,
-                            );
+                            // `@FILE` prompt: one agent per entry
+                            if let Some(file) = spawn_batch::batch_file(
+                                &self.spawn_state.prompt,
+                                &self.spawn_state.project_path,
+                            ) {
+                                if let Err(msg) = self.start_batch_spawn(&file) {
+                                    self.spawn_state.validation_error = Some(msg);
+                                    return;
+                                }
+                            } else {
+                                spawn::spawn_agent(
+                                    &self.spawn_state,
+                                    self.sprites_client.as_ref(),
+                                    &mut self.state,
+                                );
+                            }
                             self.input_mode = InputMode::Normal;
                             self.spawn_state = spawn::SpawnState::default();
                         }
@@ -245,15 +694,11 @@ impl App {
                 1 => self.spawn_state.prompt.push(' '),
                 _ => {}
             },
-            KeyCode::Left => {
-                if self.spawn_state.active_field == 3 {
-                    self.spawn_state.network_preset = self.spawn_state.network_preset.prev();
-                }
+            KeyCode::Left if self.spawn_state.active_field == 3 => {
+                self.spawn_state.network_preset = self.spawn_state.network_preset.prev();
             }
-            KeyCode::Right => {
-                if self.spawn_state.active_field == 3 {
-                    self.spawn_state.network_preset = self.spawn_state.network_preset.next();
-                }
+            KeyCode::Right if self.spawn_state.active_field == 3 => {
+                self.spawn_state.network_preset = self.spawn_state.network_preset.next();
             }
             KeyCode::Backspace => match self.spawn_state.active_field {
                 0 => {
@@ -315,6 +760,249 @@ impl App {
         }
     }
 
+    /// Handle keyboard input in Input mode
+    ///
+    /// Shift+Enter, Alt+Enter or Ctrl+J start a new line (terminals differ in
+    /// which of these they report); plain Enter sends.
+    fn handle_key_input(&mut self, key: crossterm::event::KeyEvent) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+        let input = &mut self.input_buffer;
+        match key.code {
+            KeyCode::Esc => {
+                self.input_mode = InputMode::Normal;
+                self.input_purpose = InputPurpose::Send;
+                input.clear();
+                tracing::debug!("Cancelled input mode");
+            }
+            KeyCode::Enter if alt || key.modifiers.contains(KeyModifiers::SHIFT) => {
+                input.insert('\n');
+            }
+            KeyCode::Char('j') if ctrl => input.insert('\n'),
+            KeyCode::Enter => self.submit_input(),
+            KeyCode::Backspace if alt => input.delete_word(),
+            KeyCode::Backspace => input.backspace(),
+            KeyCode::Delete => input.delete(),
+            KeyCode::Left => input.left(),
+            KeyCode::Right => input.right(),
+            KeyCode::Up => input.up(),
+            KeyCode::Down => input.down(),
+            KeyCode::Home => input.home(),
+            KeyCode::End => input.end(),
+            KeyCode::Char('a') if ctrl => input.home(),
+            KeyCode::Char('e') if ctrl => input.end(),
+            KeyCode::Char('w') if ctrl => input.delete_word(),
+            KeyCode::Char('u') if ctrl => input.delete_to_line_start(),
+            KeyCode::Char(_) if ctrl => {}
+            KeyCode::Char(c) => input.insert(c),
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input in CopyMenu mode (any other key cancels)
+    fn handle_key_copy_menu(&mut self, key: crossterm::event::KeyEvent) {
+        match key.code {
+            KeyCode::Char(c) => self.copy_selected_detail(c),
+            _ => self.input_mode = InputMode::Normal,
+        }
+    }
+
+    /// Handle keyboard input in SweepConfirm mode (anything but 'y' cancels)
+    fn handle_key_sweep_confirm(&mut self, key: crossterm::event::KeyEvent) {
+        self.input_mode = InputMode::Normal;
+        let pending = std::mem::take(&mut self.sweep_pending);
+        if !matches!(key.code, KeyCode::Char('y' | 'Y')) {
+            return;
+        }
+        let removed = self.state.sweep(&pending);
+        tracing::info!(count = removed.len(), "Swept agents");
+        self.set_status_message(match removed.len() {
+            0 => "Nothing swept (agents reported again)".to_string(),
+            n => format!("swept {n}: {}", removed.join(", ")),
+        });
+    }
+
+    /// Handle keyboard input in BulkApprove mode: `a` approves every prompt,
+    /// `1`-`9` the prompts for that tool, anything else cancels
+    fn handle_key_bulk_approve(&mut self, key: crossterm::event::KeyEvent) {
+        self.input_mode = InputMode::Normal;
+        let tools = std::mem::take(&mut self.bulk_approve_tools);
+        match key.code {
+            KeyCode::Char('a') => self.approve_pending(None),
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = (c as usize) - ('1' as usize);
+                if let Some((tool, _)) = tools.get(index) {
+                    self.approve_pending(Some(tool));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input in WorkspaceMenu mode: `1`-`9` load that
+    /// workspace, `s` names the current layout to save, anything else cancels
+    fn handle_key_workspace_menu(&mut self, key: crossterm::event::KeyEvent) {
+        self.input_mode = InputMode::Normal;
+        match key.code {
+            KeyCode::Char('s') => {
+                self.input_buffer.clear();
+                self.input_purpose = InputPurpose::Workspace;
+                self.input_mode = InputMode::Input;
+            }
+            KeyCode::Char(c @ '1'..='9') => {
+                let index = (c as usize) - ('1' as usize);
+                if let Some(name) = self.workspaces.keys().nth(index).cloned() {
+                    self.load_workspace(&name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input in QuitConfirm mode (anything but 'y' cancels)
+    fn handle_key_quit_confirm(&mut self, key: crossterm::event::KeyEvent) {
+        self.input_mode = InputMode::Normal;
+        if matches!(key.code, KeyCode::Char('y' | 'Y')) {
+            self.should_quit = true;
+        }
+    }
+
+    /// Handle keyboard input in the command palette
+    fn handle_key_palette(&mut self, key: crossterm::event::KeyEvent) {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.palette_query.clear();
+                self.input_mode = InputMode::Normal;
+            }
+            KeyCode::Enter => self.run_palette_selection(),
+            KeyCode::Down | KeyCode::Tab => self.move_palette_selection(true),
+            KeyCode::Char('n') if ctrl => self.move_palette_selection(true),
+            KeyCode::Up | KeyCode::BackTab => self.move_palette_selection(false),
+            KeyCode::Char('p') if ctrl => self.move_palette_selection(false),
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.palette_selected = 0;
+            }
+            KeyCode::Char(c) => {
+                self.palette_query.push(c);
+                self.palette_selected = 0;
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input in the event log view
+    ///
+    /// `/` edits the filter (`Enter` keeps it, `Esc` drops it); otherwise
+    /// `j`/`k` scroll, `d`/`u` page, `g`/`G` jump to newest/oldest.
+    fn handle_key_event_log(&mut self, key: crossterm::event::KeyEvent) {
+        if self.event_log.editing {
+            match key.code {
+                KeyCode::Esc => {
+                    self.event_log.query.clear();
+                    self.event_log.editing = false;
+                }
+                KeyCode::Enter => self.event_log.editing = false,
+                KeyCode::Backspace => {
+                    self.event_log.query.pop();
+                }
+                KeyCode::Char(c) => self.event_log.query.push(c),
+                _ => return,
+            }
+            self.event_log.scroll = 0;
+            return;
+        }
+        let last = self
+            .state
+            .filter_event_log(&self.event_log.query)
+            .len()
+            .saturating_sub(1);
+        let scroll = &mut self.event_log.scroll;
+        match key.code {
+            KeyCode::Esc => self.input_mode = InputMode::Normal,
+            KeyCode::Char('/') => self.event_log.editing = true,
+            KeyCode::Char('j') | KeyCode::Down => *scroll = (*scroll + 1).min(last),
+            KeyCode::Char('k') | KeyCode::Up => *scroll = scroll.saturating_sub(1),
+            KeyCode::Char('d') | KeyCode::PageDown => *scroll = (*scroll + 20).min(last),
+            KeyCode::Char('u') | KeyCode::PageUp => *scroll = scroll.saturating_sub(20),
+            KeyCode::Char('g') | KeyCode::Home => *scroll = 0,
+            KeyCode::Char('G') | KeyCode::End => *scroll = last,
+            _ => {}
+        }
+    }
+
+    fn move_palette_selection(&mut self, down: bool) {
+        if down {
+            if self.palette_selected + 1 < self.palette_entries().len() {
+                self.palette_selected += 1;
+            }
+        } else {
+            self.palette_selected = self.palette_selected.saturating_sub(1);
+        }
+    }
+
+    /// Handle keyboard input in CheckpointViewer mode
+    fn handle_key_checkpoint_viewer(&mut self, key: crossterm::event::KeyEvent) {
+        // Restore is destructive: anything but 'y' cancels the prompt
+        if self.checkpoint_viewer.confirm_restore {
+            if matches!(key.code, KeyCode::Char('y' | 'Y')) {
+                self.restore_selected_checkpoint();
+            } else {
+                self.checkpoint_viewer.confirm_restore = false;
+            }
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q' | 'C') => {
+                self.show_checkpoint_viewer = false;
+                self.input_mode = InputMode::Normal;
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                let count = self.checkpoint_viewer.records.len();
+                if self.checkpoint_viewer.selected_index + 1 < count {
+                    self.checkpoint_viewer.selected_index += 1;
+                }
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.checkpoint_viewer.selected_index =
+                    self.checkpoint_viewer.selected_index.saturating_sub(1);
+            }
+            KeyCode::Char('r') => self.fetch_checkpoints(),
+            KeyCode::Enter => {
+                if self.read_only {
+                    tracing::debug!("Ignoring restore in read-only mirror mode");
+                } else if !self.checkpoint_viewer.loading
+                    && self.checkpoint_viewer.selected_index < self.checkpoint_viewer.records.len()
+                {
+                    self.checkpoint_viewer.confirm_restore = true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle keyboard input while scrubbing a board recording
+    ///
+    /// Playback is the whole session, so closing it quits.
+    fn handle_key_playback(&mut self, key: crossterm::event::KeyEvent) {
+        let Some(playback) = self.playback.as_mut() else {
+            self.input_mode = InputMode::Normal;
+            return;
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => self.should_quit = true,
+            KeyCode::Char('l') | KeyCode::Right => playback.step(1),
+            KeyCode::Char('h') | KeyCode::Left => playback.step(-1),
+            KeyCode::PageDown => playback.step(10),
+            KeyCode::PageUp => playback.step(-10),
+            KeyCode::Char('g') | KeyCode::Home => playback.position = 0,
+            KeyCode::Char('G') | KeyCode::End => playback.end(),
+            _ => {}
+        }
+    }
+
     /// Handle keyboard input in PlanViewer mode
     fn handle_key_plan_viewer(&mut self, key: crossterm::event::KeyEvent) {
         if self.plan_viewer.viewing {
@@ -325,12 +1013,11 @@ impl App {
                     self.plan_viewer.viewing = false;
                     self.plan_viewer.content.clear();
                 }
-                KeyCode::Char('j') | KeyCode::Down => {
+                KeyCode::Char('j') | KeyCode::Down
                     if self.plan_viewer.scroll_offset
-                        < self.plan_viewer.rendered_height.saturating_sub(1)
-                    {
-                        self.plan_viewer.scroll_offset += 1;
-                    }
+                        < self.plan_viewer.rendered_height.saturating_sub(1) =>
+                {
+                    self.plan_viewer.scroll_offset += 1;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
                     self.plan_viewer.scroll_offset =
@@ -370,21 +1057,16 @@ impl App {
                     self.show_plan_viewer = false;
                     self.input_mode = InputMode::Normal;
                 }
-                KeyCode::Char('j') | KeyCode::Down => {
-                    if !self.plan_viewer.plans.is_empty() {
-                        self.plan_viewer.selected_index =
-                            (self.plan_viewer.selected_index + 1) % self.plan_viewer.plans.len();
-                    }
+                KeyCode::Char('j') | KeyCode::Down if !self.plan_viewer.plans.is_empty() => {
+                    self.plan_viewer.selected_index =
+                        (self.plan_viewer.selected_index + 1) % self.plan_viewer.plans.len();
                 }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    if !self.plan_viewer.plans.is_empty() {
-                        self.plan_viewer.selected_index =
-                            if self.plan_viewer.selected_index == 0 {
-                                self.plan_viewer.plans.len() - 1
-                            } else {
-                                self.plan_viewer.selected_index - 1
-                            };
-                    }
+                KeyCode::Char('k') | KeyCode::Up if !self.plan_viewer.plans.is_empty() => {
+                    self.plan_viewer.selected_index = if self.plan_viewer.selected_index == 0 {
+                        self.plan_viewer.plans.len() - 1
+                    } else {
+                        self.plan_viewer.selected_index - 1
+                    };
                 }
                 KeyCode::Enter => {
                     self.plan_viewer.load_selected();
@@ -402,12 +1084,12 @@ impl App {
                 self.input_mode = InputMode::Normal;
             }
             KeyCode::Tab => {
-                self.stats_viewer.active_tab = (self.stats_viewer.active_tab + 1) % 4;
+                self.stats_viewer.active_tab = (self.stats_viewer.active_tab + 1) % 5;
                 self.stats_viewer.scroll_offset = 0;
             }
             KeyCode::BackTab => {
                 self.stats_viewer.active_tab =
-                    (self.stats_viewer.active_tab + 3) % 4;
+                    (self.stats_viewer.active_tab + 4) % 5;
                 self.stats_viewer.scroll_offset = 0;
             }
             KeyCode::Char('1') => {
@@ -426,6 +1108,10 @@ impl App {
                 self.stats_viewer.active_tab = 3;
                 self.stats_viewer.scroll_offset = 0;
             }
+            KeyCode::Char('5') => {
+                self.stats_viewer.active_tab = 4;
+                self.stats_viewer.scroll_offset = 0;
+            }
             KeyCode::Char('j') | KeyCode::Down => {
                 self.stats_viewer.scroll_offset =
                     self.stats_viewer.scroll_offset.saturating_add(1);
@@ -469,12 +1155,11 @@ impl App {
                     self.debug_viewer.viewing = false;
                     self.debug_viewer.content.clear();
                 }
-                KeyCode::Char('j') | KeyCode::Down => {
+                KeyCode::Char('j') | KeyCode::Down
                     if self.debug_viewer.scroll_offset
-                        < self.debug_viewer.rendered_height.saturating_sub(1)
-                    {
-                        self.debug_viewer.scroll_offset += 1;
-                    }
+                        < self.debug_viewer.rendered_height.saturating_sub(1) =>
+                {
+                    self.debug_viewer.scroll_offset += 1;
                 }
                 KeyCode::Char('k') | KeyCode::Up => {
                     self.debug_viewer.scroll_offset =
@@ -551,19 +1236,15 @@ impl App {
                 self.show_insights_viewer = false;
                 self.input_mode = InputMode::Normal;
             }
-            KeyCode::Tab => {
-                if section_count > 0 {
-                    self.insights_viewer.active_section =
-                        (self.insights_viewer.active_section + 1) % section_count;
-                    self.insights_viewer.scroll_offset = 0;
-                }
+            KeyCode::Tab if section_count > 0 => {
+                self.insights_viewer.active_section =
+                    (self.insights_viewer.active_section + 1) % section_count;
+                self.insights_viewer.scroll_offset = 0;
             }
-            KeyCode::BackTab => {
-                if section_count > 0 {
-                    self.insights_viewer.active_section =
-                        (self.insights_viewer.active_section + section_count - 1) % section_count;
-                    self.insights_viewer.scroll_offset = 0;
-                }
+            KeyCode::BackTab if section_count > 0 => {
+                self.insights_viewer.active_section =
+                    (self.insights_viewer.active_section + section_count - 1) % section_count;
+                self.insights_viewer.scroll_offset = 0;
             }
             KeyCode::Char(c @ '1'..='9') => {
                 let idx = (c as usize) - ('1' as usize);
@@ -588,17 +1269,15 @@ impl App {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{HealthConfig, TimeoutConfig};
+    use crate::config::RehoboamConfig;
+    use crate::sprite::checkpoints::CheckpointRecord;
     use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
 
     /// Create a test App instance
     fn test_app() -> App {
-        App::new(
-            false,
-            None,
-            &HealthConfig::default(),
-            &TimeoutConfig::default(),
-        )
+        let mut config = RehoboamConfig::default();
+        config.persistence.enabled = false;
+        App::new(false, None, &config)
     }
 
     /// Create a key event from a character
@@ -723,6 +1402,18 @@ mod tests {
         assert_eq!(app.search_query, "tes", "backspace should remove last char");
     }
 
+    #[test]
+    fn test_attention_filter_toggle() {
+        let mut app = test_app();
+        assert!(!app.attention_only);
+
+        app.handle_key(key('a'));
+        assert!(app.attention_only, "'a' should enable attention filter");
+
+        app.handle_key(key('a'));
+        assert!(!app.attention_only, "'a' should disable attention filter");
+    }
+
     #[test]
     fn test_search_mode_escape() {
         let mut app = test_app();
@@ -788,6 +1479,621 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_agent_detail_toggle_and_esc() {
+        let mut app = test_app();
+        assert!(!app.show_agent_detail);
+
+        app.handle_key(key('i'));
+        assert!(app.show_agent_detail, "'i' should open agent detail");
+
+        app.handle_key(key_code(KeyCode::Esc));
+        assert!(!app.show_agent_detail, "Esc should close agent detail");
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_tab_without_attention_shows_message() {
+        let mut app = test_app();
+        app.handle_key(key_code(KeyCode::Tab));
+        assert!(app.state.selected_pane_id.is_none());
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("No agents need attention")
+        );
+    }
+
+    #[test]
+    fn test_bulk_input_reports_failures() {
+        let mut app = test_app();
+        app.handle_key(key('c'));
+        assert_eq!(app.input_mode, InputMode::Normal, "nothing to send to");
+
+        // Space marks the selected agent, a second Space unmarks it
+        app.state.selected_pane_id = Some("%404".to_string());
+        app.handle_key(key(' '));
+        assert!(app.state.selected_agents.contains("%404"));
+        app.handle_key(key(' '));
+        assert!(app.state.selected_agents.is_empty());
+
+        app.state.selected_agents.insert("%404".to_string());
+        app.handle_key(key('c'));
+        assert_eq!(app.input_mode, InputMode::Input);
+        app.handle_key(key('h'));
+        app.handle_key(key('i'));
+        assert_eq!(app.input_buffer.as_str(), "hi");
+
+        // Agent is gone, so the send fails and is reported
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("sent to 0/1 (%404 failed)")
+        );
+
+        // Esc clears marks before quitting
+        app.handle_key(key_code(KeyCode::Esc));
+        assert!(app.state.selected_agents.is_empty());
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_sweep_asks_before_removing_gone_agents() {
+        let mut app = test_app();
+        app.handle_key(key('X'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("Nothing to sweep")
+        );
+
+        let mut agent = crate::state::Agent::new("%3".to_string(), "p".to_string());
+        agent.gone_since = Some(0);
+        app.state.agents.insert("%3".to_string(), agent);
+
+        // Anything but 'y' cancels
+        app.handle_key(key('X'));
+        assert_eq!(app.input_mode, InputMode::SweepConfirm);
+        assert_eq!(app.sweep_pending, vec!["%3".to_string()]);
+        app.handle_key(key('n'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.state.agents.contains_key("%3"));
+
+        app.handle_key(key('X'));
+        app.handle_key(key('y'));
+        assert!(app.state.agents.is_empty());
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("swept 1: p (%3)")
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_restore_requires_confirmation() {
+        let mut app = test_app();
+        app.handle_key(key('C'));
+        assert_eq!(
+            app.input_mode,
+            InputMode::Normal,
+            "no sprite agent selected"
+        );
+        app.status_message = None;
+
+        app.show_checkpoint_viewer = true;
+        app.input_mode = InputMode::CheckpointViewer;
+        app.checkpoint_viewer.sprite_id = "rehoboam-app".to_string();
+        app.checkpoint_viewer.records = vec![CheckpointRecord {
+            id: "v2".to_string(),
+            comment: None,
+            created_at: None,
+        }];
+
+        // Anything but 'y' cancels
+        app.handle_key(key_code(KeyCode::Enter));
+        assert!(app.checkpoint_viewer.confirm_restore);
+        app.handle_key(key('n'));
+        assert!(!app.checkpoint_viewer.confirm_restore);
+        assert!(app.status_message.is_none());
+
+        // Sprite went away since the timeline was fetched: reported, not sent
+        app.handle_key(key_code(KeyCode::Enter));
+        app.handle_key(key('y'));
+        assert!(!app.checkpoint_viewer.confirm_restore);
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("rehoboam-app is disconnected, restore not sent")
+        );
+
+        app.handle_key(key_code(KeyCode::Esc));
+        assert!(!app.show_checkpoint_viewer);
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_density_toggle_starts_from_config() {
+        let mut config = RehoboamConfig::default();
+        config.persistence.enabled = false;
+        config.ui.density = crate::config::Density::Compact;
+        let mut app = App::new(false, None, &config);
+
+        app.handle_key(key('v'));
+        assert_eq!(app.density, crate::config::Density::Comfortable);
+        app.handle_key(key('v'));
+        assert_eq!(app.density, crate::config::Density::Compact);
+    }
+
+    #[test]
+    fn test_quit_confirms_while_agents_busy() {
+        let mut app = test_app();
+        let agent = crate::state::Agent::new("%2".to_string(), "p".to_string());
+        app.state.agents.insert("%2".to_string(), agent);
+        app.handle_key(key('q'));
+        assert!(app.should_quit, "waiting agents don't block quitting");
+
+        let mut app = test_app();
+        let mut agent = crate::state::Agent::new("%1".to_string(), "p".to_string());
+        agent.status = crate::state::Status::Working;
+        app.state.agents.insert("%1".to_string(), agent);
+        app.handle_key(key('q'));
+        assert_eq!(app.input_mode, InputMode::QuitConfirm);
+        assert!(!app.should_quit);
+        app.handle_key(key_code(KeyCode::Esc));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app.should_quit, "Esc cancels");
+        app.handle_key(key('q'));
+        app.handle_key(key('y'));
+        assert!(app.should_quit);
+
+        let mut app = test_app();
+        app.confirm_quit = false;
+        let mut agent = crate::state::Agent::new("%1".to_string(), "p".to_string());
+        agent.status = crate::state::Status::Working;
+        app.state.agents.insert("%1".to_string(), agent);
+        app.handle_key(key('q'));
+        assert!(app.should_quit, "confirm_quit = false quits instantly");
+    }
+
+    #[test]
+    fn test_minimap_moves_by_cell_and_row() {
+        let mut app = test_app();
+        for pane in ["%1", "%2", "%3", "%4", "%5"] {
+            let agent = crate::state::Agent::new(pane.to_string(), "p".to_string());
+            app.state.agents.insert(pane.to_string(), agent);
+        }
+        let visible: Vec<String> = app
+            .state
+            .visible_agents(&app.agent_filter())
+            .iter()
+            .map(|a| a.pane_id.clone())
+            .collect();
+        app.state.selected_pane_id = Some(visible[0].clone());
+
+        app.handle_key(key('M'));
+        assert!(app.show_minimap);
+        app.minimap_columns = 2;
+        app.handle_key(key('l'));
+        assert_eq!(app.state.selected_pane_id.as_ref(), Some(&visible[1]));
+        app.handle_key(key('j'));
+        assert_eq!(app.state.selected_pane_id.as_ref(), Some(&visible[3]));
+        app.handle_key(key('j'));
+        assert_eq!(
+            app.state.selected_pane_id.as_ref(),
+            Some(&visible[4]),
+            "stops at the last cell"
+        );
+        app.handle_key(key_code(KeyCode::Left));
+        app.handle_key(key('k'));
+        assert_eq!(app.state.selected_pane_id.as_ref(), Some(&visible[1]));
+
+        app.handle_key(key('M'));
+        app.handle_key(key('l'));
+        assert_eq!(
+            app.state.selected_pane_id.as_ref(),
+            Some(&visible[1]),
+            "h/l only move in the minimap"
+        );
+    }
+
+    #[test]
+    fn test_freeze_draws_one_frame_then_pauses() {
+        let mut app = test_app();
+        app.handle_key(key('f'));
+        assert_eq!(app.freeze, super::super::FreezeMode::Frozen);
+        assert!(!app.freeze.silent(), "plain freeze still notifies");
+        assert!(app.state.freeze_delta.is_some(), "collecting transitions");
+        assert!(app.wants_render(), "banner frame still owed");
+        app.rendered();
+
+        // State keeps changing underneath, but nothing is drawn
+        app.needs_render = true;
+        assert!(!app.wants_render());
+
+        app.handle_key(key('f'));
+        assert_eq!(app.freeze, super::super::FreezeMode::FrozenSilent);
+        assert!(app.freeze.silent());
+        assert!(app.wants_render());
+        app.rendered();
+        assert!(!app.wants_render());
+
+        app.handle_key(key('f'));
+        assert_eq!(app.freeze, super::super::FreezeMode::Live);
+        assert!(app.wants_render());
+        assert!(app.state.freeze_delta.is_none());
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("while frozen: nothing changed")
+        );
+    }
+
+    #[test]
+    fn test_collapsed_team_skipped_by_navigation() {
+        let mut app = test_app();
+        for (pane, team) in [("%1", Some("alpha")), ("%2", Some("alpha")), ("%3", None)] {
+            let mut agent = crate::state::Agent::new(pane.to_string(), "p".to_string());
+            agent.team_name = team.map(String::from);
+            app.state.agents.insert(pane.to_string(), agent);
+        }
+        app.state.selected_pane_id = Some("%1".to_string());
+
+        app.handle_key(key('z'));
+        assert!(app.state.collapsed_teams.contains("alpha"));
+        app.handle_key(key('j'));
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%3"));
+        app.handle_key(key('j'));
+        assert_eq!(
+            app.state.selected_pane_id.as_deref(),
+            Some("%3"),
+            "wraps past the collapsed team"
+        );
+
+        // Independent collapses too; with everything collapsed the selection stays
+        app.handle_key(key('z'));
+        app.handle_key(key('k'));
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%3"));
+        app.handle_key(key('z'));
+        assert!(!app.state.collapsed_teams.contains("Independent"));
+    }
+
+    #[test]
+    fn test_audit_trail_toggles_and_closes_on_esc() {
+        let mut app = test_app();
+        app.handle_key(key('A'));
+        assert!(app.show_audit_log);
+        app.handle_key(key_code(KeyCode::Esc));
+        assert!(!app.show_audit_log);
+        assert!(!app.should_quit, "Esc closes the overlay before quitting");
+    }
+
+    #[test]
+    fn test_pane_output_pages_back_and_closes_on_esc() {
+        let mut app = test_app();
+        let agent = crate::state::Agent::new("%1".to_string(), "p".to_string());
+        app.state.agents.insert("%1".to_string(), agent);
+        app.state.selected_pane_id = Some("%1".to_string());
+
+        app.handle_key(key('W'));
+        assert!(app.show_pane_output);
+        let output = app.pane_output.as_mut().expect("capture for the selection");
+        assert_eq!(output.pane_id, "%1");
+        output.apply(Ok("line\n".repeat(30)));
+        app.output_page = 10;
+
+        app.handle_key(key_code(KeyCode::PageUp));
+        app.handle_key(key_code(KeyCode::PageUp));
+        app.handle_key(key_code(KeyCode::PageDown));
+        assert_eq!(app.pane_output.as_ref().unwrap().scroll, 10);
+        app.handle_key(key_code(KeyCode::End));
+        assert!(app.pane_output.as_ref().unwrap().following());
+
+        app.handle_key(key_code(KeyCode::Esc));
+        assert!(!app.show_pane_output);
+        assert!(app.pane_output.is_none());
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_bulk_approve_filters_by_tool() {
+        let mut app = test_app();
+        app.handle_key(key('Y'));
+        assert_eq!(app.input_mode, InputMode::Normal, "nothing pending");
+
+        for (pane, tool) in [("w1", "Write"), ("b1", "Bash"), ("w2", "Write")] {
+            let mut agent = crate::state::Agent::new(pane.to_string(), "p".to_string());
+            agent.status = crate::state::Status::Attention(crate::state::AttentionType::Permission);
+            agent.pending_permission_tool = Some(tool.to_string());
+            app.state.agents.insert(pane.to_string(), agent);
+        }
+        app.handle_key(key('Y'));
+        assert_eq!(app.input_mode, InputMode::BulkApprove);
+        assert_eq!(app.bulk_approve_tools[0], ("Write".to_string(), 2));
+
+        // Not tmux panes, so each attempt fails, but only Write is tried
+        app.handle_key(key('1'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.audit.len(), 2);
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("approved 0 Write prompts, 2 failed")
+        );
+    }
+
+    #[test]
+    fn test_note_survives_status_changes_until_cleared() {
+        let mut app = test_app();
+        let event = |name: &str, status: &str| {
+            crate::event::HookEvent::internal(name, status, None, "%1", "p", 0)
+        };
+        let _ = app.state.process_event(event("PreToolUse", "working"));
+        app.handle_key(key('e'));
+        assert_eq!(app.input_mode, InputMode::Input);
+        for c in "flaky  test".chars() {
+            app.handle_key(key(c));
+        }
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.state.agents["%1"].note.as_deref(), Some("flaky test"));
+
+        let _ = app.state.process_event(event("Stop", "attention"));
+        assert_eq!(app.state.agents["%1"].note.as_deref(), Some("flaky test"));
+
+        // Reopening starts from the note; Esc leaves it alone
+        app.handle_key(key('e'));
+        assert_eq!(app.input_buffer.as_str(), "flaky test");
+        app.handle_key(key_code(KeyCode::Esc));
+        assert_eq!(app.input_purpose, InputPurpose::Send);
+        assert!(app.state.agents["%1"].note.is_some());
+
+        app.handle_key(key('e'));
+        app.handle_key(KeyEvent::new(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        app.handle_key(key_code(KeyCode::Enter));
+        assert!(app.state.agents["%1"].note.is_none());
+    }
+
+    #[test]
+    fn test_event_log_view_filters_and_scrolls() {
+        let mut app = test_app();
+        for (name, pane) in [("PreToolUse", "%1"), ("Stop", "%1"), ("Stop", "%2")] {
+            let event = crate::event::HookEvent::internal(name, "working", None, pane, "p", 0);
+            let _ = app.state.process_event(event);
+        }
+        app.handle_key(key('V'));
+        assert_eq!(app.input_mode, InputMode::EventLog);
+
+        // j stays in the view and stops at the last match
+        for _ in 0..5 {
+            app.handle_key(key('j'));
+        }
+        assert_eq!(app.event_log.scroll, 2);
+
+        app.handle_key(key('/'));
+        for c in "event:stop".chars() {
+            app.handle_key(key(c));
+        }
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.event_log.scroll, 0);
+        assert_eq!(app.state.filter_event_log(&app.event_log.query).len(), 2);
+        app.handle_key(key('G'));
+        assert_eq!(app.event_log.scroll, 1);
+
+        app.handle_key(key_code(KeyCode::Esc));
+        assert_eq!(app.input_mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn test_palette_filters_runs_and_greys_out() {
+        let mut app = test_app();
+        app.handle_key(key(':'));
+        assert_eq!(app.input_mode, InputMode::Palette);
+        for c in "audit".chars() {
+            app.handle_key(key(c));
+        }
+        let entries = app.palette_entries();
+        assert_eq!(entries[0].action, Action::AuditLog);
+        assert_eq!(entries[0].keys, "A");
+        app.handle_key(key_code(KeyCode::Enter));
+        assert!(app.show_audit_log);
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        // Copy needs a selected agent: listed, but Enter only explains
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(app.input_mode, InputMode::Palette);
+        assert!(app.palette_query.is_empty(), "reopens with a fresh query");
+        for c in "copy pane".chars() {
+            app.handle_key(key(c));
+        }
+        assert!(!app.palette_entries()[0].available);
+        app.handle_key(key_code(KeyCode::Enter));
+        assert_eq!(app.input_mode, InputMode::Palette);
+        assert!(app.status_message.is_some());
+
+        app.handle_key(key_code(KeyCode::Esc));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app.should_quit);
+    }
+
+    #[test]
+    fn test_auto_accept_queues_only_read_only_prompts() {
+        let mut app = test_app();
+        let permission = |tool: &str| {
+            let mut event: crate::event::HookEvent = serde_json::from_value(serde_json::json!({
+                "event": "PermissionRequest",
+                "status": "attention",
+                "attention_type": "permission",
+                "pane_id": "%3",
+                "project": "p",
+                "timestamp": 1,
+            }))
+            .unwrap();
+            event.tool_name = Some(tool.to_string());
+            crate::event::Event::Hook(Box::new(event))
+        };
+
+        app.handle_event(permission("Read"));
+        assert!(app.auto_accept_due.is_empty(), "off by default");
+
+        app.handle_key(key('p'));
+        assert_eq!(app.auto_accept, AutoAccept::ReadOnly);
+        app.handle_event(permission("Bash"));
+        assert!(app.auto_accept_due.is_empty());
+        app.handle_event(permission("Grep"));
+        assert_eq!(app.auto_accept_due.len(), 1);
+
+        // Answered by hand before it was due: dropped, not approved twice
+        app.state.agents.get_mut("%3").unwrap().status = crate::state::Status::Working;
+        app.auto_accept_due[0].2 = std::time::Instant::now();
+        app.status_message = None;
+        app.tick();
+        assert!(app.auto_accept_due.is_empty());
+        assert!(app.status_message.is_none());
+
+        app.handle_key(key('p'));
+        assert_eq!(app.auto_accept, AutoAccept::Off);
+    }
+
+    #[test]
+    fn test_do_not_disturb_cycles_over_schedule() {
+        let mut app = test_app();
+        assert_eq!(app.do_not_disturb, DoNotDisturb::Schedule);
+        assert!(!app.do_not_disturb.silences(false));
+        assert!(app.do_not_disturb.silences(true));
+
+        app.handle_key(key('n'));
+        assert_eq!(app.do_not_disturb, DoNotDisturb::On);
+        assert!(app.do_not_disturb.silences(false));
+        assert_eq!(app.do_not_disturb.label(false), " [DND]");
+
+        app.handle_key(key('n'));
+        assert_eq!(app.do_not_disturb, DoNotDisturb::Off);
+        assert!(!app.do_not_disturb.silences(true), "overrides quiet hours");
+
+        app.handle_key(key('n'));
+        assert_eq!(app.do_not_disturb, DoNotDisturb::Schedule);
+        assert_eq!(app.do_not_disturb.label(false), "");
+        assert_eq!(app.do_not_disturb.label(true), " [QUIET HOURS]");
+    }
+
+    #[test]
+    fn test_focus_follows_attention_waits_for_quiet_keyboard() {
+        let mut app = test_app();
+        let hook = |pane: &str, event: &str, status: &str, attention: Option<&str>| {
+            let event: crate::event::HookEvent = serde_json::from_value(serde_json::json!({
+                "event": event,
+                "status": status,
+                "attention_type": attention,
+                "pane_id": pane,
+                "project": "p",
+                "timestamp": 1,
+            }))
+            .unwrap();
+            crate::event::Event::Hook(Box::new(event))
+        };
+        app.handle_event(hook("%1", "PreToolUse", "working", None));
+        app.handle_event(hook("%2", "PreToolUse", "working", None));
+        app.state.selected_pane_id = Some("%1".to_string());
+        let ask = || hook("%2", "PermissionRequest", "attention", Some("permission"));
+        let quiet = std::time::Instant::now() - crate::app::FOCUS_QUIET;
+
+        // Off by default
+        app.last_interaction = quiet;
+        app.handle_event(ask());
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%1"));
+
+        // On, but a key was just pressed
+        app.focus_follows_attention = true;
+        app.handle_event(hook("%2", "PostToolUse", "working", None));
+        app.handle_event(crate::event::Event::Key(key_code(KeyCode::Null)));
+        app.handle_event(ask());
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%1"));
+
+        // On and idle: selection follows
+        app.handle_event(hook("%2", "PostToolUse", "working", None));
+        app.last_interaction = quiet;
+        app.handle_event(ask());
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%2"));
+    }
+
+    #[test]
+    fn test_copy_menu_opens_for_selection_and_cancels() {
+        let mut app = test_app();
+        app.handle_key(key('y'));
+        assert_eq!(app.input_mode, InputMode::Normal, "nothing selected");
+
+        app.state.agents.insert(
+            "%3".to_string(),
+            crate::state::Agent::new("%3".to_string(), "p".to_string()),
+        );
+        app.state.selected_pane_id = Some("%3".to_string());
+        app.status_message = None;
+
+        app.handle_key(key('y'));
+        assert_eq!(app.input_mode, InputMode::CopyMenu);
+        app.handle_key(key_code(KeyCode::Esc));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app.should_quit, "Esc only closes the menu");
+
+        // Keys without a detail behind them close the menu without copying
+        app.handle_key(key('y'));
+        app.handle_key(key('t'));
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(app.status_message.is_none());
+    }
+
+    #[test]
+    fn test_read_only_blocks_spawn() {
+        let mut app = test_app();
+        app.read_only = true;
+
+        app.handle_key(key('s'));
+        assert_eq!(
+            app.input_mode,
+            InputMode::Normal,
+            "spawn disabled when mirroring"
+        );
+
+        // Views still work
+        app.handle_key(key('i'));
+        assert!(app.show_agent_detail);
+    }
+
+    #[test]
+    fn test_control_calls_respect_read_only() {
+        use crate::event::rpc::{codes, RpcCall};
+        use crate::event::Event;
+
+        let mut app = test_app();
+        app.state.agents.insert(
+            "%3".to_string(),
+            crate::state::Agent::new("%3".to_string(), "p".to_string()),
+        );
+        let call = |app: &mut App, call: RpcCall| {
+            let (reply, mut rx) = tokio::sync::oneshot::channel();
+            app.handle_event(Event::Rpc { call, reply });
+            rx.try_recv().unwrap()
+        };
+
+        let agents = call(&mut app, RpcCall::ListAgents).unwrap();
+        assert_eq!(agents[0]["pane_id"], "%3");
+        let err = call(
+            &mut app,
+            RpcCall::Approve {
+                pane_id: "%3".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.code, codes::OPERATION_FAILED);
+
+        app.read_only = true;
+        let err = call(
+            &mut app,
+            RpcCall::SendInput {
+                pane_id: "%3".to_string(),
+                text: "hi".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert_eq!(err.code, codes::READ_ONLY);
+        assert!(call(&mut app, RpcCall::ListAgents).is_ok());
+    }
+
     #[test]
     fn test_stats_viewer_toggle() {
         let mut app = test_app();
@@ -876,5 +2182,57 @@ mod tests {
 
         app.handle_key(key('3'));
         assert_eq!(app.stats_viewer.active_tab, 2, "'3' should jump to tab 3");
+
+        app.handle_key(key('5'));
+        assert_eq!(app.stats_viewer.active_tab, 4, "'5' should jump to tab 5");
+
+        app.handle_key(key_code(KeyCode::Tab));
+        assert_eq!(app.stats_viewer.active_tab, 0, "Tab should wrap around");
+    }
+
+    #[test]
+    fn test_input_dialog_edits_at_cursor() {
+        let mut app = test_app();
+        app.input_mode = InputMode::Input;
+        for c in "ab".chars() {
+            app.handle_key(key(c));
+        }
+        app.handle_key(key_code(KeyCode::Left));
+        app.handle_key(key('X'));
+        app.handle_key(KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        assert_eq!(app.input_buffer.as_str(), "aX\nb");
+        assert_eq!(app.input_mode, InputMode::Input, "Alt+Enter is a new line");
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        app.handle_key(key_code(KeyCode::End));
+        app.handle_key(key('c'));
+        assert_eq!(app.input_buffer.as_str(), "aXbc");
+    }
+
+    #[test]
+    fn test_playback_scrubs_within_recording() {
+        let mut app = test_app();
+        let frames = (0..15)
+            .map(|timestamp| crate::state::BoardFrame {
+                timestamp,
+                agents: Vec::new(),
+            })
+            .collect();
+        app.playback = Some(crate::app::PlaybackState::new(frames));
+        app.input_mode = InputMode::Playback;
+        let position = |app: &App| app.playback.as_ref().unwrap().position;
+
+        app.handle_key(key_code(KeyCode::Left));
+        assert_eq!(position(&app), 0, "cannot scrub before the first frame");
+        app.handle_key(key_code(KeyCode::Right));
+        assert_eq!(position(&app), 1);
+        app.handle_key(key_code(KeyCode::PageDown));
+        app.handle_key(key_code(KeyCode::PageDown));
+        assert_eq!(position(&app), 14, "clamped to the last frame");
+        app.handle_key(key('g'));
+        assert_eq!(position(&app), 0);
+
+        app.handle_key(key('q'));
+        assert!(app.should_quit, "closing playback quits");
     }
 }
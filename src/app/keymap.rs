@@ -0,0 +1,509 @@
+//! Normal-mode key bindings, remappable through `[keymap]`
+//!
+//! Each action has default keys (the layout documented in `keyboard.rs`).
+//! A `[keymap]` entry replaces the defaults for that action:
+//!
+//! ```toml
+//! [keymap]
+//! quit = "Q"
+//! next_agent = ["j", "down"]
+//! spawn = []              # unbind
+//! ```
+//!
+//! Keys are a single character or a name (`enter`, `tab`, `space`, `up`, ...).
+//! `Esc`, `1`-`9`, `Ctrl+C` and `Ctrl+P` keep their fixed meanings and cannot
+//! be bound. A key claimed by two actions goes to the one configured
+//! explicitly (the alphabetically first, if both are) and the other loses it
+//! with a warning, so a bad config never stops the board from starting.
+
+use crate::config::KeyBinding;
+use crossterm::event::KeyCode;
+use std::collections::{BTreeMap, HashMap};
+
+/// Something a Normal-mode key can do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    NextAgent,
+    PrevAgent,
+    NextAttention,
+    Jump,
+    Search,
+    Spawn,
+    AttentionFilter,
+    Density,
+    Freeze,
+    CollapseTeam,
+    GroupBy,
+    AutoAccept,
+    DoNotDisturb,
+    Mark,
+    Pin,
+    Sweep,
+    ApprovePending,
+    Input,
+    Note,
+    Workspaces,
+    Copy,
+    OpenDir,
+    Minimap,
+    TaskBoard,
+    AgentDetail,
+    Transcript,
+    PaneOutput,
+    PlanViewer,
+    Stats,
+    History,
+    Debug,
+    Insights,
+    Checkpoints,
+    AuditLog,
+    Sources,
+    Failures,
+    EventLog,
+    Help,
+    Palette,
+}
+
+/// Help modal grouping
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    Views,
+    Navigation,
+    Actions,
+    Application,
+}
+
+impl Action {
+    /// Every action, in help order
+    pub const ALL: [Action; 40] = [
+        Self::Minimap,
+        Self::TaskBoard,
+        Self::AgentDetail,
+        Self::Transcript,
+        Self::PaneOutput,
+        Self::PlanViewer,
+        Self::Stats,
+        Self::History,
+        Self::Debug,
+        Self::Insights,
+        Self::Checkpoints,
+        Self::AuditLog,
+        Self::Sources,
+        Self::Failures,
+        Self::EventLog,
+        Self::Help,
+        Self::NextAgent,
+        Self::PrevAgent,
+        Self::NextAttention,
+        Self::Jump,
+        Self::Search,
+        Self::Spawn,
+        Self::AttentionFilter,
+        Self::Density,
+        Self::Freeze,
+        Self::CollapseTeam,
+        Self::GroupBy,
+        Self::AutoAccept,
+        Self::DoNotDisturb,
+        Self::Mark,
+        Self::Pin,
+        Self::Sweep,
+        Self::ApprovePending,
+        Self::Input,
+        Self::Note,
+        Self::Workspaces,
+        Self::Copy,
+        Self::OpenDir,
+        Self::Palette,
+        Self::Quit,
+    ];
+
+    /// Name used as the `[keymap]` key
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Quit => "quit",
+            Self::NextAgent => "next_agent",
+            Self::PrevAgent => "prev_agent",
+            Self::NextAttention => "next_attention",
+            Self::Jump => "jump",
+            Self::Search => "search",
+            Self::Spawn => "spawn",
+            Self::AttentionFilter => "attention_filter",
+            Self::Density => "density",
+            Self::Freeze => "freeze",
+            Self::CollapseTeam => "collapse_team",
+            Self::GroupBy => "group_by",
+            Self::AutoAccept => "auto_accept",
+            Self::DoNotDisturb => "do_not_disturb",
+            Self::Mark => "mark",
+            Self::Pin => "pin",
+            Self::Sweep => "sweep",
+            Self::ApprovePending => "approve_pending",
+            Self::Input => "input",
+            Self::Note => "note",
+            Self::Workspaces => "workspaces",
+            Self::Copy => "copy",
+            Self::OpenDir => "open_dir",
+            Self::Minimap => "minimap",
+            Self::TaskBoard => "task_board",
+            Self::AgentDetail => "agent_detail",
+            Self::Transcript => "transcript",
+            Self::PaneOutput => "pane_output",
+            Self::PlanViewer => "plan_viewer",
+            Self::Stats => "stats",
+            Self::History => "history",
+            Self::Debug => "debug",
+            Self::Insights => "insights",
+            Self::Checkpoints => "checkpoints",
+            Self::AuditLog => "audit_log",
+            Self::Sources => "sources",
+            Self::Failures => "errors",
+            Self::EventLog => "event_log",
+            Self::Help => "help",
+            Self::Palette => "palette",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    /// One-line description for the help modal
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Quit => "Quit (Esc also quits once overlays are closed)",
+            Self::NextAgent => "Next agent",
+            Self::PrevAgent => "Previous agent",
+            Self::NextAttention => "Next agent needing attention",
+            Self::Jump => "Jump to agent's terminal",
+            Self::Search => "Fuzzy search agents (ranked)",
+            Self::Spawn => "Spawn agent",
+            Self::AttentionFilter => "Attention-only filter (+ search)",
+            Self::Density => "Compact cards (one line each)",
+            Self::Freeze => "Freeze display (again: also silence alerts)",
+            Self::CollapseTeam => "Collapse/expand selected agent's team",
+            Self::GroupBy => "Group cards by team → model → permission mode",
+            Self::AutoAccept => "Auto-accept read-only tools (on/off)",
+            Self::DoNotDisturb => "Do not disturb: schedule → on → off (quiet hours)",
+            Self::Mark => "Mark agent for bulk input",
+            Self::Pin => "Pin agent to the top of its team",
+            Self::Sweep => "Sweep gone and long-idle agents off the board",
+            Self::ApprovePending => "Approve pending permission prompts (by tool)",
+            Self::Input => "Send input to marked/selected agents",
+            Self::Note => "Note on the selected agent (empty clears it)",
+            Self::Workspaces => "Load or save a workspace (saved layout)",
+            Self::Copy => "Copy pane id / transcript / cwd",
+            Self::OpenDir => "Open agent's cwd in editor ([ui] open_command)",
+            Self::Minimap => "Minimap: one cell per agent (cards ↔ grid)",
+            Self::TaskBoard => "Task board",
+            Self::AgentDetail => "Agent detail (tool history)",
+            Self::Transcript => "Transcript tail (selected agent)",
+            Self::PaneOutput => "Pane output with scrollback (selected agent)",
+            Self::PlanViewer => "Plan viewer",
+            Self::Stats => "Stats dashboard",
+            Self::History => "History log",
+            Self::Debug => "Debug viewer",
+            Self::Insights => "Insights report",
+            Self::Checkpoints => "Checkpoints (sprite agent)",
+            Self::AuditLog => "Audit trail (your approvals/input)",
+            Self::Sources => "Connections (events per socket/sprite)",
+            Self::Failures => "Recent errors, grouped across agents",
+            Self::EventLog => "Event log, full screen (filter by pane/event/source)",
+            Self::Help => "This help",
+            Self::Palette => "Command palette (also Ctrl+P)",
+        }
+    }
+
+    pub fn section(self) -> Section {
+        match self {
+            Self::Minimap
+            | Self::TaskBoard
+            | Self::AgentDetail
+            | Self::Transcript
+            | Self::PaneOutput
+            | Self::PlanViewer
+            | Self::Stats
+            | Self::History
+            | Self::Debug
+            | Self::Insights
+            | Self::Checkpoints
+            | Self::AuditLog
+            | Self::Sources
+            | Self::Failures
+            | Self::EventLog
+            | Self::Help => Section::Views,
+            Self::NextAgent | Self::PrevAgent | Self::NextAttention | Self::Jump | Self::Search => {
+                Section::Navigation
+            }
+            Self::Palette | Self::Quit => Section::Application,
+            _ => Section::Actions,
+        }
+    }
+
+    fn default_keys(self) -> Vec<KeyCode> {
+        use KeyCode::Char;
+        match self {
+            Self::Quit => vec![Char('q')],
+            Self::NextAgent => vec![Char('j'), KeyCode::Down],
+            Self::PrevAgent => vec![Char('k'), KeyCode::Up],
+            Self::NextAttention => vec![KeyCode::Tab],
+            Self::Jump => vec![KeyCode::Enter],
+            Self::Search => vec![Char('/')],
+            Self::Spawn => vec![Char('s')],
+            Self::AttentionFilter => vec![Char('a')],
+            Self::Density => vec![Char('v')],
+            Self::Freeze => vec![Char('f')],
+            Self::CollapseTeam => vec![Char('z')],
+            Self::GroupBy => vec![Char('g')],
+            Self::AutoAccept => vec![Char('p')],
+            Self::DoNotDisturb => vec![Char('n')],
+            Self::Mark => vec![Char(' ')],
+            Self::Pin => vec![Char('*')],
+            Self::Sweep => vec![Char('X')],
+            Self::ApprovePending => vec![Char('Y')],
+            Self::Input => vec![Char('c')],
+            Self::Note => vec![Char('e')],
+            Self::Workspaces => vec![Char('w')],
+            Self::Copy => vec![Char('y')],
+            Self::OpenDir => vec![Char('o')],
+            Self::Minimap => vec![Char('M')],
+            Self::TaskBoard => vec![Char('T')],
+            Self::AgentDetail => vec![Char('i')],
+            Self::Transcript => vec![Char('O')],
+            Self::PaneOutput => vec![Char('W')],
+            Self::PlanViewer => vec![Char('P')],
+            Self::Stats => vec![Char('S')],
+            Self::History => vec![Char('L')],
+            Self::Debug => vec![Char('D')],
+            Self::Insights => vec![Char('I')],
+            Self::Checkpoints => vec![Char('C')],
+            Self::AuditLog => vec![Char('A')],
+            Self::Sources => vec![Char('N')],
+            Self::Failures => vec![Char('E')],
+            Self::EventLog => vec![Char('V')],
+            Self::Help => vec![Char('?'), Char('H')],
+            Self::Palette => vec![Char(':')],
+        }
+    }
+}
+
+/// Parse a `[keymap]` key: a single character or a key name
+pub fn parse_key(spec: &str) -> Option<KeyCode> {
+    let key = match spec.to_ascii_lowercase().as_str() {
+        "enter" => KeyCode::Enter,
+        "tab" => KeyCode::Tab,
+        "backtab" | "shift+tab" => KeyCode::BackTab,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "esc" => KeyCode::Esc,
+        _ => {
+            let mut chars = spec.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => KeyCode::Char(c),
+                _ => return None,
+            }
+        }
+    };
+    Some(key)
+}
+
+/// Keys with fixed meanings in Normal mode
+fn is_reserved(key: KeyCode) -> bool {
+    matches!(key, KeyCode::Esc | KeyCode::Char('1'..='9'))
+}
+
+/// Display form of a key for the help modal
+pub fn key_label(key: KeyCode) -> String {
+    match key {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::BackTab => "Shift+Tab".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Resolved Normal-mode bindings
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    /// Keys per action, in `Action::ALL` order
+    bindings: Vec<(Action, Vec<KeyCode>)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_config(&BTreeMap::new())
+    }
+}
+
+impl Keymap {
+    /// Apply `[keymap]` overrides on top of the default bindings
+    ///
+    /// Unknown actions, unparseable or reserved keys, and conflicts are
+    /// logged and dropped.
+    pub fn from_config(config: &BTreeMap<String, KeyBinding>) -> Self {
+        let (keymap, problems) = Self::from_config_checked(config);
+        for problem in problems {
+            tracing::warn!("{problem}");
+        }
+        keymap
+    }
+
+    /// Like [`Self::from_config`], returning what was dropped instead of
+    /// logging it (for `rehoboam check`)
+    pub fn from_config_checked(config: &BTreeMap<String, KeyBinding>) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        let mut bindings: Vec<(Action, Vec<KeyCode>)> = Action::ALL
+            .into_iter()
+            .map(|action| (action, action.default_keys()))
+            .collect();
+        let mut configured = Vec::new();
+        for (name, binding) in config {
+            let Some(action) = Action::from_name(name) else {
+                problems.push(format!("Unknown keymap action {name:?}, ignoring"));
+                continue;
+            };
+            let mut keys = Vec::new();
+            for spec in binding.specs() {
+                match parse_key(spec) {
+                    Some(key) if is_reserved(key) => {
+                        problems.push(format!("Key {spec:?} for {name} is reserved, ignoring"));
+                    }
+                    Some(key) if !keys.contains(&key) => keys.push(key),
+                    Some(_) => {}
+                    None => problems.push(format!("Invalid key {spec:?} for {name}, ignoring")),
+                }
+            }
+            if let Some(slot) = bindings.iter_mut().find(|(a, _)| *a == action) {
+                slot.1 = keys;
+            }
+            configured.push(action);
+        }
+
+        // Explicit bindings claim their keys first, then the defaults
+        let order: Vec<Action> = configured
+            .iter()
+            .copied()
+            .chain(Action::ALL.into_iter().filter(|a| !configured.contains(a)))
+            .collect();
+        let mut owner: HashMap<KeyCode, Action> = HashMap::new();
+        for action in order {
+            let Some((_, keys)) = bindings.iter_mut().find(|(a, _)| *a == action) else {
+                continue;
+            };
+            keys.retain(|key| match owner.get(key) {
+                Some(winner) => {
+                    problems.push(format!(
+                        "Key {} is bound to both {} and {}; keeping {}",
+                        key_label(*key),
+                        winner.name(),
+                        action.name(),
+                        winner.name()
+                    ));
+                    false
+                }
+                None => {
+                    owner.insert(*key, action);
+                    true
+                }
+            });
+        }
+        (Self { bindings }, problems)
+    }
+
+    /// Action bound to `key`, if any
+    pub fn action(&self, key: KeyCode) -> Option<Action> {
+        self.bindings
+            .iter()
+            .find(|(_, keys)| keys.contains(&key))
+            .map(|(action, _)| *action)
+    }
+
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map_or(&[], |(_, keys)| keys.as_slice())
+    }
+
+    /// Comma-separated keys for `action` ("-" when unbound)
+    pub fn label(&self, action: Action) -> String {
+        let keys = self.keys(action);
+        if keys.is_empty() {
+            return "-".to_string();
+        }
+        keys.iter()
+            .map(|key| key_label(*key))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keymap(entries: &[(&str, &[&str])]) -> Keymap {
+        let config = entries
+            .iter()
+            .map(|(name, keys)| {
+                let keys = keys.iter().map(|k| k.to_string()).collect();
+                (name.to_string(), KeyBinding::Many(keys))
+            })
+            .collect();
+        Keymap::from_config(&config)
+    }
+
+    #[test]
+    fn test_defaults_match_documented_layout() {
+        let map = Keymap::default();
+        assert_eq!(map.action(KeyCode::Char('q')), Some(Action::Quit));
+        assert_eq!(map.action(KeyCode::Down), Some(Action::NextAgent));
+        assert_eq!(map.action(KeyCode::Char('H')), Some(Action::Help));
+        assert_eq!(map.action(KeyCode::Char(' ')), Some(Action::Mark));
+        assert_eq!(map.action(KeyCode::Esc), None);
+        assert_eq!(map.label(Action::NextAgent), "j, Down");
+        for action in Action::ALL {
+            assert_eq!(Action::from_name(action.name()), Some(action));
+            assert!(!map.keys(action).is_empty(), "{} unbound", action.name());
+        }
+    }
+
+    #[test]
+    fn test_overrides_replace_defaults_and_conflicts_resolve() {
+        let map = keymap(&[
+            ("quit", &["Q"]),
+            // Steals `s` from spawn, which is left unbound
+            ("search", &["s", "enter", "nope", "1"]),
+            ("spawn", &[]),
+            ("launch_missiles", &["x"]),
+        ]);
+        assert_eq!(map.action(KeyCode::Char('Q')), Some(Action::Quit));
+        assert_eq!(map.action(KeyCode::Char('q')), None);
+        assert_eq!(map.action(KeyCode::Char('s')), Some(Action::Search));
+        // Explicit binding wins over jump's default Enter
+        assert_eq!(map.action(KeyCode::Enter), Some(Action::Search));
+        assert!(map.keys(Action::Jump).is_empty());
+        assert_eq!(map.label(Action::Jump), "-");
+        // Reserved and unparseable keys are dropped
+        assert_eq!(map.label(Action::Search), "s, Enter");
+        assert!(map.keys(Action::Spawn).is_empty());
+        assert_eq!(map.action(KeyCode::Char('x')), None);
+
+        // Between two explicit bindings the first listed keeps the key
+        let map = keymap(&[("density", &["x"]), ("freeze", &["x"])]);
+        assert_eq!(map.action(KeyCode::Char('x')), Some(Action::Density));
+        assert!(map.keys(Action::Freeze).is_empty());
+    }
+}
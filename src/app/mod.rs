@@ -12,21 +12,74 @@
 //! - [`App::handle_event()`] - Event processing
 //! - [`App::tick()`] - Timer updates
 //! - [`App::rendered()`] - Mark frame as rendered
+//! - [`App::wants_render()`] - Whether a frame is due (honors display freeze)
 //!
 //! Direct field access is internal API and may change between versions.
 
+mod audit;
+mod focus;
 mod keyboard;
+pub mod keymap;
 mod navigation;
+mod operations;
+mod palette;
+mod pane_output;
 pub mod spawn;
+mod spawn_batch;
+mod spawn_history;
+mod text_input;
+pub mod workspace;
 
+pub use audit::{AuditAction, AuditLog};
+pub use keymap::Keymap;
+pub use operations::copy_choices;
+pub use palette::PaletteEntry;
+pub use pane_output::PaneOutput;
 pub use spawn::SpawnState;
+pub use text_input::TextInput;
 
-use crate::config::{HealthConfig, TimeoutConfig};
+use crate::config::{
+    Density, IdleTimeoutRules, QuietHours, RehoboamConfig, TerminalConfig, TimeFormat,
+};
+use crate::event::ingest::DroppedEvents;
+use crate::event::replay::EventRecorder;
+use crate::event::rpc::{self, RpcCall, RpcError};
 use crate::event::{Event, EventSource, SpriteStatusType};
 use crate::health::HealthChecker;
 use crate::plans::PlanViewerState;
-use crate::state::AppState;
-use sprites::SpritesClient;
+use crate::sprite::api::SpriteApi;
+use crate::sprite::checkpoints::{self, CheckpointRecord};
+use crate::state::{
+    AgentFilter, AppState, AttentionType, BoardFrame, BoardPersister, BoardRecorder, EventOutcome,
+    FreezeDelta, NotificationThrottle, Pricing, Status, TranscriptTail, TurnCsvWriter,
+};
+use crate::tmux::TmuxController;
+use crate::webhook::Webhooks;
+use std::time::Instant;
+use tokio::sync::mpsc;
+
+/// Seconds a footer status message stays visible
+const STATUS_MESSAGE_SECS: u64 = 3;
+
+/// Wait before answering a permission prompt automatically: the hook fires
+/// before Claude Code draws the menu, so an immediate keypress could land in
+/// the prompt instead
+const AUTO_ACCEPT_DELAY: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Keyboard silence before focus-follows-attention may move the selection
+const FOCUS_QUIET: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// What the input dialog's text is for
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputPurpose {
+    /// Sent to the marked or selected agents (`c`)
+    #[default]
+    Send,
+    /// Note on this agent (`e`)
+    Note(String),
+    /// Name to save the current layout under (`w`, then `s`)
+    Workspace,
+}
 
 /// Input mode for the application
 #[derive(Debug, Clone, PartialEq, Default)]
@@ -48,12 +101,150 @@ pub enum InputMode {
     DebugViewer,
     /// Insights report viewer mode
     InsightsViewer,
+    /// Input dialog mode (text sent to the marked or selected agents)
+    Input,
+    /// Sprite checkpoint timeline mode
+    CheckpointViewer,
+    /// Copy menu for the selected agent's details
+    CopyMenu,
+    /// Pick which pending permission prompts to approve, by tool (`Y`)
+    BulkApprove,
+    /// Load a saved workspace or save the current layout as one (`w`)
+    WorkspaceMenu,
+    /// Command palette (fuzzy list of every action)
+    Palette,
+    /// Full-screen event log with a filter (`V`)
+    EventLog,
+    /// y/N confirmation before sweeping gone and idle agents (`X`)
+    SweepConfirm,
+    /// y/N confirmation before quitting with agents still busy (`q`)
+    QuitConfirm,
+    /// Scrubbing a board recording (`--playback`)
+    Playback,
+}
+
+/// Display freeze for demos (`f` cycles Live → Frozen → FrozenSilent)
+///
+/// Hook events keep updating state in every mode, so unfreezing shows the
+/// current board; only drawing (and, for `FrozenSilent`, desktop alerts)
+/// pauses.
+/// Going live again summarises what changed (see [`FreezeDelta`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FreezeMode {
+    #[default]
+    Live,
+    /// Screen static, desktop notifications still sent
+    Frozen,
+    /// Screen static, desktop notifications suppressed too
+    FrozenSilent,
+}
+
+impl FreezeMode {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Live => Self::Frozen,
+            Self::Frozen => Self::FrozenSilent,
+            Self::FrozenSilent => Self::Live,
+        }
+    }
+
+    /// True while drawing is paused
+    pub fn paused(self) -> bool {
+        self != Self::Live
+    }
+
+    /// True when desktop notifications are dropped too
+    pub fn silent(self) -> bool {
+        self == Self::FrozenSilent
+    }
+
+    /// Header tag, empty when live
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Live => "",
+            Self::Frozen => " [FROZEN]",
+            Self::FrozenSilent => " [FROZEN, silent]",
+        }
+    }
+}
+
+/// Desktop notifications vs `[notify] quiet_hours` (`n` cycles)
+///
+/// Attention always shows on the board; this only gates desktop alerts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DoNotDisturb {
+    /// Silent during quiet hours only
+    #[default]
+    Schedule,
+    /// Silent until cycled back, whatever the schedule
+    On,
+    /// Notify even during quiet hours
+    Off,
+}
+
+impl DoNotDisturb {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Schedule => Self::On,
+            Self::On => Self::Off,
+            Self::Off => Self::Schedule,
+        }
+    }
+
+    /// Whether notifications are silenced, given whether it is quiet hours
+    pub fn silences(self, quiet_hours: bool) -> bool {
+        match self {
+            Self::Schedule => quiet_hours,
+            Self::On => true,
+            Self::Off => false,
+        }
+    }
+
+    /// Header tag, empty when following the schedule outside quiet hours
+    pub fn label(self, quiet_hours: bool) -> &'static str {
+        match self {
+            Self::Schedule if quiet_hours => " [QUIET HOURS]",
+            Self::Schedule => "",
+            Self::On => " [DND]",
+            Self::Off => " [NOTIFY]",
+        }
+    }
+}
+
+/// Which permission prompts the TUI answers by itself (`p` cycles)
+///
+/// There is deliberately no "approve everything" scope: anything beyond
+/// tools that cannot change the machine belongs in Claude Code's own
+/// `permissions.allow` rules (see ARCHITECTURE.md).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoAccept {
+    #[default]
+    Off,
+    /// Approve `operations::READ_ONLY_TOOLS`, leave the rest to the user
+    ReadOnly,
+}
+
+impl AutoAccept {
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::ReadOnly,
+            Self::ReadOnly => Self::Off,
+        }
+    }
+
+    /// Header tag, empty when off
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Off => "",
+            Self::ReadOnly => " [AUTO: read-only]",
+        }
+    }
 }
 
 /// State for the stats dashboard overlay
 #[derive(Debug, Default)]
 pub struct StatsViewerState {
-    /// Active tab: 0=Overview, 1=Models, 2=Activity, 3=Quality
+    /// Active tab: 0=Overview, 1=Models, 2=Activity, 3=Quality, 4=Latency
     pub active_tab: usize,
     /// Scroll offset within the current tab
     pub scroll_offset: u16,
@@ -68,6 +259,17 @@ pub struct HistoryViewerState {
     pub scroll_offset: usize,
 }
 
+/// State for the full-screen event log
+#[derive(Debug, Default)]
+pub struct EventLogState {
+    /// Filter query (see `state/event_log.rs`)
+    pub query: String,
+    /// Typing into the query rather than scrolling
+    pub editing: bool,
+    /// Index of the top visible entry among the matches
+    pub scroll: usize,
+}
+
 /// State for the debug log viewer overlay
 #[derive(Debug, Default)]
 pub struct DebugViewerState {
@@ -92,6 +294,57 @@ pub struct InsightsViewerState {
     pub scroll_offset: u16,
 }
 
+/// State for the sprite checkpoint timeline overlay
+#[derive(Debug, Default)]
+pub struct CheckpointViewerState {
+    /// Sprite whose timeline is shown
+    pub sprite_id: String,
+    /// Checkpoints, newest first
+    pub records: Vec<CheckpointRecord>,
+    /// Currently selected checkpoint index
+    pub selected_index: usize,
+    /// Waiting for the timeline (or a restore) to come back
+    pub loading: bool,
+    /// Last fetch error
+    pub error: Option<String>,
+    /// Restore of the selected checkpoint awaiting y/N
+    pub confirm_restore: bool,
+}
+
+/// Board recording being scrubbed (`--playback`)
+#[derive(Debug, Default)]
+pub struct PlaybackState {
+    /// Recorded frames, oldest first
+    pub frames: Vec<BoardFrame>,
+    /// Index of the frame on screen
+    pub position: usize,
+}
+
+impl PlaybackState {
+    pub fn new(frames: Vec<BoardFrame>) -> Self {
+        Self {
+            frames,
+            position: 0,
+        }
+    }
+
+    /// Frame on screen (None for an empty recording)
+    pub fn current(&self) -> Option<&BoardFrame> {
+        self.frames.get(self.position)
+    }
+
+    /// Move `delta` frames, clamped to the recording
+    pub fn step(&mut self, delta: isize) {
+        let last = self.frames.len().saturating_sub(1);
+        self.position = self.position.saturating_add_signed(delta).min(last);
+    }
+
+    /// Jump to the last frame
+    pub fn end(&mut self) {
+        self.position = self.frames.len().saturating_sub(1);
+    }
+}
+
 /// Application state and logic
 pub struct App {
     pub state: AppState,
@@ -105,15 +358,92 @@ pub struct App {
     /// Spawn dialog state
     pub spawn_state: SpawnState,
     /// Sprites API client (None if sprites not enabled)
-    pub sprites_client: Option<SpritesClient>,
+    pub sprites_client: Option<SpriteApi>,
+    /// Minimap grid in place of the team view (`M`)
+    pub show_minimap: bool,
+    /// Cells per minimap row at the last render (row step for `j`/`k`)
+    pub minimap_columns: usize,
     /// Show task board overlay
     pub show_task_board: bool,
+    /// Show audit trail overlay (`A`)
+    pub show_audit_log: bool,
+    /// Show events delivered per socket and sprite (`N`)
+    pub show_sources: bool,
+    /// Show unresolved tool failures grouped by error (`E`)
+    pub show_failures: bool,
+    /// Agents the pending sweep confirmation would remove
+    pub sweep_pending: Vec<String>,
+    /// Tools offered by the bulk approve menu, with their waiting prompts
+    pub bulk_approve_tools: Vec<(String, usize)>,
+    /// Saved layouts by name (`w`, `--workspace`)
+    pub workspaces: workspace::Workspaces,
+    /// File the workspaces are loaded from and saved to
+    pub workspaces_path: std::path::PathBuf,
+    /// Show the selected agent's transcript tail (`O`)
+    pub show_transcript: bool,
+    /// Tail following the selected agent's transcript while the view is open
+    pub transcript: Option<TranscriptTail>,
+    /// Show the selected agent's pane output with scrollback (`W`)
+    pub show_pane_output: bool,
+    /// Capture of the selected agent's pane while the view is open
+    pub pane_output: Option<PaneOutput>,
+    /// Scrollback lines captured above the visible pane
+    pub output_scrollback: usize,
+    /// Lines `PageUp`/`PageDown` scroll pane output (half the panel, set at render)
+    pub output_page: usize,
+    /// Command palette filter text and highlighted row
+    pub palette_query: String,
+    pub palette_selected: usize,
+    /// Approvals, answers and input sent from this board
+    pub audit: AuditLog,
+    /// Show selected agent's detail overlay
+    pub show_agent_detail: bool,
     /// Show plan viewer overlay
     pub show_plan_viewer: bool,
     /// Plan viewer state
     pub plan_viewer: PlanViewerState,
     /// Search query for agent filtering
     pub search_query: String,
+    /// Show only agents needing attention (narrowed further by search_query)
+    pub attention_only: bool,
+    /// Mirroring a remote board (`--connect`): local tmux actions are disabled
+    pub read_only: bool,
+    /// Card density in the team view (`v` toggles)
+    pub density: Density,
+    /// How the event log, audit log and cards show times (`[ui] timestamps`)
+    pub time_format: TimeFormat,
+    /// Normal-mode key bindings (defaults plus `[keymap]`)
+    pub keymap: Keymap,
+    /// Display freeze (`f` cycles)
+    pub freeze: FreezeMode,
+    /// Permission auto-accept scope (`p` cycles)
+    pub auto_accept: AutoAccept,
+    /// Do-not-disturb override of `[notify] quiet_hours` (`n` cycles)
+    pub do_not_disturb: DoNotDisturb,
+    /// `[notify] quiet_hours`
+    pub quiet_hours: Option<QuietHours>,
+    /// Permission prompts to approve once due: (pane_id, tool, due)
+    auto_accept_due: Vec<(String, String, Instant)>,
+    /// Select agents as they start asking for permission or input (`[ui] focus_follows_attention`)
+    pub focus_follows_attention: bool,
+    /// Shell command template for opening an agent's directory (`[ui] open_command`)
+    pub open_command: String,
+    /// Pane activation commands per terminal (`[terminal]`)
+    pub terminal: TerminalConfig,
+    /// Ask before quitting while agents are busy (`[ui] confirm_quit`)
+    pub confirm_quit: bool,
+    /// Last key press, so focus-follows-attention never moves the selection mid-action
+    last_interaction: Instant,
+    /// Selection when each open modal was opened, innermost last (see `focus.rs`)
+    focus_stack: Vec<Option<String>>,
+    /// The frame announcing the current freeze has been drawn
+    frozen_frame_drawn: bool,
+    /// Transient footer message and when it was set
+    pub status_message: Option<(String, Instant)>,
+    /// Text typed in the input dialog
+    pub input_buffer: TextInput,
+    /// What the input dialog's text is for
+    pub input_purpose: InputPurpose,
     /// Show stats dashboard overlay
     pub show_stats_viewer: bool,
     /// Stats viewer state
@@ -122,6 +452,8 @@ pub struct App {
     pub show_history_viewer: bool,
     /// History viewer state
     pub history_viewer: HistoryViewerState,
+    /// Event log view state (`V`)
+    pub event_log: EventLogState,
     /// Show debug log viewer overlay
     pub show_debug_viewer: bool,
     /// Debug viewer state
@@ -130,22 +462,61 @@ pub struct App {
     pub show_insights_viewer: bool,
     /// Insights viewer state
     pub insights_viewer: InsightsViewerState,
+    /// Show sprite checkpoint timeline overlay
+    pub show_checkpoint_viewer: bool,
+    /// Checkpoint timeline state
+    pub checkpoint_viewer: CheckpointViewerState,
+    /// Sender for results of background operations (set by the TUI loop)
+    pub event_tx: Option<mpsc::Sender<Event>>,
+    /// Tool events the ingestion paths dropped under backpressure
+    pub dropped_events: DroppedEvents,
+    /// `[[webhooks]]` fired on status transitions
+    pub webhooks: Webhooks,
+    /// `--record` sink for received hook events
+    pub recorder: Option<EventRecorder>,
+    /// `--record-board` sink for periodic board frames
+    pub board_recorder: Option<BoardRecorder>,
+    /// `--playback` timeline (replaces the live board)
+    pub playback: Option<PlaybackState>,
+    /// Completed-turn log (`--metrics-csv`)
+    pub turn_csv: Option<TurnCsvWriter>,
     /// hooks.log health checker
     health_checker: HealthChecker,
+    /// Periodic board saver (restores on startup)
+    board_persister: BoardPersister,
 }
 
 impl App {
     pub fn new(
         debug_mode: bool,
-        sprites_client: Option<SpritesClient>,
-        health_config: &HealthConfig,
-        timeout_config: &TimeoutConfig,
+        sprites_client: Option<SpriteApi>,
+        config: &RehoboamConfig,
     ) -> Self {
+        let mut state = AppState::with_timeouts(
+            config.timeouts.idle_timeout_secs,
+            config.timeouts.stale_timeout_secs,
+        );
+        state.columns = config.ui.columns.clone();
+        state.card_sort = config.ui.card_sort;
+        state.group_by = config.ui.group_by;
+        state.fleet_pulse_ticks = config.ui.pulse_ticks;
+        state.context_warn_percent = config.ui.context_warn_percent;
+        state.context_critical_percent = config.ui.context_critical_percent;
+        state.pricing = Pricing::new(&config.pricing);
+        state.event_log_size = config.ui.event_log_size;
+        state.capture_prompts = config.ui.capture_prompts;
+        state.stuck_tool_secs = config.timeouts.stuck_tool_secs;
+        state.gone_grace_secs = config.timeouts.gone_grace_secs;
+        state.sweep_idle_secs = config.timeouts.sweep_idle_secs;
+        state.max_agents = config.limits.max_agents;
+        state.eviction = config.limits.eviction;
+        state.idle_rules = IdleTimeoutRules::compile(&config.timeouts);
+        state.notifications = NotificationThrottle::new(&config.notify);
+        let board_persister = BoardPersister::new(&config.persistence);
+        board_persister.restore(&mut state);
+
         Self {
-            state: AppState::with_timeouts(
-                timeout_config.idle_timeout_secs,
-                timeout_config.stale_timeout_secs,
-            ),
+            state,
             should_quit: false,
             debug_mode,
             show_help: false,
@@ -153,19 +524,69 @@ impl App {
             input_mode: InputMode::Normal,
             spawn_state: SpawnState::default(),
             sprites_client,
+            show_minimap: false,
+            minimap_columns: 1,
             show_task_board: false,
+            show_audit_log: false,
+            show_sources: false,
+            show_failures: false,
+            sweep_pending: Vec::new(),
+            bulk_approve_tools: Vec::new(),
+            workspaces: workspace::load(&workspace::workspaces_path()),
+            workspaces_path: workspace::workspaces_path(),
+            show_transcript: false,
+            transcript: None,
+            show_pane_output: false,
+            pane_output: None,
+            output_scrollback: config.ui.output_scrollback,
+            output_page: 10,
+            palette_query: String::new(),
+            palette_selected: 0,
+            audit: AuditLog::default(),
+            show_agent_detail: false,
             show_plan_viewer: false,
             plan_viewer: PlanViewerState::default(),
             search_query: String::new(),
+            attention_only: false,
+            read_only: false,
+            density: config.ui.density,
+            time_format: config.ui.timestamps,
+            keymap: Keymap::from_config(&config.keymap),
+            freeze: FreezeMode::Live,
+            auto_accept: AutoAccept::Off,
+            do_not_disturb: DoNotDisturb::Schedule,
+            quiet_hours: config.notify.quiet_hours,
+            auto_accept_due: Vec::new(),
+            focus_follows_attention: config.ui.focus_follows_attention,
+            open_command: config.ui.open_command.clone(),
+            terminal: config.terminal.clone(),
+            confirm_quit: config.ui.confirm_quit,
+            last_interaction: Instant::now(),
+            focus_stack: Vec::new(),
+            frozen_frame_drawn: false,
+            status_message: None,
+            input_buffer: TextInput::default(),
+            input_purpose: InputPurpose::Send,
             show_stats_viewer: false,
             stats_viewer: StatsViewerState::default(),
             show_history_viewer: false,
             history_viewer: HistoryViewerState::default(),
+            event_log: EventLogState::default(),
             show_debug_viewer: false,
             debug_viewer: DebugViewerState::default(),
             show_insights_viewer: false,
             insights_viewer: InsightsViewerState::default(),
-            health_checker: HealthChecker::new(health_config),
+            show_checkpoint_viewer: false,
+            checkpoint_viewer: CheckpointViewerState::default(),
+            event_tx: None,
+            dropped_events: DroppedEvents::default(),
+            webhooks: Webhooks::new(config.webhooks.clone()),
+            recorder: None,
+            board_recorder: None,
+            playback: None,
+            turn_csv: None,
+            health_checker: HealthChecker::new(&config.health),
+            board_persister,
         }
     }
 
@@ -173,20 +594,47 @@ impl App {
     pub fn handle_event(&mut self, event: Event) {
         match event {
             Event::Hook(hook_event) => {
-                let changed = self.state.process_event(*hook_event);
-                self.needs_render = self.needs_render || changed;
+                if let Some(recorder) = self.recorder.as_mut() {
+                    recorder.record(&hook_event);
+                }
+                self.queue_auto_accept(&hook_event);
+                self.note_auth_success(&hook_event);
+                let pane_id = hook_event.pane_id.clone();
+                let outcome = self.state.process_event(*hook_event);
+                self.follow_attention(&pane_id, &outcome);
+                self.send_webhooks(&pane_id, &outcome);
+                self.write_completed_turns();
+                self.needs_render = self.needs_render || outcome.changed();
+            }
+            Event::ReplayClock(timestamp) => {
+                self.state.advance_clock(timestamp);
+                self.needs_render = true;
             }
             Event::Key(key) => {
+                self.last_interaction = Instant::now();
                 self.handle_key(key);
                 self.needs_render = true;
             }
+            Event::Paste(text) => {
+                // Only the input dialog takes pasted text; multi-line stays one prompt
+                if self.input_mode == InputMode::Input {
+                    self.input_buffer.insert_str(&text);
+                    self.needs_render = true;
+                }
+            }
             Event::RemoteHook { sprite_id, event } => {
                 let mut hook_event = *event;
                 hook_event.source = EventSource::Sprite {
                     sprite_id: sprite_id.clone(),
                 };
-                let changed = self.state.process_event(hook_event);
-                self.needs_render = self.needs_render || changed;
+                self.queue_auto_accept(&hook_event);
+                self.note_auth_success(&hook_event);
+                let pane_id = hook_event.pane_id.clone();
+                let outcome = self.state.process_event(hook_event);
+                self.follow_attention(&pane_id, &outcome);
+                self.send_webhooks(&pane_id, &outcome);
+                self.write_completed_turns();
+                self.needs_render = self.needs_render || outcome.changed();
             }
             Event::SpriteStatus { sprite_id, status } => {
                 match status {
@@ -201,6 +649,238 @@ impl App {
                 }
                 self.needs_render = true;
             }
+            Event::Checkpoints { sprite_id, result } => {
+                // Ignore stale results for a timeline that was closed or switched
+                if self.show_checkpoint_viewer && self.checkpoint_viewer.sprite_id == sprite_id {
+                    if let Err(ref e) = result {
+                        self.set_status_message(format!("Checkpoints for {sprite_id}: {e}"));
+                    }
+                    let viewer = &mut self.checkpoint_viewer;
+                    viewer.loading = false;
+                    match result {
+                        Ok(records) => {
+                            viewer.records = records;
+                            viewer.error = None;
+                        }
+                        Err(e) => viewer.error = Some(e),
+                    }
+                    viewer.selected_index = viewer
+                        .selected_index
+                        .min(viewer.records.len().saturating_sub(1));
+                    self.needs_render = true;
+                }
+            }
+            Event::CheckpointRestored {
+                sprite_id,
+                checkpoint_id,
+                result,
+            } => {
+                match result {
+                    Ok(()) => {
+                        self.set_status_message(format!(
+                            "Restored {sprite_id} to checkpoint {checkpoint_id}"
+                        ));
+                        if self.show_checkpoint_viewer
+                            && self.checkpoint_viewer.sprite_id == sprite_id
+                        {
+                            self.fetch_checkpoints();
+                        }
+                    }
+                    Err(e) => {
+                        self.checkpoint_viewer.loading = false;
+                        self.set_status_message(format!("Restore of {sprite_id} failed: {e}"));
+                    }
+                }
+                self.needs_render = true;
+            }
+            Event::SpriteInputFailed { sprite_id, error } => {
+                self.set_status_message(format!("input to {sprite_id} failed: {error}"));
+                self.needs_render = true;
+            }
+            Event::PaneOutput { pane_id, result } => {
+                if let Some(output) = self
+                    .pane_output
+                    .as_mut()
+                    .filter(|output| output.pane_id == pane_id)
+                {
+                    output.apply(result);
+                    self.needs_render = true;
+                }
+            }
+            Event::GitUpstream { counts } => {
+                self.state.apply_upstream(counts);
+                self.needs_render = true;
+            }
+            Event::BatchSpawned { spawned, failed } => {
+                let report = spawn_batch::BatchReport { spawned, failed };
+                self.set_status_message(report.summary());
+                self.needs_render = true;
+            }
+            Event::Snapshot { reply } => {
+                // Receiver may have timed out; nothing to do if so
+                let _ = reply.send(self.state.snapshot());
+            }
+            Event::Metrics { reply } => {
+                self.state.dropped_events = self.dropped_events.total();
+                self.state.webhook_failures = self.webhooks.failures.total();
+                let _ = reply.send(self.state.prometheus_metrics());
+            }
+            Event::ListenerStatus { socket, error } => {
+                self.health_checker
+                    .set_listener_status(&mut self.state, socket, error);
+                self.needs_render = true;
+            }
+            Event::Rpc { call, reply } => {
+                let outcome = self.handle_rpc(call);
+                let _ = reply.send(outcome);
+                self.needs_render = true;
+            }
+        }
+    }
+
+    /// Select an agent that just started asking for permission or input
+    ///
+    /// Only with `focus_follows_attention` on, in normal mode, and once no
+    /// key has been pressed for [`FOCUS_QUIET`], so the selection never
+    /// moves under a keystroke.
+    fn follow_attention(&mut self, pane_id: &str, outcome: &EventOutcome) {
+        let EventOutcome::Updated(Some(transition)) = outcome else {
+            return;
+        };
+        let blocking = matches!(
+            transition.to,
+            Status::Attention(AttentionType::Permission | AttentionType::Input)
+        );
+        if !blocking
+            || !self.focus_follows_attention
+            || self.input_mode != InputMode::Normal
+            || self.last_interaction.elapsed() < FOCUS_QUIET
+            || self.state.selected_pane_id.as_deref() == Some(pane_id)
+            || !self.state.agents.contains_key(pane_id)
+        {
+            return;
+        }
+        tracing::debug!(pane_id = %pane_id, "Focus follows attention");
+        self.state.selected_pane_id = Some(pane_id.to_string());
+        let project = self.state.agents[pane_id].project.clone();
+        self.set_status_message(format!("Followed {project} ({pane_id})"));
+    }
+
+    /// POST a status transition to the `[[webhooks]]` it triggers
+    ///
+    /// Skipped when read-only: a mirror or playback would repeat the alerts
+    /// the watched host already sent.
+    fn send_webhooks(&self, pane_id: &str, outcome: &EventOutcome) {
+        let EventOutcome::Updated(Some(transition)) = outcome else {
+            return;
+        };
+        if self.read_only {
+            return;
+        }
+        if let Some(agent) = self.state.agents.get(pane_id) {
+            self.webhooks.dispatch(pane_id, &agent.project, transition);
+        }
+    }
+
+    /// Footer notice for a sign-in completing (`auth_success` notification)
+    ///
+    /// The agent's status is left alone, so this is the only trace of it.
+    fn note_auth_success(&mut self, event: &crate::event::HookEvent) {
+        if crate::state::is_auth_success(event) {
+            self.set_status_message(format!("{}: signed in", event.project));
+        }
+    }
+
+    /// Schedule an automatic approval for a read-only tool's permission prompt
+    fn queue_auto_accept(&mut self, event: &crate::event::HookEvent) {
+        if self.auto_accept == AutoAccept::Off
+            || self.read_only
+            || event.event != "PermissionRequest"
+        {
+            return;
+        }
+        let Some(tool) = event.tool_name.as_deref() else {
+            return;
+        };
+        if operations::is_read_only_tool(tool) {
+            self.auto_accept_due.push((
+                event.pane_id.clone(),
+                tool.to_string(),
+                Instant::now() + AUTO_ACCEPT_DELAY,
+            ));
+        }
+    }
+
+    /// Approve queued prompts that are due and still waiting
+    ///
+    /// A prompt the user already answered (agent no longer in Permission) is
+    /// dropped rather than approved twice.
+    fn run_auto_accept(&mut self) {
+        let now = Instant::now();
+        let (due, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut self.auto_accept_due)
+            .into_iter()
+            .partition(|(_, _, at)| *at <= now);
+        self.auto_accept_due = pending;
+        for (pane_id, tool, _) in due {
+            let waiting =
+                self.state.agents.get(&pane_id).is_some_and(|agent| {
+                    agent.status == Status::Attention(AttentionType::Permission)
+                });
+            if !waiting {
+                continue;
+            }
+            let result = operations::respond_to_permission(
+                &self.state,
+                &pane_id,
+                true,
+                self.sprites_client.as_ref(),
+                self.event_tx.as_ref(),
+            );
+            self.audit(AuditAction::AutoApprove, &pane_id, &tool, result.is_ok());
+            match result {
+                Ok(()) => {
+                    tracing::info!(pane_id = %pane_id, tool = %tool, "Auto-approved read-only tool");
+                    self.set_status_message(format!("auto-approved {tool} for {pane_id}"));
+                }
+                Err(e) => {
+                    tracing::warn!(pane_id = %pane_id, error = %e, "Auto-approve failed");
+                }
+            }
+        }
+    }
+
+    /// Whether it is `[notify] quiet_hours` by the local clock
+    pub fn in_quiet_hours(&self) -> bool {
+        self.quiet_hours.is_some_and(|quiet| quiet.contains_now())
+    }
+
+    /// Whether desktop notifications are held back right now
+    fn notifications_silenced(&self) -> bool {
+        self.do_not_disturb.silences(self.in_quiet_hours())
+    }
+
+    /// Recompute stale ahead/behind counts on a blocking thread (60s per agent)
+    fn refresh_upstream(&mut self) {
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let due = self.state.upstream_refresh_due();
+        if due.is_empty() {
+            return;
+        }
+        tokio::task::spawn_blocking(move || {
+            let counts = crate::state::upstream_counts_for(due);
+            let _ = tx.blocking_send(Event::GitUpstream { counts });
+        });
+    }
+
+    /// Drain finished turns into the metrics CSV (dropped when not recording)
+    fn write_completed_turns(&mut self) {
+        let turns = std::mem::take(&mut self.state.completed_turns);
+        if let Some(writer) = self.turn_csv.as_mut() {
+            for turn in &turns {
+                writer.write(turn);
+            }
         }
     }
 
@@ -219,6 +899,10 @@ impl App {
         // Throttled internally alongside team metadata refresh
         self.state.refresh_task_data();
 
+        // Re-read each agent's git branch (per-agent 30s throttle)
+        self.state.refresh_git_branches();
+        self.refresh_upstream();
+
         // Stats always refreshes (small file, 60s throttle)
         self.state.refresh_stats_data();
 
@@ -232,6 +916,22 @@ impl App {
         if self.show_insights_viewer {
             self.state.refresh_insights_data();
         }
+        if self.show_transcript {
+            self.refresh_transcript();
+        }
+        if self.show_pane_output {
+            self.refresh_pane_output();
+        }
+
+        // Warn while the event channel is dropping tool events
+        self.state.dropped_events = self.dropped_events.total();
+        let dropped = self.state.dropped_events;
+        if self
+            .health_checker
+            .note_dropped_events(&mut self.state, dropped)
+        {
+            self.needs_render = true;
+        }
 
         // Run hooks.log health check (throttled to every 60s by default)
         if self.health_checker.should_run() {
@@ -239,12 +939,474 @@ impl App {
             self.needs_render = self.needs_render || modified;
         }
 
+        // Expire transient footer message
+        if self
+            .status_message
+            .as_ref()
+            .is_some_and(|(_, set_at)| set_at.elapsed().as_secs() >= STATUS_MESSAGE_SECS)
+        {
+            self.status_message = None;
+        }
+
+        // Answer read-only permission prompts queued by the auto-accept scope
+        if !self.auto_accept_due.is_empty() {
+            self.run_auto_accept();
+        }
+
+        // Send throttled desktop notifications once their coalesce window closes
+        // (drained but dropped while silently frozen or do-not-disturb)
+        if let Some(note) = self.state.notifications.flush(std::time::Instant::now()) {
+            if self.freeze.silent() {
+                tracing::debug!(title = %note.title, "Frozen: notification suppressed");
+            } else if self.notifications_silenced() {
+                tracing::debug!(title = %note.title, "Do not disturb: notification suppressed");
+            } else {
+                crate::notify::send(&note.title, &note.message, note.sound.as_deref());
+            }
+        }
+
+        // Append a board frame to the recording (`[recording] interval_secs`)
+        if let Some(recorder) = self.board_recorder.as_mut() {
+            recorder.record_due(&self.state);
+        }
+
+        // Save board for restore after restart (throttled to every 10s by default)
+        if self.board_persister.should_run() {
+            self.board_persister.save(&self.state);
+        }
+
         // Tick triggers re-render for elapsed time updates
         self.needs_render = true;
     }
 
+    /// Current view filter for the team list and navigation
+    pub fn agent_filter(&self) -> AgentFilter<'_> {
+        AgentFilter {
+            attention_only: self.attention_only,
+            query: &self.search_query,
+        }
+    }
+
+    /// Follow the selected agent's transcript, reading what was appended
+    ///
+    /// Switching agents (or an agent starting a new session file) swaps the
+    /// tail; the file is read from the top again in that case.
+    fn refresh_transcript(&mut self) {
+        let path = self
+            .state
+            .selected_agent()
+            .and_then(|agent| agent.transcript_path.as_deref());
+        let Some(path) = path else {
+            self.transcript = None;
+            return;
+        };
+        if !self
+            .transcript
+            .as_ref()
+            .is_some_and(|tail| tail.follows(std::path::Path::new(path)))
+        {
+            self.transcript = Some(TranscriptTail::new(path));
+        }
+        if let Some(tail) = self.transcript.as_mut() {
+            tail.refresh();
+        }
+    }
+
+    /// Capture the selected agent's pane on a blocking thread
+    ///
+    /// Switching agents starts a fresh capture; a capture is skipped while
+    /// one is in flight or while the view is scrolled back.
+    fn refresh_pane_output(&mut self) {
+        let Some(agent) = self.state.selected_agent() else {
+            self.pane_output = None;
+            return;
+        };
+        if agent.is_sprite {
+            self.pane_output = Some(PaneOutput {
+                error: Some("agent runs on a sprite, not in local tmux".to_string()),
+                ..PaneOutput::new(agent.pane_id.clone())
+            });
+            return;
+        }
+        if self
+            .pane_output
+            .as_ref()
+            .is_none_or(|output| output.pane_id != agent.pane_id)
+        {
+            self.pane_output = Some(PaneOutput::new(agent.pane_id.clone()));
+        }
+        let Some(tx) = self.event_tx.clone() else {
+            return;
+        };
+        let Some(output) = self.pane_output.as_mut() else {
+            return;
+        };
+        if output.pending || !output.following() {
+            return;
+        }
+        output.pending = true;
+        let pane_id = output.pane_id.clone();
+        let scrollback = self.output_scrollback;
+        tokio::task::spawn_blocking(move || {
+            let result =
+                TmuxController::capture_pane(&pane_id, scrollback).map_err(|e| e.to_string());
+            let _ = tx.blocking_send(Event::PaneOutput { pane_id, result });
+        });
+    }
+
+    /// Show a hook setup problem found at startup in the footer
+    pub fn set_setup_warning(&mut self, warning: Option<String>) {
+        if let Some(ref warning) = warning {
+            tracing::warn!(warning = %warning, "Hook setup problem");
+        }
+        self.health_checker
+            .set_setup_warning(&mut self.state, warning);
+    }
+
+    /// Add an operator action to the audit trail
+    fn audit(&mut self, action: AuditAction, pane_id: &str, detail: &str, ok: bool) {
+        let project = self
+            .state
+            .agents
+            .get(pane_id)
+            .map(|agent| agent.project.as_str())
+            .unwrap_or("");
+        self.audit.record(action, pane_id, project, detail, ok);
+    }
+
+    /// Show a transient message in the footer
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), Instant::now()));
+    }
+
+    /// Approve every pending permission prompt for `tool` (all if None)
+    fn approve_pending(&mut self, tool: Option<&str>) {
+        let panes = self.state.pending_permissions(tool);
+        let (mut approved, mut failed) = (0, 0);
+        for pane_id in &panes {
+            let result = operations::respond_to_permission(
+                &self.state,
+                pane_id,
+                true,
+                self.sprites_client.as_ref(),
+                self.event_tx.as_ref(),
+            );
+            let detail = self
+                .state
+                .agents
+                .get(pane_id)
+                .and_then(|agent| agent.pending_permission.clone())
+                .unwrap_or_default();
+            self.audit(AuditAction::Approve, pane_id, &detail, result.is_ok());
+            match result {
+                Ok(()) => approved += 1,
+                Err(e) => {
+                    tracing::warn!(pane_id = %pane_id, error = %e, "Bulk approve failed");
+                    failed += 1;
+                }
+            }
+        }
+        tracing::info!(tool = ?tool, approved, failed, "Bulk approved permission prompts");
+        let what = match tool {
+            Some(tool) => format!("{tool} prompt"),
+            None => "prompt".to_string(),
+        };
+        let plural = if approved == 1 { "" } else { "s" };
+        let mut message = format!("approved {approved} {what}{plural}");
+        if failed > 0 {
+            message.push_str(&format!(", {failed} failed"));
+        }
+        self.set_status_message(message);
+    }
+
+    /// Agents the input dialog sends to: marked agents, else the selection
+    pub fn input_targets(&self) -> Vec<String> {
+        if self.state.selected_agents.is_empty() {
+            self.state
+                .selected_agent()
+                .map(|a| vec![a.pane_id.clone()])
+                .unwrap_or_default()
+        } else {
+            let mut targets: Vec<String> = self.state.selected_agents.iter().cloned().collect();
+            targets.sort();
+            targets
+        }
+    }
+
+    /// Spawn one local agent per entry of a `@FILE` batch, in the background
+    ///
+    /// Errors (unreadable file, sprite mode) are for the spawn dialog.
+    fn start_batch_spawn(&mut self, file: &std::path::Path) -> Result<(), String> {
+        if self.spawn_state.use_sprite {
+            return Err("Batch spawn (@FILE) runs local agents only".to_string());
+        }
+        let entries = spawn_batch::load(file)?;
+        self.set_status_message(format!("spawning {} agents...", entries.len()));
+        spawn_batch::spawn_all(
+            self.spawn_state.project_path.clone(),
+            entries,
+            self.event_tx.clone(),
+        );
+        Ok(())
+    }
+
+    /// Act on the input dialog's text: send it to its targets and report the
+    /// outcome, save it as the note being edited, or name a workspace
+    fn submit_input(&mut self) {
+        let text = self.input_buffer.take();
+        self.input_mode = InputMode::Normal;
+        match std::mem::take(&mut self.input_purpose) {
+            InputPurpose::Send => {}
+            InputPurpose::Note(pane_id) => {
+                let message = if !self.state.set_note(&pane_id, &text) {
+                    format!("{pane_id} is gone, note not saved")
+                } else if text.trim().is_empty() {
+                    format!("note on {pane_id} cleared")
+                } else {
+                    format!("note on {pane_id} saved")
+                };
+                self.set_status_message(message);
+                return;
+            }
+            InputPurpose::Workspace => {
+                self.save_workspace(text.trim());
+                return;
+            }
+        }
+        if text.trim().is_empty() {
+            return;
+        }
+        let targets = self.input_targets();
+        let report = operations::send_input_to_agents(
+            &self.state,
+            &targets,
+            &text,
+            self.sprites_client.as_ref(),
+            self.event_tx.as_ref(),
+        );
+        tracing::info!(
+            sent = report.sent,
+            failed = report.failed.len(),
+            "Sent input to agents"
+        );
+        for pane_id in &targets {
+            let ok = !report.failed.contains(pane_id);
+            self.audit(AuditAction::Input, pane_id, &text, ok);
+        }
+        self.set_status_message(report.summary());
+    }
+
+    /// Agents still working or compacting (gone agents don't count)
+    pub fn busy_agent_count(&self) -> usize {
+        self.state
+            .agents
+            .values()
+            .filter(|a| !a.is_gone() && matches!(a.status, Status::Working | Status::Compacting))
+            .count()
+    }
+
+    /// Quit, or ask first when `[ui] confirm_quit` is on and agents are busy
+    pub(super) fn request_quit(&mut self) {
+        if self.confirm_quit && self.busy_agent_count() > 0 {
+            self.input_mode = InputMode::QuitConfirm;
+        } else {
+            self.should_quit = true;
+        }
+    }
+
+    /// Open the selected agent's working directory with `[ui] open_command`
+    fn open_selected_dir(&mut self) {
+        let Some(agent) = self.state.selected_agent() else {
+            self.set_status_message("No agent selected");
+            return;
+        };
+        let Some(dir) = agent.cwd.clone() else {
+            let message = format!("{}: working directory unknown", agent.pane_id);
+            self.set_status_message(message);
+            return;
+        };
+        match operations::open_directory(&self.open_command, &dir) {
+            Ok(()) => self.set_status_message(format!("opening {dir}")),
+            Err(e) => {
+                tracing::warn!(dir = %dir, error = %e, "Failed to open directory");
+                self.set_status_message(format!("open failed: {e}"));
+            }
+        }
+    }
+
+    /// Copy one of the selected agent's details (chosen from the copy menu)
+    ///
+    /// Falls back to showing the value when no clipboard tool works, e.g. over
+    /// SSH without X forwarding.
+    fn copy_selected_detail(&mut self, key: char) {
+        self.input_mode = InputMode::Normal;
+        let Some(agent) = self.state.selected_agent() else {
+            return;
+        };
+        let Some((_, label, value)) = operations::copy_choices(agent)
+            .into_iter()
+            .find(|(k, ..)| *k == key)
+        else {
+            return;
+        };
+        match crate::clipboard::copy(&value) {
+            Ok(_) => self.set_status_message(format!("copied {label}")),
+            Err(e) => {
+                tracing::debug!(error = %e, "Clipboard unavailable, showing value instead");
+                self.set_status_message(format!("{label}: {value}"));
+            }
+        }
+    }
+
+    /// Execute a control-socket call
+    ///
+    /// Mutating calls go through the same operations as the keybindings and
+    /// are refused on read-only boards.
+    fn handle_rpc(&mut self, call: RpcCall) -> Result<serde_json::Value, RpcError> {
+        if self.read_only && call.mutates() {
+            return Err(RpcError::new(
+                rpc::codes::READ_ONLY,
+                "board is read-only (--connect/--replay)",
+            ));
+        }
+        tracing::info!(?call, "Control call");
+        let client = self.sprites_client.as_ref();
+        let events = self.event_tx.as_ref();
+        let approve = matches!(call, RpcCall::Approve { .. });
+        match call {
+            RpcCall::ListAgents => serde_json::to_value(self.state.snapshot())
+                .map_err(|e| RpcError::failed(e.to_string())),
+            RpcCall::Spawn {
+                project,
+                prompt,
+                sprite,
+                github_repo,
+            } => {
+                let spawn_state = SpawnState {
+                    project_path: project,
+                    github_repo,
+                    prompt,
+                    use_sprite: sprite,
+                    ..SpawnState::default()
+                };
+                spawn::validate_spawn(&spawn_state, client.is_some()).map_err(RpcError::failed)?;
+                if let Some(msg) = spawn::spawn_agent(&spawn_state, client, &mut self.state) {
+                    return Err(RpcError::failed(msg));
+                }
+                Ok(serde_json::json!({ "ok": true }))
+            }
+            RpcCall::Approve { pane_id } | RpcCall::Reject { pane_id } => {
+                let result = operations::respond_to_permission(
+                    &self.state,
+                    &pane_id,
+                    approve,
+                    client,
+                    events,
+                );
+                let action = if approve {
+                    AuditAction::Approve
+                } else {
+                    AuditAction::Reject
+                };
+                self.audit(action, &pane_id, "via control socket", result.is_ok());
+                result
+                    .map(|()| serde_json::json!({ "ok": true }))
+                    .map_err(|e| RpcError::failed(e.to_string()))
+            }
+            RpcCall::SendInput { pane_id, text } => {
+                if !self.state.agents.contains_key(&pane_id) {
+                    return Err(RpcError::failed(format!("unknown agent {pane_id}")));
+                }
+                let report = operations::send_input_to_agents(
+                    &self.state,
+                    std::slice::from_ref(&pane_id),
+                    &text,
+                    client,
+                    events,
+                );
+                self.audit(AuditAction::Input, &pane_id, &text, report.sent > 0);
+                if report.sent == 0 {
+                    return Err(RpcError::failed(report.summary()));
+                }
+                Ok(serde_json::json!({ "ok": true }))
+            }
+        }
+    }
+
+    /// Request the checkpoint timeline for the viewer's sprite
+    fn fetch_checkpoints(&mut self) {
+        let (Some(client), Some(tx)) = (self.sprites_client.as_ref(), self.event_tx.clone()) else {
+            self.checkpoint_viewer.error = Some("sprites not enabled".to_string());
+            return;
+        };
+        self.checkpoint_viewer.loading = true;
+        checkpoints::fetch(client, &self.checkpoint_viewer.sprite_id, tx);
+    }
+
+    /// Restore the selected checkpoint (after the y/N confirmation)
+    fn restore_selected_checkpoint(&mut self) {
+        self.checkpoint_viewer.confirm_restore = false;
+        let sprite_id = self.checkpoint_viewer.sprite_id.clone();
+        let Some(record) = self
+            .checkpoint_viewer
+            .records
+            .get(self.checkpoint_viewer.selected_index)
+        else {
+            return;
+        };
+        let checkpoint_id = record.id.clone();
+
+        // The sprite may have gone away since the timeline was fetched
+        if !self.state.connected_sprites.contains(&sprite_id) {
+            self.set_status_message(format!("{sprite_id} is disconnected, restore not sent"));
+            return;
+        }
+        let (Some(client), Some(tx)) = (self.sprites_client.as_ref(), self.event_tx.clone()) else {
+            self.set_status_message("Sprites not enabled, restore not sent");
+            return;
+        };
+        checkpoints::restore(client, &sprite_id, &checkpoint_id, tx);
+        self.checkpoint_viewer.loading = true;
+        self.set_status_message(format!("Restoring {sprite_id} to {checkpoint_id}..."));
+    }
+
+    /// Save the board immediately (used on shutdown)
+    pub fn persist_board(&mut self) {
+        self.board_persister.save(&self.state);
+    }
+
     /// Called after render to reset dirty flag
     pub fn rendered(&mut self) {
         self.needs_render = false;
+        self.frozen_frame_drawn = self.freeze.paused();
+    }
+
+    /// True when a frame should be drawn: changes are pending and the display
+    /// is live, or it was just frozen and the banner frame is still owed
+    pub fn wants_render(&self) -> bool {
+        self.needs_render && !(self.freeze.paused() && self.frozen_frame_drawn)
+    }
+
+    /// Advance the freeze mode, drawing one frame to show the new state
+    ///
+    /// Freezing starts collecting transitions; going live again shows what
+    /// changed in the meantime.
+    fn cycle_freeze(&mut self) {
+        self.freeze = self.freeze.next();
+        match self.freeze {
+            FreezeMode::Frozen => {
+                self.state.freeze_delta = Some(FreezeDelta::new(self.state.agents.keys()));
+            }
+            FreezeMode::FrozenSilent => {}
+            FreezeMode::Live => {
+                if let Some(delta) = self.state.freeze_delta.take() {
+                    let summary = delta.summary(&self.state.agents);
+                    self.set_status_message(summary);
+                }
+            }
+        }
+        self.frozen_frame_drawn = false;
+        self.needs_render = true;
+        tracing::info!(freeze = ?self.freeze, "Display freeze changed");
     }
 }
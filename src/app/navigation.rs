@@ -1,10 +1,11 @@
 //! Navigation operations: jump to agent, search
 
-use crate::state::AppState;
-use std::process::Command;
+use crate::config::TerminalConfig;
+use crate::state::{AgentFilter, AppState};
+use crate::terminal;
 
-/// Jump to selected agent's tmux pane
-pub fn jump_to_selected(state: &AppState) {
+/// Jump to selected agent's pane (tmux, WezTerm or Kitty, see [`terminal`])
+pub fn jump_to_selected(state: &AppState, config: &TerminalConfig) {
     let Some(agent) = state.selected_agent() else {
         return;
     };
@@ -16,50 +17,37 @@ pub fn jump_to_selected(state: &AppState) {
         tracing::debug!(pane_id = %pane_id, "Cannot jump to phantom agent");
         return;
     }
-    if !pane_id.starts_with('%') {
-        tracing::debug!(pane_id = %pane_id, "Cannot jump: not a tmux pane");
+    if terminal::PaneScheme::detect(pane_id).is_none() {
+        tracing::debug!(pane_id = %pane_id, "Cannot jump: not a terminal pane");
         return;
     }
 
-    let result = Command::new("tmux")
-        .args(["select-pane", "-t", pane_id])
-        .output();
-
-    match result {
-        Ok(output) if !output.status.success() => {
-            tracing::warn!(
-                pane_id = %pane_id,
-                "Failed to activate pane: {}",
-                String::from_utf8_lossy(&output.stderr)
-            );
-        }
-        Err(e) => {
-            tracing::warn!(pane_id = %pane_id, error = %e, "Terminal CLI not available for pane activation");
-        }
-        _ => {}
+    if let Err(e) = terminal::activate_pane(config, pane_id) {
+        tracing::warn!(pane_id = %pane_id, error = %e, "Failed to activate pane");
     }
 }
 
-/// Jump to the first agent matching the search query
+/// Jump to the best fuzzy match for the search query
 pub fn jump_to_search_match(state: &mut AppState, query: &str) {
-    let query = query.to_lowercase();
-    if query.is_empty() {
-        return;
-    }
-
-    // Find first matching agent
-    for agent in state.agents.values() {
-        let project_lower = agent.project.to_lowercase();
-        let pane_lower = agent.pane_id.to_lowercase();
-
-        if project_lower.contains(&query) || pane_lower.contains(&query) {
-            state.selected_pane_id = Some(agent.pane_id.clone());
-            tracing::debug!(
-                project = %agent.project,
-                pane_id = %agent.pane_id,
-                "Jumping to search match"
-            );
-            break;
-        }
+    let filter = AgentFilter {
+        attention_only: false,
+        query,
+    };
+    // Ties go to the lowest pane id so the jump is stable
+    let best = state
+        .agents
+        .values()
+        .filter_map(|agent| filter.hit(agent).map(|hit| (hit.score, agent)))
+        .max_by(|(a_score, a), (b_score, b)| {
+            a_score.cmp(b_score).then_with(|| b.pane_id.cmp(&a.pane_id))
+        });
+    if let Some((score, agent)) = best {
+        tracing::debug!(
+            project = %agent.project,
+            pane_id = %agent.pane_id,
+            score,
+            "Jumping to search match"
+        );
+        state.selected_pane_id = Some(agent.pane_id.clone());
     }
 }
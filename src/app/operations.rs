@@ -0,0 +1,372 @@
+//! Agent operations: send input to one or many agents, answer questions and
+//! permission prompts, copy agent details, open an agent's directory
+//!
+//! Local agents receive text through their tmux pane. Sprite agents run
+//! Claude inside a tmux session on the sprite (`claude-<sprite_id>`, created
+//! by the spawn dialog), so their input goes through the Sprites API instead.
+//! Sprite sends complete in the background; failures come back to the App as
+//! [`Event::SpriteInputFailed`] so they reach the footer like local ones.
+
+use crate::event::Event;
+use crate::sprite::api::SpriteApi;
+use crate::state::{Agent, AppState, AttentionType, Status};
+use crate::tmux::TmuxController;
+use color_eyre::eyre::{bail, Result, WrapErr};
+use std::process::{Command, Stdio};
+use tokio::sync::mpsc;
+
+/// Outcome of sending the same input to several agents
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BulkSendReport {
+    /// Agents the input was delivered (or dispatched, for sprites) to
+    pub sent: usize,
+    /// How many of `sent` were sprites still completing in the background
+    pub sprites: usize,
+    /// Pane ids that failed, in send order
+    pub failed: Vec<String>,
+}
+
+impl BulkSendReport {
+    /// Footer summary, e.g. "sent to 4/5 (2 via sprite; %7 failed)"
+    pub fn summary(&self) -> String {
+        let total = self.sent + self.failed.len();
+        let mut notes = Vec::new();
+        if self.sprites > 0 {
+            notes.push(format!("{} via sprite", self.sprites));
+        }
+        if !self.failed.is_empty() {
+            notes.push(format!("{} failed", self.failed.join(", ")));
+        }
+        if notes.is_empty() {
+            format!("sent to {}/{}", self.sent, total)
+        } else {
+            format!("sent to {}/{} ({})", self.sent, total, notes.join("; "))
+        }
+    }
+}
+
+/// Send `text` to every agent in `targets`
+///
+/// Targets are visited in sorted order so the report is stable. Sprites are
+/// recognised by `sprite_agent_ids` (or the agent's own flag) and dispatched
+/// in the background; their failures arrive later on `events`.
+pub fn send_input_to_agents(
+    state: &AppState,
+    targets: &[String],
+    text: &str,
+    sprites_client: Option<&SpriteApi>,
+    events: Option<&mpsc::Sender<Event>>,
+) -> BulkSendReport {
+    let mut targets: Vec<&String> = targets.iter().collect();
+    targets.sort();
+    targets.dedup();
+
+    let mut report = BulkSendReport::default();
+    for pane_id in targets {
+        let mut is_sprite = false;
+        let result = match state.agents.get(pane_id) {
+            Some(agent) if agent.is_sprite || state.sprite_agent_ids.contains(pane_id) => {
+                is_sprite = true;
+                let sprite_id = agent.sprite_id.as_deref().unwrap_or(pane_id);
+                send_to_sprite(sprites_client, events, sprite_id, text, true)
+            }
+            Some(_) => send_to_pane(pane_id, text),
+            None => Err(color_eyre::eyre::eyre!("agent no longer exists")),
+        };
+        match result {
+            Ok(()) => {
+                report.sent += 1;
+                if is_sprite {
+                    report.sprites += 1;
+                }
+            }
+            Err(e) => {
+                tracing::warn!(pane_id = %pane_id, error = %e, "Failed to send input");
+                report.failed.push(pane_id.clone());
+            }
+        }
+    }
+    report
+}
+
+/// Answer an agent's pending AskUserQuestion with option `choice` (1-based)
+///
+/// Claude Code's option menu reacts to the digit keypress, so no Enter is sent.
+pub fn answer_question(
+    state: &AppState,
+    pane_id: &str,
+    choice: usize,
+    sprites_client: Option<&SpriteApi>,
+    events: Option<&mpsc::Sender<Event>>,
+) -> Result<()> {
+    let Some(agent) = state.agents.get(pane_id) else {
+        bail!("agent no longer exists");
+    };
+    if choice == 0 || choice > agent.pending_options.len() {
+        bail!("no option {choice}");
+    }
+    let key = choice.to_string();
+    if agent.is_sprite {
+        let sprite_id = agent.sprite_id.as_deref().unwrap_or(pane_id);
+        send_to_sprite(sprites_client, events, sprite_id, &key, false)
+    } else if pane_id.starts_with('%') {
+        TmuxController::send_key(pane_id, &key)
+    } else {
+        bail!("not a tmux pane")
+    }
+}
+
+/// Tools that only read, approved by the read-only auto-accept scope
+pub const READ_ONLY_TOOLS: [&str; 4] = ["Read", "Glob", "Grep", "WebFetch"];
+
+/// True for tools in [`READ_ONLY_TOOLS`]
+pub fn is_read_only_tool(tool: &str) -> bool {
+    READ_ONLY_TOOLS.contains(&tool)
+}
+
+/// Approve or reject an agent's pending permission prompt
+///
+/// Claude Code's permission menu takes `1` for "Yes"; Escape declines.
+pub fn respond_to_permission(
+    state: &AppState,
+    pane_id: &str,
+    approve: bool,
+    sprites_client: Option<&SpriteApi>,
+    events: Option<&mpsc::Sender<Event>>,
+) -> Result<()> {
+    let Some(agent) = state.agents.get(pane_id) else {
+        bail!("agent no longer exists");
+    };
+    if agent.status != Status::Attention(AttentionType::Permission) {
+        bail!("agent is not waiting for permission");
+    }
+    if agent.is_sprite {
+        let sprite_id = agent.sprite_id.as_deref().unwrap_or(pane_id);
+        // Sent literally, so Escape goes as the raw control character
+        let key = if approve { "1" } else { "\u{1b}" };
+        send_to_sprite(sprites_client, events, sprite_id, key, false)
+    } else if pane_id.starts_with('%') {
+        TmuxController::send_key(pane_id, if approve { "1" } else { "Escape" })
+    } else {
+        bail!("not a tmux pane")
+    }
+}
+
+/// Agent details offered by the copy menu: (key, label, value)
+///
+/// Only details the agent actually reported are listed.
+pub fn copy_choices(agent: &Agent) -> Vec<(char, &'static str, String)> {
+    let mut choices = vec![('p', "pane id", agent.pane_id.clone())];
+    if let Some(ref path) = agent.transcript_path {
+        choices.push(('t', "transcript", path.clone()));
+    }
+    if let Some(ref cwd) = agent.cwd {
+        choices.push(('d', "cwd", cwd.clone()));
+    }
+    if let Some(ref session_id) = agent.session_id {
+        choices.push(('s', "session id", session_id.clone()));
+    }
+    choices
+}
+
+/// Run `[ui] open_command` for `dir`, detached from the TUI
+///
+/// `{dir}` in the template becomes the single-quoted path and the result runs
+/// under `sh -c`. Returns once the command has started; it is reaped on a
+/// background thread so a long-lived editor never blocks the board.
+pub fn open_directory(template: &str, dir: &str) -> Result<()> {
+    let command = expand_open_command(template, dir);
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .wrap_err_with(|| format!("Failed to run {command}"))?;
+    std::thread::spawn(move || {
+        if let Ok(status) = child.wait() {
+            if !status.success() {
+                tracing::warn!(command = %command, %status, "Open command failed");
+            }
+        }
+    });
+    Ok(())
+}
+
+fn expand_open_command(template: &str, dir: &str) -> String {
+    let quoted = format!("'{}'", dir.replace('\'', "'\\''"));
+    template.replace("{dir}", &quoted)
+}
+
+/// Send text to a local tmux pane
+fn send_to_pane(pane_id: &str, text: &str) -> Result<()> {
+    if !pane_id.starts_with('%') {
+        bail!("not a tmux pane");
+    }
+    TmuxController::send_buffered(pane_id, text)
+}
+
+/// Wrap multi-line text in bracketed-paste markers
+///
+/// `send-keys -l` types each newline as a key press, which would submit the
+/// prompt line by line; as a bracketed paste it arrives as one prompt.
+fn bracket_multiline(text: &str) -> String {
+    if text.contains('\n') {
+        format!("\x1b[200~{text}\x1b[201~")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Send text to the Claude tmux session on a sprite, optionally followed by Enter
+///
+/// Returns once the send is dispatched; a failure is reported on `events`.
+fn send_to_sprite(
+    api: Option<&SpriteApi>,
+    events: Option<&mpsc::Sender<Event>>,
+    sprite_id: &str,
+    text: &str,
+    press_enter: bool,
+) -> Result<()> {
+    let Some(api) = api else {
+        bail!("sprites not enabled");
+    };
+    let api = api.clone();
+    let sprite = api.sprite(sprite_id);
+    let session = format!("claude-{sprite_id}");
+    let text = bracket_multiline(text);
+    let sprite_id = sprite_id.to_string();
+    let events = events.cloned();
+
+    tokio::spawn(async move {
+        // Keystrokes must not be repeated, so no retries
+        let sent = api
+            .call_once("send input", || async {
+                // -l sends the text literally; Enter is a separate key
+                let output = sprite
+                    .command("tmux")
+                    .args(["send-keys", "-t", &session, "-l", &text])
+                    .output()
+                    .await?;
+                if !output.success() {
+                    tracing::warn!(stderr = %output.stderr_str(), "tmux send-keys failed on sprite");
+                    return Ok(false);
+                }
+                if !press_enter {
+                    return Ok(true);
+                }
+                sprite
+                    .command("tmux")
+                    .args(["send-keys", "-t", &session, "Enter"])
+                    .output()
+                    .await
+                    .map(|o| o.success())
+            })
+            .await;
+        let error = match sent {
+            Ok(true) => {
+                tracing::debug!(sprite_id = %sprite_id, "Sent input to sprite");
+                return;
+            }
+            Ok(false) => {
+                tracing::warn!(sprite_id = %sprite_id, "Failed to send input to sprite");
+                "tmux send-keys failed".to_string()
+            }
+            Err(error) => {
+                tracing::warn!(sprite_id = %sprite_id, error = %error, "Failed to reach sprite");
+                error
+            }
+        };
+        if let Some(tx) = events {
+            let _ = tx.send(Event::SpriteInputFailed { sprite_id, error }).await;
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_lists_failures() {
+        let report = BulkSendReport {
+            sent: 4,
+            sprites: 0,
+            failed: vec!["%7".to_string()],
+        };
+        assert_eq!(report.summary(), "sent to 4/5 (%7 failed)");
+
+        let report = BulkSendReport {
+            sent: 3,
+            sprites: 0,
+            failed: Vec::new(),
+        };
+        assert_eq!(report.summary(), "sent to 3/3");
+
+        let report = BulkSendReport {
+            sent: 3,
+            sprites: 2,
+            failed: vec!["%7".to_string()],
+        };
+        assert_eq!(report.summary(), "sent to 3/4 (2 via sprite; %7 failed)");
+    }
+
+    #[test]
+    fn test_permission_response_requires_pending_permission() {
+        let mut state = AppState::default();
+        state.agents.insert(
+            "%3".to_string(),
+            crate::state::Agent::new("%3".to_string(), "p".to_string()),
+        );
+        let err = respond_to_permission(&state, "%3", true, None, None).unwrap_err();
+        assert_eq!(err.to_string(), "agent is not waiting for permission");
+        let err = respond_to_permission(&state, "%404", false, None, None).unwrap_err();
+        assert_eq!(err.to_string(), "agent no longer exists");
+    }
+
+    #[test]
+    fn test_unknown_and_phantom_targets_fail() {
+        let mut state = AppState::default();
+        state.agents.insert(
+            "team:x".to_string(),
+            crate::state::Agent::new("team:x".to_string(), "p".to_string()),
+        );
+        let targets = vec!["team:x".to_string(), "%404".to_string()];
+        let report = send_input_to_agents(&state, &targets, "hello", None, None);
+        assert_eq!(report.sent, 0);
+        assert_eq!(
+            report.failed,
+            vec!["%404".to_string(), "team:x".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_copy_choices_skip_missing_details() {
+        let mut agent = crate::state::Agent::new("%3".to_string(), "p".to_string());
+        let keys: Vec<char> = copy_choices(&agent).iter().map(|(k, ..)| *k).collect();
+        assert_eq!(keys, vec!['p']);
+
+        agent.transcript_path = Some("/tmp/t.jsonl".to_string());
+        agent.cwd = Some("/src/p".to_string());
+        let choices = copy_choices(&agent);
+        assert_eq!(choices[1], ('t', "transcript", "/tmp/t.jsonl".to_string()));
+        assert_eq!(choices[2].0, 'd');
+    }
+
+    #[test]
+    fn test_multiline_sprite_input_is_one_paste() {
+        assert_eq!(bracket_multiline("hi"), "hi");
+        assert_eq!(bracket_multiline("a\nb"), "\x1b[200~a\nb\x1b[201~");
+    }
+
+    #[test]
+    fn test_open_command_quotes_dir() {
+        assert_eq!(
+            expand_open_command("code {dir}", "/src/it's here"),
+            r"code '/src/it'\''s here'"
+        );
+        assert_eq!(expand_open_command("true", "/src"), "true");
+    }
+}
@@ -0,0 +1,115 @@
+//! Command palette (`:` or `Ctrl+P`): every keymap action, fuzzy-filtered
+//!
+//! Entries list each action's current keys, so the palette doubles as a
+//! searchable help. Actions that can't run right now (nothing selected,
+//! read-only mirror) stay listed but greyed out, so the list doesn't jump
+//! around as the selection changes.
+
+use super::keymap::Action;
+use super::{App, InputMode};
+use crate::state::fuzzy_match;
+
+/// One palette row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub action: Action,
+    /// Bound keys ("-" when unbound)
+    pub keys: String,
+    /// False when the action would do nothing in the current context
+    pub available: bool,
+    /// Matched char indices in the description, for highlighting
+    pub positions: Vec<usize>,
+}
+
+impl App {
+    /// Whether `action` can do anything right now
+    fn action_available(&self, action: Action) -> bool {
+        let selected = self.state.selected_agent();
+        match action {
+            Action::Palette => false,
+            Action::Jump
+            | Action::AutoAccept
+            | Action::Spawn
+            | Action::PaneOutput
+            | Action::ApprovePending
+                if self.read_only =>
+            {
+                false
+            }
+            Action::Jump
+            | Action::CollapseTeam
+            | Action::Mark
+            | Action::Pin
+            | Action::Note
+            | Action::Copy => selected.is_some(),
+            Action::Input => !self.read_only && !self.input_targets().is_empty(),
+            Action::Checkpoints => selected.is_some_and(|a| a.sprite_id.is_some()),
+            Action::Transcript => selected.is_some_and(|a| a.transcript_path.is_some()),
+            Action::PaneOutput => selected.is_some_and(|a| !a.is_sprite),
+            Action::OpenDir => !self.read_only && selected.is_some_and(|a| a.cwd.is_some()),
+            Action::Sweep => !self.state.sweep_candidates().is_empty(),
+            Action::ApprovePending => !self.state.pending_permissions(None).is_empty(),
+            _ => true,
+        }
+    }
+
+    /// Actions matching the palette query, best match first
+    ///
+    /// With an empty query every action is listed in help order.
+    pub fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let mut scored: Vec<(i32, PaletteEntry)> = Action::ALL
+            .into_iter()
+            .filter(|action| *action != Action::Palette)
+            .filter_map(|action| {
+                let description = fuzzy_match(&self.palette_query, action.description());
+                // Name matches count too ("audit_log"), without highlighting
+                let name = fuzzy_match(&self.palette_query, action.name());
+                let score = description
+                    .as_ref()
+                    .map(|(s, _)| *s)
+                    .max(name.map(|(s, _)| s))?;
+                let positions = description.map(|(_, p)| p).unwrap_or_default();
+                Some((
+                    score,
+                    PaletteEntry {
+                        action,
+                        keys: self.keymap.label(action),
+                        available: self.action_available(action),
+                        positions,
+                    },
+                ))
+            })
+            .collect();
+        // Stable sort keeps help order among equal scores
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        scored.into_iter().map(|(_, entry)| entry).collect()
+    }
+
+    /// Open the palette with an empty query
+    pub(super) fn open_palette(&mut self) {
+        self.palette_query.clear();
+        self.palette_selected = 0;
+        self.input_mode = InputMode::Palette;
+        tracing::debug!("Opened command palette");
+    }
+
+    /// Run the highlighted entry (greyed-out entries only explain themselves)
+    pub(super) fn run_palette_selection(&mut self) {
+        let Some(entry) = self
+            .palette_entries()
+            .into_iter()
+            .nth(self.palette_selected)
+        else {
+            return;
+        };
+        if !entry.available {
+            self.set_status_message(format!(
+                "{} isn't available here",
+                entry.action.description()
+            ));
+            return;
+        }
+        self.input_mode = InputMode::Normal;
+        self.run_action(entry.action);
+    }
+}
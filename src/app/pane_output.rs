@@ -0,0 +1,101 @@
+//! Pane output: the selected agent's tmux pane with its scrollback (`W`)
+//!
+//! Captured with `tmux capture-pane -e` off the UI thread each tick, colors
+//! kept. Scrolling back pauses capture so the page being read doesn't slide
+//! away under a fast-scrolling agent; `End` resumes following.
+
+use ansi_to_tui::IntoText;
+use ratatui::text::Line;
+
+/// Output captured from one pane
+#[derive(Debug, Default)]
+pub struct PaneOutput {
+    pub pane_id: String,
+    pub lines: Vec<Line<'static>>,
+    /// Lines scrolled back from the newest; 0 follows the output
+    pub scroll: usize,
+    /// Why the last capture failed (pane closed, no tmux)
+    pub error: Option<String>,
+    /// A capture is in flight
+    pub pending: bool,
+}
+
+impl PaneOutput {
+    pub fn new(pane_id: impl Into<String>) -> Self {
+        Self {
+            pane_id: pane_id.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Whether new captures replace the lines (not scrolled back)
+    pub fn following(&self) -> bool {
+        self.scroll == 0
+    }
+
+    /// Take a finished capture
+    pub fn apply(&mut self, result: Result<String, String>) {
+        self.pending = false;
+        match result {
+            Ok(text) => {
+                self.lines = parse_ansi(&text);
+                self.error = None;
+            }
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    /// Scroll back (positive) or forward, stopping at either end
+    pub fn scroll_by(&mut self, delta: isize) {
+        let max = self.lines.len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(delta).min(max);
+    }
+
+    /// Lines to show in a panel `height` rows tall
+    pub fn visible(&self, height: usize) -> &[Line<'static>] {
+        let end = self.lines.len().saturating_sub(self.scroll);
+        &self.lines[end.saturating_sub(height)..end]
+    }
+}
+
+/// Turn captured text into styled lines, dropping the blank rows tmux
+/// reports below the cursor
+fn parse_ansi(text: &str) -> Vec<Line<'static>> {
+    let mut lines = match text.as_bytes().into_text() {
+        Ok(text) => text.lines,
+        Err(e) => {
+            tracing::debug!(error = %e, "Unparseable pane colors, showing plain text");
+            text.lines()
+                .map(|line| Line::raw(line.to_string()))
+                .collect()
+        }
+    };
+    while lines.last().is_some_and(|line| line.width() == 0) {
+        lines.pop();
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_pane_output_keeps_colors_and_scrolls_back() {
+        let mut output = PaneOutput::new("%1");
+        output.apply(Ok("one\n\u{1b}[31mtwo\u{1b}[0m\nthree\n\n\n".to_string()));
+        assert_eq!(output.lines.len(), 3);
+        let two = &output.lines[1].spans[0];
+        assert_eq!(two.content, "two");
+        assert_eq!(two.style.fg, Some(Color::Red));
+
+        assert_eq!(output.visible(2), &output.lines[1..3]);
+        output.scroll_by(5);
+        assert_eq!(output.scroll, 2);
+        assert!(!output.following());
+        assert_eq!(output.visible(2), &output.lines[0..1]);
+        output.scroll_by(-10);
+        assert!(output.following());
+    }
+}
@@ -8,18 +8,23 @@
 //! 1. User presses `s` to open spawn dialog
 //! 2. Configure agent settings:
 //!    - **Project**: Local path or GitHub repo (e.g., `owner/repo`)
-//!    - **Prompt**: Initial task for the agent
+//!    - **Prompt**: Initial task for the agent, or `@FILE` to spawn a batch
+//!      (see [`spawn_batch`](super::spawn_batch))
 //!    - **Sprite**: Enable for remote VM execution (cloud)
 //! 3. Press `Enter` to spawn or `Esc` to cancel
 //!
+//! In the project field, `Up`/`Down` cycle through recently spawned projects
+//! (see [`spawn_history`](super::spawn_history)).
+//!
 //! # Execution Environments
 //!
 //! - **Local (Tmux)**: Spawns Claude Code in a new tmux pane
 //! - **Sprite (Cloud)**: Spawns on remote Fly.io VM with checkpoint support
 
+use super::spawn_history::{self, RecentProject};
+use crate::sprite::api::SpriteApi;
 use crate::sprite::config::NetworkPreset;
 use crate::tmux::TmuxController;
-use sprites::SpritesClient;
 use std::path::PathBuf;
 
 /// Number of fields in spawn dialog
@@ -45,6 +50,10 @@ pub struct SpawnState {
     pub active_field: usize,
     /// Validation error to display in the dialog
     pub validation_error: Option<String>,
+    /// Recently spawned projects, most recent first
+    pub recents: Vec<RecentProject>,
+    /// Position in the suggestions for the current mode (None = typed value)
+    pub recent_index: Option<usize>,
 }
 
 impl Default for SpawnState {
@@ -63,7 +72,73 @@ impl Default for SpawnState {
             network_preset: NetworkPreset::ClaudeOnly,
             active_field: 0,
             validation_error: None,
+            recents: Vec::new(),
+            recent_index: None,
+        }
+    }
+}
+
+impl SpawnState {
+    /// Load the recent projects list from disk
+    pub fn load_recents(&mut self) {
+        self.recents = spawn_history::load(&spawn_history::history_path());
+        self.recent_index = None;
+    }
+
+    /// Recent projects applicable to the current mode
+    ///
+    /// Local mode can only use directories; sprite mode accepts both.
+    fn suggestions(&self) -> Vec<&RecentProject> {
+        self.recents
+            .iter()
+            .filter(|r| self.use_sprite || matches!(r, RecentProject::Local(_)))
+            .collect()
+    }
+
+    /// Fill the project field with the next (or previous) recent project
+    ///
+    /// Returns `false` when there is nothing to cycle through.
+    pub fn cycle_recent(&mut self, forward: bool) -> bool {
+        let suggestions = self.suggestions();
+        let count = suggestions.len();
+        if count == 0 {
+            return false;
+        }
+        let index = match (self.recent_index, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(i), true) => (i + 1) % count,
+            (Some(i), false) => (i + count - 1) % count,
+        };
+        match suggestions[index].clone() {
+            RecentProject::Local(path) => {
+                self.project_path = path;
+                self.github_repo.clear();
+            }
+            RecentProject::Github(repo) => {
+                self.github_repo = repo;
+                self.project_path.clear();
+            }
+        }
+        self.recent_index = Some(index);
+        true
+    }
+
+    /// Hint for the project field, e.g. "↑/↓ recent 2/5: github: owner/repo"
+    pub fn recent_hint(&self) -> Option<String> {
+        let suggestions = self.suggestions();
+        if suggestions.is_empty() {
+            return None;
         }
+        Some(match self.recent_index {
+            Some(i) if i < suggestions.len() => format!(
+                " ↑/↓ recent {}/{}: {} ",
+                i + 1,
+                suggestions.len(),
+                suggestions[i].label()
+            ),
+            _ => format!(" ↑/↓ {} recent ", suggestions.len()),
+        })
     }
 }
 
@@ -107,8 +182,8 @@ pub fn validate_spawn(state: &SpawnState, has_sprites_client: bool) -> Result<()
 /// Returns an optional status message if there's an error.
 pub fn spawn_agent(
     spawn_state: &SpawnState,
-    sprites_client: Option<&SpritesClient>,
-    state: &mut crate::state::AppState,
+    sprites_client: Option<&SpriteApi>,
+    _state: &mut crate::state::AppState,
 ) -> Option<String> {
     // For sprites, we can use either a local project path OR a GitHub repo
     let use_github = spawn_state.use_sprite && !spawn_state.github_repo.is_empty();
@@ -119,8 +194,8 @@ pub fn spawn_agent(
 
     // Branch: Sprite spawning vs tmux spawning
     if spawn_state.use_sprite {
-        if let Some(client) = sprites_client {
-            spawn_sprite_agent(spawn_state, client);
+        if let Some(api) = sprites_client {
+            spawn_sprite_agent(spawn_state, api);
             return None;
         }
         tracing::error!(
@@ -134,13 +209,11 @@ pub fn spawn_agent(
     let project_path = &spawn_state.project_path;
     let prompt = &spawn_state.prompt;
 
-    spawn_tmux_agent(project_path, prompt, state);
-
-    None
+    spawn_tmux_agent(project_path, prompt).err()
 }
 
 /// Spawn agent on remote sprite
-fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
+fn spawn_sprite_agent(spawn_state: &SpawnState, api: &SpriteApi) {
     let github_repo = spawn_state.github_repo.clone();
     let project_path = spawn_state.project_path.clone();
     let prompt = spawn_state.prompt.clone();
@@ -152,7 +225,7 @@ fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
         "Spawning agent on remote sprite"
     );
 
-    let client = client.clone();
+    let api = api.clone();
 
     tokio::spawn(async move {
         // Determine sprite name and working directory
@@ -168,7 +241,10 @@ fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
 
         tracing::info!(sprite_name = %sprite_name, "Creating sprite...");
 
-        match client.create(&sprite_name).await {
+        match api
+            .call_once("create sprite", || api.client().create(&sprite_name))
+            .await
+        {
             Ok(sprite) => {
                 tracing::info!(sprite_name = %sprite_name, "Sprite created");
 
@@ -182,6 +258,7 @@ fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
 
                     let clone_target = normalize_github_repo(&github_repo);
 
+                    // Runs outside SpriteApi: a large repo can outlast the API timeout
                     let clone_result = sprite
                         .command("gh")
                         .arg("repo")
@@ -234,14 +311,28 @@ fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
                     tmux_session, work_dir, claude_cmd, tmux_session
                 );
 
-                match sprite
-                    .command("bash")
-                    .arg("-c")
-                    .arg(&tmux_cmd)
-                    .spawn()
+                match api
+                    .call_once("start claude", || async {
+                        sprite
+                            .command("bash")
+                            .arg("-c")
+                            .arg(&tmux_cmd)
+                            .spawn()
+                            .await
+                    })
                     .await
                 {
                     Ok(_) => {
+                        let recent = if github_repo.is_empty() {
+                            RecentProject::Local(project_path)
+                        } else {
+                            RecentProject::Github(github_repo)
+                        };
+                        // File I/O: keep it off the async runtime
+                        let _ = tokio::task::spawn_blocking(move || {
+                            spawn_history::record(&spawn_history::history_path(), recent);
+                        })
+                        .await;
                         tracing::info!(
                             sprite_name = %sprite_name,
                             tmux_session = %tmux_session,
@@ -270,8 +361,8 @@ fn spawn_sprite_agent(spawn_state: &SpawnState, client: &SpritesClient) {
     });
 }
 
-/// Spawn agent in local tmux pane
-fn spawn_tmux_agent(project_path: &str, prompt: &str, _state: &mut crate::state::AppState) {
+/// Spawn agent in local tmux pane, returning its pane id
+pub(super) fn spawn_tmux_agent(project_path: &str, prompt: &str) -> Result<String, String> {
     let working_dir = PathBuf::from(project_path);
 
     tracing::info!(
@@ -288,12 +379,18 @@ fn spawn_tmux_agent(project_path: &str, prompt: &str, _state: &mut crate::state:
     match pane_result {
         Ok(pane_id) => {
             tracing::info!(pane_id = %pane_id, "Created new tmux pane");
+            spawn_history::record(
+                &spawn_history::history_path(),
+                RecentProject::Local(project_path.to_string()),
+            );
 
             // Start Claude Code in the new pane
             start_claude_in_pane(&pane_id, prompt);
+            Ok(pane_id)
         }
         Err(e) => {
             tracing::error!(error = %e, "Failed to create tmux pane");
+            Err(format!("Failed to create tmux pane: {e}"))
         }
     }
 }
@@ -411,6 +508,36 @@ mod tests {
         assert_eq!(expand_tilde("relative/path"), "relative/path");
     }
 
+    #[test]
+    fn test_cycle_recent_filters_by_mode() {
+        let mut state = SpawnState {
+            recents: vec![
+                RecentProject::Github("owner/repo".to_string()),
+                RecentProject::Local("/a".to_string()),
+                RecentProject::Local("/b".to_string()),
+            ],
+            ..SpawnState::default()
+        };
+
+        // Local mode skips GitHub entries
+        assert!(state.cycle_recent(true));
+        assert_eq!(state.project_path, "/a");
+        assert!(state.cycle_recent(true));
+        assert_eq!(state.project_path, "/b");
+        assert!(state.cycle_recent(true));
+        assert_eq!(state.project_path, "/a");
+
+        // Sprite mode offers both kinds
+        state.use_sprite = true;
+        state.recent_index = None;
+        assert!(state.cycle_recent(true));
+        assert_eq!(state.github_repo, "owner/repo");
+        assert!(state.project_path.is_empty());
+
+        state.recents.clear();
+        assert!(!state.cycle_recent(false));
+    }
+
     #[test]
     fn test_extract_repo_name() {
         assert_eq!(extract_repo_name("owner/repo"), "repo");
@@ -0,0 +1,305 @@
+//! Batch spawn: several local agents from one file
+//!
+//! Typing `@FILE` as the spawn dialog's prompt spawns one agent per entry in
+//! FILE (relative paths are resolved against the project directory). Two
+//! formats are accepted:
+//!
+//! - plain text: one prompt per line; blank lines and `#` comments skipped
+//! - `.toml`: an `[[agent]]` table per agent with a `prompt` and an optional
+//!   `branch`
+//!
+//! ```toml
+//! [[agent]]
+//! prompt = "Profile the parser"
+//! branch = "perf-parser"
+//!
+//! [[agent]]
+//! prompt = "Write fuzz targets"
+//! ```
+//!
+//! An agent with a `branch` runs in its own git worktree, a sibling of the
+//! project directory named `<project>-<branch>`. It is created with the branch
+//! on first use and reused afterwards.
+//!
+//! Agents start [`STAGGER`] apart on a blocking thread, so tmux and Claude
+//! Code startup don't all land at once and the UI stays responsive. The
+//! outcome comes back as [`Event::BatchSpawned`] for the footer, with any
+//! failure tagged by its line (or agent number, for TOML).
+
+use super::spawn::{expand_tilde, spawn_tmux_agent};
+use crate::event::Event;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Most agents one file may spawn
+pub const MAX_BATCH: usize = 12;
+
+/// Delay between starting consecutive agents
+const STAGGER: Duration = Duration::from_secs(2);
+
+/// One agent to spawn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchEntry {
+    /// Where it came from, e.g. "line 3" or "agent 2"
+    pub origin: String,
+    pub prompt: String,
+    /// Run in a worktree on this branch
+    pub branch: Option<String>,
+}
+
+/// Outcome of a batch spawn
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BatchReport {
+    /// Agents whose pane was created
+    pub spawned: usize,
+    /// Failed entries as "origin: error", in file order
+    pub failed: Vec<String>,
+}
+
+impl BatchReport {
+    /// Footer summary, e.g. "spawned 4/5 agents (line 3: branch exists)"
+    pub fn summary(&self) -> String {
+        let total = self.spawned + self.failed.len();
+        if self.failed.is_empty() {
+            format!("spawned {}/{total} agents", self.spawned)
+        } else {
+            format!(
+                "spawned {}/{total} agents ({})",
+                self.spawned,
+                self.failed.join("; ")
+            )
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlBatch {
+    #[serde(default)]
+    agent: Vec<TomlAgent>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TomlAgent {
+    prompt: String,
+    branch: Option<String>,
+}
+
+/// The batch file named by a `@FILE` prompt, resolved against `project`
+pub fn batch_file(prompt: &str, project: &str) -> Option<PathBuf> {
+    let file = prompt.trim().strip_prefix('@')?.trim();
+    if file.is_empty() {
+        return None;
+    }
+    let path = PathBuf::from(expand_tilde(file));
+    Some(if path.is_absolute() {
+        path
+    } else {
+        Path::new(&expand_tilde(project)).join(path)
+    })
+}
+
+/// Parse a batch file's contents (TOML when `path` ends in `.toml`)
+pub fn parse(path: &Path, content: &str) -> Result<Vec<BatchEntry>, String> {
+    let entries: Vec<BatchEntry> = if path.extension().is_some_and(|ext| ext == "toml") {
+        let batch: TomlBatch =
+            toml::from_str(content).map_err(|e| format!("{}: {}", path.display(), e.message()))?;
+        batch
+            .agent
+            .into_iter()
+            .enumerate()
+            .map(|(i, agent)| BatchEntry {
+                origin: format!("agent {}", i + 1),
+                prompt: agent.prompt.trim().to_string(),
+                branch: agent.branch.filter(|b| !b.trim().is_empty()),
+            })
+            .collect()
+    } else {
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+            .map(|(i, line)| BatchEntry {
+                origin: format!("line {}", i + 1),
+                prompt: line.trim().to_string(),
+                branch: None,
+            })
+            .collect()
+    };
+    match entries.len() {
+        0 => Err(format!("{}: no agents listed", path.display())),
+        n if n > MAX_BATCH => Err(format!(
+            "{}: {n} agents listed, at most {MAX_BATCH} per batch",
+            path.display()
+        )),
+        _ => Ok(entries),
+    }
+}
+
+/// Read and parse a batch file
+pub fn load(path: &Path) -> Result<Vec<BatchEntry>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    parse(path, &content)
+}
+
+/// Spawn every entry in the background, staggered; the report arrives on `events`
+pub fn spawn_all(project: String, entries: Vec<BatchEntry>, events: Option<mpsc::Sender<Event>>) {
+    tracing::info!(project = %project, count = entries.len(), "Batch spawning agents");
+    tokio::task::spawn_blocking(move || {
+        let mut report = BatchReport::default();
+        for (i, entry) in entries.iter().enumerate() {
+            if i > 0 {
+                std::thread::sleep(STAGGER);
+            }
+            let result = match &entry.branch {
+                Some(branch) => prepare_worktree(&project, branch),
+                None => Ok(project.clone()),
+            }
+            .and_then(|dir| spawn_tmux_agent(&dir, &entry.prompt));
+            match result {
+                Ok(_) => report.spawned += 1,
+                Err(e) => {
+                    tracing::warn!(origin = %entry.origin, error = %e, "Batch spawn entry failed");
+                    report.failed.push(format!("{}: {e}", entry.origin));
+                }
+            }
+        }
+        tracing::info!(summary = %report.summary(), "Batch spawn finished");
+        if let Some(tx) = events {
+            let _ = tx.blocking_send(Event::BatchSpawned {
+                spawned: report.spawned,
+                failed: report.failed,
+            });
+        }
+    });
+}
+
+/// Worktree directory for `branch`: `<project>-<branch>` beside the project
+fn worktree_dir(project: &Path, branch: &str) -> PathBuf {
+    let name = project
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "worktree".to_string());
+    let suffix: String = branch
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    project.with_file_name(format!("{name}-{suffix}"))
+}
+
+/// Create (or reuse) the worktree for `branch`, returning its directory
+fn prepare_worktree(project: &str, branch: &str) -> Result<String, String> {
+    // A leading dash would reach git as an option (`--detach`, `-f`, ...)
+    if branch.starts_with('-') {
+        return Err(format!("invalid branch name: {branch}"));
+    }
+    let project = PathBuf::from(expand_tilde(project));
+    let dir = worktree_dir(&project, branch);
+    if dir.exists() {
+        return Ok(dir.to_string_lossy().into_owned());
+    }
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .arg("-C")
+            .arg(&project)
+            .args(args)
+            .output()
+            .map_err(|e| format!("git failed to run: {e}"))
+    };
+    let dir_arg = dir.to_string_lossy();
+    // New branch first; an existing one is checked out instead
+    let mut output = git(&["worktree", "add", "-b", branch, "--", &dir_arg])?;
+    if !output.status.success() {
+        output = git(&["worktree", "add", "--", &dir_arg, branch])?;
+    }
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git worktree add: {}", stderr.trim()));
+    }
+    tracing::info!(branch = %branch, dir = %dir.display(), "Created worktree");
+    Ok(dir.to_string_lossy().into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_text_and_toml_batches() {
+        let text = "# workers\nfix the parser\n\n  add tests  \n";
+        let entries = parse(Path::new("jobs.txt"), text).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                BatchEntry {
+                    origin: "line 2".to_string(),
+                    prompt: "fix the parser".to_string(),
+                    branch: None,
+                },
+                BatchEntry {
+                    origin: "line 4".to_string(),
+                    prompt: "add tests".to_string(),
+                    branch: None,
+                },
+            ]
+        );
+
+        let toml = "[[agent]]\nprompt = \"a\"\nbranch = \"feat/a\"\n\n[[agent]]\nprompt = \"b\"\n";
+        let entries = parse(Path::new("jobs.toml"), toml).unwrap();
+        assert_eq!(entries[0].branch.as_deref(), Some("feat/a"));
+        assert_eq!(entries[1].origin, "agent 2");
+
+        assert!(parse(
+            Path::new("jobs.toml"),
+            "[[agent]]\nprompt = \"a\"\nrole = \"x\"\n"
+        )
+        .unwrap_err()
+        .contains("role"));
+        assert!(parse(Path::new("jobs.txt"), "# only comments\n").is_err());
+        let many = "task\n".repeat(MAX_BATCH + 1);
+        assert!(parse(Path::new("jobs.txt"), &many).is_err());
+    }
+
+    #[test]
+    fn test_batch_file_and_worktree_paths() {
+        assert_eq!(batch_file("fix it", "/p"), None);
+        assert_eq!(batch_file("@", "/p"), None);
+        assert_eq!(
+            batch_file("@jobs.toml", "/src/app"),
+            Some(PathBuf::from("/src/app/jobs.toml"))
+        );
+        assert_eq!(
+            batch_file(" @/tmp/jobs.txt", "/src/app"),
+            Some(PathBuf::from("/tmp/jobs.txt"))
+        );
+        assert_eq!(
+            worktree_dir(Path::new("/src/app"), "feat/x y"),
+            PathBuf::from("/src/app-feat-x-y")
+        );
+        // Rejected before git ever sees it as an option
+        assert!(prepare_worktree("/src/app", "--detach").is_err());
+    }
+
+    #[test]
+    fn test_report_summary_names_failures() {
+        let report = BatchReport {
+            spawned: 2,
+            failed: vec!["line 3: Failed to create tmux pane: no space".to_string()],
+        };
+        assert_eq!(
+            report.summary(),
+            "spawned 2/3 agents (line 3: Failed to create tmux pane: no space)"
+        );
+    }
+}
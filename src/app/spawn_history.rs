@@ -0,0 +1,123 @@
+//! Recently spawned projects for the spawn dialog
+//!
+//! After each successful spawn the project is recorded in
+//! `~/.cache/rehoboam/spawn_history.json` (most recent first, at most
+//! [`MAX_RECENT_PROJECTS`] distinct entries). Local directories that no longer
+//! exist are pruned on load; GitHub repos are always kept.
+
+use super::spawn::expand_tilde;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Distinct projects remembered
+pub const MAX_RECENT_PROJECTS: usize = 10;
+
+/// A project the user spawned an agent in
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value", rename_all = "lowercase")]
+pub enum RecentProject {
+    /// Local directory (as typed, may start with `~/`)
+    Local(String),
+    /// GitHub repository cloned onto a sprite
+    Github(String),
+}
+
+impl RecentProject {
+    /// Label shown in the spawn dialog
+    pub fn label(&self) -> String {
+        match self {
+            RecentProject::Local(path) => format!("dir: {path}"),
+            RecentProject::Github(repo) => format!("github: {repo}"),
+        }
+    }
+}
+
+/// Default location of the spawn history file
+pub fn history_path() -> PathBuf {
+    directories::BaseDirs::new().map_or_else(
+        || PathBuf::from("/tmp/rehoboam/spawn_history.json"),
+        |dirs| dirs.cache_dir().join("rehoboam").join("spawn_history.json"),
+    )
+}
+
+/// Load recent projects, dropping local directories that no longer exist
+pub fn load(path: &Path) -> Vec<RecentProject> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let entries: Vec<RecentProject> = match serde_json::from_str(&content) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::warn!(error = %e, "Ignoring unreadable spawn history");
+            return Vec::new();
+        }
+    };
+    entries
+        .into_iter()
+        .filter(|entry| match entry {
+            RecentProject::Local(dir) => Path::new(&expand_tilde(dir)).exists(),
+            RecentProject::Github(_) => true,
+        })
+        .take(MAX_RECENT_PROJECTS)
+        .collect()
+}
+
+/// Move `entry` to the front of the history file
+pub fn record(path: &Path, entry: RecentProject) {
+    let mut entries = load(path);
+    entries.retain(|e| *e != entry);
+    entries.insert(0, entry);
+    entries.truncate(MAX_RECENT_PROJECTS);
+
+    let result = serde_json::to_vec(&entries)
+        .map_err(std::io::Error::other)
+        .and_then(|json| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, json)
+        });
+    if let Err(e) = result {
+        tracing::warn!(error = %e, path = %path.display(), "Failed to save spawn history");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_dedups_and_prunes_missing_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spawn_history.json");
+        let existing = dir.path().display().to_string();
+
+        record(&path, RecentProject::Local(existing.clone()));
+        record(&path, RecentProject::Github("owner/repo".to_string()));
+        record(&path, RecentProject::Local("/no/such/dir".to_string()));
+        record(&path, RecentProject::Local(existing.clone()));
+
+        assert_eq!(
+            load(&path),
+            vec![
+                RecentProject::Local(existing),
+                RecentProject::Github("owner/repo".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_record_caps_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spawn_history.json");
+        for i in 0..15 {
+            record(&path, RecentProject::Github(format!("owner/repo{i}")));
+        }
+        let entries = load(&path);
+        assert_eq!(entries.len(), MAX_RECENT_PROJECTS);
+        assert_eq!(
+            entries[0],
+            RecentProject::Github("owner/repo14".to_string())
+        );
+    }
+}
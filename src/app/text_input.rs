@@ -0,0 +1,220 @@
+//! Editable multi-line text for the input dialog
+//!
+//! A `String` plus a cursor kept on a char boundary. Movement and deletion
+//! are char-aware so non-ASCII prompts edit correctly; `Home`/`End` and
+//! word-delete work within the cursor's line.
+
+/// Text being composed, with a cursor
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextInput {
+    text: String,
+    /// Byte offset into `text`, always on a char boundary
+    cursor: usize,
+}
+
+impl TextInput {
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn clear(&mut self) {
+        self.text.clear();
+        self.cursor = 0;
+    }
+
+    /// Take the text, leaving the input empty
+    pub fn take(&mut self) -> String {
+        self.cursor = 0;
+        std::mem::take(&mut self.text)
+    }
+
+    /// Text before and after the cursor
+    pub fn split_at_cursor(&self) -> (&str, &str) {
+        self.text.split_at(self.cursor)
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.text.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    /// Insert pasted text, normalising CRLF and lone CR line endings
+    pub fn insert_str(&mut self, s: &str) {
+        let s = s.replace("\r\n", "\n").replace('\r', "\n");
+        self.text.insert_str(self.cursor, &s);
+        self.cursor += s.len();
+    }
+
+    /// Delete the char before the cursor
+    pub fn backspace(&mut self) {
+        if let Some(c) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+            self.text.remove(self.cursor);
+        }
+    }
+
+    /// Delete the char under the cursor
+    pub fn delete(&mut self) {
+        if self.cursor < self.text.len() {
+            self.text.remove(self.cursor);
+        }
+    }
+
+    pub fn left(&mut self) {
+        if let Some(c) = self.text[..self.cursor].chars().next_back() {
+            self.cursor -= c.len_utf8();
+        }
+    }
+
+    pub fn right(&mut self) {
+        if let Some(c) = self.text[self.cursor..].chars().next() {
+            self.cursor += c.len_utf8();
+        }
+    }
+
+    /// Start of the cursor's line
+    pub fn home(&mut self) {
+        self.cursor = self.line_start();
+    }
+
+    /// End of the cursor's line
+    pub fn end(&mut self) {
+        self.cursor = self.line_end();
+    }
+
+    /// Same column on the previous line (clamped to its length)
+    pub fn up(&mut self) {
+        let start = self.line_start();
+        if start == 0 {
+            return;
+        }
+        let column = self.column();
+        self.cursor = start - 1;
+        self.cursor = self.line_start();
+        self.advance_within_line(column);
+    }
+
+    /// Same column on the next line (clamped to its length)
+    pub fn down(&mut self) {
+        let end = self.line_end();
+        if end == self.text.len() {
+            return;
+        }
+        let column = self.column();
+        self.cursor = end + 1;
+        self.advance_within_line(column);
+    }
+
+    /// Delete back to the start of the previous word (`Ctrl+W`)
+    ///
+    /// Whitespace before the cursor goes with the word, and a newline
+    /// directly before the cursor is deleted on its own.
+    pub fn delete_word(&mut self) {
+        let before = &self.text[..self.cursor];
+        if before.ends_with('\n') {
+            self.backspace();
+            return;
+        }
+        let trimmed = before.trim_end_matches([' ', '\t']);
+        let start = trimmed
+            .char_indices()
+            .rfind(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    /// Delete back to the start of the cursor's line (`Ctrl+U`)
+    pub fn delete_to_line_start(&mut self) {
+        let start = self.line_start();
+        self.text.replace_range(start..self.cursor, "");
+        self.cursor = start;
+    }
+
+    fn line_start(&self) -> usize {
+        self.text[..self.cursor].rfind('\n').map_or(0, |i| i + 1)
+    }
+
+    fn line_end(&self) -> usize {
+        self.text[self.cursor..]
+            .find('\n')
+            .map_or(self.text.len(), |i| self.cursor + i)
+    }
+
+    /// Chars between the start of the line and the cursor
+    fn column(&self) -> usize {
+        self.text[self.line_start()..self.cursor].chars().count()
+    }
+
+    /// Move right up to `chars`, stopping at the end of the line
+    fn advance_within_line(&mut self, chars: usize) {
+        let end = self.line_end();
+        let offset = self.text[self.cursor..end]
+            .char_indices()
+            .nth(chars)
+            .map_or(end - self.cursor, |(i, _)| i);
+        self.cursor += offset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(s: &str) -> TextInput {
+        let mut input = TextInput::default();
+        input.insert_str(s);
+        input
+    }
+
+    #[test]
+    fn test_edit_mid_string() {
+        let mut input = typed("héllo");
+        input.left();
+        input.left();
+        input.insert('X');
+        assert_eq!(input.as_str(), "hélXlo");
+        input.backspace();
+        input.backspace();
+        assert_eq!(input.as_str(), "hélo");
+        assert_eq!(input.split_at_cursor(), ("hé", "lo"));
+        input.home();
+        input.delete();
+        assert_eq!(input.split_at_cursor(), ("", "élo"));
+    }
+
+    #[test]
+    fn test_lines_and_vertical_movement() {
+        let mut input = typed("first line\r\nab\nthird");
+        assert_eq!(input.as_str(), "first line\nab\nthird");
+        input.up();
+        assert_eq!(
+            input.split_at_cursor().1,
+            "\nthird",
+            "clamped to the short line"
+        );
+        input.up();
+        assert_eq!(input.split_at_cursor().1, "rst line\nab\nthird");
+        input.end();
+        input.down();
+        assert_eq!(input.split_at_cursor().1, "\nthird");
+        input.down();
+        assert_eq!(input.split_at_cursor().1, "ird");
+    }
+
+    #[test]
+    fn test_delete_word_and_line() {
+        let mut input = typed("fix the  bug");
+        input.delete_word();
+        assert_eq!(input.as_str(), "fix the  ");
+        input.delete_word();
+        assert_eq!(input.as_str(), "fix ");
+        input.insert_str("\nnext");
+        input.delete_to_line_start();
+        assert_eq!(input.as_str(), "fix \n");
+        input.delete_word();
+        assert_eq!(input.as_str(), "fix ");
+        assert_eq!(input.take(), "fix ");
+        assert_eq!(input.split_at_cursor(), ("", ""));
+    }
+}
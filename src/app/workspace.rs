@@ -0,0 +1,275 @@
+//! Named workspaces: saved board layouts (`w`, `--workspace NAME`)
+//!
+//! A workspace bundles the layout that otherwise resets on every launch:
+//! minimap or cards, card density, grouping and sort, the attention filter
+//! and search, the pane output view and the project it follows, collapsed
+//! teams and pins. Workspaces live in `workspaces.toml` next to
+//! `config.toml`, one table per name, and can be written by hand:
+//!
+//! ```toml
+//! [triage]
+//! attention_only = true
+//! density = "compact"
+//! ```
+//!
+//! Fields left out are left alone on load. A project, team or pin that is not
+//! on the board any more is not an error: pins and collapsed teams apply once
+//! the agent reports again, and the status message says what was missing.
+//! Saving rewrites the file (comments in it are lost); `config.toml` is never
+//! written.
+
+use super::App;
+use crate::config::{CardSort, Density, GroupBy, RehoboamConfig};
+use color_eyre::eyre;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+/// One saved layout; `None` fields keep the current setting
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Workspace {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimap: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub density: Option<Density>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<GroupBy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card_sort: Option<CardSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attention_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
+    /// Show the pane output view (`W`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pane_output: Option<bool>,
+    /// Select the first agent of this project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collapsed_teams: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pinned: Option<Vec<String>>,
+}
+
+/// Workspaces by name
+pub type Workspaces = BTreeMap<String, Workspace>;
+
+/// Default location of the workspaces file (beside `config.toml`)
+pub fn workspaces_path() -> PathBuf {
+    RehoboamConfig::default_path().with_file_name("workspaces.toml")
+}
+
+/// Load saved workspaces; a missing or unreadable file yields none
+pub fn load(path: &Path) -> Workspaces {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Workspaces::new();
+    };
+    toml::from_str(&content).unwrap_or_else(|e| {
+        tracing::warn!(error = %e, path = %path.display(), "Ignoring unreadable workspaces");
+        Workspaces::new()
+    })
+}
+
+/// Write all workspaces to `path` (atomic via temp file + rename)
+pub fn save(path: &Path, workspaces: &Workspaces) -> eyre::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    std::fs::write(&tmp, toml::to_string(workspaces)?)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+impl App {
+    /// The current layout, every field set
+    fn capture_workspace(&self) -> Workspace {
+        Workspace {
+            minimap: Some(self.show_minimap),
+            density: Some(self.density),
+            group_by: Some(self.state.group_by),
+            card_sort: Some(self.state.card_sort),
+            attention_only: Some(self.attention_only),
+            search: Some(self.search_query.clone()),
+            pane_output: Some(self.show_pane_output),
+            project: self.state.selected_agent().map(|a| a.project.clone()),
+            collapsed_teams: Some(self.state.collapsed_teams.iter().cloned().collect()),
+            pinned: Some(self.state.pinned.iter().cloned().collect()),
+        }
+    }
+
+    /// Save the current layout as `name`, replacing any workspace of that name
+    pub(super) fn save_workspace(&mut self, name: &str) {
+        if name.is_empty() {
+            self.set_status_message("Workspace not saved: no name given");
+            return;
+        }
+        let mut workspaces = self.workspaces.clone();
+        workspaces.insert(name.to_string(), self.capture_workspace());
+        match save(&self.workspaces_path, &workspaces) {
+            Ok(()) => {
+                self.workspaces = workspaces;
+                self.set_status_message(format!("workspace {name} saved"));
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to save workspaces");
+                self.set_status_message(format!("workspace {name} not saved: {e}"));
+            }
+        }
+    }
+
+    /// Switch to the workspace called `name`, reporting what it names that
+    /// is not on the board
+    pub fn load_workspace(&mut self, name: &str) {
+        let Some(workspace) = self.workspaces.get(name).cloned() else {
+            tracing::warn!(workspace = name, "No such workspace, keeping the layout");
+            self.set_status_message(format!("no workspace named {name}"));
+            return;
+        };
+        let mut missing = Vec::new();
+
+        if let Some(minimap) = workspace.minimap {
+            self.show_minimap = minimap;
+        }
+        if let Some(density) = workspace.density {
+            self.density = density;
+        }
+        if let Some(group_by) = workspace.group_by {
+            self.state.group_by = group_by;
+        }
+        if let Some(card_sort) = workspace.card_sort {
+            self.state.card_sort = card_sort;
+        }
+        if let Some(attention_only) = workspace.attention_only {
+            self.attention_only = attention_only;
+        }
+        if let Some(search) = workspace.search {
+            self.search_query = search;
+        }
+        if let Some(pane_output) = workspace.pane_output {
+            self.show_pane_output = pane_output;
+            self.pane_output = None;
+        }
+        if let Some(teams) = workspace.collapsed_teams {
+            let present: BTreeSet<&str> = self
+                .state
+                .agents
+                .values()
+                .map(|a| self.state.display_group(a))
+                .collect();
+            let absent = teams
+                .iter()
+                .filter(|t| !present.contains(t.as_str()))
+                .count();
+            if absent > 0 {
+                missing.push(plural(absent, "team"));
+            }
+            self.state.collapsed_teams = teams.into_iter().collect();
+        }
+        if let Some(pinned) = workspace.pinned {
+            let absent = pinned
+                .iter()
+                .filter(|p| !self.state.agents.contains_key(*p))
+                .count();
+            if absent > 0 {
+                missing.push(plural(absent, "pin"));
+            }
+            self.state.pinned = pinned.into_iter().collect();
+        }
+
+        // Follow the project if it is still around, else keep the selection
+        // unless the new filter hides it
+        let (project_pane, hidden) = {
+            let filter = self.agent_filter();
+            let project_pane = workspace.project.as_deref().and_then(|project| {
+                self.state
+                    .agents_grouped(&filter)
+                    .into_iter()
+                    .flat_map(|(_, agents)| agents)
+                    .find(|a| a.project == project)
+                    .map(|a| a.pane_id.clone())
+            });
+            let hidden = self
+                .state
+                .selected_agent()
+                .is_some_and(|a| !filter.matches(a));
+            (project_pane, hidden)
+        };
+        if project_pane.is_some() {
+            self.state.selected_pane_id = project_pane;
+        } else {
+            if let Some(project) = workspace.project {
+                missing.push(format!("project {project}"));
+            }
+            if hidden {
+                self.state.selected_pane_id = None;
+            }
+        }
+
+        tracing::info!(workspace = name, "Loaded workspace");
+        self.set_status_message(if missing.is_empty() {
+            format!("workspace {name} loaded")
+        } else {
+            format!(
+                "workspace {name} loaded ({} not on the board)",
+                missing.join(", ")
+            )
+        });
+    }
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {noun}")
+    } else {
+        format!("{count} {noun}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Agent;
+
+    #[test]
+    fn test_workspace_round_trip_degrades_for_missing_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = RehoboamConfig::default();
+        config.persistence.enabled = false;
+        let mut app = App::new(false, None, &config);
+        app.workspaces = Workspaces::new();
+        app.workspaces_path = dir.path().join("workspaces.toml");
+        for (pane, project) in [("%1", "api"), ("%2", "web")] {
+            let agent = Agent::new(pane.to_string(), project.to_string());
+            app.state.agents.insert(pane.to_string(), agent);
+        }
+
+        app.state.selected_pane_id = Some("%2".to_string());
+        app.attention_only = false;
+        app.density = Density::Compact;
+        app.state.toggle_pinned("%2");
+        app.save_workspace("triage");
+        assert_eq!(load(&app.workspaces_path), app.workspaces);
+
+        app.density = Density::Comfortable;
+        app.state.pinned.clear();
+        app.state.selected_pane_id = Some("%1".to_string());
+        app.load_workspace("triage");
+        assert_eq!(app.density, Density::Compact);
+        assert!(app.state.pinned.contains("%2"));
+        assert_eq!(app.state.selected_pane_id.as_deref(), Some("%2"));
+
+        // The project and pinned agent left the board: load anyway, say so
+        app.state.agents.remove("%2");
+        app.load_workspace("triage");
+        assert_eq!(
+            app.status_message.as_ref().map(|(m, _)| m.as_str()),
+            Some("workspace triage loaded (1 pin, project web not on the board)")
+        );
+
+        app.load_workspace("nope");
+        assert_eq!(app.density, Density::Compact);
+    }
+}
@@ -0,0 +1,173 @@
+//! `rehoboam check`: validate the config and hook setup without the TUI
+//!
+//! Every check runs even after a failure, so one report lists everything to
+//! fix. The config is parsed strictly here (the TUI falls back to defaults on
+//! a parse error), and `[theme]`/`[keymap]` entries the TUI would ignore with
+//! a log warning are reported as problems.
+
+use crate::app::Keymap;
+use crate::config::{Palette, RehoboamConfig};
+use crate::init;
+use std::path::{Path, PathBuf};
+
+/// Outcome of one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckLine {
+    pub ok: bool,
+    pub message: String,
+}
+
+/// Everything `rehoboam check` found
+#[derive(Debug, Default)]
+pub struct CheckReport {
+    pub lines: Vec<CheckLine>,
+}
+
+impl CheckReport {
+    fn pass(&mut self, message: impl Into<String>) {
+        self.lines.push(CheckLine {
+            ok: true,
+            message: message.into(),
+        });
+    }
+
+    fn fail(&mut self, message: impl Into<String>) {
+        self.lines.push(CheckLine {
+            ok: false,
+            message: message.into(),
+        });
+    }
+
+    pub fn problems(&self) -> usize {
+        self.lines.iter().filter(|line| !line.ok).count()
+    }
+
+    /// "✓ ..." / "✗ ..." lines, multi-line messages indented under their mark
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for line in &self.lines {
+            let mark = if line.ok { "\u{2713}" } else { "\u{2717}" }; // ✓ ✗
+            for (i, text) in line.message.lines().enumerate() {
+                let prefix = if i == 0 { mark } else { " " };
+                out.push_str(&format!("{prefix} {text}\n"));
+            }
+        }
+        out
+    }
+}
+
+/// Check the config at `config_path`, the `sockets`' directories and the
+/// hooks for `project`
+pub fn run(
+    config_path: &Path,
+    sockets: &[PathBuf],
+    project: &Path,
+    home: Option<&Path>,
+) -> CheckReport {
+    let mut report = CheckReport::default();
+    check_config(&mut report, config_path);
+    for socket in sockets {
+        check_socket_dir(&mut report, socket);
+    }
+    match init::diagnose_hooks(project, home) {
+        Some(problem) => report.fail(format!("hooks: {problem}")),
+        None => report.pass(format!("hooks: {} is wired up", project.display())),
+    }
+    report
+}
+
+/// Parse the config strictly, then the sections the TUI only warns about
+fn check_config(report: &mut CheckReport, path: &Path) {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            report.pass(format!(
+                "config: {} not found, using defaults",
+                path.display()
+            ));
+            return;
+        }
+        Err(e) => {
+            report.fail(format!("config: cannot read {}: {e}", path.display()));
+            return;
+        }
+    };
+    // toml's error already quotes the offending line with a caret
+    let config: RehoboamConfig = match toml::from_str(&content) {
+        Ok(config) => config,
+        Err(e) => {
+            report.fail(format!(
+                "config: {}\n{}",
+                path.display(),
+                e.to_string().trim_end()
+            ));
+            return;
+        }
+    };
+    report.pass(format!("config: {} parses", path.display()));
+
+    let (_, theme_problems) = Palette::from_theme_checked(&config.theme);
+    let (_, keymap_problems) = Keymap::from_config_checked(&config.keymap);
+    for (section, problems) in [("theme", theme_problems), ("keymap", keymap_problems)] {
+        if problems.is_empty() {
+            report.pass(format!("[{section}]: ok"));
+        }
+        for problem in problems {
+            report.fail(format!("[{section}]: {problem}"));
+        }
+    }
+}
+
+/// The socket's directory must exist (or be creatable) and be writable
+fn check_socket_dir(report: &mut CheckReport, socket: &Path) {
+    let Some(dir) = socket.parent().filter(|d| !d.as_os_str().is_empty()) else {
+        report.pass(format!("socket: {} (current directory)", socket.display()));
+        return;
+    };
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        report.fail(format!("socket: cannot create {}: {e}", dir.display()));
+        return;
+    }
+    // Permission bits don't tell the whole story (read-only mounts, ACLs)
+    let probe = dir.join(format!(".rehoboam-check-{}", std::process::id()));
+    match std::fs::File::create(&probe) {
+        Ok(_) => {
+            let _ = std::fs::remove_file(&probe);
+            report.pass(format!("socket: {} is writable", dir.display()));
+        }
+        Err(e) => report.fail(format!("socket: {} is not writable: {e}", dir.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_reports_parse_error_and_ignored_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("config.toml");
+        let sockets = [dir.path().join("run").join("rehoboam.sock")];
+
+        std::fs::write(&config, "[ui]\ndensity = \"tiny\"\n").unwrap();
+        let report = run(&config, &sockets, dir.path(), None);
+        let parse = &report.lines[0];
+        assert!(!parse.ok);
+        assert!(parse.message.contains("line 2"), "{}", parse.message);
+        assert!(report
+            .lines
+            .iter()
+            .any(|l| l.ok && l.message.contains("writable")));
+
+        std::fs::write(
+            &config,
+            "[theme]\nfg = \"#zzzzzz\"\n[keymap]\nquit = \"Q\"\nfly = \"F\"\n",
+        )
+        .unwrap();
+        let report = run(&config, &sockets, dir.path(), None);
+        assert_eq!(report.problems(), 2, "{}", report.render());
+        assert!(report
+            .render()
+            .contains("\u{2717} [keymap]: Unknown keymap action \"fly\""));
+    }
+}
@@ -6,7 +6,7 @@
 use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::{generate, Shell};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Get default socket path, preferring XDG_RUNTIME_DIR on Linux
 fn default_socket_path() -> PathBuf {
@@ -35,14 +35,18 @@ pub struct Cli {
     pub command: Option<Commands>,
 
     /// Socket path for receiving hook events (default: $XDG_RUNTIME_DIR/rehoboam.sock or /tmp/rehoboam.sock)
+    ///
+    /// Repeat to listen on several sockets (e.g. separate personal and work
+    /// Claude Code setups); agents are then tagged with the socket they came from.
+    /// Client subcommands (hook, heartbeat, export-state) use the first one.
     #[arg(
         short,
         long,
         env = "REHOBOAM_SOCKET",
-        default_value_os_t = default_socket_path(),
+        default_values_os_t = vec![default_socket_path()],
         global = true
     )]
-    pub socket: PathBuf,
+    pub socket: Vec<PathBuf>,
 
     /// Log level (trace, debug, info, warn, error)
     #[arg(short, long, env = "RUST_LOG", default_value = "info", global = true)]
@@ -81,6 +85,98 @@ pub struct Cli {
         global = true
     )]
     pub sprite_ws_port: u16,
+
+    // Mirror mode options
+    /// Rebroadcast hook events to TCP mirror clients on this address (e.g. 0.0.0.0:7878)
+    #[arg(long, value_name = "ADDR", conflicts_with = "connect")]
+    pub listen_tcp: Option<String>,
+
+    /// Mirror a remote rehoboam started with --listen-tcp (read-only TUI)
+    #[arg(long, value_name = "ADDR")]
+    pub connect: Option<String>,
+
+    /// Shared token for --listen-tcp / --connect
+    #[arg(long, env = "REHOBOAM_TCP_TOKEN", hide_env_values = true)]
+    pub tcp_token: Option<String>,
+
+    /// Replay newline-delimited HookEvent JSON (e.g. from --record) instead of listening
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["connect", "listen_tcp"])]
+    pub replay: Option<PathBuf>,
+
+    /// Replay speed multiplier (0 = as fast as possible)
+    #[arg(long, default_value_t = 1.0, requires = "replay")]
+    pub replay_speed: f64,
+
+    /// Accept JSON-RPC commands (spawn, approve, send_input, ...) on this Unix socket
+    ///
+    /// Separate from the hook socket and created owner-only (0600).
+    /// See `src/event/rpc.rs` for the protocol.
+    #[arg(long, value_name = "PATH", env = "REHOBOAM_CONTROL_SOCKET")]
+    pub control_socket: Option<PathBuf>,
+
+    /// Serve Prometheus metrics on http://127.0.0.1:PORT/metrics
+    #[arg(long, value_name = "PORT")]
+    pub metrics_port: Option<u16>,
+
+    /// Serve a read-only web dashboard on http://127.0.0.1:PORT/
+    ///
+    /// Live updates stream the hook events received on this TUI's sockets.
+    #[arg(long, value_name = "PORT")]
+    pub web_port: Option<u16>,
+
+    /// Start with the layout saved as workspace NAME (see `w` in the TUI)
+    ///
+    /// An unknown name starts with the default layout and says so in the footer.
+    #[arg(long, value_name = "NAME", env = "REHOBOAM_WORKSPACE")]
+    pub workspace: Option<String>,
+
+    /// Append every received HookEvent to FILE as one JSON line (replayable with --replay)
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Append a snapshot of the whole board to FILE periodically (scrub with --playback)
+    ///
+    /// One JSON line per frame, in the `export-state` format. Frequency and
+    /// length come from `[recording] interval_secs` and `max_duration_mins`.
+    #[arg(long, value_name = "FILE")]
+    pub record_board: Option<PathBuf>,
+
+    /// Scrub through a --record-board recording instead of watching live agents
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["connect", "listen_tcp", "replay"])]
+    pub playback: Option<PathBuf>,
+
+    /// Append one CSV row per completed agent turn (UserPromptSubmit → Stop) to FILE
+    ///
+    /// Columns: iteration, duration_secs, tool_calls, completion_reason,
+    /// timestamp, pane_id, project. The header is written when the file is created.
+    #[arg(long, value_name = "FILE")]
+    pub metrics_csv: Option<PathBuf>,
+
+    /// Append every approval, answer and input sent from the TUI to FILE as JSON lines
+    ///
+    /// The last few hundred are also viewable in the TUI with `A`.
+    #[arg(long, value_name = "FILE")]
+    pub audit_log: Option<PathBuf>,
+}
+
+impl Cli {
+    /// Socket used by client subcommands (the first `--socket`)
+    pub fn primary_socket(&self) -> &PathBuf {
+        // clap always supplies the default when no --socket is given
+        &self.socket[0]
+    }
+
+    /// Socket source tag for events, or None when only one socket is used
+    pub fn socket_label(&self, path: &Path) -> Option<String> {
+        (self.socket.len() > 1).then(|| socket_label(path))
+    }
+}
+
+/// Short name for a socket: its file stem ("work" for `/run/user/1000/work.sock`)
+pub fn socket_label(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
 }
 
 #[derive(Subcommand, Debug)]
@@ -97,6 +193,11 @@ pub enum Commands {
         /// Enable desktop notifications (legacy flag, now on by default)
         #[arg(short = 'N', long, hide = true, default_value_t = false)]
         notify: bool,
+
+        /// Log a warning for each missing, mistyped or unrecognised field in
+        /// the hook JSON (never fails the hook)
+        #[arg(long, env = "REHOBOAM_STRICT_HOOKS", default_value_t = false)]
+        strict_hooks: bool,
     },
 
     /// Install Claude Code hooks to a project
@@ -129,6 +230,51 @@ pub enum Commands {
         #[command(subcommand)]
         action: SpritesAction,
     },
+
+    /// Send liveness heartbeats for a pane while Claude is responding
+    ///
+    /// Started automatically by `rehoboam hook` on UserPromptSubmit and stopped
+    /// on Stop/SessionEnd.
+    #[command(hide = true)]
+    Heartbeat {
+        /// Pane to keep alive (default: $TMUX_PANE)
+        #[arg(long, env = "TMUX_PANE")]
+        pane_id: String,
+
+        /// Seconds between heartbeats
+        #[arg(long, default_value_t = 10)]
+        interval: u64,
+    },
+
+    /// Validate the config and hook setup without starting the TUI
+    ///
+    /// Parses the config strictly, reports `[theme]`/`[keymap]` entries the
+    /// TUI would ignore, checks the socket directories are writable and the
+    /// current project's hooks point at an existing binary. Exits non-zero
+    /// if anything is wrong.
+    Check {
+        /// Config file to check (default: ~/.config/rehoboam/config.toml)
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Print a snapshot of the running TUI's agent board
+    ///
+    /// Connects to the TUI over the socket and exits non-zero if it isn't running.
+    ExportState {
+        /// Output format
+        #[arg(short = 'f', long, value_enum, default_value_t = ExportFormat::Json)]
+        format: ExportFormat,
+    },
+}
+
+/// Output format for `export-state`
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// JSON array of agents
+    Json,
+    /// CSV with a header row
+    Csv,
 }
 
 #[derive(Subcommand, Debug)]
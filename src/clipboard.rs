@@ -0,0 +1,72 @@
+//! System clipboard access for copying agent details
+//!
+//! Shells out to the platform's clipboard tool instead of linking a
+//! clipboard library, in the same spirit as the tmux integration:
+//! - macOS: `pbcopy`
+//! - Wayland: `wl-copy`
+//! - X11: `xclip`, then `xsel`
+//! - Inside tmux: `tmux load-buffer -w`, which forwards to the outer
+//!   terminal via OSC 52 (works over SSH when `set-clipboard` is on)
+//!
+//! Callers should show the value instead when every tool fails.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use color_eyre::eyre::{bail, Result, WrapErr};
+
+/// Clipboard commands to try, in order, for the current environment
+fn candidates() -> Vec<(&'static str, &'static [&'static str])> {
+    let mut tools: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+    if cfg!(target_os = "macos") {
+        tools.push(("pbcopy", &[]));
+    }
+    if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+        tools.push(("wl-copy", &[]));
+    }
+    if std::env::var_os("DISPLAY").is_some() {
+        tools.push(("xclip", &["-selection", "clipboard"]));
+        tools.push(("xsel", &["--clipboard", "--input"]));
+    }
+    if std::env::var_os("TMUX").is_some() {
+        tools.push(("tmux", &["load-buffer", "-w", "-"]));
+    }
+    tools
+}
+
+/// Pipe `text` into one clipboard command
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .wrap_err_with(|| format!("Failed to spawn {program}"))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .wrap_err_with(|| format!("Failed to write to {program}"))?;
+    }
+    let status = child
+        .wait()
+        .wrap_err_with(|| format!("Failed to wait for {program}"))?;
+    if !status.success() {
+        bail!("{program} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Copy `text` to the system clipboard, returning the tool that succeeded
+pub fn copy(text: &str) -> Result<&'static str> {
+    for (program, args) in candidates() {
+        match pipe_to(program, args, text) {
+            Ok(()) => {
+                tracing::debug!(tool = program, len = text.len(), "Copied to clipboard");
+                return Ok(program);
+            }
+            Err(e) => tracing::debug!(tool = program, error = %e, "Clipboard tool failed"),
+        }
+    }
+    bail!("no clipboard tool available")
+}
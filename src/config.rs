@@ -1,11 +1,13 @@
+use globset::{Glob, GlobSet, GlobSetBuilder};
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-/// Maximum events to keep in history
+/// Default event log size (`[ui] event_log_size`)
 pub const MAX_EVENTS: usize = 50;
 
-/// Maximum agents to track (prevents unbounded memory growth)
+/// Default cap on tracked agents (prevents unbounded memory growth)
 pub const MAX_AGENTS: usize = 500;
 
 /// Application configuration loaded from file
@@ -22,6 +24,310 @@ pub struct RehoboamConfig {
     /// Health check configuration (hooks.log monitoring)
     #[serde(default)]
     pub health: HealthConfig,
+
+    /// Board persistence across restarts
+    #[serde(default)]
+    pub persistence: PersistenceConfig,
+
+    /// Board size limits
+    #[serde(default)]
+    pub limits: LimitsConfig,
+
+    /// Board recordings (`--record-board`)
+    #[serde(default)]
+    pub recording: RecordingConfig,
+
+    /// Display configuration
+    #[serde(default)]
+    pub ui: UiConfig,
+
+    /// Desktop notification throttling
+    #[serde(default)]
+    pub notify: NotifyConfig,
+
+    /// Pane activation per terminal (`Enter`)
+    #[serde(default)]
+    pub terminal: TerminalConfig,
+
+    /// Outbound webhooks on status transitions (`[[webhooks]]`)
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+
+    /// Color overrides (`fg = "#1e1e2e"`, `border = 8`); missing keys keep the dark theme
+    #[serde(default)]
+    pub theme: BTreeMap<String, ThemeColor>,
+
+    /// Normal-mode key overrides (`quit = "Q"`, `next_agent = ["j", "down"]`)
+    #[serde(default)]
+    pub keymap: BTreeMap<String, KeyBinding>,
+
+    /// Cost estimate rates in USD per million tokens, matched against the
+    /// model name (`opus = 15.0`, `"claude-sonnet-4" = 3.0`); on top of
+    /// built-in opus/sonnet/haiku rates
+    #[serde(default)]
+    pub pricing: BTreeMap<String, f64>,
+}
+
+/// Status column that can be shown in the UI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusColumn {
+    /// Agents needing attention (permission, input, waiting)
+    Attention,
+    /// Agents actively working
+    Working,
+    /// Agents compacting context
+    Compacting,
+}
+
+impl StatusColumn {
+    /// Display label used in the header summary
+    pub fn label(self) -> &'static str {
+        match self {
+            StatusColumn::Attention => "attention",
+            StatusColumn::Working => "working",
+            StatusColumn::Compacting => "compacting",
+        }
+    }
+}
+
+/// Display configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UiConfig {
+    /// Visible status columns, in display order (default: all three)
+    ///
+    /// Attention and Working are always shown. Omitting "compacting" folds
+    /// compacting agents into Working.
+    #[serde(default = "default_columns")]
+    pub columns: Vec<StatusColumn>,
+
+    /// Ordering of agents within a status group (default: project)
+    #[serde(default)]
+    pub card_sort: CardSort,
+
+    /// What agent cards are grouped under: "team" (default), "model" or
+    /// "permission_mode"
+    #[serde(default)]
+    pub group_by: GroupBy,
+
+    /// Card density: "comfortable" (default) or "compact" (one line per agent)
+    #[serde(default)]
+    pub density: Density,
+
+    /// How event-log and card times read: "compact" (default, "2m"),
+    /// "verbose" ("2 minutes ago") or "clock" (local wall-clock time)
+    #[serde(default)]
+    pub timestamps: TimeFormat,
+
+    /// Cards turn yellow when remaining context drops below this percentage
+    /// (default: 20)
+    #[serde(default = "default_context_warn")]
+    pub context_warn_percent: f64,
+
+    /// Cards turn red when remaining context drops below this percentage
+    /// (default: 10)
+    #[serde(default = "default_context_critical")]
+    pub context_critical_percent: f64,
+
+    /// Keep each agent's latest prompt for its card and detail view
+    /// (default: true). Set false when prompt text must not be held in
+    /// memory or written to the persisted board.
+    #[serde(default = "default_capture_prompts")]
+    pub capture_prompts: bool,
+
+    /// Move the selection to an agent that starts asking for permission or
+    /// input, once the keyboard has been quiet for a few seconds (default:
+    /// false). The header shows [FOLLOW] while on.
+    #[serde(default)]
+    pub focus_follows_attention: bool,
+
+    /// Shell command `o` runs to open the selected agent's directory, with
+    /// `{dir}` replaced by the quoted path (default: the editor in a new tmux
+    /// window). For a GUI editor: `open_command = "code {dir}"`.
+    #[serde(default = "default_open_command")]
+    pub open_command: String,
+
+    /// Ask before `q` quits while agents are working or compacting
+    /// (default: true; false quits instantly)
+    #[serde(default = "default_confirm_quit")]
+    pub confirm_quit: bool,
+
+    /// Ticks the header's fleet pulse covers, one bar per tick of working
+    /// agents (default: 60, one minute at the default tick rate)
+    #[serde(default = "default_pulse_ticks")]
+    pub pulse_ticks: usize,
+
+    /// Scrollback lines captured above the visible pane in the pane output
+    /// view (`W`) (default: 2000)
+    #[serde(default = "default_output_scrollback")]
+    pub output_scrollback: usize,
+
+    /// Hook events kept for the event log view (`V`), newest first
+    /// (default: 50)
+    #[serde(default = "default_event_log_size")]
+    pub event_log_size: usize,
+}
+
+impl Default for UiConfig {
+    fn default() -> Self {
+        Self {
+            columns: default_columns(),
+            card_sort: CardSort::default(),
+            group_by: GroupBy::default(),
+            density: Density::default(),
+            timestamps: TimeFormat::default(),
+            context_warn_percent: default_context_warn(),
+            context_critical_percent: default_context_critical(),
+            capture_prompts: default_capture_prompts(),
+            focus_follows_attention: false,
+            open_command: default_open_command(),
+            confirm_quit: default_confirm_quit(),
+            pulse_ticks: default_pulse_ticks(),
+            output_scrollback: default_output_scrollback(),
+            event_log_size: default_event_log_size(),
+        }
+    }
+}
+
+fn default_context_warn() -> f64 {
+    20.0
+}
+
+fn default_context_critical() -> f64 {
+    10.0
+}
+
+fn default_capture_prompts() -> bool {
+    true
+}
+
+fn default_open_command() -> String {
+    r#"tmux new-window -c {dir} "${EDITOR:-vi} .""#.to_string()
+}
+
+fn default_confirm_quit() -> bool {
+    true
+}
+
+fn default_pulse_ticks() -> usize {
+    60
+}
+
+fn default_output_scrollback() -> usize {
+    2000
+}
+
+fn default_event_log_size() -> usize {
+    MAX_EVENTS
+}
+
+/// Secondary sort key for agents within a status group
+///
+/// Attention agents are always ordered by attention priority first
+/// (permission before input before waiting); this key breaks ties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CardSort {
+    /// Alphabetical by project name
+    #[default]
+    Project,
+    /// Oldest activity first (surfaces stuck agents)
+    Elapsed,
+    /// Most recent activity first
+    Recent,
+}
+
+/// What the team view groups agents under (`g` cycles)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    /// Agent team; agents outside one are "Independent"
+    #[default]
+    Team,
+    /// Model the agent runs
+    Model,
+    /// Claude Code permission mode, for auditing risky modes
+    PermissionMode,
+}
+
+impl GroupBy {
+    pub fn next(self) -> Self {
+        match self {
+            GroupBy::Team => GroupBy::Model,
+            GroupBy::Model => GroupBy::PermissionMode,
+            GroupBy::PermissionMode => GroupBy::Team,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupBy::Team => "team",
+            GroupBy::Model => "model",
+            GroupBy::PermissionMode => "permission mode",
+        }
+    }
+
+    /// Group for agents that haven't reported a value (always listed last)
+    pub fn fallback(self) -> &'static str {
+        match self {
+            GroupBy::Team => "Independent",
+            GroupBy::Model => "unknown",
+            GroupBy::PermissionMode => "default",
+        }
+    }
+}
+
+/// How much of each agent card the team view shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// Agent line plus detail lines (errors, questions, metadata, subagents)
+    #[default]
+    Comfortable,
+    /// One line per agent, for large boards on small screens
+    Compact,
+}
+
+impl Density {
+    /// The other density (for the `v` toggle)
+    pub fn toggled(self) -> Self {
+        match self {
+            Density::Comfortable => Density::Compact,
+            Density::Compact => Density::Comfortable,
+        }
+    }
+}
+
+/// How times are shown in the event log, audit log and agent cards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFormat {
+    /// Short relative age: "45s", "2m", "1h 05m", "3d"
+    #[default]
+    Compact,
+    /// Spelled-out age: "2 minutes ago"
+    Verbose,
+    /// Local wall-clock time: "14:02:33", or "Oct 14 14:02" before today
+    Clock,
+}
+
+impl TimeFormat {
+    /// Widest string this format produces, for aligning log columns
+    pub fn width(self) -> usize {
+        match self {
+            TimeFormat::Compact => 6,
+            TimeFormat::Verbose => 14,
+            TimeFormat::Clock => 12,
+        }
+    }
+}
+
+fn default_columns() -> Vec<StatusColumn> {
+    vec![
+        StatusColumn::Attention,
+        StatusColumn::Working,
+        StatusColumn::Compacting,
+    ]
 }
 
 /// Timeout configuration for state transitions
@@ -31,9 +337,41 @@ pub struct TimeoutConfig {
     #[serde(default = "default_idle_timeout")]
     pub idle_timeout_secs: i64,
 
-    /// Seconds before removing stale sessions (default: 300)
+    /// Seconds without events before an agent is marked gone (default: 300)
     #[serde(default = "default_stale_timeout")]
     pub stale_timeout_secs: i64,
+
+    /// Seconds a gone agent stays on the board, greyed out, before removal
+    /// (default: 300; 0 removes stale agents immediately)
+    ///
+    /// Any hook event from a gone agent revives it in place.
+    #[serde(default = "default_gone_grace")]
+    pub gone_grace_secs: i64,
+
+    /// Seconds a single tool may run before its agent is flagged STUCK (default: 300)
+    ///
+    /// The agent stays Working; the flag is only a visual warning.
+    #[serde(default = "default_stuck_tool")]
+    pub stuck_tool_secs: i64,
+
+    /// Seconds an agent must sit idle (waiting after its turn) before the
+    /// manual sweep (`X`) offers to remove it (default: 600)
+    ///
+    /// Gone agents are always offered.
+    #[serde(default = "default_sweep_idle")]
+    pub sweep_idle_secs: i64,
+
+    /// Per-project idle timeout overrides: project-name glob -> seconds
+    ///
+    /// ```toml
+    /// [timeouts.per_project]
+    /// "build-*" = 180
+    /// "chat-*" = 30
+    /// ```
+    ///
+    /// When several globs match, the longest (most specific) pattern wins.
+    #[serde(default)]
+    pub per_project: BTreeMap<String, i64>,
 }
 
 impl Default for TimeoutConfig {
@@ -41,10 +379,59 @@ impl Default for TimeoutConfig {
         Self {
             idle_timeout_secs: default_idle_timeout(),
             stale_timeout_secs: default_stale_timeout(),
+            gone_grace_secs: default_gone_grace(),
+            stuck_tool_secs: default_stuck_tool(),
+            sweep_idle_secs: default_sweep_idle(),
+            per_project: BTreeMap::new(),
         }
     }
 }
 
+/// Per-project idle timeouts with globs compiled once at config load
+#[derive(Debug, Clone, Default)]
+pub struct IdleTimeoutRules {
+    set: GlobSet,
+    /// (pattern length, seconds) for each glob in `set`, by index
+    rules: Vec<(usize, i64)>,
+}
+
+impl IdleTimeoutRules {
+    /// Compile `[timeouts.per_project]`, skipping invalid globs with a warning
+    pub fn compile(config: &TimeoutConfig) -> Self {
+        let mut builder = GlobSetBuilder::new();
+        let mut rules = Vec::new();
+        for (pattern, secs) in &config.per_project {
+            match Glob::new(pattern) {
+                Ok(glob) => {
+                    builder.add(glob);
+                    rules.push((pattern.len(), *secs));
+                }
+                Err(e) => tracing::warn!("Ignoring invalid project glob {:?}: {}", pattern, e),
+            }
+        }
+        match builder.build() {
+            Ok(set) => Self { set, rules },
+            Err(e) => {
+                tracing::warn!("Failed to compile per-project timeouts: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Idle timeout override for `project`, if any glob matches
+    pub fn for_project(&self, project: &str) -> Option<i64> {
+        if self.rules.is_empty() {
+            return None;
+        }
+        self.set
+            .matches(project)
+            .into_iter()
+            .map(|i| self.rules[i])
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, secs)| secs)
+    }
+}
+
 fn default_idle_timeout() -> i64 {
     60
 }
@@ -53,6 +440,18 @@ fn default_stale_timeout() -> i64 {
     300
 }
 
+fn default_gone_grace() -> i64 {
+    300
+}
+
+fn default_stuck_tool() -> i64 {
+    300
+}
+
+fn default_sweep_idle() -> i64 {
+    600
+}
+
 /// Health check configuration for hooks.log monitoring
 ///
 /// Claude Code writes to `~/.claude/hooks.log` on every hook invocation.
@@ -113,6 +512,327 @@ fn default_health_truncate_keep_lines() -> usize {
     1000
 }
 
+/// Board persistence configuration
+///
+/// The agent board is saved to `~/.cache/rehoboam/state.json` so a restarted
+/// TUI shows existing sessions immediately instead of waiting for new hooks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistenceConfig {
+    /// Save and restore the board (default: true)
+    #[serde(default = "default_persistence_enabled")]
+    pub enabled: bool,
+
+    /// Seconds between saves (default: 10)
+    #[serde(default = "default_persistence_interval")]
+    pub interval_secs: u64,
+}
+
+impl Default for PersistenceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_persistence_enabled(),
+            interval_secs: default_persistence_interval(),
+        }
+    }
+}
+
+fn default_persistence_enabled() -> bool {
+    true
+}
+
+fn default_persistence_interval() -> u64 {
+    10
+}
+
+/// Board recordings (`--record-board`)
+///
+/// One frame holds every agent, so the interval and duration cap together
+/// bound the file: the defaults write at most 1440 frames.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingConfig {
+    /// Seconds between frames (default: 10)
+    #[serde(default = "default_recording_interval")]
+    pub interval_secs: u64,
+
+    /// Stop recording after this many minutes (default: 240)
+    #[serde(default = "default_recording_max_duration")]
+    pub max_duration_mins: u64,
+}
+
+impl Default for RecordingConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: default_recording_interval(),
+            max_duration_mins: default_recording_max_duration(),
+        }
+    }
+}
+
+fn default_recording_interval() -> u64 {
+    10
+}
+
+fn default_recording_max_duration() -> u64 {
+    240
+}
+
+/// One outbound webhook (`[[webhooks]]`)
+///
+/// Each status transition the agent enters that matches `on` is POSTed to
+/// `url` as JSON, e.g. to a Slack or PagerDuty integration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+
+    /// Statuses that trigger the webhook when an agent enters them
+    /// (default: ["permission", "input"])
+    #[serde(default = "default_webhook_on")]
+    pub on: Vec<WebhookTrigger>,
+}
+
+/// Status an agent enters that fires a webhook
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookTrigger {
+    /// Any attention type
+    Attention,
+    Permission,
+    Input,
+    Notification,
+    /// Turn finished (`Stop`)
+    Waiting,
+    Working,
+    Compacting,
+}
+
+fn default_webhook_on() -> Vec<WebhookTrigger> {
+    vec![WebhookTrigger::Permission, WebhookTrigger::Input]
+}
+
+/// Board size limits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LimitsConfig {
+    /// Most agents tracked at once (default: 500)
+    #[serde(default = "default_max_agents")]
+    pub max_agents: usize,
+
+    /// What happens when a new agent arrives at the cap (default: oldest-waiting)
+    #[serde(default)]
+    pub eviction: EvictionPolicy,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_agents: default_max_agents(),
+            eviction: EvictionPolicy::default(),
+        }
+    }
+}
+
+fn default_max_agents() -> usize {
+    MAX_AGENTS
+}
+
+/// How room is made for a new agent once `max_agents` are tracked
+///
+/// Gone agents are always evicted first under the evicting policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EvictionPolicy {
+    /// Evict the agent idle longest after its turn, else the oldest of any status
+    #[default]
+    OldestWaiting,
+    /// Evict the agent with the oldest activity, whatever its status
+    OldestAny,
+    /// Evict nothing: new agents are not tracked and the footer warns
+    Never,
+}
+
+/// Desktop notification throttling
+///
+/// While the TUI is running it sends notifications on behalf of hooks, so
+/// flapping agents and bursts of attention can be deduplicated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotifyConfig {
+    /// Suppress repeat notifications for the same agent and attention type
+    /// within this many seconds (default: 30, 0 disables)
+    #[serde(default = "default_notify_cooldown")]
+    pub cooldown_secs: u64,
+
+    /// Notifications arriving within this window are merged into one summary
+    /// (default: 2, 0 disables)
+    #[serde(default = "default_notify_coalesce")]
+    pub coalesce_secs: u64,
+
+    /// Sound per attention type (`[notify.sounds]`)
+    #[serde(default)]
+    pub sounds: NotifySounds,
+
+    /// Local-time window with no desktop notifications, e.g. "22:00-08:00"
+    /// (default: none). Attention still shows on the board. Read by the hook
+    /// too, for when the TUI is not running.
+    #[serde(default)]
+    pub quiet_hours: Option<QuietHours>,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_secs: default_notify_cooldown(),
+            coalesce_secs: default_notify_coalesce(),
+            sounds: NotifySounds::default(),
+            quiet_hours: None,
+        }
+    }
+}
+
+impl NotifyConfig {
+    /// Whether local time is inside `quiet_hours`
+    pub fn is_quiet_now(&self) -> bool {
+        self.quiet_hours.is_some_and(|quiet| quiet.contains_now())
+    }
+}
+
+/// Daily "HH:MM-HH:MM" window; a start after the end crosses midnight
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct QuietHours {
+    /// Minutes after midnight
+    start: u32,
+    end: u32,
+}
+
+impl QuietHours {
+    /// Whether `minute` (minutes after midnight) falls in the window
+    ///
+    /// The start is inclusive and the end exclusive; equal times are empty.
+    pub fn contains(self, minute: u32) -> bool {
+        if self.start <= self.end {
+            (self.start..self.end).contains(&minute)
+        } else {
+            minute >= self.start || minute < self.end
+        }
+    }
+
+    /// Whether the local wall-clock time falls in the window
+    pub fn contains_now(self) -> bool {
+        use chrono::Timelike;
+        let now = chrono::Local::now();
+        self.contains(now.hour() * 60 + now.minute())
+    }
+}
+
+impl TryFrom<String> for QuietHours {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let minutes = |time: &str| {
+            let (hours, minutes) = time.trim().split_once(':')?;
+            let (hours, minutes) = (hours.parse::<u32>().ok()?, minutes.parse::<u32>().ok()?);
+            (hours < 24 && minutes < 60).then_some(hours * 60 + minutes)
+        };
+        value
+            .split_once('-')
+            .and_then(|(start, end)| Some((minutes(start)?, minutes(end)?)))
+            .map(|(start, end)| Self { start, end })
+            .ok_or_else(|| format!("invalid quiet_hours {value:?}, expected \"HH:MM-HH:MM\""))
+    }
+}
+
+impl From<QuietHours> for String {
+    fn from(quiet: QuietHours) -> Self {
+        let time = |m: u32| format!("{:02}:{:02}", m / 60, m % 60);
+        format!("{}-{}", time(quiet.start), time(quiet.end))
+    }
+}
+
+/// Notification sounds by attention type
+///
+/// Values are sound names (`"Glass"` on macOS, a freedesktop sound name on
+/// Linux) or, on Linux, a path to a sound file. Unset types keep the built-in
+/// choice; names the platform doesn't know play its default sound.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotifySounds {
+    #[serde(default)]
+    pub permission: Option<String>,
+    #[serde(default)]
+    pub input: Option<String>,
+    #[serde(default)]
+    pub notification: Option<String>,
+    /// Turn finished (`Stop`)
+    #[serde(default)]
+    pub waiting: Option<String>,
+}
+
+impl NotifySounds {
+    /// Sound for an attention type: configured, else urgent prompts get
+    /// "Basso" and everything else the system default
+    pub fn for_attention(&self, attention: &str) -> &str {
+        let (configured, fallback) = match attention {
+            "permission" => (&self.permission, "Basso"),
+            "input" => (&self.input, "Basso"),
+            "notification" => (&self.notification, "default"),
+            "waiting" => (&self.waiting, "default"),
+            _ => (&None, "default"),
+        };
+        configured
+            .as_deref()
+            .filter(|sound| !sound.trim().is_empty())
+            .unwrap_or(fallback)
+    }
+}
+
+fn default_notify_cooldown() -> u64 {
+    30
+}
+
+fn default_notify_coalesce() -> u64 {
+    2
+}
+
+/// Command `Enter` runs to focus an agent's pane, per terminal
+///
+/// `{pane}` becomes the quoted terminal-native id (`%3` for tmux, the bare
+/// number for WezTerm and Kitty) and the result runs under `sh -c`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminalConfig {
+    /// (default: `tmux select-pane -t {pane}`)
+    #[serde(default = "default_tmux_activate")]
+    pub tmux: String,
+
+    /// (default: `wezterm cli activate-pane --pane-id {pane}`)
+    #[serde(default = "default_wezterm_activate")]
+    pub wezterm: String,
+
+    /// Needs `allow_remote_control` in kitty.conf
+    /// (default: `kitty @ focus-window --match id:{pane}`)
+    #[serde(default = "default_kitty_activate")]
+    pub kitty: String,
+}
+
+impl Default for TerminalConfig {
+    fn default() -> Self {
+        Self {
+            tmux: default_tmux_activate(),
+            wezterm: default_wezterm_activate(),
+            kitty: default_kitty_activate(),
+        }
+    }
+}
+
+fn default_tmux_activate() -> String {
+    "tmux select-pane -t {pane}".to_string()
+}
+
+fn default_wezterm_activate() -> String {
+    "wezterm cli activate-pane --pane-id {pane}".to_string()
+}
+
+fn default_kitty_activate() -> String {
+    "kitty @ focus-window --match id:{pane}".to_string()
+}
+
 /// Sprites-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpritesConfig {
@@ -143,6 +863,22 @@ pub struct SpritesConfig {
     /// Checkpoint configuration
     #[serde(default)]
     pub checkpoints: CheckpointConfig,
+
+    /// Seconds before a Sprites API call is abandoned
+    #[serde(default = "default_api_timeout_secs")]
+    pub api_timeout_secs: u64,
+
+    /// Retries for idempotent API calls that fail transiently
+    #[serde(default = "default_api_retries")]
+    pub api_retries: u32,
+
+    /// Transient failures in a row before API calls are paused
+    #[serde(default = "default_breaker_failures")]
+    pub breaker_failures: u32,
+
+    /// Seconds API calls stay paused once the breaker opens
+    #[serde(default = "default_breaker_cooldown_secs")]
+    pub breaker_cooldown_secs: u64,
 }
 
 impl Default for SpritesConfig {
@@ -155,6 +891,10 @@ impl Default for SpritesConfig {
             network_preset: NetworkPresetConfig::default(),
             ws_port: default_ws_port(),
             checkpoints: CheckpointConfig::default(),
+            api_timeout_secs: default_api_timeout_secs(),
+            api_retries: default_api_retries(),
+            breaker_failures: default_breaker_failures(),
+            breaker_cooldown_secs: default_breaker_cooldown_secs(),
         }
     }
 }
@@ -175,6 +915,22 @@ fn default_ws_port() -> u16 {
     9876
 }
 
+fn default_api_timeout_secs() -> u64 {
+    15
+}
+
+fn default_api_retries() -> u32 {
+    2
+}
+
+fn default_breaker_failures() -> u32 {
+    5
+}
+
+fn default_breaker_cooldown_secs() -> u64 {
+    30
+}
+
 /// Network preset for sprites
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -274,6 +1030,80 @@ impl RehoboamConfig {
             );
         }
 
+        let old_grace = self.timeouts.gone_grace_secs;
+        self.timeouts.gone_grace_secs = self.timeouts.gone_grace_secs.clamp(0, 86400);
+        if old_grace != self.timeouts.gone_grace_secs {
+            tracing::warn!(
+                "Gone grace period clamped: {}->{}s",
+                old_grace,
+                self.timeouts.gone_grace_secs
+            );
+        }
+
+        let old_stuck = self.timeouts.stuck_tool_secs;
+        self.timeouts.stuck_tool_secs = self.timeouts.stuck_tool_secs.clamp(30, 86400);
+        if old_stuck != self.timeouts.stuck_tool_secs {
+            tracing::warn!(
+                "Stuck tool threshold clamped: {}->{}s",
+                old_stuck,
+                self.timeouts.stuck_tool_secs
+            );
+        }
+
+        // Per-project idle timeouts share the global idle range
+        for (pattern, secs) in &mut self.timeouts.per_project {
+            let clamped = (*secs).clamp(5, 3600);
+            if clamped != *secs {
+                tracing::warn!(
+                    "Idle timeout for {:?} clamped: {}->{}s",
+                    pattern,
+                    secs,
+                    clamped
+                );
+                *secs = clamped;
+            }
+        }
+
+        let old_pulse = self.ui.pulse_ticks;
+        self.ui.pulse_ticks = self.ui.pulse_ticks.clamp(2, 3600);
+        if old_pulse != self.ui.pulse_ticks {
+            tracing::warn!(
+                "Fleet pulse window clamped: {}->{} ticks",
+                old_pulse,
+                self.ui.pulse_ticks
+            );
+        }
+
+        let old_scrollback = self.ui.output_scrollback;
+        self.ui.output_scrollback = self.ui.output_scrollback.min(50_000);
+        if old_scrollback != self.ui.output_scrollback {
+            tracing::warn!(
+                "Pane output scrollback clamped: {}->{} lines",
+                old_scrollback,
+                self.ui.output_scrollback
+            );
+        }
+
+        let old_log = self.ui.event_log_size;
+        self.ui.event_log_size = self.ui.event_log_size.clamp(10, 10_000);
+        if old_log != self.ui.event_log_size {
+            tracing::warn!(
+                "Event log size clamped: {}->{} events",
+                old_log,
+                self.ui.event_log_size
+            );
+        }
+
+        let old_max = self.limits.max_agents;
+        self.limits.max_agents = self.limits.max_agents.clamp(1, 10_000);
+        if old_max != self.limits.max_agents {
+            tracing::warn!(
+                "Agent limit clamped: {}->{}",
+                old_max,
+                self.limits.max_agents
+            );
+        }
+
         // Clamp sprite resources (512MB - 16GB RAM, 1-8 CPUs)
         let old_ram = self.sprites.default_ram_mb;
         let old_cpus = self.sprites.default_cpus;
@@ -289,6 +1119,40 @@ impl RehoboamConfig {
             );
         }
 
+        // Clamp Sprites API resilience (1-300s timeout, 0-5 retries)
+        let old_api = (
+            self.sprites.api_timeout_secs,
+            self.sprites.api_retries,
+            self.sprites.breaker_failures,
+            self.sprites.breaker_cooldown_secs,
+        );
+        self.sprites.api_timeout_secs = self.sprites.api_timeout_secs.clamp(1, 300);
+        self.sprites.api_retries = self.sprites.api_retries.min(5);
+        self.sprites.breaker_failures = self.sprites.breaker_failures.clamp(1, 50);
+        self.sprites.breaker_cooldown_secs = self.sprites.breaker_cooldown_secs.clamp(5, 600);
+        let new_api = (
+            self.sprites.api_timeout_secs,
+            self.sprites.api_retries,
+            self.sprites.breaker_failures,
+            self.sprites.breaker_cooldown_secs,
+        );
+        if old_api != new_api {
+            tracing::warn!(
+                "Sprites API values clamped: timeout/retries/breaker/cooldown {:?}->{:?}",
+                old_api,
+                new_api
+            );
+        }
+
+        // A rate must be a finite, non-negative price
+        self.pricing.retain(|model, rate| {
+            let valid = rate.is_finite() && *rate >= 0.0;
+            if !valid {
+                tracing::warn!("Ignoring price for {:?}: {}", model, rate);
+            }
+            valid
+        });
+
         // Clamp health check values
         let old_interval = self.health.interval_secs;
         let old_warn = self.health.warn_mb;
@@ -319,19 +1183,364 @@ impl RehoboamConfig {
                 self.health.truncate_keep_lines
             );
         }
+
+        // Clamp persistence interval (2s - 5min)
+        let old_persist = self.persistence.interval_secs;
+        self.persistence.interval_secs = self.persistence.interval_secs.clamp(2, 300);
+        if old_persist != self.persistence.interval_secs {
+            tracing::warn!(
+                "Persistence interval clamped: {}->{}s",
+                old_persist,
+                self.persistence.interval_secs
+            );
+        }
+
+        // Clamp notification windows (cooldown up to 1hr, coalesce up to 30s)
+        let old_cooldown = self.notify.cooldown_secs;
+        let old_coalesce = self.notify.coalesce_secs;
+        self.notify.cooldown_secs = self.notify.cooldown_secs.min(3600);
+        self.notify.coalesce_secs = self.notify.coalesce_secs.min(30);
+        if old_cooldown != self.notify.cooldown_secs || old_coalesce != self.notify.coalesce_secs {
+            tracing::warn!(
+                "Notify values clamped: cooldown {}->{}s, coalesce {}->{}s",
+                old_cooldown,
+                self.notify.cooldown_secs,
+                old_coalesce,
+                self.notify.coalesce_secs
+            );
+        }
+
+        // Context thresholds are percentages; critical can't exceed warn
+        let old_ctx = (
+            self.ui.context_warn_percent,
+            self.ui.context_critical_percent,
+        );
+        self.ui.context_warn_percent = self.ui.context_warn_percent.clamp(0.0, 100.0);
+        self.ui.context_critical_percent = self
+            .ui
+            .context_critical_percent
+            .clamp(0.0, self.ui.context_warn_percent);
+        if old_ctx
+            != (
+                self.ui.context_warn_percent,
+                self.ui.context_critical_percent,
+            )
+        {
+            tracing::warn!(
+                "Context thresholds clamped: warn {}->{}%, critical {}->{}%",
+                old_ctx.0,
+                self.ui.context_warn_percent,
+                old_ctx.1,
+                self.ui.context_critical_percent
+            );
+        }
+
+        // Attention and Working can't be hidden (nothing to fold them into)
+        let old_columns = self.ui.columns.clone();
+        let mut columns: Vec<StatusColumn> = Vec::new();
+        for column in &self.ui.columns {
+            if !columns.contains(column) {
+                columns.push(*column);
+            }
+        }
+        for required in [StatusColumn::Attention, StatusColumn::Working] {
+            if !columns.contains(&required) {
+                columns.push(required);
+            }
+        }
+        self.ui.columns = columns;
+        if old_columns != self.ui.columns {
+            tracing::warn!(
+                "UI columns adjusted: {:?} -> {:?}",
+                old_columns,
+                self.ui.columns
+            );
+        }
     }
 }
 
-/// Tokyo Night color palette
+/// A `[theme]` color value: `"#rrggbb"` or an ANSI 256-color index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ThemeColor {
+    Index(u8),
+    Hex(String),
+}
+
+impl ThemeColor {
+    /// Resolve to a terminal color (None if the hex string is malformed)
+    pub fn to_color(&self) -> Option<Color> {
+        match self {
+            ThemeColor::Index(i) => Some(Color::Indexed(*i)),
+            ThemeColor::Hex(hex) => {
+                let hex = hex.strip_prefix('#')?;
+                if hex.len() != 6 || !hex.is_ascii() {
+                    return None;
+                }
+                let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+                Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?))
+            }
+        }
+    }
+}
+
+/// A `[keymap]` value: one key or a list of keys (empty unbinds)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyBinding {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyBinding {
+    pub fn specs(&self) -> &[String] {
+        match self {
+            KeyBinding::One(key) => std::slice::from_ref(key),
+            KeyBinding::Many(keys) => keys,
+        }
+    }
+}
+
+/// Resolved UI colors
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub bg: Color,
+    pub fg: Color,
+    pub working: Color,
+    pub attention: Color,
+    pub idle: Color,
+    pub compacting: Color,
+    pub border: Color,
+    pub highlight: Color,
+    /// Cards whose current tool has run past `stuck_tool_secs`
+    pub stuck: Color,
+    /// Failures, health warnings, critical context, bypassed permissions
+    pub error: Color,
+    /// Status messages and prompts in the footer
+    pub warning: Color,
+}
+
+impl Palette {
+    /// Tokyo Night (the default dark theme)
+    pub const DARK: Palette = Palette {
+        bg: Color::Rgb(26, 27, 38),            // #1a1b26
+        fg: Color::Rgb(192, 202, 245),         // #c0caf5
+        working: Color::Rgb(122, 162, 247),    // #7aa2f7 blue
+        attention: Color::Rgb(255, 158, 100),  // #ff9e64 orange
+        idle: Color::Rgb(86, 95, 137),         // #565f89 gray
+        compacting: Color::Rgb(224, 175, 104), // #e0af68 yellow
+        border: Color::Rgb(59, 66, 97),        // #3b4261
+        highlight: Color::Rgb(187, 154, 247),  // #bb9af7 purple
+        stuck: Color::Magenta,
+        error: Color::Red,
+        warning: Color::Yellow,
+    };
+
+    /// Apply `[theme]` overrides on top of the dark theme
+    ///
+    /// Keys are case-insensitive. Unknown keys and unparseable values are
+    /// logged and ignored.
+    pub fn from_theme(theme: &BTreeMap<String, ThemeColor>) -> Self {
+        let (palette, problems) = Self::from_theme_checked(theme);
+        for problem in problems {
+            tracing::warn!("{problem}");
+        }
+        palette
+    }
+
+    /// Like [`Self::from_theme`], returning what was ignored instead of
+    /// logging it (for `rehoboam check`)
+    pub fn from_theme_checked(theme: &BTreeMap<String, ThemeColor>) -> (Self, Vec<String>) {
+        let mut problems = Vec::new();
+        let mut palette = Self::DARK;
+        for (name, value) in theme {
+            let slot = match name.to_ascii_lowercase().as_str() {
+                "bg" => &mut palette.bg,
+                "fg" => &mut palette.fg,
+                "working" => &mut palette.working,
+                "attention" => &mut palette.attention,
+                "idle" => &mut palette.idle,
+                "compacting" => &mut palette.compacting,
+                "border" => &mut palette.border,
+                "highlight" => &mut palette.highlight,
+                "stuck" => &mut palette.stuck,
+                "error" => &mut palette.error,
+                "warning" => &mut palette.warning,
+                _ => {
+                    problems.push(format!("Unknown theme color {name:?}, ignoring"));
+                    continue;
+                }
+            };
+            match value.to_color() {
+                Some(color) => *slot = color,
+                None => problems.push(format!(
+                    "Invalid theme color {name} = {value:?}, keeping default"
+                )),
+            }
+        }
+        (palette, problems)
+    }
+}
+
+/// Active UI colors
+///
+/// Set once at startup from `[theme]` via [`colors::init`]; the dark theme is
+/// used until then (and in tests).
 pub mod colors {
-    use super::Color;
-
-    pub const BG: Color = Color::Rgb(26, 27, 38); // #1a1b26
-    pub const FG: Color = Color::Rgb(192, 202, 245); // #c0caf5
-    pub const WORKING: Color = Color::Rgb(122, 162, 247); // #7aa2f7 blue
-    pub const ATTENTION: Color = Color::Rgb(255, 158, 100); // #ff9e64 orange
-    pub const IDLE: Color = Color::Rgb(86, 95, 137); // #565f89 gray
-    pub const COMPACTING: Color = Color::Rgb(224, 175, 104); // #e0af68 yellow
-    pub const BORDER: Color = Color::Rgb(59, 66, 97); // #3b4261
-    pub const HIGHLIGHT: Color = Color::Rgb(187, 154, 247); // #bb9af7 purple
+    use super::{Color, Palette};
+    use std::sync::OnceLock;
+
+    static PALETTE: OnceLock<Palette> = OnceLock::new();
+
+    /// Install the configured palette (later calls are ignored)
+    pub fn init(palette: Palette) {
+        let _ = PALETTE.set(palette);
+    }
+
+    fn palette() -> &'static Palette {
+        PALETTE.get().unwrap_or(&Palette::DARK)
+    }
+
+    pub fn bg() -> Color {
+        palette().bg
+    }
+    pub fn fg() -> Color {
+        palette().fg
+    }
+    pub fn working() -> Color {
+        palette().working
+    }
+    pub fn attention() -> Color {
+        palette().attention
+    }
+    pub fn idle() -> Color {
+        palette().idle
+    }
+    pub fn compacting() -> Color {
+        palette().compacting
+    }
+    pub fn border() -> Color {
+        palette().border
+    }
+    pub fn highlight() -> Color {
+        palette().highlight
+    }
+    pub fn stuck() -> Color {
+        palette().stuck
+    }
+    pub fn error() -> Color {
+        palette().error
+    }
+    pub fn warning() -> Color {
+        palette().warning
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_overrides_fall_back_to_dark() {
+        let config: RehoboamConfig = toml::from_str(
+            r##"
+            [theme]
+            FG = "#101010"
+            border = 8
+            highlight = "purple"
+            nope = "#ffffff"
+            error = 9
+            "##,
+        )
+        .unwrap();
+        let palette = Palette::from_theme(&config.theme);
+        assert_eq!(palette.fg, Color::Rgb(16, 16, 16));
+        assert_eq!(palette.border, Color::Indexed(8));
+        assert_eq!(palette.highlight, Palette::DARK.highlight);
+        assert_eq!(palette.bg, Palette::DARK.bg);
+        assert_eq!(palette.error, Color::Indexed(9));
+        assert_eq!(palette.warning, Color::Yellow);
+    }
+
+    #[test]
+    fn test_keymap_accepts_one_key_or_a_list() {
+        let config: RehoboamConfig =
+            toml::from_str("[keymap]\nquit = \"Q\"\nnext_agent = [\"j\", \"down\"]\nspawn = []\n")
+                .unwrap();
+        assert_eq!(config.keymap["quit"].specs(), ["Q"]);
+        assert_eq!(config.keymap["next_agent"].specs(), ["j", "down"]);
+        assert!(config.keymap["spawn"].specs().is_empty());
+    }
+
+    #[test]
+    fn test_notify_sounds_fall_back_to_builtin() {
+        let config: RehoboamConfig = toml::from_str(
+            r#"
+            [notify.sounds]
+            permission = "Sosumi"
+            waiting = "/usr/share/sounds/done.oga"
+            input = ""
+            "#,
+        )
+        .unwrap();
+        let sounds = &config.notify.sounds;
+        assert_eq!(sounds.for_attention("permission"), "Sosumi");
+        assert_eq!(
+            sounds.for_attention("waiting"),
+            "/usr/share/sounds/done.oga"
+        );
+        assert_eq!(sounds.for_attention("input"), "Basso");
+        assert_eq!(sounds.for_attention("notification"), "default");
+        assert_eq!(config.notify.cooldown_secs, 30);
+    }
+
+    #[test]
+    fn test_density_parses_lowercase() {
+        let config: RehoboamConfig = toml::from_str("[ui]\ndensity = \"compact\"\n").unwrap();
+        assert_eq!(config.ui.density, Density::Compact);
+        assert_eq!(RehoboamConfig::default().ui.density, Density::Comfortable);
+    }
+
+    #[test]
+    fn test_timestamps_parses_lowercase() {
+        let config: RehoboamConfig = toml::from_str("[ui]\ntimestamps = \"clock\"\n").unwrap();
+        assert_eq!(config.ui.timestamps, TimeFormat::Clock);
+        assert_eq!(RehoboamConfig::default().ui.timestamps, TimeFormat::Compact);
+    }
+
+    #[test]
+    fn test_context_thresholds_clamped() {
+        let mut config: RehoboamConfig = toml::from_str(
+            r#"
+            [ui]
+            context_warn_percent = 150
+            context_critical_percent = 120
+            "#,
+        )
+        .unwrap();
+        config.validate();
+        assert_eq!(config.ui.context_warn_percent, 100.0);
+        assert_eq!(config.ui.context_critical_percent, 100.0);
+
+        let config = RehoboamConfig::default();
+        assert_eq!(config.ui.context_warn_percent, 20.0);
+        assert_eq!(config.ui.context_critical_percent, 10.0);
+    }
+
+    #[test]
+    fn test_quiet_hours_cross_midnight() {
+        let config: RehoboamConfig =
+            toml::from_str("[notify]\nquiet_hours = \"22:00-08:00\"\n").unwrap();
+        let quiet = config.notify.quiet_hours.unwrap();
+        assert!(quiet.contains(23 * 60));
+        assert!(quiet.contains(2 * 60));
+        assert!(!quiet.contains(8 * 60), "end is exclusive");
+        assert!(!quiet.contains(12 * 60));
+        assert_eq!(String::from(quiet), "22:00-08:00");
+
+        let day = QuietHours::try_from("9:30-17:00".to_string()).unwrap();
+        assert!(day.contains(9 * 60 + 30) && !day.contains(17 * 60));
+        assert!(QuietHours::try_from("25:00-08:00".to_string()).is_err());
+        assert!(toml::from_str::<RehoboamConfig>("[notify]\nquiet_hours = \"late\"\n").is_err());
+    }
 }
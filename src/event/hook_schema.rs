@@ -0,0 +1,177 @@
+//! `rehoboam hook --strict-hooks`: spot drift in Claude Code's hook JSON
+//!
+//! [`super::ClaudeHookInput`] defaults every optional field, so a field that
+//! Claude Code renames or drops simply reads as absent and the board is
+//! quietly wrong. In strict mode the hook checks the raw JSON before parsing
+//! and logs one warning per problem: a field the hook type should carry but
+//! doesn't, a field of the wrong JSON type, a field nothing here knows
+//! (possibly a rename) or an unknown hook name. It only logs; the hook still
+//! exits 0 either way.
+
+use serde_json::Value;
+
+/// JSON type a field is expected to have
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Str,
+    Bool,
+    Num,
+    Obj,
+    Any,
+}
+
+impl Kind {
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            Self::Str => value.is_string(),
+            Self::Bool => value.is_boolean(),
+            Self::Num => value.is_number(),
+            Self::Obj => value.is_object(),
+            Self::Any => true,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Str => "a string",
+            Self::Bool => "a boolean",
+            Self::Num => "a number",
+            Self::Obj => "an object",
+            Self::Any => "anything",
+        }
+    }
+}
+
+/// Every top-level field rehoboam knows, read or deliberately ignored
+const KNOWN_FIELDS: &[(&str, Kind)] = &[
+    ("session_id", Kind::Str),
+    ("hook_event_name", Kind::Str),
+    ("transcript_path", Kind::Str),
+    ("cwd", Kind::Str),
+    ("permission_mode", Kind::Str),
+    ("tool_name", Kind::Str),
+    ("tool_input", Kind::Obj),
+    ("tool_use_id", Kind::Str),
+    ("tool_response", Kind::Any),
+    ("reason", Kind::Str),
+    ("message", Kind::Str),
+    ("notification_type", Kind::Str),
+    ("title", Kind::Str),
+    ("error", Kind::Str),
+    ("is_interrupt", Kind::Bool),
+    ("prompt", Kind::Str),
+    ("agent_id", Kind::Str),
+    ("subagent_id", Kind::Str),
+    ("agent_type", Kind::Str),
+    ("agent_transcript_path", Kind::Str),
+    ("description", Kind::Str),
+    ("duration_ms", Kind::Num),
+    ("context_window", Kind::Obj),
+    ("model", Kind::Str),
+    ("source", Kind::Str),
+    ("stop_hook_active", Kind::Bool),
+    ("permission_suggestions", Kind::Any),
+    ("trigger", Kind::Str),
+    ("custom_instructions", Kind::Any),
+    ("team_name", Kind::Str),
+    ("teammate_name", Kind::Str),
+    ("task_id", Kind::Str),
+    ("task_subject", Kind::Str),
+    ("task_description", Kind::Str),
+];
+
+/// Fields every hook carries
+const COMMON_FIELDS: &[&str] = &["session_id", "hook_event_name", "transcript_path", "cwd"];
+
+/// Fields each hook type is expected to carry beyond [`COMMON_FIELDS`]
+fn expected_fields(hook: &str) -> Option<&'static [&'static str]> {
+    let fields: &[&str] = match hook {
+        "PreToolUse" => &["tool_name", "tool_input", "tool_use_id"],
+        "PostToolUse" => &["tool_name", "tool_input", "tool_use_id", "tool_response"],
+        "PostToolUseFailure" => &["tool_name", "tool_use_id", "error"],
+        "PermissionRequest" => &["tool_name", "tool_input"],
+        "Notification" => &["message", "notification_type"],
+        "UserPromptSubmit" => &["prompt"],
+        "Stop" => &["stop_hook_active"],
+        "SubagentStart" => &["agent_id", "agent_type"],
+        "SubagentStop" => &["stop_hook_active", "agent_id", "agent_transcript_path"],
+        "SessionStart" => &["source"],
+        "SessionEnd" => &["reason"],
+        "PreCompact" => &["trigger"],
+        "TeammateIdle" => &["teammate_name", "team_name"],
+        "TaskCompleted" => &["task_id", "task_subject"],
+        _ => return None,
+    };
+    Some(fields)
+}
+
+/// Problems with one hook payload, e.g. "PostToolUse without tool_use_id"
+pub fn check(raw: &Value) -> Vec<String> {
+    let Some(object) = raw.as_object() else {
+        return vec!["hook input is not a JSON object".to_string()];
+    };
+    let hook = object
+        .get("hook_event_name")
+        .and_then(Value::as_str)
+        .unwrap_or("hook");
+    let mut problems = Vec::new();
+
+    let expected = expected_fields(hook);
+    if expected.is_none() && object.contains_key("hook_event_name") {
+        problems.push(format!("unknown hook {hook}"));
+    }
+    for field in COMMON_FIELDS.iter().chain(expected.unwrap_or_default()) {
+        if !object.contains_key(*field) {
+            problems.push(format!("{hook} without {field}"));
+        }
+    }
+
+    for (field, value) in object {
+        match KNOWN_FIELDS.iter().find(|(name, _)| name == field) {
+            Some((_, kind)) if !value.is_null() && !kind.matches(value) => {
+                problems.push(format!("{hook}: {field} is not {}", kind.name()));
+            }
+            Some(_) => {}
+            None => problems.push(format!("{hook}: unrecognised field {field} (renamed?)")),
+        }
+    }
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_check_reports_missing_mistyped_and_unknown_fields() {
+        let common = |hook: &str| {
+            json!({
+                "session_id": "abc",
+                "hook_event_name": hook,
+                "transcript_path": "/t.jsonl",
+                "cwd": "/src",
+            })
+        };
+        let mut ok = common("UserPromptSubmit");
+        ok["prompt"] = json!("hi");
+        assert!(check(&ok).is_empty(), "{:?}", check(&ok));
+
+        let mut drifted = common("PostToolUse");
+        drifted["tool_name"] = json!("Bash");
+        drifted["tool_input"] = json!({"command": "ls"});
+        drifted["tool_response"] = json!({});
+        drifted["toolUseId"] = json!("t1");
+        drifted["duration_ms"] = json!("12");
+        assert_eq!(
+            check(&drifted),
+            [
+                "PostToolUse without tool_use_id",
+                "PostToolUse: duration_ms is not a number",
+                "PostToolUse: unrecognised field toolUseId (renamed?)",
+            ]
+        );
+
+        assert_eq!(check(&common("PreToolLint")), ["unknown hook PreToolLint"]);
+    }
+}
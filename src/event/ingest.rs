@@ -0,0 +1,204 @@
+//! Backpressure for hook ingestion (socket listeners and sprite events)
+//!
+//! The App drains one bounded channel. When a burst of events from many
+//! agents fills it, a blocking `send` would stall the socket connection
+//! tasks, and through them the hook scripts waiting on the socket. Instead,
+//! `PreToolUse` is offered with `try_send` and dropped when the channel is
+//! full: the card misses the running tool (its label, the stuck check and
+//! one latency sample) until the matching `PostToolUse` arrives. The
+//! exception is a `PreToolUse` for a user-input tool (`AskUserQuestion`):
+//! it is what moves the card to Attention(Input) and carries the question,
+//! and without it the later `Stop` can't tell the agent is waiting either.
+//! Every other hook waits for room.
+//! `PostToolUse` is the only event that takes a card out of Permission after
+//! an approval (a later `Stop` ranks below it), so losing it would leave
+//! auto-accept and bulk approve typing into a pane that is no longer at a
+//! prompt; `PostToolUseFailure` carries the error the recent-errors panel
+//! groups, and `SubagentStop` is what ends a running subagent.
+//!
+//! Drops are counted in [`DroppedEvents`], which the App turns into a footer
+//! warning and `rehoboam_events_dropped_total` on `/metrics`.
+//!
+//! Events are not coalesced per pane before enqueueing: pre/post pairs feed
+//! the latency histograms, so merging them would lose data even when the
+//! channel had room.
+
+use super::Event;
+use crate::state::USER_INPUT_TOOLS;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::mpsc::{self, error::TrySendError};
+
+/// Hook events that may be dropped under backpressure
+const DROPPABLE_HOOKS: &[&str] = &["PreToolUse"];
+
+/// Shared count of events dropped because the App fell behind
+#[derive(Debug, Clone, Default)]
+pub struct DroppedEvents(Arc<AtomicU64>);
+
+impl DroppedEvents {
+    /// Events dropped since startup
+    pub fn total(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn record(&self) -> u64 {
+        self.0.fetch_add(1, Ordering::Relaxed) + 1
+    }
+}
+
+/// Sender for the ingestion paths: drops tool events instead of blocking
+#[derive(Debug, Clone)]
+pub struct IngestSender {
+    tx: mpsc::Sender<Event>,
+    dropped: DroppedEvents,
+}
+
+impl IngestSender {
+    pub fn new(tx: mpsc::Sender<Event>, dropped: DroppedEvents) -> Self {
+        Self { tx, dropped }
+    }
+
+    /// The underlying channel, for requests that need an answer
+    pub fn sender(&self) -> &mpsc::Sender<Event> {
+        &self.tx
+    }
+
+    /// Forward `event`, dropping it if the channel is full and it is droppable
+    ///
+    /// Returns `Err` only once the App has gone away.
+    pub async fn forward(&self, event: Event) -> Result<(), mpsc::error::SendError<Event>> {
+        match self.tx.try_send(event) {
+            Ok(()) => Ok(()),
+            Err(TrySendError::Full(event)) if is_droppable(&event) => {
+                let total = self.dropped.record();
+                tracing::debug!(total, "Event channel full, dropped tool event");
+                Ok(())
+            }
+            Err(TrySendError::Full(event)) => self.tx.send(event).await,
+            Err(TrySendError::Closed(event)) => Err(mpsc::error::SendError(event)),
+        }
+    }
+}
+
+/// Whether losing `event` only costs detail, not an agent's status
+fn is_droppable(event: &Event) -> bool {
+    match event {
+        Event::Hook(hook) | Event::RemoteHook { event: hook, .. } => {
+            DROPPABLE_HOOKS.contains(&hook.event.as_str())
+                && !hook
+                    .tool_name
+                    .as_deref()
+                    .is_some_and(|tool| USER_INPUT_TOOLS.contains(&tool))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::HookEvent;
+
+    fn hook(name: &str) -> Event {
+        let json = format!(
+            r#"{{"event":"{name}","status":"working","pane_id":"%1","project":"p","timestamp":1}}"#
+        );
+        Event::Hook(Box::new(serde_json::from_str::<HookEvent>(&json).unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_full_channel_drops_tool_events_but_waits_for_lifecycle() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let dropped = DroppedEvents::default();
+        let ingest = IngestSender::new(tx, dropped.clone());
+
+        ingest.forward(hook("PreToolUse")).await.unwrap();
+        ingest.forward(hook("PreToolUse")).await.unwrap();
+        assert_eq!(dropped.total(), 1, "second tool event dropped");
+
+        // A lifecycle event waits for room rather than being lost
+        let stop = tokio::spawn({
+            let ingest = ingest.clone();
+            async move { ingest.forward(hook("Stop")).await }
+        });
+        let Some(Event::Hook(first)) = rx.recv().await else {
+            panic!("expected hook");
+        };
+        assert_eq!(first.event, "PreToolUse");
+        stop.await.unwrap().unwrap();
+        let Some(Event::Hook(second)) = rx.recv().await else {
+            panic!("expected hook");
+        };
+        assert_eq!(second.event, "Stop");
+        assert_eq!(dropped.total(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_full_channel_keeps_post_tool_use_after_permission() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let dropped = DroppedEvents::default();
+        let ingest = IngestSender::new(tx, dropped.clone());
+
+        let mut permission = hook("PermissionRequest");
+        if let Event::Hook(event) = &mut permission {
+            event.status = "attention".to_string();
+            event.attention_type = Some("permission".to_string());
+        }
+        ingest.forward(permission).await.unwrap();
+        // The approval's PostToolUse arrives while the channel is full
+        let post = tokio::spawn({
+            let ingest = ingest.clone();
+            async move { ingest.forward(hook("PostToolUse")).await }
+        });
+
+        tokio::task::yield_now().await;
+        let mut state = crate::state::AppState::new();
+        let Some(Event::Hook(event)) = rx.recv().await else {
+            panic!("expected hook");
+        };
+        let _ = state.process_event(*event);
+        post.await.unwrap().unwrap();
+        drop(ingest);
+        while let Some(Event::Hook(event)) = rx.recv().await {
+            let _ = state.process_event(*event);
+        }
+        assert_eq!(dropped.total(), 0);
+        assert_eq!(state.agents["%1"].status, crate::state::Status::Working);
+    }
+
+    #[tokio::test]
+    async fn test_full_channel_keeps_user_input_question() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let dropped = DroppedEvents::default();
+        let ingest = IngestSender::new(tx, dropped.clone());
+
+        ingest.forward(hook("UserPromptSubmit")).await.unwrap();
+        // The question arrives while the channel is full
+        let mut ask = hook("PreToolUse");
+        if let Event::Hook(event) = &mut ask {
+            event.tool_name = Some("AskUserQuestion".to_string());
+        }
+        let ask = tokio::spawn({
+            let ingest = ingest.clone();
+            async move { ingest.forward(ask).await }
+        });
+
+        tokio::task::yield_now().await;
+        let mut state = crate::state::AppState::new();
+        let Some(Event::Hook(event)) = rx.recv().await else {
+            panic!("expected hook");
+        };
+        let _ = state.process_event(*event);
+        ask.await.unwrap().unwrap();
+        drop(ingest);
+        while let Some(Event::Hook(event)) = rx.recv().await {
+            let _ = state.process_event(*event);
+        }
+        assert_eq!(dropped.total(), 0);
+        assert_eq!(
+            state.agents["%1"].status,
+            crate::state::Status::Attention(crate::state::AttentionType::Input)
+        );
+    }
+}
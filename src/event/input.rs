@@ -1,7 +1,7 @@
 //! Keyboard input handling for TUI interaction
 //!
-//! Polls for keyboard events using crossterm and forwards them
-//! to the main event loop via an async channel.
+//! Polls for keyboard and bracketed-paste events using crossterm and
+//! forwards them to the main event loop via an async channel.
 
 use super::Event;
 use crossterm::event::{self, Event as CrosstermEvent};
@@ -22,11 +22,14 @@ pub async fn listen(tx: mpsc::Sender<Event>, cancel: CancellationToken) {
             () = tokio::time::sleep(Duration::from_millis(100)) => {
                 // Use non-blocking poll (Duration::ZERO) since we're already in a timeout
                 if event::poll(Duration::ZERO).unwrap_or(false) {
-                    if let Ok(CrosstermEvent::Key(key)) = event::read() {
-                        if tx.send(Event::Key(key)).await.is_err() {
-                            // Channel closed, exit
-                            break;
-                        }
+                    let event = match event::read() {
+                        Ok(CrosstermEvent::Key(key)) => Event::Key(key),
+                        Ok(CrosstermEvent::Paste(text)) => Event::Paste(text),
+                        _ => continue,
+                    };
+                    if tx.send(event).await.is_err() {
+                        // Channel closed, exit
+                        break;
                     }
                 }
             }
@@ -0,0 +1,106 @@
+//! Prometheus scrape endpoint (`--metrics-port`)
+//!
+//! A deliberately tiny HTTP/1.1 responder: `GET /metrics` is answered with a
+//! snapshot rendered by the App (via [`Event::Metrics`], the same round trip
+//! the control socket uses for `export-state`), anything else gets a 404.
+//! Each connection serves one request and is closed.
+
+use super::Event;
+use color_eyre::eyre::{self, WrapErr};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{timeout, Duration};
+
+/// How long a scraper has to send its request line
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait for the App to render a snapshot
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Serve `/metrics` on `addr` until the task is aborted
+pub async fn serve(tx: mpsc::Sender<Event>, addr: &str) -> eyre::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .wrap_err_with(|| format!("Failed to bind metrics endpoint on {addr}"))?;
+    tracing::info!("Metrics endpoint on http://{}/metrics", addr);
+
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::error!("Metrics accept error: {}", e);
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                continue;
+            }
+        };
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_scrape(stream, &tx).await {
+                tracing::debug!("Metrics request failed: {}", e);
+            }
+        });
+    }
+}
+
+/// Answer one HTTP request
+async fn handle_scrape(stream: TcpStream, tx: &mpsc::Sender<Event>) -> eyre::Result<()> {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+    let request_line = timeout(REQUEST_TIMEOUT, lines.next_line())
+        .await
+        .wrap_err("request timed out")??
+        .unwrap_or_default();
+
+    let response = if is_metrics_request(&request_line) {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        tx.send(Event::Metrics { reply: reply_tx })
+            .await
+            .wrap_err("App is gone")?;
+        match timeout(SNAPSHOT_TIMEOUT, reply_rx).await {
+            Ok(Ok(body)) => http_response("200 OK", "text/plain; version=0.0.4", &body),
+            _ => http_response("503 Service Unavailable", "text/plain", "busy\n"),
+        }
+    } else {
+        http_response("404 Not Found", "text/plain", "try /metrics\n")
+    };
+
+    write_half.write_all(response.as_bytes()).await?;
+    write_half.shutdown().await?;
+    Ok(())
+}
+
+/// True for `GET /metrics` (query strings are ignored)
+fn is_metrics_request(request_line: &str) -> bool {
+    let mut parts = request_line.split_whitespace();
+    parts.next() == Some("GET")
+        && parts
+            .next()
+            .is_some_and(|path| path.split('?').next() == Some("/metrics"))
+}
+
+pub(super) fn http_response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_get_metrics_is_served() {
+        assert!(is_metrics_request("GET /metrics HTTP/1.1"));
+        assert!(is_metrics_request("GET /metrics?x=1 HTTP/1.1"));
+        assert!(!is_metrics_request("POST /metrics HTTP/1.1"));
+        assert!(!is_metrics_request("GET / HTTP/1.1"));
+        assert!(!is_metrics_request(""));
+
+        let response = http_response("200 OK", "text/plain", "a 1\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("Content-Length: 4\r\n"));
+        assert!(response.ends_with("\r\n\r\na 1\n"));
+    }
+}
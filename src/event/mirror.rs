@@ -0,0 +1,278 @@
+//! TCP mirror mode: watch a remote rehoboam board read-only
+//!
+//! The host TUI (`--listen-tcp <addr>`) rebroadcasts every validated hook
+//! event from its Unix socket to authenticated TCP clients. A mirror TUI
+//! (`--connect <addr>`) consumes that stream instead of binding a local
+//! socket, and disables keybinds that would act on local tmux panes.
+//!
+//! Wire protocol (newline-delimited JSON, same `HookEvent` shape as the
+//! Unix socket):
+//! 1. Client sends `{"token":"<shared token>"}` (at most 512 bytes, newline included)
+//! 2. Host compares it in constant time and closes the connection on mismatch
+//!    or an oversized line
+//! 3. Host streams one `HookEvent` per line until either side disconnects
+
+use super::{Event, HookEvent};
+use color_eyre::eyre::{self, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{broadcast, mpsc, Semaphore};
+use tokio::time::{timeout, Duration};
+
+/// Maximum concurrent mirror clients
+const MAX_MIRROR_CLIENTS: usize = 16;
+
+/// Buffered lines per client before a slow mirror starts skipping events
+const MIRROR_BUFFER: usize = 256;
+
+/// How long a client has to send its token line
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest token line read from an unauthenticated client
+const MAX_HANDSHAKE_BYTES: u64 = 512;
+
+/// Reconnect backoff ceiling for `--connect`
+const MAX_RECONNECT_BACKOFF_MS: u64 = 10_000;
+
+/// Sender side of the host's event rebroadcast (raw JSON lines)
+pub type MirrorHub = broadcast::Sender<Arc<str>>;
+
+/// Create the hub the Unix socket listener publishes into
+pub fn hub() -> MirrorHub {
+    broadcast::channel(MIRROR_BUFFER).0
+}
+
+/// First line a mirror client sends
+#[derive(Debug, Serialize, Deserialize)]
+struct Handshake {
+    token: String,
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch
+///
+/// Only the length is observable through timing, which reveals nothing
+/// useful about the token contents.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Serve the hook event stream to authenticated mirror clients
+pub async fn listen_tcp(hub: MirrorHub, addr: &str, token: Arc<str>) -> eyre::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .wrap_err_with(|| format!("Failed to bind mirror listener on {addr}"))?;
+    tracing::info!("Mirror listener on {}", addr);
+
+    let semaphore = Arc::new(Semaphore::new(MAX_MIRROR_CLIENTS));
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::error!("Mirror accept error: {}", e);
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                continue;
+            }
+        };
+
+        let Ok(permit) = semaphore.clone().try_acquire_owned() else {
+            tracing::warn!(
+                %peer,
+                "Mirror client limit reached ({} max), dropping connection",
+                MAX_MIRROR_CLIENTS
+            );
+            continue;
+        };
+
+        let rx = hub.subscribe();
+        let token = token.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            if let Err(e) = serve_client(stream, rx, &token).await {
+                tracing::debug!(%peer, error = %e, "Mirror client disconnected");
+            }
+        });
+    }
+}
+
+/// Authenticate one client, then forward events until it goes away
+async fn serve_client(
+    stream: TcpStream,
+    mut rx: broadcast::Receiver<Arc<str>>,
+    token: &str,
+) -> eyre::Result<()> {
+    let peer = stream.peer_addr()?;
+    let (read_half, mut write_half) = stream.into_split();
+    // Bounded before authentication: a peer can't make us buffer a huge line
+    let mut reader = BufReader::new(read_half.take(MAX_HANDSHAKE_BYTES));
+
+    let mut line = Vec::new();
+    timeout(HANDSHAKE_TIMEOUT, reader.read_until(b'\n', &mut line))
+        .await
+        .map_err(|_| eyre::eyre!("handshake timed out"))??;
+    if !line.ends_with(b"\n") {
+        tracing::warn!(%peer, "Rejected mirror client: handshake too long or incomplete");
+        return Ok(());
+    }
+    let presented = serde_json::from_slice::<Handshake>(&line)
+        .map(|h| h.token)
+        .unwrap_or_default();
+    if !constant_time_eq(presented.as_bytes(), token.as_bytes()) {
+        tracing::warn!(%peer, "Rejected mirror client with bad token");
+        return Ok(());
+    }
+    tracing::info!(%peer, "Mirror client connected");
+
+    loop {
+        match rx.recv().await {
+            Ok(json) => {
+                write_half.write_all(json.as_bytes()).await?;
+                write_half.write_all(b"\n").await?;
+            }
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!(%peer, skipped, "Mirror client lagging, events skipped");
+            }
+            Err(broadcast::error::RecvError::Closed) => return Ok(()),
+        }
+    }
+}
+
+/// Feed a read-only TUI from a remote host, reconnecting on disconnect
+///
+/// Received events are also republished on `hub` (when set), so the web
+/// dashboard's `/events` and our own `--listen-tcp` clients see them.
+pub async fn connect(tx: mpsc::Sender<Event>, addr: &str, token: &str, hub: Option<MirrorHub>) {
+    let mut backoff_ms: u64 = 0;
+
+    loop {
+        match stream_from(&tx, addr, token, hub.as_ref()).await {
+            Ok(()) => {
+                // Clean EOF: host went away, or our channel closed
+                if tx.is_closed() {
+                    return;
+                }
+                tracing::info!("Mirror host {} closed the connection", addr);
+                backoff_ms = 0;
+            }
+            Err(e) => tracing::warn!("Mirror connection to {} failed: {}", addr, e),
+        }
+
+        backoff_ms = if backoff_ms == 0 {
+            500
+        } else {
+            (backoff_ms * 2).min(MAX_RECONNECT_BACKOFF_MS)
+        };
+        tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
+    }
+}
+
+/// One connection lifetime of `connect`
+async fn stream_from(
+    tx: &mpsc::Sender<Event>,
+    addr: &str,
+    token: &str,
+    hub: Option<&MirrorHub>,
+) -> eyre::Result<()> {
+    let mut stream = TcpStream::connect(addr).await?;
+    let handshake = serde_json::to_string(&Handshake {
+        token: token.to_string(),
+    })?;
+    stream
+        .write_all(format!("{handshake}\n").as_bytes())
+        .await?;
+    tracing::info!("Mirroring rehoboam at {}", addr);
+
+    let mut lines = BufReader::new(stream).lines();
+    while let Some(line) = lines.next_line().await? {
+        match serde_json::from_str::<HookEvent>(&line) {
+            Ok(event) => {
+                if let Err(e) = event.validate() {
+                    tracing::warn!("Invalid mirrored event: {}", e);
+                    continue;
+                }
+                if let Some(hub) = hub {
+                    // No receivers is fine: no page or client is open
+                    let _ = hub.send(Arc::from(line.as_str()));
+                }
+                if tx.send(Event::Hook(Box::new(event))).await.is_err() {
+                    return Ok(());
+                }
+            }
+            Err(e) => tracing::warn!("Failed to parse mirrored event: {}", e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret!"));
+        assert!(!constant_time_eq(b"", b"x"));
+        assert!(constant_time_eq(b"", b""));
+    }
+
+    #[tokio::test]
+    async fn test_mirror_round_trip_requires_token() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+        drop(listener);
+
+        let hub = hub();
+        let server_hub = hub.clone();
+        let server_addr = addr.clone();
+        tokio::spawn(async move {
+            let _ = listen_tcp(server_hub, &server_addr, Arc::from("s3cret")).await;
+        });
+        // Give the listener a moment to bind
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        // Wrong token: connection is closed without any events
+        let mut bad = TcpStream::connect(&addr).await.unwrap();
+        bad.write_all(b"{\"token\":\"nope\"}\n").await.unwrap();
+        let mut bad_lines = BufReader::new(bad).lines();
+        assert!(bad_lines.next_line().await.unwrap().is_none());
+
+        // Right token, but past the handshake limit: also closed
+        let mut long = TcpStream::connect(&addr).await.unwrap();
+        let padded = format!("{{\"token\":\"s3cret\",\"pad\":\"{}\"}}\n", "x".repeat(600));
+        long.write_all(padded.as_bytes()).await.unwrap();
+        // (unread bytes may turn the close into a reset)
+        let mut long_lines = BufReader::new(long).lines();
+        assert!(!matches!(long_lines.next_line().await, Ok(Some(_))));
+
+        // Right token: events flow through as HookEvents
+        let (tx, mut rx) = mpsc::channel(8);
+        let client_addr = addr.clone();
+        let relay = super::hub();
+        let mut relayed = relay.subscribe();
+        tokio::spawn(async move {
+            let _ = stream_from(&tx, &client_addr, "s3cret", Some(&relay)).await;
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let json = r#"{"event":"PreToolUse","status":"working","pane_id":"%1","project":"proj","timestamp":1}"#;
+        hub.send(Arc::from(json)).unwrap();
+
+        let received = timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        match received {
+            Event::Hook(event) => assert_eq!(event.pane_id, "%1"),
+            other => panic!("unexpected event: {other:?}"),
+        }
+        // The mirror republishes what it receives (for its own /events)
+        assert_eq!(&*relayed.recv().await.unwrap(), json);
+    }
+}
@@ -1,7 +1,16 @@
+pub mod hook_schema;
+pub mod ingest;
 pub mod input;
+pub mod metrics;
+pub mod mirror;
+pub mod replay;
+pub mod rpc;
 pub mod socket;
 pub mod status;
+pub mod web;
 
+use crate::sprite::checkpoints::CheckpointRecord;
+use crate::state::AgentSnapshot;
 use serde::{Deserialize, Serialize};
 
 /// Context window usage information from Claude Code 2.1.x
@@ -27,8 +36,12 @@ pub struct ContextWindow {
 pub enum Event {
     /// Hook event from Claude Code (boxed to reduce enum size)
     Hook(Box<HookEvent>),
+    /// `--replay` reached this recorded timestamp (sent before each replayed hook)
+    ReplayClock(i64),
     /// Keyboard input
     Key(crossterm::event::KeyEvent),
+    /// Bracketed paste (the whole pasted text, newlines included)
+    Paste(String),
     /// Remote hook event from a sprite
     RemoteHook {
         /// Sprite identifier
@@ -43,6 +56,83 @@ pub enum Event {
         /// New status
         status: SpriteStatusType,
     },
+    /// Checkpoint timeline fetched for a sprite
+    Checkpoints {
+        /// Sprite identifier
+        sprite_id: String,
+        /// Checkpoints (newest first) or the API error
+        result: Result<Vec<CheckpointRecord>, String>,
+    },
+    /// Checkpoint restore finished
+    CheckpointRestored {
+        /// Sprite identifier
+        sprite_id: String,
+        /// Checkpoint the sprite was rolled back to
+        checkpoint_id: String,
+        /// API error, if the restore failed
+        result: Result<(), String>,
+    },
+    /// Input dispatched to a sprite could not be delivered
+    SpriteInputFailed {
+        /// Sprite identifier
+        sprite_id: String,
+        /// What went wrong
+        error: String,
+    },
+    /// Batch spawn (`@FILE` prompt) finished starting its agents
+    BatchSpawned {
+        /// Agents whose pane was created
+        spawned: usize,
+        /// Failed entries as "line 3: error"
+        failed: Vec<String>,
+    },
+    /// Pane output captured for the pane output view
+    PaneOutput {
+        /// Pane the capture is from
+        pane_id: String,
+        /// Captured text (color escapes included) or the tmux error
+        result: Result<String, String>,
+    },
+    /// Ahead/behind counts computed off the UI thread
+    GitUpstream {
+        /// (pane_id, (ahead, behind)); None when the branch has no upstream
+        counts: Vec<(String, Option<(u32, u32)>)>,
+    },
+    /// Board snapshot requested over the control socket
+    Snapshot {
+        /// Channel the App answers on
+        reply: tokio::sync::oneshot::Sender<Vec<AgentSnapshot>>,
+    },
+    /// Command from a JSON-RPC control client
+    Rpc {
+        /// Validated call
+        call: rpc::RpcCall,
+        /// Channel the App answers on (result value or error object)
+        reply: tokio::sync::oneshot::Sender<Result<serde_json::Value, rpc::RpcError>>,
+    },
+    /// Prometheus scrape from the metrics endpoint
+    Metrics {
+        /// Channel the App answers on (exposition text)
+        reply: tokio::sync::oneshot::Sender<String>,
+    },
+    /// Socket listener went down or came back (see `socket::listen`)
+    ListenerStatus {
+        /// Socket the listener serves
+        socket: std::path::PathBuf,
+        /// Why it is down and when it retries; `None` once it is back up
+        error: Option<String>,
+    },
+}
+
+/// Request/response messages on the Unix socket
+///
+/// Hook events are fire-and-forget; control requests get a single JSON
+/// line written back on the same connection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "request", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Dump every agent on the board
+    Snapshot,
 }
 
 /// Sprite status types
@@ -84,7 +174,7 @@ pub enum EventSource {
 ///
 /// # Validation
 /// Use `validate()` to check that required fields are present and status is valid.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct HookEvent {
     /// Hook name that triggered this event
     pub event: String,
@@ -227,12 +317,56 @@ pub struct HookEvent {
     /// Tool response output (PostToolUse)
     #[serde(default)]
     pub tool_response: Option<serde_json::Value>,
+
+    /// Desktop notification the hook wants shown (sent by the TUI so it can throttle)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop_notification: Option<DesktopNotification>,
+
+    /// Local socket the event arrived on (set by the TUI when listening on
+    /// several `--socket` paths)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+}
+
+/// Desktop notification requested by a hook
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DesktopNotification {
+    pub title: String,
+    pub message: String,
+    /// Sound name, or sound file path on Linux (`[notify.sounds]`)
+    #[serde(default)]
+    pub sound: Option<String>,
+    /// Permission or input prompt: its sound wins when notifications coalesce
+    #[serde(default)]
+    pub urgent: bool,
 }
 
 impl HookEvent {
     /// Valid status values (idle removed - now Attention with Waiting type)
     pub const VALID_STATUSES: [&'static str; 3] = ["working", "attention", "compacting"];
 
+    /// Event generated by the TUI itself rather than a hook (e.g. idle timeouts)
+    ///
+    /// Only used for the event log; it is never fed back into `process_event`.
+    pub fn internal(
+        event: &str,
+        status: &str,
+        attention_type: Option<&str>,
+        pane_id: &str,
+        project: &str,
+        timestamp: i64,
+    ) -> Self {
+        Self {
+            event: event.to_string(),
+            status: status.to_string(),
+            attention_type: attention_type.map(String::from),
+            pane_id: pane_id.to_string(),
+            project: project.to_string(),
+            timestamp,
+            ..Self::default()
+        }
+    }
+
     /// Validate that required fields are present and status is valid
     ///
     /// # Returns
@@ -463,45 +597,10 @@ mod tests {
         let event = HookEvent {
             event: "Test".to_string(),
             status: "working".to_string(),
-            attention_type: None,
             pane_id: "".to_string(),
             project: "test".to_string(),
             timestamp: 0,
-            session_id: None,
-            tool_name: None,
-            tool_input: None,
-            tool_use_id: None,
-            reason: None,
-            notification_type: None,
-            notification_title: None,
-            error: None,
-            is_interrupt: None,
-            prompt: None,
-            subagent_id: None,
-            description: None,
-            subagent_duration_ms: None,
-            source: EventSource::Local,
-            context_window: None,
-            agent_type: None,
-            permission_mode: None,
-            cwd: None,
-            transcript_path: None,
-            team_name: None,
-            team_agent_id: None,
-            team_agent_name: None,
-            team_agent_type: None,
-            claude_code_version: None,
-            model: None,
-            session_source: None,
-            stop_hook_active: None,
-            agent_transcript_path: None,
-            trigger: None,
-            effort_level: None,
-            teammate_name: None,
-            task_id: None,
-            task_subject: None,
-            task_description: None,
-            tool_response: None,
+            ..HookEvent::default()
         };
         assert_eq!(event.validate(), Err("pane_id is required"));
     }
@@ -511,45 +610,10 @@ mod tests {
         let event = HookEvent {
             event: "Test".to_string(),
             status: "working".to_string(),
-            attention_type: None,
             pane_id: "42".to_string(),
             project: "".to_string(),
             timestamp: 0,
-            session_id: None,
-            tool_name: None,
-            tool_input: None,
-            tool_use_id: None,
-            reason: None,
-            notification_type: None,
-            notification_title: None,
-            error: None,
-            is_interrupt: None,
-            prompt: None,
-            subagent_id: None,
-            description: None,
-            subagent_duration_ms: None,
-            source: EventSource::Local,
-            context_window: None,
-            agent_type: None,
-            permission_mode: None,
-            cwd: None,
-            transcript_path: None,
-            team_name: None,
-            team_agent_id: None,
-            team_agent_name: None,
-            team_agent_type: None,
-            claude_code_version: None,
-            model: None,
-            session_source: None,
-            stop_hook_active: None,
-            agent_transcript_path: None,
-            trigger: None,
-            effort_level: None,
-            teammate_name: None,
-            task_id: None,
-            task_subject: None,
-            task_description: None,
-            tool_response: None,
+            ..HookEvent::default()
         };
         assert_eq!(event.validate(), Err("project is required"));
     }
@@ -559,45 +623,10 @@ mod tests {
         let event = HookEvent {
             event: "Test".to_string(),
             status: "invalid".to_string(),
-            attention_type: None,
             pane_id: "42".to_string(),
             project: "test".to_string(),
             timestamp: 0,
-            session_id: None,
-            tool_name: None,
-            tool_input: None,
-            tool_use_id: None,
-            reason: None,
-            notification_type: None,
-            notification_title: None,
-            error: None,
-            is_interrupt: None,
-            prompt: None,
-            subagent_id: None,
-            description: None,
-            subagent_duration_ms: None,
-            source: EventSource::Local,
-            context_window: None,
-            agent_type: None,
-            permission_mode: None,
-            cwd: None,
-            transcript_path: None,
-            team_name: None,
-            team_agent_id: None,
-            team_agent_name: None,
-            team_agent_type: None,
-            claude_code_version: None,
-            model: None,
-            session_source: None,
-            stop_hook_active: None,
-            agent_transcript_path: None,
-            trigger: None,
-            effort_level: None,
-            teammate_name: None,
-            task_id: None,
-            task_subject: None,
-            task_description: None,
-            tool_response: None,
+            ..HookEvent::default()
         };
         assert_eq!(
             event.validate(),
@@ -611,45 +640,10 @@ mod tests {
             let event = HookEvent {
                 event: "Test".to_string(),
                 status: status.to_string(),
-                attention_type: None,
                 pane_id: "42".to_string(),
                 project: "test".to_string(),
                 timestamp: 0,
-                session_id: None,
-                tool_name: None,
-                tool_input: None,
-                tool_use_id: None,
-                reason: None,
-                notification_type: None,
-                notification_title: None,
-                error: None,
-                is_interrupt: None,
-                prompt: None,
-                subagent_id: None,
-                description: None,
-                subagent_duration_ms: None,
-                source: EventSource::Local,
-                context_window: None,
-                agent_type: None,
-                permission_mode: None,
-                cwd: None,
-                transcript_path: None,
-                team_name: None,
-                team_agent_id: None,
-                team_agent_name: None,
-                team_agent_type: None,
-                claude_code_version: None,
-                model: None,
-                session_source: None,
-                stop_hook_active: None,
-                agent_transcript_path: None,
-                trigger: None,
-                effort_level: None,
-                teammate_name: None,
-                task_id: None,
-                task_subject: None,
-                task_description: None,
-                tool_response: None,
+                ..HookEvent::default()
             };
             assert!(
                 event.validate().is_ok(),
@@ -0,0 +1,184 @@
+//! Record and replay hook event streams
+//!
+//! `--record <file>` appends every `HookEvent` the TUI receives as one JSON
+//! line, exactly as it arrived. `--replay <file>` feeds such a file back
+//! through `AppState::process_event` instead of binding the socket, pacing
+//! events by the gaps between their `timestamp`s (divided by
+//! `--replay-speed`), so a hook-handling bug can be reproduced on demand.
+//!
+//! Replayed state runs on the recorded clock, not the wall clock: before
+//! each event the App moves `AppState::clock` to its timestamp and ticks, so
+//! idle, stale and stuck timeouts fire where they did live even when a fast
+//! replay squeezes an hour of gaps into a second.
+
+use super::{Event, HookEvent};
+use color_eyre::eyre::{Result, WrapErr};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+/// Longest pause between replayed events, however large the recorded gap
+const MAX_REPLAY_GAP: Duration = Duration::from_secs(5);
+
+/// Appends received hook events to a file
+#[derive(Debug)]
+pub struct EventRecorder {
+    writer: BufWriter<File>,
+}
+
+impl EventRecorder {
+    /// Open `path` for appending (created if missing)
+    pub fn open(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open record file {}", path.display()))?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    /// Write one event; flushed immediately so a crash loses nothing
+    pub fn record(&mut self, event: &HookEvent) {
+        let result = serde_json::to_writer(&mut self.writer, event)
+            .map_err(std::io::Error::from)
+            .and_then(|()| self.writer.write_all(b"\n"))
+            .and_then(|()| self.writer.flush());
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "Failed to record event");
+        }
+    }
+}
+
+/// Delay before replaying an event recorded `gap_secs` after the previous one
+fn replay_delay(gap_secs: i64, speed: f64) -> Duration {
+    if speed <= 0.0 || gap_secs <= 0 {
+        return Duration::ZERO;
+    }
+    Duration::from_secs_f64(gap_secs as f64 / speed).min(MAX_REPLAY_GAP)
+}
+
+/// Feed a recorded event file into the TUI
+pub async fn replay(tx: mpsc::Sender<Event>, path: &Path, speed: f64) -> Result<()> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read replay file {}", path.display()))?;
+    let mut previous: Option<i64> = None;
+    let mut count = 0usize;
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event = match serde_json::from_str::<HookEvent>(line) {
+            Ok(event) => event,
+            Err(e) => {
+                tracing::warn!("Skipping unparseable replay line: {}", e);
+                continue;
+            }
+        };
+        if let Err(e) = event.validate() {
+            tracing::warn!("Skipping invalid replay event: {}", e);
+            continue;
+        }
+
+        if let Some(prev) = previous {
+            tokio::time::sleep(replay_delay(event.timestamp - prev, speed)).await;
+        }
+        previous = Some(event.timestamp);
+
+        if tx.send(Event::ReplayClock(event.timestamp)).await.is_err() {
+            break;
+        }
+        if tx.send(Event::Hook(Box::new(event))).await.is_err() {
+            break;
+        }
+        count += 1;
+    }
+    tracing::info!(count, "Replay finished");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_delay_scales_and_caps() {
+        assert_eq!(replay_delay(4, 2.0), Duration::from_secs(2));
+        assert_eq!(replay_delay(4, 0.0), Duration::ZERO);
+        assert_eq!(replay_delay(-3, 1.0), Duration::ZERO);
+        assert_eq!(replay_delay(600, 1.0), MAX_REPLAY_GAP);
+    }
+
+    #[tokio::test]
+    async fn test_recorded_events_replay_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+
+        let mut recorder = EventRecorder::open(&path).unwrap();
+        for (i, pane) in ["%1", "%2"].iter().enumerate() {
+            let json = format!(
+                r#"{{"event":"PreToolUse","status":"working","pane_id":"{pane}","project":"p","timestamp":{i}}}"#
+            );
+            recorder.record(&serde_json::from_str(&json).unwrap());
+        }
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        let (tx, mut rx) = mpsc::channel(8);
+        replay(tx, &path, 0.0).await.unwrap();
+        let mut panes = Vec::new();
+        while let Some(event) = rx.recv().await {
+            if let Event::Hook(event) = event {
+                panes.push(event.pane_id);
+            }
+        }
+        assert_eq!(panes, vec!["%1", "%2"]);
+    }
+
+    #[tokio::test]
+    async fn test_fast_replay_keeps_recorded_idle_timeout() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+
+        // %1 went quiet for two minutes before %2 showed up
+        let mut recorder = EventRecorder::open(&path).unwrap();
+        for (event, pane, timestamp) in
+            [("PostToolUse", "%1", 1_000), ("SessionStart", "%2", 1_120)]
+        {
+            let json = format!(
+                r#"{{"event":"{event}","status":"working","pane_id":"{pane}","project":"p","timestamp":{timestamp}}}"#
+            );
+            recorder.record(&serde_json::from_str(&json).unwrap());
+        }
+
+        let (tx, mut rx) = mpsc::channel(8);
+        replay(tx, &path, 0.0).await.unwrap();
+        let mut config = crate::config::RehoboamConfig::default();
+        config.persistence.enabled = false;
+        let mut app = crate::app::App::new(false, None, &config);
+        while let Some(event) = rx.recv().await {
+            app.handle_event(event);
+        }
+
+        let state = &app.state;
+        assert_eq!(
+            state.agents["%1"].status,
+            crate::state::Status::Attention(crate::state::AttentionType::Waiting)
+        );
+        let timeout = state
+            .events
+            .iter()
+            .find(|logged| logged.event.event == "IdleTimeout")
+            .expect("idle timeout logged");
+        assert_eq!(timeout.event.timestamp, 1_120);
+        assert_eq!(state.agents["%2"].last_update, 1_120);
+    }
+}
@@ -0,0 +1,480 @@
+//! JSON-RPC control socket (`--control-socket`)
+//!
+//! Lets scripts drive the TUI without keystroke injection. Kept separate from
+//! the hook socket: hook events are fire-and-forget one-liners, while control
+//! clients hold a connection open and issue commands that mutate state.
+//!
+//! # Framing
+//!
+//! Newline-delimited JSON-RPC 2.0. Each request is one line; each response is
+//! one line on the same connection, in request order. Requests without an
+//! `id` are notifications and get no response. Batches are not supported.
+//!
+//! ```json
+//! {"jsonrpc":"2.0","id":1,"method":"approve","params":{"pane_id":"%3"}}
+//! {"jsonrpc":"2.0","id":1,"result":{"ok":true}}
+//! ```
+//!
+//! # Versioning
+//!
+//! `version` returns `{"protocol": PROTOCOL_VERSION, "rehoboam": "<crate version>"}`.
+//! The protocol number is bumped on any breaking change to a method's params
+//! or result; new methods and new optional params are not breaking.
+//!
+//! # Methods
+//!
+//! | Method | Params | Result |
+//! |--------|--------|--------|
+//! | `version` | | protocol and crate version |
+//! | `list_agents` | | agent snapshots (same shape as `export-state`) |
+//! | `spawn` | `project`, `prompt?`, `sprite?`, `github_repo?` | `{"ok":true}` |
+//! | `approve` / `reject` | `pane_id` | `{"ok":true}` |
+//! | `send_input` | `pane_id`, `text` | `{"ok":true}` |
+
+use super::Event;
+use color_eyre::eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::time::{timeout, Duration};
+
+/// Bumped on breaking changes to any method's params or result
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Maximum concurrent control clients
+const MAX_CONTROL_CLIENTS: usize = 16;
+
+/// Close connections that send nothing for this long
+const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How long to wait for the App to answer one call
+const CALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Standard JSON-RPC error codes, plus application codes in the -32000 range
+pub mod codes {
+    pub const PARSE_ERROR: i64 = -32700;
+    pub const INVALID_REQUEST: i64 = -32600;
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    pub const INVALID_PARAMS: i64 = -32602;
+    /// The operation ran and failed (unknown agent, tmux error, ...)
+    pub const OPERATION_FAILED: i64 = -32000;
+    /// Mutating call on a board that is only watched (`--connect`/`--replay`)
+    pub const READ_ONLY: i64 = -32001;
+    /// The App did not answer in time
+    pub const BUSY: i64 = -32002;
+}
+
+/// Error object of a failed call
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+impl RpcError {
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// An operation that ran and failed
+    pub fn failed(message: impl Into<String>) -> Self {
+        Self::new(codes::OPERATION_FAILED, message)
+    }
+}
+
+/// One request line
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    /// Absent for notifications
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// One response line
+#[derive(Debug, Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+impl RpcResponse {
+    fn new(id: Value, outcome: Result<Value, RpcError>) -> Self {
+        let (result, error) = match outcome {
+            Ok(value) => (Some(value), None),
+            Err(e) => (None, Some(e)),
+        };
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result,
+            error,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PaneParams {
+    pane_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SendInputParams {
+    pane_id: String,
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpawnParams {
+    project: String,
+    #[serde(default)]
+    prompt: String,
+    #[serde(default)]
+    sprite: bool,
+    #[serde(default)]
+    github_repo: String,
+}
+
+/// A validated call, ready for the App
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RpcCall {
+    ListAgents,
+    Spawn {
+        project: String,
+        prompt: String,
+        sprite: bool,
+        github_repo: String,
+    },
+    Approve {
+        pane_id: String,
+    },
+    Reject {
+        pane_id: String,
+    },
+    SendInput {
+        pane_id: String,
+        text: String,
+    },
+}
+
+impl RpcCall {
+    /// Map a method name and its params onto a call
+    ///
+    /// `version` is answered by the socket task itself and never gets here.
+    fn parse(method: &str, params: Value) -> Result<Self, RpcError> {
+        // Absent params and `{}` are equivalent
+        let params = if params.is_null() {
+            Value::Object(serde_json::Map::new())
+        } else {
+            params
+        };
+        fn decode<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+            serde_json::from_value(params)
+                .map_err(|e| RpcError::new(codes::INVALID_PARAMS, e.to_string()))
+        }
+
+        match method {
+            "list_agents" => Ok(Self::ListAgents),
+            "spawn" => {
+                let p: SpawnParams = decode(params)?;
+                Ok(Self::Spawn {
+                    project: p.project,
+                    prompt: p.prompt,
+                    sprite: p.sprite,
+                    github_repo: p.github_repo,
+                })
+            }
+            "approve" => {
+                let p: PaneParams = decode(params)?;
+                Ok(Self::Approve { pane_id: p.pane_id })
+            }
+            "reject" => {
+                let p: PaneParams = decode(params)?;
+                Ok(Self::Reject { pane_id: p.pane_id })
+            }
+            "send_input" => {
+                let p: SendInputParams = decode(params)?;
+                Ok(Self::SendInput {
+                    pane_id: p.pane_id,
+                    text: p.text,
+                })
+            }
+            other => Err(RpcError::new(
+                codes::METHOD_NOT_FOUND,
+                format!("unknown method {other:?}"),
+            )),
+        }
+    }
+
+    /// True for calls that change agents (refused on read-only boards)
+    pub fn mutates(&self) -> bool {
+        !matches!(self, Self::ListAgents)
+    }
+}
+
+/// Result of the `version` method
+fn version_info() -> Value {
+    serde_json::json!({
+        "protocol": PROTOCOL_VERSION,
+        "rehoboam": env!("CARGO_PKG_VERSION"),
+    })
+}
+
+/// Listen for control clients on `socket_path` until the task is aborted
+pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
+    let listener = bind_private(socket_path)
+        .wrap_err_with(|| format!("Failed to bind control socket {socket_path:?}"))?;
+    tracing::info!("Control socket on {:?}", socket_path);
+
+    let semaphore = Arc::new(Semaphore::new(MAX_CONTROL_CLIENTS));
+
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                let Ok(permit) = semaphore.clone().try_acquire_owned() else {
+                    tracing::warn!(
+                        "Control connection limit reached ({} max), dropping connection",
+                        MAX_CONTROL_CLIENTS
+                    );
+                    continue;
+                };
+                let tx = tx.clone();
+                tokio::spawn(async move {
+                    let _permit = permit;
+                    if let Err(e) = serve_client(stream, &tx).await {
+                        tracing::debug!("Control client error: {}", e);
+                    }
+                });
+            }
+            Err(e) => {
+                tracing::error!("Control accept error: {}", e);
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+    }
+}
+
+/// Bind `socket_path` so no other user can ever connect
+///
+/// Commands reach every agent's terminal, so the socket is owner only. A
+/// chmod after binding at the final path leaves a window where the umask
+/// decides who may connect; instead the socket is bound inside a fresh 0700
+/// directory next to it, restricted to 0600 there, then renamed into place.
+fn bind_private(socket_path: &Path) -> Result<UnixListener> {
+    if socket_path.exists() {
+        std::fs::remove_file(socket_path)?;
+    }
+    let name = socket_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let dir = socket_path.with_file_name(format!(".{name}.{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::DirBuilder::new().mode(0o700).create(&dir)?;
+
+    let staged = dir.join("sock");
+    let bound = UnixListener::bind(&staged)
+        .map_err(Into::into)
+        .and_then(|listener| {
+            std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+            std::fs::rename(&staged, socket_path)?;
+            Ok(listener)
+        });
+    let _ = std::fs::remove_dir_all(&dir);
+    bound
+}
+
+/// Answer requests from one client until it disconnects or goes idle
+async fn serve_client(stream: UnixStream, tx: &mpsc::Sender<Event>) -> Result<()> {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+
+    while let Ok(line) = timeout(IDLE_TIMEOUT, lines.next_line()).await {
+        let Some(line) = line? else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(&line, tx).await {
+            let mut data = serde_json::to_string(&response)?;
+            data.push('\n');
+            write_half.write_all(data.as_bytes()).await?;
+        }
+    }
+    let _ = write_half.shutdown().await;
+    Ok(())
+}
+
+/// Handle one request line, returning the response unless it was a notification
+async fn handle_line(line: &str, tx: &mpsc::Sender<Event>) -> Option<RpcResponse> {
+    let value: Value = match serde_json::from_str(line) {
+        Ok(value) => value,
+        Err(e) => {
+            return Some(RpcResponse::new(
+                Value::Null,
+                Err(RpcError::new(codes::PARSE_ERROR, e.to_string())),
+            ))
+        }
+    };
+    let request: RpcRequest = match serde_json::from_value(value) {
+        Ok(request) => request,
+        Err(e) => {
+            return Some(RpcResponse::new(
+                Value::Null,
+                Err(RpcError::new(codes::INVALID_REQUEST, e.to_string())),
+            ))
+        }
+    };
+
+    let outcome = if request.jsonrpc != "2.0" {
+        Err(RpcError::new(
+            codes::INVALID_REQUEST,
+            "jsonrpc must be \"2.0\"",
+        ))
+    } else if request.method == "version" {
+        Ok(version_info())
+    } else {
+        match RpcCall::parse(&request.method, request.params) {
+            Ok(call) => dispatch(call, tx).await,
+            Err(e) => Err(e),
+        }
+    };
+
+    if let Err(ref e) = outcome {
+        tracing::debug!(method = %request.method, code = e.code, "Control call failed: {}", e.message);
+    }
+    request.id.map(|id| RpcResponse::new(id, outcome))
+}
+
+/// Round-trip a call through the App event loop
+async fn dispatch(call: RpcCall, tx: &mpsc::Sender<Event>) -> Result<Value, RpcError> {
+    let (reply_tx, reply_rx) = oneshot::channel();
+    tx.send(Event::Rpc {
+        call,
+        reply: reply_tx,
+    })
+    .await
+    .map_err(|_| RpcError::new(codes::BUSY, "rehoboam is shutting down"))?;
+    match timeout(CALL_TIMEOUT, reply_rx).await {
+        Ok(Ok(outcome)) => outcome,
+        _ => Err(RpcError::new(codes::BUSY, "timed out waiting for rehoboam")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run one line through `handle_line`, answering any App round trip with `null`
+    async fn call(line: &str) -> (Option<RpcResponse>, Option<RpcCall>) {
+        let (tx, mut rx) = mpsc::channel(1);
+        let app = tokio::spawn(async move {
+            let Some(Event::Rpc { call, reply }) = rx.recv().await else {
+                return None;
+            };
+            let _ = reply.send(Ok(Value::Null));
+            Some(call)
+        });
+        let response = handle_line(line, &tx).await;
+        drop(tx);
+        (response, app.await.unwrap())
+    }
+
+    #[test]
+    fn test_parse_maps_methods_and_validates_params() {
+        assert_eq!(
+            RpcCall::parse("list_agents", Value::Null),
+            Ok(RpcCall::ListAgents)
+        );
+        assert_eq!(
+            RpcCall::parse("approve", serde_json::json!({"pane_id": "%3"})),
+            Ok(RpcCall::Approve {
+                pane_id: "%3".to_string()
+            })
+        );
+        let spawn = RpcCall::parse("spawn", serde_json::json!({"project": "/src/app"})).unwrap();
+        assert_eq!(
+            spawn,
+            RpcCall::Spawn {
+                project: "/src/app".to_string(),
+                prompt: String::new(),
+                sprite: false,
+                github_repo: String::new(),
+            }
+        );
+        assert!(spawn.mutates());
+        assert!(!RpcCall::ListAgents.mutates());
+
+        let err = RpcCall::parse("send_input", serde_json::json!({"pane_id": "%3"})).unwrap_err();
+        assert_eq!(err.code, codes::INVALID_PARAMS);
+        let err = RpcCall::parse("reboot", Value::Null).unwrap_err();
+        assert_eq!(err.code, codes::METHOD_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_framing_errors_and_notifications() {
+        let (response, _) = call("{not json").await;
+        let response = serde_json::to_value(response.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], codes::PARSE_ERROR);
+        assert_eq!(response["id"], Value::Null);
+
+        let (response, _) = call(r#"{"jsonrpc":"1.0","id":7,"method":"version"}"#).await;
+        let response = serde_json::to_value(response.unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], codes::INVALID_REQUEST);
+        assert_eq!(response["id"], 7);
+
+        let (response, forwarded) = call(r#"{"jsonrpc":"2.0","id":"a","method":"version"}"#).await;
+        let response = serde_json::to_value(response.unwrap()).unwrap();
+        assert_eq!(response["result"]["protocol"], PROTOCOL_VERSION);
+        assert!(response.get("error").is_none());
+        assert!(forwarded.is_none(), "version is answered without the App");
+
+        // Notifications are executed but never answered
+        let (response, forwarded) =
+            call(r#"{"jsonrpc":"2.0","method":"approve","params":{"pane_id":"%1"}}"#).await;
+        assert!(response.is_none());
+        assert_eq!(
+            forwarded,
+            Some(RpcCall::Approve {
+                pane_id: "%1".to_string()
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_control_socket_is_owner_only_from_bind() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("control.sock");
+        std::fs::write(&path, "stale").unwrap();
+
+        let listener = bind_private(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        // The staging directory is gone; only the socket remains
+        let entries: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+
+        let client = UnixStream::connect(&path).await.unwrap();
+        let (_server, _) = listener.accept().await.unwrap();
+        drop(client);
+    }
+}
@@ -3,16 +3,33 @@
 //! Receives JSON hook events from Claude Code via Unix domain socket.
 //! The socket is created at `/tmp/rehoboam.sock` and listens for connections
 //! from the `rehoboam hook` command invoked by Claude Code hooks.
+//!
+//! The same socket also answers [`ControlRequest`]s (e.g. `rehoboam export-state`),
+//! which get a single JSON response line written back before the connection closes.
+//!
+//! When a [`MirrorHub`] is supplied (`--listen-tcp`), every valid hook event is
+//! also republished to TCP mirror clients.
+//!
+//! [`listen`] supervises its own accept loop: if it dies, the socket is
+//! rebound with backoff instead of leaving the board deaf.
+//!
+//! Hook events go through an [`IngestSender`], so a full event channel drops
+//! tool events rather than holding hook connections open.
 
-use super::{Event, HookEvent};
+use super::ingest::IngestSender;
+use super::mirror::MirrorHub;
+use super::{ControlRequest, Event, HookEvent};
 use color_eyre::Result;
 use std::os::unix::io::{FromRawFd, IntoRawFd};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::io::{AsyncBufReadExt, BufReader};
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::unix::OwnedWriteHalf;
 use tokio::net::UnixListener;
-use tokio::sync::{mpsc, Semaphore};
+use tokio::sync::{mpsc, oneshot, Semaphore};
 use tokio::time::{timeout, Duration};
+use tokio_util::sync::CancellationToken;
 
 /// Maximum concurrent connections to prevent resource exhaustion
 const MAX_CONNECTIONS: usize = 100;
@@ -20,8 +37,106 @@ const MAX_CONNECTIONS: usize = 100;
 /// Socket receive buffer size (4KB is plenty for ~500 byte JSON messages)
 const SOCKET_RECV_BUF: usize = 4096;
 
-/// Listen for hook events on Unix socket
-pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
+/// Longest wait between listener restarts
+const MAX_RESTART_DELAY_SECS: u64 = 30;
+
+/// A listener that ran this long before dying was healthy; restart promptly
+const STABLE_AFTER: Duration = Duration::from_secs(60);
+
+/// Listen for hook events on Unix socket until `cancel` fires
+///
+/// `source` tags every hook event with the socket it arrived on; it is only
+/// set when the TUI listens on more than one socket.
+///
+/// If the bind fails or the accept loop dies (error or panic), the socket
+/// file is recreated after a backoff of [`restart_delay`]. While it is down
+/// the App gets [`Event::ListenerStatus`] with the error, then one with
+/// `error: None` once it is back, so the footer can warn in between.
+pub async fn listen(
+    ingest: IngestSender,
+    socket_path: PathBuf,
+    mirror: Option<MirrorHub>,
+    source: Option<String>,
+    cancel: CancellationToken,
+) {
+    let tx = ingest.sender().clone();
+    let mut attempt: u32 = 0;
+    loop {
+        let started = Instant::now();
+        let reason = match bind(&socket_path) {
+            Ok(listener) => {
+                if attempt > 0 {
+                    tracing::info!("Socket listener on {:?} restarted", socket_path);
+                    let status = Event::ListenerStatus {
+                        socket: socket_path.clone(),
+                        error: None,
+                    };
+                    if tx.send(status).await.is_err() {
+                        return;
+                    }
+                }
+                let mut task = tokio::spawn(serve(
+                    listener,
+                    ingest.clone(),
+                    mirror.clone(),
+                    source.clone(),
+                ));
+                tokio::select! {
+                    result = &mut task => match result {
+                        Ok(Ok(())) => "listener stopped".to_string(),
+                        Ok(Err(e)) => e.to_string(),
+                        Err(e) => format!("listener crashed: {e}"),
+                    },
+                    () = cancel.cancelled() => {
+                        task.abort();
+                        return;
+                    }
+                }
+            }
+            Err(e) => e.to_string(),
+        };
+        if cancel.is_cancelled() {
+            return;
+        }
+
+        // A listener that served for a while starts the backoff over
+        if started.elapsed() >= STABLE_AFTER {
+            attempt = 0;
+        }
+        attempt += 1;
+        let delay = restart_delay(attempt);
+        tracing::error!(
+            "Socket listener on {:?} down ({}), restarting in {:?}",
+            socket_path,
+            reason,
+            delay
+        );
+        let status = Event::ListenerStatus {
+            socket: socket_path.clone(),
+            error: Some(format!(
+                "socket {} down: {reason} (retrying in {}s)",
+                socket_path.display(),
+                delay.as_secs().max(1)
+            )),
+        };
+        if tx.send(status).await.is_err() {
+            return;
+        }
+        tokio::select! {
+            () = tokio::time::sleep(delay) => {}
+            () = cancel.cancelled() => return,
+        }
+    }
+}
+
+/// Wait before the `attempt`th restart: 1s doubling up to 30s
+pub fn restart_delay(attempt: u32) -> Duration {
+    let secs = 1u64 << attempt.saturating_sub(1).min(5);
+    Duration::from_secs(secs.min(MAX_RESTART_DELAY_SECS))
+}
+
+/// Create the socket file and start listening on it
+fn bind(socket_path: &Path) -> Result<UnixListener> {
     // Remove existing socket file
     if socket_path.exists() {
         std::fs::remove_file(socket_path)?;
@@ -47,7 +162,16 @@ pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
     let listener = UnixListener::from_std(std_listener)?;
 
     tracing::info!("Listening on {:?}", socket_path);
+    Ok(listener)
+}
 
+/// Accept connections until the listener fails
+async fn serve(
+    listener: UnixListener,
+    ingest: IngestSender,
+    mirror: Option<MirrorHub>,
+    source: Option<String>,
+) -> Result<()> {
     // Semaphore to limit concurrent connections
     let semaphore = Arc::new(Semaphore::new(MAX_CONNECTIONS));
 
@@ -73,12 +197,15 @@ pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
                     }
                 };
 
-                let tx = tx.clone();
+                let ingest = ingest.clone();
+                let mirror = mirror.clone();
+                let source = source.clone();
                 tokio::spawn(async move {
                     // Permit is held until this task completes
                     let _permit = permit;
 
-                    let reader = BufReader::new(stream);
+                    let (read_half, mut write_half) = stream.into_split();
+                    let reader = BufReader::new(read_half);
                     let mut lines = reader.lines();
 
                     // Read with timeout - hooks send single-line JSON messages
@@ -87,14 +214,26 @@ pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
 
                     match read_result {
                         Ok(Ok(Some(line))) if !line.trim().is_empty() => {
+                            if let Ok(request) = serde_json::from_str::<ControlRequest>(&line) {
+                                handle_control_request(request, ingest.sender(), &mut write_half)
+                                    .await;
+                                return;
+                            }
                             match serde_json::from_str::<HookEvent>(&line) {
-                                Ok(event) => {
+                                Ok(mut event) => {
+                                    if source.is_some() {
+                                        event.socket = source;
+                                    }
                                     // Validate event before processing
                                     if let Err(e) = event.validate() {
                                         tracing::warn!("Invalid event: {} - {:?}", e, event);
                                     } else {
                                         tracing::debug!("Received event: {:?}", event);
-                                        let _ = tx.send(Event::Hook(Box::new(event))).await;
+                                        if let Some(hub) = &mirror {
+                                            // No receivers is fine: nobody is mirroring yet
+                                            let _ = hub.send(Arc::from(line.as_str()));
+                                        }
+                                        let _ = ingest.forward(Event::Hook(Box::new(event))).await;
                                     }
                                 }
                                 Err(e) => {
@@ -129,3 +268,74 @@ pub async fn listen(tx: mpsc::Sender<Event>, socket_path: &Path) -> Result<()> {
         }
     }
 }
+
+/// Answer a control request by round-tripping through the App event loop
+async fn handle_control_request(
+    request: ControlRequest,
+    tx: &mpsc::Sender<Event>,
+    writer: &mut OwnedWriteHalf,
+) {
+    let response = match request {
+        ControlRequest::Snapshot => {
+            let (reply_tx, reply_rx) = oneshot::channel();
+            if tx.send(Event::Snapshot { reply: reply_tx }).await.is_err() {
+                return;
+            }
+            match timeout(Duration::from_secs(2), reply_rx).await {
+                Ok(Ok(agents)) => serde_json::to_string(&agents),
+                _ => {
+                    tracing::warn!("Snapshot request timed out waiting for App");
+                    return;
+                }
+            }
+        }
+    };
+
+    match response {
+        Ok(json) => {
+            let data = format!("{json}\n");
+            if let Err(e) = writer.write_all(data.as_bytes()).await {
+                tracing::warn!("Failed to write control response: {}", e);
+            }
+            let _ = writer.shutdown().await;
+        }
+        Err(e) => {
+            tracing::warn!("Failed to serialize control response: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_delay_doubles_then_caps() {
+        let delays: Vec<u64> = (1..=8).map(|n| restart_delay(n).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30, 30]);
+    }
+
+    #[tokio::test]
+    async fn test_listen_creates_socket_and_stops_on_cancel() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.sock");
+        let (tx, _rx) = mpsc::channel(8);
+        let ingest = IngestSender::new(tx, Default::default());
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(listen(ingest, path.clone(), None, None, cancel.clone()));
+
+        for _ in 0..50 {
+            if path.exists() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(path.exists());
+
+        cancel.cancel();
+        timeout(Duration::from_secs(1), task)
+            .await
+            .unwrap()
+            .unwrap();
+    }
+}
@@ -0,0 +1,272 @@
+//! Read-only web dashboard (`--web-port`)
+//!
+//! Same tiny HTTP/1.1 responder style as [`super::metrics`], with three routes:
+//! - `GET /` - a single static page (`dashboard.html`) laid out like the TUI
+//! - `GET /state` - agent snapshots, the same JSON as `rehoboam export-state`
+//! - `GET /events` - Server-Sent Events, one change notice per `data:` line
+//!
+//! `/events` subscribes to the [`MirrorHub`] the Unix socket listener already
+//! publishes into for `--listen-tcp`, so the browser hears of exactly the
+//! events the TUI consumes. Each frame keeps only the hook name, status, pane,
+//! project and timestamp; tool input and output, prompts and paths stay out
+//! of the browser. The page refetches `/state` when one arrives rather than
+//! replaying hook logic in JavaScript. Nothing on the page can act on agents.
+//!
+//! Binding 127.0.0.1 does not stop a page the browser loaded from elsewhere:
+//! DNS rebinding points a foreign hostname at the loopback address. Requests
+//! whose `Host` is not `127.0.0.1:<port>` or `localhost:<port>` get a 403.
+
+use super::metrics::http_response;
+use super::mirror::MirrorHub;
+use super::Event;
+use color_eyre::eyre::{self, WrapErr};
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::OwnedWriteHalf;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+use tokio::time::{timeout, Duration};
+
+/// The whole front end
+const DASHBOARD_HTML: &str = include_str!("dashboard.html");
+
+/// Maximum concurrent browser connections (each open page holds one SSE stream)
+const MAX_WEB_CLIENTS: usize = 16;
+
+/// How long a browser has to send its request line
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long to wait for the App to render a snapshot
+const SNAPSHOT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Comment line sent on idle SSE streams so proxies don't drop them
+const KEEPALIVE: Duration = Duration::from_secs(15);
+
+/// Most header lines read from one request
+const MAX_HEADERS: usize = 64;
+
+/// `HookEvent` fields forwarded on `/events`
+const NOTICE_FIELDS: &[&str] = &["event", "status", "pane_id", "project", "timestamp"];
+
+/// Routes the dashboard answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Route {
+    Page,
+    State,
+    Events,
+    NotFound,
+}
+
+/// Serve the dashboard on `addr` until the task is aborted
+pub async fn serve(tx: mpsc::Sender<Event>, hub: MirrorHub, addr: &str) -> eyre::Result<()> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .wrap_err_with(|| format!("Failed to bind web dashboard on {addr}"))?;
+    let port = listener.local_addr()?.port();
+    tracing::info!("Web dashboard on http://{}/", addr);
+
+    let semaphore = Arc::new(Semaphore::new(MAX_WEB_CLIENTS));
+
+    loop {
+        let (stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                tracing::error!("Web dashboard accept error: {}", e);
+                tokio::time::sleep(Duration::from_millis(500)).await;
+                continue;
+            }
+        };
+        let Ok(permit) = semaphore.clone().try_acquire_owned() else {
+            tracing::warn!(
+                "Web dashboard connection limit reached ({} max), dropping connection",
+                MAX_WEB_CLIENTS
+            );
+            continue;
+        };
+        let (tx, hub) = (tx.clone(), hub.clone());
+        tokio::spawn(async move {
+            let _permit = permit;
+            if let Err(e) = handle_request(stream, &tx, &hub, port).await {
+                tracing::debug!("Web dashboard request failed: {}", e);
+            }
+        });
+    }
+}
+
+/// Answer one HTTP request (an SSE request holds the connection open)
+async fn handle_request(
+    stream: TcpStream,
+    tx: &mpsc::Sender<Event>,
+    hub: &MirrorHub,
+    port: u16,
+) -> eyre::Result<()> {
+    let (read_half, mut write_half) = stream.into_split();
+    let mut lines = BufReader::new(read_half).lines();
+    let (request_line, host) = timeout(REQUEST_TIMEOUT, async {
+        let request_line = lines.next_line().await?.unwrap_or_default();
+        let mut host = None;
+        for _ in 0..MAX_HEADERS {
+            let Some(line) = lines.next_line().await? else {
+                break;
+            };
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("host") {
+                    host = Some(value.trim().to_string());
+                }
+            }
+        }
+        Ok::<_, std::io::Error>((request_line, host))
+    })
+    .await
+    .wrap_err("request timed out")??;
+
+    if !host_allowed(host.as_deref(), port) {
+        tracing::warn!(host = ?host, "Web dashboard request with foreign Host refused");
+        let response = http_response("403 Forbidden", "text/plain", "forbidden\n");
+        write_half.write_all(response.as_bytes()).await?;
+        write_half.shutdown().await?;
+        return Ok(());
+    }
+
+    let response = match route(&request_line) {
+        Route::Page => http_response("200 OK", "text/html; charset=utf-8", DASHBOARD_HTML),
+        Route::State => {
+            let (reply_tx, reply_rx) = oneshot::channel();
+            tx.send(Event::Snapshot { reply: reply_tx })
+                .await
+                .wrap_err("App is gone")?;
+            match timeout(SNAPSHOT_TIMEOUT, reply_rx).await {
+                Ok(Ok(agents)) => http_response(
+                    "200 OK",
+                    "application/json",
+                    &serde_json::to_string(&agents)?,
+                ),
+                _ => http_response("503 Service Unavailable", "text/plain", "busy\n"),
+            }
+        }
+        Route::Events => return stream_events(write_half, hub).await,
+        Route::NotFound => http_response("404 Not Found", "text/plain", "try /\n"),
+    };
+
+    write_half.write_all(response.as_bytes()).await?;
+    write_half.shutdown().await?;
+    Ok(())
+}
+
+/// Forward hub events as SSE until the browser goes away
+async fn stream_events(mut writer: OwnedWriteHalf, hub: &MirrorHub) -> eyre::Result<()> {
+    let mut rx = hub.subscribe();
+    writer
+        .write_all(
+            b"HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\nConnection: keep-alive\r\n\r\n",
+        )
+        .await?;
+
+    loop {
+        let chunk = match timeout(KEEPALIVE, rx.recv()).await {
+            Ok(Ok(line)) => sse_data(&notice(&line)),
+            // The page refetches /state on any event, so skipped ones only cost detail
+            Ok(Err(RecvError::Lagged(skipped))) => {
+                tracing::debug!(skipped, "Web dashboard client lagging, skipped events");
+                continue;
+            }
+            Ok(Err(RecvError::Closed)) => return Ok(()),
+            Err(_) => ": keepalive\n\n".to_string(),
+        };
+        // A write error means the tab was closed
+        writer.write_all(chunk.as_bytes()).await?;
+    }
+}
+
+/// Whether `host` names the loopback listener itself (DNS rebinding guard)
+fn host_allowed(host: Option<&str>, port: u16) -> bool {
+    let Some(host) = host else {
+        return false;
+    };
+    let host = host.to_ascii_lowercase();
+    host == format!("127.0.0.1:{port}") || host == format!("localhost:{port}")
+}
+
+/// Cut a hook event's JSON down to [`NOTICE_FIELDS`]
+fn notice(json: &str) -> String {
+    let Ok(serde_json::Value::Object(event)) = serde_json::from_str(json) else {
+        return "{}".to_string();
+    };
+    let kept: serde_json::Map<String, serde_json::Value> = event
+        .into_iter()
+        .filter(|(key, _)| NOTICE_FIELDS.contains(&key.as_str()))
+        .collect();
+    serde_json::Value::Object(kept).to_string()
+}
+
+/// Frame one JSON line as an SSE message
+fn sse_data(json: &str) -> String {
+    // Hook events are single-line JSON, but never let a newline end the frame early
+    let mut frame = String::with_capacity(json.len() + 8);
+    for line in json.lines() {
+        frame.push_str("data: ");
+        frame.push_str(line);
+        frame.push('\n');
+    }
+    frame.push('\n');
+    frame
+}
+
+/// Route a request line (only `GET` is served; query strings are ignored)
+fn route(request_line: &str) -> Route {
+    let mut parts = request_line.split_whitespace();
+    if parts.next() != Some("GET") {
+        return Route::NotFound;
+    }
+    match parts.next().and_then(|path| path.split('?').next()) {
+        Some("/" | "/index.html") => Route::Page,
+        Some("/state") => Route::State,
+        Some("/events") => Route::Events,
+        _ => Route::NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_routes_are_get_only() {
+        assert_eq!(route("GET / HTTP/1.1"), Route::Page);
+        assert_eq!(route("GET /state?t=1 HTTP/1.1"), Route::State);
+        assert_eq!(route("GET /events HTTP/1.1"), Route::Events);
+        assert_eq!(route("POST /state HTTP/1.1"), Route::NotFound);
+        assert_eq!(route("GET /metrics HTTP/1.1"), Route::NotFound);
+        assert_eq!(route(""), Route::NotFound);
+    }
+
+    #[test]
+    fn test_only_loopback_host_is_served() {
+        assert!(host_allowed(Some("127.0.0.1:8080"), 8080));
+        assert!(host_allowed(Some("LocalHost:8080"), 8080));
+        assert!(!host_allowed(Some("localhost:9090"), 8080));
+        assert!(!host_allowed(Some("evil.example:8080"), 8080));
+        assert!(!host_allowed(None, 8080));
+    }
+
+    #[test]
+    fn test_notice_drops_tool_payloads_and_paths() {
+        let json = r#"{"event":"PreToolUse","status":"working","pane_id":"%1","project":"p","timestamp":1,"tool_input":{"command":"cat .env"},"tool_response":"x","prompt":"secret","cwd":"/home","transcript_path":"/t.jsonl"}"#;
+        let notice: serde_json::Value = serde_json::from_str(&notice(json)).unwrap();
+        assert_eq!(
+            notice,
+            serde_json::json!({"event":"PreToolUse","status":"working","pane_id":"%1","project":"p","timestamp":1})
+        );
+        assert_eq!(super::notice("not json"), "{}");
+    }
+
+    #[test]
+    fn test_sse_frame_never_splits_on_embedded_newlines() {
+        assert_eq!(sse_data(r#"{"a":1}"#), "data: {\"a\":1}\n\n");
+        assert_eq!(sse_data("a\nb"), "data: a\ndata: b\n\n");
+    }
+}
@@ -7,13 +7,21 @@
 //! This module periodically checks the file size and:
 //! - Warns in the TUI footer when it exceeds a configurable threshold
 //! - Auto-truncates (keeping last N lines) when it exceeds a critical threshold
+//!
+//! It also owns the other footer warnings that outlive a single check: hook
+//! setup problems found at startup, socket listeners that are down and tool
+//! events dropped because the event channel was full.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use crate::config::HealthConfig;
 use crate::state::AppState;
 
+/// How long the dropped-events warning stays after the last drop
+const DROP_WARNING_TTL: Duration = Duration::from_secs(60);
+
 /// Health checker for hooks.log file size monitoring
 pub struct HealthChecker {
     /// Whether health checking is enabled
@@ -32,6 +40,14 @@ pub struct HealthChecker {
     last_check: Instant,
     /// Whether we've already sent a desktop notification for the current warning
     notified: bool,
+    /// Hook setup problem found at startup, shown whenever hooks.log is fine
+    setup_warning: Option<String>,
+    /// Socket listeners currently down, with the supervisor's message
+    listener_warnings: BTreeMap<PathBuf, String>,
+    /// Dropped-event total already reported
+    dropped_seen: u64,
+    /// Dropped-events warning and when the last drop was noticed
+    drop_warning: Option<(String, Instant)>,
 }
 
 impl HealthChecker {
@@ -46,9 +62,105 @@ impl HealthChecker {
             path: hooks_log_path(),
             last_check: Instant::now(),
             notified: false,
+            setup_warning: None,
+            listener_warnings: BTreeMap::new(),
+            dropped_seen: 0,
+            drop_warning: None,
+        }
+    }
+
+    /// Record the startup hook-setup check (see `init::diagnose_hooks`)
+    ///
+    /// A hooks.log size warning takes precedence; this one fills in when the
+    /// log is healthy, so the periodic check doesn't erase it.
+    pub fn set_setup_warning(&mut self, state: &mut AppState, warning: Option<String>) {
+        self.setup_warning = warning;
+        if state.health_warning.is_none() {
+            state.health_warning = self.standing_warning();
         }
     }
 
+    /// Record a socket listener going down (`Some`) or coming back (`None`)
+    ///
+    /// A dead listener means no events at all, so its warning replaces
+    /// whatever the footer showed until the supervisor reports it back up.
+    pub fn set_listener_status(
+        &mut self,
+        state: &mut AppState,
+        socket: PathBuf,
+        error: Option<String>,
+    ) {
+        match error {
+            Some(error) => {
+                state.health_warning = Some(error.clone());
+                self.listener_warnings.insert(socket, error);
+            }
+            None => {
+                let cleared = self.listener_warnings.remove(&socket);
+                if cleared.is_some() && state.health_warning == cleared {
+                    state.health_warning = self.standing_warning();
+                }
+            }
+        }
+    }
+
+    /// Record the ingestion drop counter (see `event::ingest`)
+    ///
+    /// New drops raise a warning (unless a listener is down, which is worse);
+    /// it clears once [`DROP_WARNING_TTL`] passes without another drop.
+    /// Returns true if the footer warning changed.
+    pub fn note_dropped_events(&mut self, state: &mut AppState, total: u64) -> bool {
+        if total > self.dropped_seen {
+            tracing::warn!(
+                dropped = total - self.dropped_seen,
+                total,
+                "Event channel full, tool events dropped"
+            );
+            self.dropped_seen = total;
+            let warning = format!("event channel full: {total} tool events dropped, cards may lag");
+            self.drop_warning = Some((warning.clone(), Instant::now()));
+            if self.listener_warnings.is_empty() {
+                state.health_warning = Some(warning);
+                return true;
+            }
+            return false;
+        }
+        let Some((warning, since)) = &self.drop_warning else {
+            return false;
+        };
+        if since.elapsed() < DROP_WARNING_TTL {
+            return false;
+        }
+        let warning = warning.clone();
+        self.drop_warning = None;
+        if state.health_warning.as_ref() != Some(&warning) {
+            return false;
+        }
+        state.health_warning = self.standing_warning();
+        true
+    }
+
+    /// Warning to fall back on when hooks.log is fine
+    fn standing_warning(&self) -> Option<String> {
+        self.listener_warnings
+            .values()
+            .next()
+            .cloned()
+            .or_else(|| self.drop_warning.as_ref().map(|(w, _)| w.clone()))
+            .or_else(|| self.setup_warning.clone())
+    }
+
+    /// Drop the hooks.log warning, falling back to the standing warning
+    fn clear_warning(&mut self, state: &mut AppState) -> bool {
+        self.notified = false;
+        let standing = self.standing_warning();
+        if state.health_warning == standing {
+            return false;
+        }
+        state.health_warning = standing;
+        true
+    }
+
     /// Check if health check should run (timer-gated)
     pub fn should_run(&self) -> bool {
         self.enabled && self.last_check.elapsed().as_secs() >= self.interval_secs
@@ -66,12 +178,7 @@ impl HealthChecker {
             Ok(meta) => meta.len(),
             Err(_) => {
                 // File doesn't exist or can't be read — no warning needed
-                if state.health_warning.is_some() {
-                    state.health_warning = None;
-                    self.notified = false;
-                    return true;
-                }
-                return false;
+                return self.clear_warning(state);
             }
         };
 
@@ -93,10 +200,7 @@ impl HealthChecker {
             }
 
             // Clear warning after successful truncation
-            let had_warning = state.health_warning.is_some();
-            state.health_warning = None;
-            self.notified = false;
-            return had_warning;
+            return self.clear_warning(state);
         }
 
         // Warning threshold
@@ -123,13 +227,7 @@ impl HealthChecker {
         }
 
         // Below thresholds — clear warning if present
-        if state.health_warning.is_some() {
-            state.health_warning = None;
-            self.notified = false;
-            return true;
-        }
-
-        false
+        self.clear_warning(state)
     }
 }
 
@@ -184,6 +282,10 @@ mod tests {
             path,
             last_check: Instant::now(),
             notified: false,
+            setup_warning: None,
+            listener_warnings: BTreeMap::new(),
+            dropped_seen: 0,
+            drop_warning: None,
         }
     }
 
@@ -293,6 +395,77 @@ mod tests {
         assert!(state.health_warning.is_none());
     }
 
+    #[test]
+    fn test_setup_warning_survives_log_checks() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("hooks.log");
+        let mut checker = test_checker(path.clone(), 100, 1000);
+        let mut state = AppState::new();
+        let setup = Some("No rehoboam hooks for app: run `rehoboam init`".to_string());
+
+        checker.set_setup_warning(&mut state, setup.clone());
+        assert_eq!(state.health_warning, setup);
+        assert!(
+            !checker.check(&mut state),
+            "missing log keeps the setup warning"
+        );
+
+        // A large log takes over, then hands back to the setup warning
+        std::fs::write(&path, "x".repeat(200)).unwrap();
+        assert!(checker.check(&mut state));
+        assert!(state
+            .health_warning
+            .as_deref()
+            .unwrap()
+            .contains("hooks.log"));
+        std::fs::write(&path, "x").unwrap();
+        assert!(checker.check(&mut state));
+        assert_eq!(state.health_warning, setup);
+    }
+
+    #[test]
+    fn test_listener_warning_until_back_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut checker = test_checker(dir.path().join("hooks.log"), 100, 1000);
+        let mut state = AppState::new();
+        let setup = Some("No rehoboam hooks for app: run `rehoboam init`".to_string());
+        checker.set_setup_warning(&mut state, setup.clone());
+        let socket = PathBuf::from("/tmp/rehoboam.sock");
+
+        let down = "socket /tmp/rehoboam.sock down: address in use (retrying in 1s)";
+        checker.set_listener_status(&mut state, socket.clone(), Some(down.to_string()));
+        assert_eq!(state.health_warning.as_deref(), Some(down));
+        // A healthy hooks.log doesn't hide it
+        assert!(!checker.check(&mut state));
+        assert_eq!(state.health_warning.as_deref(), Some(down));
+
+        checker.set_listener_status(&mut state, socket, None);
+        assert_eq!(state.health_warning, setup);
+    }
+
+    #[test]
+    fn test_dropped_events_warn_until_quiet() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut checker = test_checker(dir.path().join("hooks.log"), 100, 1000);
+        let mut state = AppState::new();
+        assert!(!checker.note_dropped_events(&mut state, 0));
+        assert!(state.health_warning.is_none());
+
+        assert!(checker.note_dropped_events(&mut state, 3));
+        let warning = state.health_warning.clone().unwrap();
+        assert!(warning.contains("3 tool events dropped"), "{warning}");
+        // A healthy hooks.log doesn't hide it, and no new drops keep it up
+        assert!(!checker.check(&mut state));
+        assert!(!checker.note_dropped_events(&mut state, 3));
+        assert_eq!(state.health_warning.as_deref(), Some(warning.as_str()));
+
+        // A quiet minute later it clears
+        let since = Instant::now().checked_sub(DROP_WARNING_TTL).unwrap();
+        checker.drop_warning = Some((warning, since));
+        assert!(checker.note_dropped_events(&mut state, 3));
+        assert!(state.health_warning.is_none());
+    }
+
     #[test]
     fn test_truncate_file() {
         use std::io::Write;
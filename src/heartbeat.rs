@@ -0,0 +1,128 @@
+//! Liveness heartbeats while Claude is responding
+//!
+//! Hooks only fire around tools and turns, so an agent streaming a long
+//! answer looks silent and can time out to Waiting or be reaped as stale.
+//! On `UserPromptSubmit` the hook starts a detached `rehoboam heartbeat`
+//! process that sends a `Heartbeat` event every few seconds; `Stop` and
+//! `SessionEnd` stop it by removing its pidfile.
+//!
+//! Socket message shape (one JSON line, same socket as hook events):
+//!
+//! ```json
+//! {"event":"Heartbeat","status":"working","pane_id":"%3","project":"my-app","timestamp":1760000000}
+//! ```
+//!
+//! The TUI only bumps the agent's `last_update`; status is left untouched and
+//! unknown panes are ignored, so custom hooks can send heartbeats freely.
+
+use color_eyre::eyre::{Result, WrapErr};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use tokio::io::AsyncWriteExt;
+use tokio::net::UnixStream;
+use tokio::time::{timeout, Duration};
+
+/// Give up after this long even if no Stop arrives (e.g. Claude was killed)
+const MAX_HEARTBEAT_SECS: u64 = 3600;
+
+/// Pidfile owned by the heartbeat process for `pane_id`
+pub fn pidfile_path(socket_path: &Path, pane_id: &str) -> PathBuf {
+    let name = format!("rehoboam-heartbeat-{}.pid", pane_id.replace(['%', '/'], ""));
+    socket_path
+        .parent()
+        .map_or_else(|| PathBuf::from("/tmp").join(&name), |dir| dir.join(&name))
+}
+
+/// Start a detached heartbeat process for `pane_id` (replacing any previous one)
+pub fn start(socket_path: &Path, pane_id: &str) -> Result<()> {
+    let exe = std::env::current_exe().wrap_err("Failed to locate rehoboam binary")?;
+    let child = Command::new(exe)
+        .args(["heartbeat", "--pane-id", pane_id, "--socket"])
+        .arg(socket_path)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .wrap_err("Failed to spawn heartbeat process")?;
+    // The old process sees a foreign pid and exits on its next beat
+    std::fs::write(pidfile_path(socket_path, pane_id), child.id().to_string())
+        .wrap_err("Failed to write heartbeat pidfile")?;
+    Ok(())
+}
+
+/// Tell the heartbeat process for `pane_id` to exit
+pub fn stop(socket_path: &Path, pane_id: &str) {
+    let _ = std::fs::remove_file(pidfile_path(socket_path, pane_id));
+}
+
+/// True while the pidfile still names this process
+fn still_owner(pidfile: &Path) -> bool {
+    std::fs::read_to_string(pidfile).is_ok_and(|pid| pid.trim() == std::process::id().to_string())
+}
+
+/// Heartbeat line for the socket
+pub fn heartbeat_json(pane_id: &str, project: &str, timestamp: i64) -> String {
+    serde_json::json!({
+        "event": "Heartbeat",
+        "status": "working",
+        "pane_id": pane_id,
+        "project": project,
+        "timestamp": timestamp,
+    })
+    .to_string()
+}
+
+/// Send heartbeats until stopped, the TUI goes away, or the time cap is hit
+pub async fn run(socket_path: &Path, pane_id: &str, project: &str, interval_secs: u64) {
+    let pidfile = pidfile_path(socket_path, pane_id);
+    let interval = Duration::from_secs(interval_secs.max(1));
+    let started = std::time::Instant::now();
+
+    // Let `start` write the pidfile before the first ownership check
+    tokio::time::sleep(interval).await;
+    while still_owner(&pidfile) && started.elapsed().as_secs() < MAX_HEARTBEAT_SECS {
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        let line = format!("{}\n", heartbeat_json(pane_id, project, timestamp));
+        let sent = match timeout(Duration::from_millis(500), UnixStream::connect(socket_path)).await
+        {
+            Ok(Ok(mut stream)) => {
+                let ok = stream.write_all(line.as_bytes()).await.is_ok();
+                let _ = stream.shutdown().await;
+                ok
+            }
+            _ => false,
+        };
+        if !sent {
+            // TUI not running: nobody to keep informed
+            break;
+        }
+        tokio::time::sleep(interval).await;
+    }
+
+    if still_owner(&pidfile) {
+        let _ = std::fs::remove_file(&pidfile);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::HookEvent;
+
+    #[test]
+    fn test_heartbeat_line_is_a_valid_hook_event() {
+        let event: HookEvent =
+            serde_json::from_str(&heartbeat_json("%3", "my-app", 1_760_000_000)).unwrap();
+        assert_eq!(event.event, "Heartbeat");
+        assert!(event.validate().is_ok());
+
+        let path = pidfile_path(Path::new("/run/user/1000/rehoboam.sock"), "%3");
+        assert_eq!(
+            path,
+            PathBuf::from("/run/user/1000/rehoboam-heartbeat-3.pid")
+        );
+    }
+}
@@ -96,6 +96,11 @@ fn get_rehoboam_path() -> String {
     std::env::var("REHOBOAM_PATH").unwrap_or_else(|_| "~/.local/bin/rehoboam".to_string())
 }
 
+/// Hook template for the binary from [`get_rehoboam_path`]
+fn hook_template() -> String {
+    hook_template_for(&get_rehoboam_path())
+}
+
 /// Generate hook template with configurable binary path (v1.0)
 ///
 /// Uses `rehoboam hook` which reads JSON from stdin - status is derived
@@ -108,8 +113,7 @@ fn get_rehoboam_path() -> String {
 /// Team identity is recovered via: (1) JSON team_name field on TeammateIdle/TaskCompleted,
 /// (2) session-ID correlation, (3) ~/.claude/teams/ filesystem discovery,
 /// (4) tool_input parsing from TeamCreate/SendMessage calls.
-fn hook_template() -> String {
-    let path = get_rehoboam_path();
+fn hook_template_for(path: &str) -> String {
     format!(
         r#"{{
   "env": {{
@@ -276,6 +280,98 @@ pub fn has_rehoboam_hooks(project: &Path) -> bool {
     false
 }
 
+/// Startup self-check of the hook setup for `project`
+///
+/// Looks at the project's `.claude/settings.json` and `settings.local.json`
+/// plus the user-level `~/.claude/settings.json` (`home`), and returns the
+/// first problem that would keep agents off the board: no rehoboam hooks
+/// (only reported for git or Claude projects), legacy `rehoboam send` hooks,
+/// a hook command pointing at a binary that no longer exists, or hook events
+/// that `init` installs but the settings lack.
+pub fn diagnose_hooks(project: &Path, home: Option<&Path>) -> Option<String> {
+    let mut files = vec![
+        project.join(".claude").join("settings.json"),
+        project.join(".claude").join("settings.local.json"),
+    ];
+    if let Some(home) = home {
+        files.push(home.join(".claude").join("settings.json"));
+    }
+
+    // Event name -> rehoboam hook commands registered for it
+    let mut commands: Vec<(String, String)> = Vec::new();
+    for path in &files {
+        let Ok(content) = fs::read_to_string(path) else {
+            continue;
+        };
+        let settings: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(settings) => settings,
+            Err(e) => return Some(format!("{} is not valid JSON: {e}", path.display())),
+        };
+        let Some(hooks) = settings.get("hooks").and_then(|h| h.as_object()) else {
+            continue;
+        };
+        for (event, matchers) in hooks {
+            let entries = matchers.as_array().into_iter().flatten();
+            let hook_commands = entries
+                .filter_map(|m| m.get("hooks")?.as_array())
+                .flatten()
+                .filter_map(|h| h.get("command")?.as_str());
+            for command in hook_commands {
+                if command.contains("rehoboam hook") || command.contains("rehoboam send") {
+                    commands.push((event.clone(), command.to_string()));
+                }
+            }
+        }
+    }
+
+    let name = project.file_name().map_or_else(
+        || project.display().to_string(),
+        |n| n.to_string_lossy().to_string(),
+    );
+    if commands.is_empty() {
+        let is_project = project.join(".git").exists() || project.join(".claude").exists();
+        return is_project.then(|| format!("No rehoboam hooks for {name}: run `rehoboam init`"));
+    }
+
+    if commands.iter().any(|(_, c)| c.contains("rehoboam send")) {
+        return Some("Legacy `rehoboam send` hooks: run `rehoboam init --force`".to_string());
+    }
+
+    // Hooks run through a shell, so a leading ~ is the home directory
+    for (_, command) in &commands {
+        let binary = command.split_whitespace().next().unwrap_or_default();
+        if !binary.contains('/') {
+            continue; // Resolved through PATH
+        }
+        let resolved = match (binary.strip_prefix("~/"), home) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => PathBuf::from(binary),
+        };
+        if !resolved.exists() {
+            return Some(format!(
+                "Hook binary {binary} not found: run `rehoboam init --force` (or set REHOBOAM_PATH)"
+            ));
+        }
+    }
+
+    let template: serde_json::Value = serde_json::from_str(&hook_template()).ok()?;
+    let installed: HashSet<&str> = commands.iter().map(|(e, _)| e.as_str()).collect();
+    let mut missing: Vec<&str> = template["hooks"]
+        .as_object()?
+        .keys()
+        .map(String::as_str)
+        .filter(|event| !installed.contains(event))
+        .collect();
+    if missing.is_empty() {
+        return None;
+    }
+    missing.sort_unstable();
+    Some(format!(
+        "Hooks missing for {}: run `rehoboam init --force`",
+        missing.join(", ")
+    ))
+}
+
 /// List discovered projects with status
 pub fn list_projects() {
     let projects = discover_projects();
@@ -309,6 +405,11 @@ pub fn list_projects() {
 
 /// Initialize a single project with hooks
 pub fn init_project(project: &Path, force: bool) -> Result<(), RehoboamError> {
+    init_project_with(project, force, &get_rehoboam_path())
+}
+
+/// Initialize a single project with hooks that run `binary`
+fn init_project_with(project: &Path, force: bool, binary: &str) -> Result<(), RehoboamError> {
     let name = project.file_name().map_or_else(
         || "unknown".to_string(),
         |n| n.to_string_lossy().to_string(),
@@ -334,7 +435,7 @@ pub fn init_project(project: &Path, force: bool) -> Result<(), RehoboamError> {
     let settings_path = claude_dir.join("settings.json");
 
     // Parse our settings template (hooks + env)
-    let template = hook_template();
+    let template = hook_template_for(binary);
     let our_settings: serde_json::Value =
         serde_json::from_str(&template).map_err(|e| RehoboamError::InitError {
             project: name.clone(),
@@ -837,6 +938,46 @@ mod tests {
         assert!(content.contains("echo stop"), "should preserve user hook");
     }
 
+    #[test]
+    fn test_diagnose_hooks() {
+        let tmp = TempDir::new().unwrap();
+        let home = tmp.path().join("home");
+        let project = tmp.path().join("app");
+        fs::create_dir_all(project.join(".git")).unwrap();
+        fs::create_dir_all(home.join(".local/bin")).unwrap();
+
+        // Git project without hooks anywhere
+        let problem = diagnose_hooks(&project, Some(&home)).unwrap();
+        assert!(problem.contains("No rehoboam hooks for app"), "{problem}");
+
+        // Not a project: nothing to say
+        assert_eq!(diagnose_hooks(&home, Some(&home)), None);
+
+        // Installed by init, but the binary is gone (a tempdir path, whatever
+        // REHOBOAM_PATH says)
+        let binary = home.join(".local/bin/rehoboam");
+        init_project_with(&project, false, &binary.display().to_string()).unwrap();
+        let problem = diagnose_hooks(&project, Some(&home)).unwrap();
+        assert!(problem.contains("not found"), "{problem}");
+
+        // Binary present: healthy
+        fs::write(&binary, "").unwrap();
+        assert_eq!(diagnose_hooks(&project, Some(&home)), None);
+
+        // A hand-trimmed settings file loses events
+        let settings = project.join(".claude/settings.json");
+        let mut parsed: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&settings).unwrap()).unwrap();
+        let hooks = parsed["hooks"].as_object_mut().unwrap();
+        hooks.remove("Stop");
+        hooks.remove("PreToolUse");
+        fs::write(&settings, parsed.to_string()).unwrap();
+        assert_eq!(
+            diagnose_hooks(&project, Some(&home)).as_deref(),
+            Some("Hooks missing for PreToolUse, Stop: run `rehoboam init --force`")
+        );
+    }
+
     #[test]
     fn test_hook_template_has_all_events() {
         let template = hook_template();
@@ -36,33 +36,36 @@
 //!   rehoboam hook     # Process hook event from stdin (Claude Code pipes JSON)
 
 mod app;
+mod check;
 mod cli;
+mod clipboard;
 mod config;
 mod errors;
 mod event;
 mod health;
+mod heartbeat;
 mod init;
 mod notify;
 mod picker;
 mod plans;
 mod sprite;
 mod state;
+mod terminal;
 mod tmux;
 mod tui;
 mod ui;
+mod webhook;
 
 use app::App;
 use clap::Parser;
-use cli::{Cli, Commands, SpritesAction};
+use cli::{Cli, Commands, ExportFormat, SpritesAction};
 use color_eyre::Result;
 use std::path::PathBuf;
 use std::process::Command;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-/// Length of session_id prefix used as fallback pane identifier
-const SESSION_ID_PREFIX_LEN: usize = 8;
-
 /// Get the log directory path
 fn get_log_dir() -> PathBuf {
     directories::BaseDirs::new().map_or_else(
@@ -104,12 +107,16 @@ async fn handle_sprites_command(action: SpritesAction, token: Option<String>) ->
         color_eyre::eyre::eyre!("SPRITES_TOKEN required. Set env var or use --sprites-token")
     })?;
 
-    let client = sprites::SpritesClient::new(&token);
+    let api = sprite::api::SpriteApi::new(
+        sprites::SpritesClient::new(&token),
+        &config::RehoboamConfig::load().sprites,
+    );
+    let client = api.client();
 
     match action {
         SpritesAction::List => {
-            let sprites = client
-                .list()
+            let sprites = api
+                .call("list sprites", || client.list())
                 .await
                 .map_err(|e| color_eyre::eyre::eyre!("Failed to list sprites: {}", e))?;
 
@@ -133,8 +140,8 @@ async fn handle_sprites_command(action: SpritesAction, token: Option<String>) ->
             }
         }
         SpritesAction::Info { name } => {
-            let info = client
-                .get(&name)
+            let info = api
+                .call("get sprite", || client.get(&name))
                 .await
                 .map_err(|e| color_eyre::eyre::eyre!("Failed to get sprite '{}': {}", name, e))?;
             println!("Name:    {}", info.name);
@@ -144,14 +151,16 @@ async fn handle_sprites_command(action: SpritesAction, token: Option<String>) ->
             }
         }
         SpritesAction::Destroy { name } => {
-            client.delete(&name).await.map_err(|e| {
-                color_eyre::eyre::eyre!("Failed to destroy sprite '{}': {}", name, e)
-            })?;
+            api.call_once("destroy sprite", || client.delete(&name))
+                .await
+                .map_err(|e| {
+                    color_eyre::eyre::eyre!("Failed to destroy sprite '{}': {}", name, e)
+                })?;
             println!("Destroyed: {}", name);
         }
         SpritesAction::DestroyAll { yes } => {
-            let sprites = client
-                .list()
+            let sprites = api
+                .call("list sprites", || client.list())
                 .await
                 .map_err(|e| color_eyre::eyre::eyre!("Failed to list sprites: {}", e))?;
 
@@ -178,7 +187,10 @@ async fn handle_sprites_command(action: SpritesAction, token: Option<String>) ->
             }
 
             for sprite in sprites {
-                match client.delete(&sprite.name).await {
+                match api
+                    .call_once("destroy sprite", || client.delete(&sprite.name))
+                    .await
+                {
                     Ok(()) => println!("Destroyed: {}", sprite.name),
                     Err(e) => eprintln!("Failed to destroy {}: {}", sprite.name, e),
                 }
@@ -195,12 +207,16 @@ async fn handle_sprites_command(action: SpritesAction, token: Option<String>) ->
 /// enriches with terminal context, and sends to TUI via Unix socket.
 ///
 /// Agent identification: TMUX_PANE (with recovery via `tmux display-message`),
-/// falls back to session_id prefix.
+/// then WEZTERM_PANE or KITTY_WINDOW_ID (see [`terminal`]), falls back to
+/// session_id prefix.
+///
+/// With `strict`, schema drift in the hook JSON is logged first (see
+/// [`event::hook_schema`]).
 ///
 /// Silently succeeds if:
 /// - No stdin input (empty hook call)
 /// - Socket unavailable (TUI not running)
-async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
+async fn handle_hook(socket_path: &PathBuf, should_notify: bool, strict: bool) -> Result<()> {
     use std::io::{self, BufRead};
     use tokio::io::AsyncWriteExt;
     use tokio::net::UnixStream as TokioUnixStream;
@@ -228,6 +244,15 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
         return Ok(()); // Silent exit - no input
     }
 
+    // Strict mode: say which fields drifted before the typed parse hides them
+    if strict {
+        if let Ok(raw) = serde_json::from_str::<serde_json::Value>(&input) {
+            for problem in event::hook_schema::check(&raw) {
+                tracing::warn!(problem = %problem, "Hook JSON does not match the expected schema");
+            }
+        }
+    }
+
     // Parse Claude Code's hook JSON
     let hook_input: event::ClaudeHookInput = match serde_json::from_str(&input) {
         Ok(parsed) => parsed,
@@ -250,7 +275,7 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
     // Capture effort level configuration
     let effort_level = std::env::var("CLAUDE_CODE_EFFORT_LEVEL").ok();
 
-    // Get pane ID from TMUX_PANE, fall back to session_id
+    // Get pane ID from TMUX_PANE, then WezTerm or Kitty, fall back to session_id
     let tmux_pane = std::env::var("TMUX_PANE").ok().or_else(|| {
         // Recovery: if inside tmux but TMUX_PANE not propagated, query tmux directly
         if std::env::var("TMUX").is_ok() {
@@ -271,12 +296,13 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
 
     let pane_id = tmux_pane
         .filter(|s| !s.is_empty())
+        .or_else(terminal::foreign_pane_from_env)
         .unwrap_or_else(|| {
             // Fallback: first 8 chars of session_id (always available)
             tracing::warn!(
-                "TMUX_PANE not found — using session_id fallback. Rehoboam requires tmux for full functionality."
+                "No tmux, WezTerm or Kitty pane found — using session_id fallback. Rehoboam requires tmux for full functionality."
             );
-            hook_input.session_id.chars().take(SESSION_ID_PREFIX_LEN).collect()
+            terminal::session_fallback_pane(&hook_input.session_id)
         });
 
     // Derive status from hook event name
@@ -300,6 +326,17 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
     let team_agent_name = std::env::var("CLAUDE_CODE_AGENT_NAME").ok();
     let team_agent_type = std::env::var("CLAUDE_CODE_AGENT_TYPE").ok();
 
+    // Desktop notification, if this event warrants one. The TUI sends it
+    // (throttled); we only send it ourselves when the TUI isn't reachable.
+    let mut quiet_hours = false;
+    let desktop_notification = if should_notify && status == "attention" {
+        let notify = config::RehoboamConfig::load().notify;
+        quiet_hours = notify.is_quiet_now();
+        hook_notification(&hook_input, attention_type, &project, &notify.sounds)
+    } else {
+        None
+    };
+
     // Build enriched socket message with ALL v1.0 fields
     let socket_event = event::HookEvent {
         event: hook_input.hook_event_name.clone(),
@@ -356,9 +393,12 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
         task_description: hook_input.task_description.clone(),
         // PostToolUse response
         tool_response: hook_input.tool_response.clone(),
+        desktop_notification,
+        socket: None,
     };
 
     // Try to send to TUI via socket (non-blocking, best effort)
+    let mut delivered = false;
     if socket_path.exists() {
         let connect_result = timeout(
             Duration::from_millis(500),
@@ -369,64 +409,155 @@ async fn handle_hook(socket_path: &PathBuf, should_notify: bool) -> Result<()> {
         if let Ok(Ok(mut stream)) = connect_result {
             if let Ok(json) = serde_json::to_string(&socket_event) {
                 let data = format!("{json}\n");
-                let _ = timeout(
-                    Duration::from_millis(500),
-                    stream.write_all(data.as_bytes()),
-                )
-                .await;
+                delivered = matches!(
+                    timeout(
+                        Duration::from_millis(500),
+                        stream.write_all(data.as_bytes()),
+                    )
+                    .await,
+                    Ok(Ok(()))
+                );
                 let _ = stream.shutdown().await;
             }
         }
     }
 
-    // Send desktop notification if requested
-    if should_notify {
-        match (status, attention_type) {
-            // Permission request - needs user approval
-            ("attention", Some("permission")) => {
-                notify::send(
-                    "Claude Needs Attention",
-                    &format!("Approve in {project}"),
-                    Some("Basso"),
-                );
-            }
-            // Input request - waiting for user response
-            ("attention", Some("input")) => {
-                notify::send(
-                    "Claude Needs Attention",
-                    &format!("Input needed in {project}"),
-                    Some("Basso"),
-                );
-            }
-            // Notification from Claude
-            ("attention", Some("notification")) => {
-                let title = hook_input
-                    .title
-                    .clone()
-                    .unwrap_or_else(|| "Claude Notification".to_string());
-                let msg = hook_input
-                    .message
-                    .unwrap_or_else(|| "Notification".to_string());
-                // Use urgency based on notification_type
-                let sound = match hook_input.notification_type.as_deref() {
-                    Some("permission_prompt") => "Basso",
-                    _ => "default",
-                };
-                notify::send(&title, &msg, Some(sound));
-            }
-            // Waiting (was idle) - only notify on Stop event (completion)
-            ("attention", Some("waiting")) if hook_input.hook_event_name == "Stop" => {
-                let reason = hook_input.reason.unwrap_or_else(|| "Complete".to_string());
-                notify::send(
-                    "Claude Done",
-                    &format!("{project}: {reason}"),
-                    Some("default"),
-                );
+    // Keep the agent alive while Claude streams a response without tool calls
+    if delivered {
+        match hook_input.hook_event_name.as_str() {
+            "UserPromptSubmit" => {
+                if let Err(e) = heartbeat::start(socket_path, &pane_id) {
+                    tracing::debug!(error = %e, "Heartbeat not started");
+                }
             }
+            "Stop" | "SessionEnd" => heartbeat::stop(socket_path, &pane_id),
             _ => {}
         }
     }
 
+    // Nobody received the event (TUI not running): notify directly, unthrottled,
+    // unless it is [notify] quiet_hours
+    if !delivered {
+        if let Some(note) = socket_event.desktop_notification {
+            if quiet_hours {
+                tracing::debug!(title = %note.title, "Quiet hours: notification suppressed");
+            } else {
+                notify::send(&note.title, &note.message, note.sound.as_deref());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Desktop notification warranted by an attention hook event, if any
+fn hook_notification(
+    hook_input: &event::ClaudeHookInput,
+    attention_type: Option<&str>,
+    project: &str,
+    sounds: &config::NotifySounds,
+) -> Option<event::DesktopNotification> {
+    let note = |title: &str, message: String, attention: &str| event::DesktopNotification {
+        title: title.to_string(),
+        message,
+        sound: Some(sounds.for_attention(attention).to_string()),
+        urgent: matches!(attention, "permission" | "input"),
+    };
+
+    match attention_type {
+        // Permission request - needs user approval
+        Some("permission") => Some(note(
+            "Claude Needs Attention",
+            format!("Approve in {project}"),
+            "permission",
+        )),
+        // Input request - waiting for user response
+        Some("input") => Some(note(
+            "Claude Needs Attention",
+            format!("Input needed in {project}"),
+            "input",
+        )),
+        // Sign-in notices show in the TUI footer only
+        Some("notification") if hook_input.notification_type.as_deref() == Some("auth_success") => {
+            None
+        }
+        // Notification from Claude
+        Some("notification") => {
+            let title = hook_input
+                .title
+                .clone()
+                .unwrap_or_else(|| "Claude Notification".to_string());
+            let msg = hook_input
+                .message
+                .clone()
+                .unwrap_or_else(|| "Notification".to_string());
+            // Permission prompts announced as notifications sound like permissions
+            let attention = match hook_input.notification_type.as_deref() {
+                Some("permission_prompt") => "permission",
+                _ => "notification",
+            };
+            Some(note(&title, msg, attention))
+        }
+        // Waiting (was idle) - only notify on Stop event (completion)
+        Some("waiting") if hook_input.hook_event_name == "Stop" => {
+            let reason = hook_input.reason.as_deref().unwrap_or("Complete");
+            Some(note(
+                "Claude Done",
+                format!("{project}: {reason}"),
+                "waiting",
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Request a board snapshot from the running TUI and print it
+///
+/// Unlike the hook path, this fails loudly when the TUI isn't running so
+/// scripts can tell "no agents" apart from "nothing listening".
+async fn handle_export_state(socket_path: &PathBuf, format: ExportFormat) -> Result<()> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::UnixStream as TokioUnixStream;
+    use tokio::time::{timeout, Duration};
+
+    let not_running = || {
+        color_eyre::eyre::eyre!(
+            "rehoboam TUI is not running (no listener at {})",
+            socket_path.display()
+        )
+    };
+
+    let mut stream = timeout(
+        Duration::from_millis(500),
+        TokioUnixStream::connect(socket_path),
+    )
+    .await
+    .map_err(|_| not_running())?
+    .map_err(|_| not_running())?;
+
+    let request = serde_json::to_string(&event::ControlRequest::Snapshot)?;
+    stream.write_all(format!("{request}\n").as_bytes()).await?;
+
+    let mut response = String::new();
+    timeout(
+        Duration::from_secs(5),
+        BufReader::new(stream).read_line(&mut response),
+    )
+    .await
+    .map_err(|_| color_eyre::eyre::eyre!("Timed out waiting for snapshot from TUI"))??;
+
+    if response.trim().is_empty() {
+        return Err(color_eyre::eyre::eyre!(
+            "TUI closed the connection without a snapshot (older rehoboam version?)"
+        ));
+    }
+
+    let agents: Vec<state::AgentSnapshot> = serde_json::from_str(&response)?;
+    match format {
+        ExportFormat::Json => println!("{}", serde_json::to_string_pretty(&agents)?),
+        ExportFormat::Csv => print!("{}", state::snapshot_to_csv(&agents)),
+    }
+
     Ok(())
 }
 
@@ -475,10 +606,11 @@ async fn main() -> Result<()> {
         Some(Commands::Hook {
             no_notify,
             notify: _,
+            strict_hooks,
         }) => {
             // Hook mode: read stdin JSON, enrich with context, send to TUI
             // Notifications are ON by default, use --no-notify to disable
-            return handle_hook(&cli.socket, !no_notify).await;
+            return handle_hook(cli.primary_socket(), !no_notify, strict_hooks).await;
         }
         Some(Commands::Init {
             path,
@@ -498,6 +630,35 @@ async fn main() -> Result<()> {
             // Sprites management commands
             return handle_sprites_command(action, cli.sprites_token).await;
         }
+        Some(Commands::Heartbeat {
+            ref pane_id,
+            interval,
+        }) => {
+            // Detached liveness pinger started by the UserPromptSubmit hook
+            heartbeat::run(cli.primary_socket(), pane_id, &get_project_name(), interval).await;
+            return Ok(());
+        }
+        Some(Commands::Check { ref config }) => {
+            // Diagnostics only: validate the config and hook setup, then exit
+            let config_path = config
+                .clone()
+                .unwrap_or_else(config::RehoboamConfig::default_path);
+            let cwd = std::env::current_dir()?;
+            let home = directories::BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+            let report = check::run(&config_path, &cli.socket, &cwd, home.as_deref());
+            print!("{}", report.render());
+            return match report.problems() {
+                0 => Ok(()),
+                n => Err(color_eyre::eyre::eyre!(
+                    "{n} problem{} found",
+                    if n == 1 { "" } else { "s" }
+                )),
+            };
+        }
+        Some(Commands::ExportState { format }) => {
+            // Query the running TUI for a board snapshot
+            return handle_export_state(cli.primary_socket(), format).await;
+        }
         None => {
             // TUI mode: continue with full setup
         }
@@ -506,8 +667,26 @@ async fn main() -> Result<()> {
     // Initialize error handling
     color_eyre::install()?;
 
+    // Mirror mode needs a shared token on both ends
+    let tcp_token: Option<std::sync::Arc<str>> = cli.tcp_token.as_deref().map(Into::into);
+    if (cli.listen_tcp.is_some() || cli.connect.is_some()) && tcp_token.is_none() {
+        return Err(color_eyre::eyre::eyre!(
+            "--listen-tcp and --connect require --tcp-token (or REHOBOAM_TCP_TOKEN)"
+        ));
+    }
+
+    // Read a board recording before taking over the terminal, so a bad path fails cleanly
+    let playback = match cli.playback.as_deref() {
+        Some(path) => Some(state::load_frames(path)?),
+        None => None,
+    };
+
     // Load configuration
-    let app_config = config::RehoboamConfig::load();
+    let mut app_config = config::RehoboamConfig::load();
+    if cli.connect.is_some() || cli.replay.is_some() || cli.playback.is_some() {
+        // A mirrored, replayed or played-back board must not overwrite the local saved board
+        app_config.persistence.enabled = false;
+    }
 
     // Setup file logging with rotation
     let log_dir = get_log_dir();
@@ -529,20 +708,105 @@ async fn main() -> Result<()> {
 
     tracing::info!("Starting rehoboam v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!("Log directory: {:?}", log_dir);
-    tracing::debug!("Socket path: {:?}", cli.socket);
+    tracing::debug!("Socket paths: {:?}", cli.socket);
 
-    // Create event channel
+    // Create event channel. When it is full, hook ingestion drops and counts
+    // tool events instead of waiting (see event::ingest)
     let (event_tx, event_rx) = mpsc::channel(100);
+    let dropped_events = event::ingest::DroppedEvents::default();
+
+    // Mirror host: rebroadcast local hook events to TCP clients (and the web dashboard)
+    let mirror_hub = (cli.listen_tcp.is_some() || cli.web_port.is_some()).then(event::mirror::hub);
+    let mirror_handle = match (&cli.listen_tcp, &mirror_hub, &tcp_token) {
+        (Some(addr), Some(hub), Some(token)) => {
+            let (addr, hub, token) = (addr.clone(), hub.clone(), token.clone());
+            Some(tokio::spawn(async move {
+                if let Err(e) = event::mirror::listen_tcp(hub, &addr, token).await {
+                    tracing::error!("Mirror listener error: {}", e);
+                }
+            }))
+        }
+        _ => None,
+    };
 
-    // Spawn socket listener
-    let socket_path = cli.socket.clone();
-    let socket_tx = event_tx.clone();
-    let socket_handle = tokio::spawn(async move {
-        if let Err(e) = event::socket::listen(socket_tx, &socket_path).await {
-            tracing::error!("Socket listener error: {}", e);
+    // Prometheus scrape endpoint (opt-in, localhost only)
+    let metrics_handle = cli.metrics_port.map(|port| {
+        let tx = event_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = event::metrics::serve(tx, &format!("127.0.0.1:{port}")).await {
+                tracing::error!("Metrics endpoint error: {}", e);
+            }
+        })
+    });
+
+    // Read-only web dashboard (opt-in, localhost only)
+    let web_handle = match (cli.web_port, &mirror_hub) {
+        (Some(port), Some(hub)) => {
+            let (tx, hub) = (event_tx.clone(), hub.clone());
+            Some(tokio::spawn(async move {
+                let addr = format!("127.0.0.1:{port}");
+                if let Err(e) = event::web::serve(tx, hub, &addr).await {
+                    tracing::error!("Web dashboard error: {}", e);
+                }
+            }))
         }
+        _ => None,
+    };
+
+    // JSON-RPC control socket (opt-in)
+    let control_handle = cli.control_socket.clone().map(|path| {
+        let tx = event_tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = event::rpc::listen(tx, &path).await {
+                tracing::error!("Control socket error: {}", e);
+            }
+        })
     });
 
+    // Spawn socket listener, or the mirror client when watching a remote board,
+    // or the file reader when replaying a recording (nothing during board playback)
+    let socket_tx = event_tx.clone();
+    let shutdown = CancellationToken::new();
+    let socket_handle = if cli.playback.is_some() {
+        tokio::spawn(async {})
+    } else if let Some(path) = cli.replay.clone() {
+        let speed = cli.replay_speed;
+        tokio::spawn(async move {
+            if let Err(e) = event::replay::replay(socket_tx, &path, speed).await {
+                tracing::error!("Replay error: {}", e);
+            }
+        })
+    } else if let (Some(addr), Some(token)) = (cli.connect.clone(), tcp_token) {
+        let hub = mirror_hub.clone();
+        tokio::spawn(async move {
+            event::mirror::connect(socket_tx, &addr, &token, hub).await;
+        })
+    } else {
+        // One supervised listener per --socket; the outer task owns them so a
+        // single abort stops all, and the shutdown token stops restarts
+        let listeners: Vec<_> = cli
+            .socket
+            .iter()
+            .map(|path| (path.clone(), cli.socket_label(path)))
+            .collect();
+        let cancel = shutdown.clone();
+        let ingest = event::ingest::IngestSender::new(socket_tx, dropped_events.clone());
+        tokio::spawn(async move {
+            let mut tasks = tokio::task::JoinSet::new();
+            for (socket_path, label) in listeners {
+                let (ingest, hub) = (ingest.clone(), mirror_hub.clone());
+                tasks.spawn(event::socket::listen(
+                    ingest,
+                    socket_path,
+                    hub,
+                    label,
+                    cancel.clone(),
+                ));
+            }
+            while tasks.join_next().await.is_some() {}
+        })
+    };
+
     // Optionally spawn sprite event forwarder (WebSocket server for remote sprites)
     // Sprites are auto-enabled when SPRITES_TOKEN is set, use --no-sprites to disable
     let sprites_enabled = !cli.no_sprites && cli.sprites_token.is_some();
@@ -586,7 +850,8 @@ async fn main() -> Result<()> {
         });
 
         // Spawn task to convert RemoteHookEvent -> Event::RemoteHook
-        let sprite_event_tx = event_tx.clone();
+        let sprite_ingest =
+            event::ingest::IngestSender::new(event_tx.clone(), dropped_events.clone());
         let converter_handle = tokio::spawn(async move {
             while let Some(remote_event) = sprite_rx.recv().await {
                 // Convert forwarder's RemoteHookEvent to our HookEvent
@@ -665,11 +930,14 @@ async fn main() -> Result<()> {
                     task_description: None,
                     // PostToolUse response - not yet available from sprites
                     tool_response: None,
+                    // Sprites don't request desktop notifications
+                    desktop_notification: None,
+                    socket: None,
                 };
 
                 // Send as RemoteHook event
-                if let Err(e) = sprite_event_tx
-                    .send(event::Event::RemoteHook {
+                if let Err(e) = sprite_ingest
+                    .forward(event::Event::RemoteHook {
                         sprite_id: remote_event.sprite_id,
                         event: Box::new(hook_event),
                     })
@@ -700,27 +968,73 @@ async fn main() -> Result<()> {
     // Create SpritesClient if token is provided
     let sprites_client = cli.sprites_token.as_ref().map(|token| {
         tracing::info!("Creating SpritesClient");
-        sprites::SpritesClient::new(token)
+        sprite::api::SpriteApi::new(sprites::SpritesClient::new(token), &app_config.sprites)
     });
 
     // Clamp rates to prevent division-by-zero or extreme values
     let tick_rate = cli.tick_rate.clamp(0.1, 60.0);
     let frame_rate = cli.frame_rate.clamp(0.1, 120.0);
 
+    // Resolve [theme] colors before the first frame
+    config::colors::init(config::Palette::from_theme(&app_config.theme));
+
+    // Create app state with sprites client and config
+    let mut app = App::new(cli.debug, sprites_client, &app_config);
+    app.read_only = cli.connect.is_some() || cli.replay.is_some() || cli.playback.is_some();
+    if let Some(frames) = playback {
+        app.playback = Some(app::PlaybackState::new(frames));
+        app.input_mode = app::InputMode::Playback;
+    }
+    if let Some(ref name) = cli.workspace {
+        app.load_workspace(name);
+    }
+    if let Some(ref path) = cli.record {
+        app.recorder = Some(event::replay::EventRecorder::open(path)?);
+    }
+    if let Some(ref path) = cli.record_board {
+        app.board_recorder = Some(state::BoardRecorder::open(path, &app_config.recording)?);
+    }
+    if let Some(ref path) = cli.metrics_csv {
+        app.turn_csv = Some(state::TurnCsvWriter::open(path)?);
+    }
+    if let Some(ref path) = cli.audit_log {
+        app.audit.open_file(path)?;
+    }
+    app.event_tx = Some(event_tx.clone());
+    app.dropped_events = dropped_events;
+    // An empty board is usually a hook setup problem: say so up front
+    if !app.read_only {
+        if let Ok(cwd) = std::env::current_dir() {
+            let home = directories::BaseDirs::new().map(|dirs| dirs.home_dir().to_path_buf());
+            app.set_setup_warning(init::diagnose_hooks(&cwd, home.as_deref()));
+        }
+    }
+
     // Run TUI
     let result = run_tui(
         event_tx,
         event_rx,
-        cli.debug,
         tick_rate,
         frame_rate,
-        sprites_client,
-        &app_config,
+        app,
+        shutdown.clone(),
     )
     .await;
 
     // Cleanup
     socket_handle.abort();
+    if let Some(handle) = mirror_handle {
+        handle.abort();
+    }
+    if let Some(handle) = metrics_handle {
+        handle.abort();
+    }
+    if let Some(handle) = web_handle {
+        handle.abort();
+    }
+    if let Some(handle) = control_handle {
+        handle.abort();
+    }
 
     // Cleanup sprite handles if enabled
     if let Some((forwarder_handle, converter_handle, status_handle, reaper_handle)) = sprite_handle
@@ -732,9 +1046,14 @@ async fn main() -> Result<()> {
         tracing::debug!("Sprite forwarder shut down");
     }
 
-    // Remove socket file
-    if cli.socket.exists() {
-        let _ = std::fs::remove_file(&cli.socket);
+    // Remove socket files (mirror clients, replays and playback never created any)
+    if cli.connect.is_none() && cli.replay.is_none() && cli.playback.is_none() {
+        for path in cli.socket.iter().filter(|p| p.exists()) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+    if let Some(path) = cli.control_socket.as_ref().filter(|p| p.exists()) {
+        let _ = std::fs::remove_file(path);
     }
 
     result
@@ -743,14 +1062,12 @@ async fn main() -> Result<()> {
 async fn run_tui(
     event_tx: mpsc::Sender<event::Event>,
     mut event_rx: mpsc::Receiver<event::Event>,
-    debug_mode: bool,
     tick_rate: f64,
     frame_rate: f64,
-    sprites_client: Option<sprites::SpritesClient>,
-    config: &config::RehoboamConfig,
+    mut app: App,
+    cancel: CancellationToken,
 ) -> Result<()> {
     use std::time::{Duration, Instant};
-    use tokio_util::sync::CancellationToken;
 
     // Calculate durations from rates
     let tick_duration = Duration::from_secs_f64(1.0 / tick_rate);
@@ -768,12 +1085,6 @@ async fn run_tui(
     // RAII guard ensures terminal is restored on panic or early return
     let _guard = tui::TerminalGuard;
 
-    // Create app state with sprites client, event channel, and config
-    let mut app = App::new(debug_mode, sprites_client, &config.health, &config.timeouts);
-
-    // Create cancellation token for graceful shutdown
-    let cancel = CancellationToken::new();
-
     // Spawn input event handler with cancellation support
     let input_tx = event_tx.clone();
     let input_cancel = cancel.clone();
@@ -788,7 +1099,7 @@ async fn run_tui(
     loop {
         // Frame rate limiting with dirty flag check
         let now = Instant::now();
-        if app.needs_render && now.duration_since(last_frame) >= frame_duration {
+        if app.wants_render() && now.duration_since(last_frame) >= frame_duration {
             terminal.draw(|f| ui::render(f, &mut app))?;
             app.rendered();
             last_frame = now;
@@ -809,8 +1120,11 @@ async fn run_tui(
         }
     }
 
-    // Graceful shutdown: signal input listener to stop
-    tracing::debug!("Shutting down input listener");
+    // Save final board so the next launch starts where we left off
+    app.persist_board();
+
+    // Graceful shutdown: signal input listener and socket supervisors to stop
+    tracing::debug!("Shutting down listeners");
     cancel.cancel();
     input_handle.abort();
 
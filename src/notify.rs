@@ -10,17 +10,36 @@ use notify_rust::Notification;
 /// On macOS, uses native NSUserNotification or UNUserNotification APIs.
 /// On Linux, uses libnotify (freedesktop.org compliant).
 ///
-/// Sound is currently ignored but kept for API compatibility.
-pub fn send(title: &str, message: &str, _sound: Option<&str>) {
+/// `sound` is a sound name or, on Linux, a sound file path (see
+/// `[notify.sounds]`). `None` or `"default"` plays the platform default, and
+/// so does a name the platform doesn't know.
+pub fn send(title: &str, message: &str, sound: Option<&str>) {
     // Spawn notification async - don't block on it
     let title = title.to_string();
     let message = message.to_string();
+    let sound = sound.map(String::from);
 
     std::thread::spawn(move || {
-        let _ = Notification::new()
-            .summary(&title)
-            .body(&message)
-            .timeout(5000) // 5 seconds
-            .show();
+        let mut notification = Notification::new();
+        notification.summary(&title).body(&message).timeout(5000); // 5 seconds
+        if let Some(sound) = sound.as_deref() {
+            apply_sound(&mut notification, sound);
+        }
+        let _ = notification.show();
     });
 }
+
+fn apply_sound(notification: &mut Notification, sound: &str) {
+    if sound.is_empty() || sound == "default" {
+        return;
+    }
+    #[cfg(all(unix, not(target_os = "macos")))]
+    if sound.contains('/') {
+        // A missing file would silence the notification: keep the default
+        if std::path::Path::new(sound).is_file() {
+            notification.hint(notify_rust::Hint::SoundFile(sound.to_string()));
+        }
+        return;
+    }
+    notification.sound_name(sound);
+}
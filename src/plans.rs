@@ -105,7 +105,7 @@ pub fn discover_plans() -> Vec<PlanEntry> {
         })
         .collect();
 
-    plans.sort_by(|a, b| b.modified.cmp(&a.modified));
+    plans.sort_by_key(|p| std::cmp::Reverse(p.modified));
     plans
 }
 
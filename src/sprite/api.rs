@@ -0,0 +1,262 @@
+//! Timeouts, retries and a circuit breaker around Sprites API calls
+//!
+//! The `sprites` client has no overall deadline, so a hung API would leave a
+//! checkpoint fetch or restore pending forever. [`SpriteApi`] bounds every
+//! attempt by `[sprites] api_timeout_secs`, retries idempotent calls on
+//! transient failures with exponential backoff, and after
+//! `breaker_failures` transient failures in a row stops calling the API for
+//! `breaker_cooldown_secs`. Calls made while the breaker is open fail at once
+//! with a message for the status line; the first call after the cooldown is
+//! let through as a probe.
+//!
+//! Transient means a timeout, a transport error or a 5xx/429 response. Any
+//! other error (not found, 4xx) proves the API is up and is returned as is.
+
+use crate::config::SpritesConfig;
+use sprites::{Sprite, SpritesClient};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Delay before the first retry; doubles on each further one
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Sprites client plus the timeout, retry and breaker policy for its calls
+#[derive(Clone)]
+pub struct SpriteApi {
+    client: SpritesClient,
+    timeout: Duration,
+    retries: u32,
+    backoff: Duration,
+    breaker: Arc<Mutex<Breaker>>,
+}
+
+impl SpriteApi {
+    pub fn new(client: SpritesClient, config: &SpritesConfig) -> Self {
+        Self {
+            client,
+            timeout: Duration::from_secs(config.api_timeout_secs),
+            retries: config.api_retries,
+            backoff: BASE_BACKOFF,
+            breaker: Arc::new(Mutex::new(Breaker::new(
+                config.breaker_failures,
+                Duration::from_secs(config.breaker_cooldown_secs),
+            ))),
+        }
+    }
+
+    pub fn client(&self) -> &SpritesClient {
+        &self.client
+    }
+
+    pub fn sprite(&self, name: &str) -> Sprite {
+        self.client.sprite(name)
+    }
+
+    /// Run an idempotent call, retrying transient failures
+    ///
+    /// `what` names the call in logs and errors ("list checkpoints").
+    pub async fn call<T, F, Fut>(&self, what: &str, op: F) -> Result<T, String>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = sprites::Result<T>>,
+    {
+        self.run(what, self.retries, op).await
+    }
+
+    /// Run a call that must not be repeated (create, restore, keystrokes)
+    pub async fn call_once<T, F, Fut>(&self, what: &str, op: F) -> Result<T, String>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = sprites::Result<T>>,
+    {
+        self.run(what, 0, op).await
+    }
+
+    async fn run<T, F, Fut>(&self, what: &str, retries: u32, mut op: F) -> Result<T, String>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = sprites::Result<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            self.breaker().admit(Instant::now())?;
+            let error = match tokio::time::timeout(self.timeout, op()).await {
+                Ok(Ok(value)) => {
+                    self.breaker().succeeded();
+                    return Ok(value);
+                }
+                Ok(Err(e)) if !is_transient(&e) => {
+                    self.breaker().succeeded();
+                    return Err(e.to_string());
+                }
+                Ok(Err(e)) => e.to_string(),
+                Err(_) => format!("timed out after {}s", self.timeout.as_secs()),
+            };
+            self.breaker().failed(Instant::now());
+            if attempt >= retries {
+                tracing::warn!(call = what, attempts = attempt + 1, error = %error, "Sprites API call failed");
+                return Err(format!("{what}: {error}"));
+            }
+            let delay = self.backoff * 2u32.pow(attempt);
+            tracing::debug!(call = what, error = %error, ?delay, "Retrying Sprites API call");
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    fn breaker(&self) -> std::sync::MutexGuard<'_, Breaker> {
+        // A panic mid-update leaves only counters behind, safe to reuse
+        self.breaker.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Failures worth retrying and counting against the breaker
+fn is_transient(error: &sprites::Error) -> bool {
+    match error {
+        sprites::Error::Http(_)
+        | sprites::Error::WebSocket(_)
+        | sprites::Error::Timeout
+        | sprites::Error::Connection(_)
+        | sprites::Error::Io(_) => true,
+        sprites::Error::Api { status, .. } => *status >= 500 || *status == 429,
+        _ => false,
+    }
+}
+
+/// Consecutive-failure circuit breaker
+#[derive(Debug)]
+struct Breaker {
+    threshold: u32,
+    cooldown: Duration,
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl Breaker {
+    fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            failures: 0,
+            open_until: None,
+        }
+    }
+
+    /// Err with a status-line message while open
+    fn admit(&mut self, now: Instant) -> Result<(), String> {
+        match self.open_until {
+            Some(until) if now < until => Err(format!(
+                "Sprites API unavailable after {} failures, next try in {}s",
+                self.failures,
+                (until - now).as_secs().max(1)
+            )),
+            // Cooldown over: let one probe through, a failure re-opens
+            Some(_) => {
+                self.open_until = None;
+                self.failures = self.threshold.saturating_sub(1);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn succeeded(&mut self) {
+        self.failures = 0;
+        self.open_until = None;
+    }
+
+    fn failed(&mut self, now: Instant) {
+        self.failures += 1;
+        if self.failures >= self.threshold {
+            if self.open_until.is_none() {
+                tracing::warn!(
+                    failures = self.failures,
+                    cooldown_secs = self.cooldown.as_secs(),
+                    "Sprites API circuit open"
+                );
+            }
+            self.open_until = Some(now + self.cooldown);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn test_api(retries: u32, threshold: u32) -> SpriteApi {
+        let config = SpritesConfig {
+            api_timeout_secs: 1,
+            api_retries: retries,
+            breaker_failures: threshold,
+            ..SpritesConfig::default()
+        };
+        let mut api = SpriteApi::new(SpritesClient::new("test"), &config);
+        api.backoff = Duration::ZERO;
+        api
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_failures_then_opens_breaker() {
+        let api = test_api(2, 4);
+        let calls = Cell::new(0);
+        let flaky = || {
+            calls.set(calls.get() + 1);
+            let n = calls.get();
+            async move {
+                if n < 3 {
+                    Err(sprites::Error::api(503, "down"))
+                } else {
+                    Ok(n)
+                }
+            }
+        };
+        assert_eq!(api.call("list", flaky).await, Ok(3));
+
+        // Not found is an answer: no retry, breaker stays closed
+        calls.set(0);
+        let missing = || {
+            calls.set(calls.get() + 1);
+            async { Err::<(), _>(sprites::Error::NotFound("x".into())) }
+        };
+        assert!(api.call("get", missing).await.is_err());
+        assert_eq!(calls.get(), 1);
+
+        // Three failed attempts, then a fourth trips the breaker
+        let down = || async { Err::<(), _>(sprites::Error::Timeout) };
+        let error = api.call("list", down).await.unwrap_err();
+        assert!(error.starts_with("list: "), "{error}");
+        assert!(api.call_once("restore", down).await.is_err());
+        calls.set(0);
+        let counted = || {
+            calls.set(calls.get() + 1);
+            async { Ok(()) }
+        };
+        let error = api.call("list", counted).await.unwrap_err();
+        assert!(error.starts_with("Sprites API unavailable"), "{error}");
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn test_breaker_probes_after_cooldown() {
+        let mut breaker = Breaker::new(2, Duration::from_secs(30));
+        let start = Instant::now();
+        breaker.failed(start);
+        assert!(breaker.admit(start).is_ok());
+        breaker.failed(start);
+        assert!(breaker.admit(start + Duration::from_secs(10)).is_err());
+
+        // A failed probe re-opens at once; a good one closes
+        let later = start + Duration::from_secs(31);
+        assert!(breaker.admit(later).is_ok());
+        breaker.failed(later);
+        assert!(breaker.admit(later).is_err());
+        let later = later + Duration::from_secs(31);
+        assert!(breaker.admit(later).is_ok());
+        breaker.succeeded();
+        breaker.failed(later);
+        assert!(breaker.admit(later).is_ok());
+    }
+}
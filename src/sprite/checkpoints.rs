@@ -0,0 +1,90 @@
+//! Sprite checkpoint timeline and restore
+//!
+//! Both operations run on the Tokio runtime and report back to the TUI as
+//! [`Event::Checkpoints`] / [`Event::CheckpointRestored`], so the render loop
+//! never blocks on the Sprites API. Calls go through [`SpriteApi`], so a hung
+//! or failing API ends in an error result rather than a spinner that never
+//! stops.
+
+use crate::event::Event;
+use crate::sprite::api::SpriteApi;
+use tokio::sync::mpsc;
+
+/// One entry in a sprite's checkpoint timeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointRecord {
+    /// Checkpoint ID (e.g. "v3")
+    pub id: String,
+    /// Comment given when the checkpoint was taken
+    pub comment: Option<String>,
+    /// Creation time, formatted for display
+    pub created_at: Option<String>,
+}
+
+impl From<sprites::Checkpoint> for CheckpointRecord {
+    fn from(checkpoint: sprites::Checkpoint) -> Self {
+        Self {
+            id: checkpoint.id,
+            comment: checkpoint.comment,
+            created_at: checkpoint
+                .created_at
+                .map(|t| t.format("%Y-%m-%d %H:%M").to_string()),
+        }
+    }
+}
+
+/// Fetch the checkpoint timeline for a sprite (newest first)
+pub fn fetch(api: &SpriteApi, sprite_id: &str, tx: mpsc::Sender<Event>) {
+    let api = api.clone();
+    let sprite = api.sprite(sprite_id);
+    let sprite_id = sprite_id.to_string();
+
+    tokio::spawn(async move {
+        let result = api
+            .call("list checkpoints", || sprite.list_checkpoints())
+            .await
+            .map(|checkpoints| {
+                let mut records: Vec<CheckpointRecord> = checkpoints
+                    .into_iter()
+                    .map(CheckpointRecord::from)
+                    .collect();
+                // Timestamps are formatted year-first, so they sort as strings
+                records.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                records
+            });
+        if let Err(ref e) = result {
+            tracing::warn!(sprite_id = %sprite_id, error = %e, "Failed to list checkpoints");
+        }
+        let _ = tx.send(Event::Checkpoints { sprite_id, result }).await;
+    });
+}
+
+/// Roll a sprite back to `checkpoint_id`
+pub fn restore(api: &SpriteApi, sprite_id: &str, checkpoint_id: &str, tx: mpsc::Sender<Event>) {
+    let api = api.clone();
+    let sprite = api.sprite(sprite_id);
+    let sprite_id = sprite_id.to_string();
+    let checkpoint_id = checkpoint_id.to_string();
+
+    tokio::spawn(async move {
+        tracing::info!(sprite_id = %sprite_id, checkpoint_id = %checkpoint_id, "Restoring checkpoint");
+        let result = api
+            .call_once("restore checkpoint", || sprite.restore(&checkpoint_id))
+            .await;
+        if let Err(ref e) = result {
+            tracing::warn!(
+                sprite_id = %sprite_id,
+                checkpoint_id = %checkpoint_id,
+                error = %e,
+                "Checkpoint restore failed"
+            );
+        }
+        let _ = tx
+            .send(Event::CheckpointRestored {
+                sprite_id,
+                checkpoint_id,
+                result,
+            })
+            .await;
+    });
+}
@@ -3,5 +3,7 @@
 //! This module enables running Claude Code agents inside Sprites sandboxes
 //! (remote VMs) while maintaining real-time monitoring through Rehoboam.
 
+pub mod api;
+pub mod checkpoints;
 pub mod config;
 pub mod forwarder;
@@ -2,7 +2,12 @@
 //!
 //! Tracks the status and activity of each Claude Code agent running in tmux panes.
 
-use std::collections::{HashSet, VecDeque};
+use super::latency::LatencyHistogram;
+use super::turns::TurnRecord;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Maximum completed tool calls retained per agent for the detail view
+pub const MAX_RECENT_TOOL_CALLS: usize = 20;
 
 /// Agent role classification based on tool usage patterns
 ///
@@ -103,13 +108,12 @@ pub struct Subagent {
     pub duration_ms: Option<u64>,
 
     // v1.3: Parent-child relationship tracking
-    /// Parent pane ID (the agent that spawned this subagent).
-    /// Used for future hierarchical subagent tree visualization.
-    #[allow(dead_code)] // Tracked for future nested tree display
+    /// Parent pane ID (the agent whose session hosts this subagent)
+    #[allow(dead_code)] // Always the owning agent's pane today
     pub parent_pane_id: String,
-    /// Nesting depth (0 = root agent's direct child, 1 = grandchild, etc.).
-    /// Used for hierarchical subagent tree visualization.
-    #[allow(dead_code)] // Tracked for future nested tree display
+    /// Subagent that spawned this one (None = the pane's main agent)
+    pub parent_subagent_id: Option<String>,
+    /// Nesting depth (0 = root agent's direct child, 1 = grandchild, etc.)
     pub depth: u8,
     /// Inferred role based on subagent description
     #[allow(dead_code)] // Tracked for future subagent tree display
@@ -124,6 +128,19 @@ pub struct Subagent {
     pub transcript_path: Option<String>,
 }
 
+/// A completed tool invocation (PreToolUse → PostToolUse/PostToolUseFailure)
+#[derive(Debug, Clone)]
+pub struct ToolCall {
+    /// Tool name (Bash, Read, mcp__server__tool, etc.)
+    pub name: String,
+    /// Pre→Post latency in milliseconds (None if PreToolUse was missed)
+    pub latency_ms: Option<u64>,
+    /// Completion timestamp (Unix seconds)
+    pub completed_at: i64,
+    /// True if the call ended with PostToolUseFailure
+    pub failed: bool,
+}
+
 /// Current status of a Claude Code agent
 ///
 /// Status determines visual representation and column placement:
@@ -174,7 +191,7 @@ impl Status {
 /// - **Input**: Agent is waiting for user response in the conversation
 /// - **Notification**: Claude sent a notification (informational)
 /// - **Waiting**: Agent is idle, ready for new prompt (lowest priority, was Status::Idle)
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AttentionType {
     /// A tool or action requires explicit user permission
     Permission,
@@ -208,12 +225,91 @@ impl AttentionType {
     }
 }
 
+/// How an agent's last turn ended (`Stop`)
+///
+/// Tells "finished the work" apart from "was stopped" when an agent sits
+/// in Waiting. `SessionEnd` removes the agent, so it has no variant here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnEnd {
+    /// Claude finished responding
+    Completed,
+    /// The user interrupted a tool and the turn stopped there
+    Interrupted,
+}
+
+impl TurnEnd {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Completed => "completed",
+            Self::Interrupted => "interrupted",
+        }
+    }
+}
+
+/// Where an agent's `team_name` came from
+///
+/// Sources disagree when a team is renamed or a pane is reused. Precedence,
+/// strongest first: the agent's own hook payload (env vars, `TeamCreate`),
+/// then session correlation from teammate events, then the `~/.claude/teams/`
+/// scan. A stronger source overwrites a weaker one; the filesystem scan only
+/// fills agents with no team at all, since its config files can be stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TeamSource {
+    /// Team fields on the agent's own hook events
+    Hook,
+    /// `session_to_team`, fed by TeammateIdle/TaskCompleted events
+    Session,
+    /// Member lists in `~/.claude/teams/*/config.json`
+    Filesystem,
+}
+
+/// Permission mode that skips every prompt (`--dangerously-skip-permissions`)
+pub const BYPASS_PERMISSIONS: &str = "bypassPermissions";
+
+/// Recent turn durations kept per agent for the iteration sparkline
+pub const TURN_HISTORY: usize = 12;
+
+/// Turns needed before a duration trend is reported
+const MIN_TREND_TURNS: usize = 4;
+
+/// Direction recent turn durations are moving (loop agents)
+///
+/// Compares the mean of the newer half of [`Agent::turn_durations`] with the
+/// older half; a change of less than a quarter reads as steady.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnTrend {
+    /// Turns are getting shorter
+    Faster,
+    /// Turns take about as long as before
+    Steady,
+    /// Turns are getting longer
+    Slower,
+}
+
+impl TurnTrend {
+    /// Arrow for the card: duration going up, down, or flat
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Faster => "\u{2193}", // ↓
+            Self::Steady => "\u{2192}", // →
+            Self::Slower => "\u{2191}", // ↑
+        }
+    }
+}
+
+/// Buckets in each agent's activity sparkline
+pub const ACTIVITY_BUCKETS: usize = 30;
+
+/// Seconds per activity bucket (30 x 10s = last 5 minutes)
+pub const ACTIVITY_BUCKET_SECS: i64 = 10;
+
 /// A Claude Code agent instance running in a tmux pane
 ///
 /// Tracks an agent's current state and activity history. Each agent is uniquely
 /// identified by its `pane_id` (tmux pane where it's running).
 ///
 /// # Session Timing
+/// - `created_at`: When the agent first appeared (never reset)
 /// - `start_time`: When the session was first started (or restarted)
 /// - `last_update`: When the last hook event was received
 ///
@@ -228,6 +324,10 @@ pub struct Agent {
     pub project: String,
     /// Current agent status
     pub status: Status,
+    /// First event from this agent (Unix timestamp in seconds, 0 until then)
+    ///
+    /// Unlike `start_time`, a new session in the same pane doesn't reset it.
+    pub created_at: i64,
     /// Session start time (Unix timestamp in seconds)
     pub start_time: i64,
     /// Last event timestamp (Unix timestamp in seconds)
@@ -241,6 +341,13 @@ pub struct Agent {
     pub current_tool: Option<String>,
     /// PreToolUse timestamp for latency calculation
     pub pending_tool_start: Option<i64>,
+    /// When `current_tool` was last set (cleared with it on PostToolUse)
+    pub current_tool_since: Option<i64>,
+    /// Set by `tick()` while `current_tool` has run past `stuck_tool_secs`
+    pub stuck: bool,
+    /// When the agent went quiet past `stale_timeout_secs` (greyed out,
+    /// not counted in the header, removed after `gone_grace_secs`)
+    pub gone_since: Option<i64>,
     /// tool_use_id for correlating Pre→Post events
     pub pending_tool_use_id: Option<String>,
     /// Last tool execution time in milliseconds
@@ -252,10 +359,21 @@ pub struct Agent {
     /// True when Claude is actively responding (between UserPromptSubmit and Stop)
     /// Prevents timeout to Waiting while Claude is generating text (no tool hooks)
     pub in_response: bool,
+    /// UserPromptSubmit timestamp of the turn in progress
+    pub turn_started: Option<i64>,
+    /// `total_tool_calls` when the current turn started
+    pub turn_start_tool_calls: u32,
+    /// Turns completed this session
+    pub turns_completed: u32,
+    /// Durations of the last [`TURN_HISTORY`] turns in seconds, oldest first
+    pub turn_durations: VecDeque<i64>,
 
     // v0.9.0 Subagent tracking
     /// Subagents spawned by this agent
     pub subagents: Vec<Subagent>,
+    /// Spawner of the last subagent-launching tool call, claimed by the next
+    /// SubagentStart: `Some(None)` is the main agent, `Some(Some(id))` a subagent
+    pub pending_subagent_parent: Option<Option<String>>,
 
     // v0.10.0 Sprite tracking
     /// True if this agent is running in a remote Sprite VM
@@ -268,6 +386,10 @@ pub struct Agent {
     pub role: AgentRole,
     /// Recent tool names for role inference (last 10 tools)
     pub tool_history: VecDeque<String>,
+    /// Completed tool calls with latency, newest last (bounded ring buffer)
+    pub recent_tool_calls: VecDeque<ToolCall>,
+    /// Per-tool latency samples for percentile stats
+    pub tool_latencies: HashMap<String, LatencyHistogram>,
 
     // v2.0 Per-agent file tracking (Phase 7)
     /// Files modified by this agent (tracked from Edit/Write tool_input)
@@ -280,14 +402,26 @@ pub struct Agent {
     pub context_remaining_percent: Option<f64>,
     /// Total tokens in context
     pub context_total_tokens: Option<u64>,
+    /// Context growth summed across snapshots, priced for the cost estimate
+    pub tokens_accrued: u64,
     /// Agent type from --agent flag (explicit, overrides inferred role)
     pub explicit_agent_type: Option<String>,
     /// Permission mode (plan, acceptEdits, etc.)
     pub permission_mode: Option<String>,
     /// Working directory (from cwd field, may differ from project)
     pub cwd: Option<String>,
+    /// Git branch checked out in `cwd` (None if detached or not a repo)
+    pub git_branch: Option<String>,
+    /// When `git_branch` was last refreshed
+    pub git_branch_checked_at: i64,
+    /// Commits (ahead, behind) the branch's upstream (None without one)
+    pub upstream: Option<(u32, u32)>,
+    /// When `upstream` was last queued for refresh
+    pub upstream_checked_at: i64,
     /// Transcript path for linking to conversation
     pub transcript_path: Option<String>,
+    /// Local socket the agent reports on (only with several `--socket` paths)
+    pub socket: Option<String>,
 
     // Claude Code Tasks API integration (v2.2)
     /// Last task tool used (TaskCreate, TaskUpdate, TaskList, TaskGet)
@@ -308,6 +442,8 @@ pub struct Agent {
     pub team_agent_name: Option<String>,
     /// Agent type from CLAUDE_CODE_AGENT_TYPE env var (e.g., "planner", "worker")
     pub team_agent_type: Option<String>,
+    /// Which source set `team_name` (None for restored or team-less agents)
+    pub team_source: Option<TeamSource>,
 
     // Claude Code version tracking
     /// Claude Code version from CLAUDE_CODE_VERSION env var
@@ -322,7 +458,7 @@ pub struct Agent {
 
     // Task dependency tracking (v2.1.x)
     /// Tasks tracked by this agent (keyed by task ID)
-    pub tasks: std::collections::HashMap<String, TaskInfo>,
+    pub tasks: HashMap<String, TaskInfo>,
 
     // Tool failure tracking (v2.1.x)
     /// True if the last tool call failed (PostToolUseFailure)
@@ -345,12 +481,16 @@ pub struct Agent {
     pub session_source: Option<String>,
     /// Whether stop hook is active (Claude continues after Stop)
     pub stop_hook_active: bool,
+    /// How the last turn ended
+    pub last_turn_end: Option<TurnEnd>,
 
     // Compaction tracking (v0.9.16)
     /// Number of context compactions this session
     pub compaction_count: u32,
     /// What triggered the last compaction: "manual" or "auto"
     pub last_compact_trigger: Option<String>,
+    /// When the in-progress compaction started (cleared on the next non-compacting event)
+    pub compaction_started_at: Option<i64>,
 
     /// Effort level from CLAUDE_CODE_EFFORT_LEVEL env var
     pub effort_level: Option<String>,
@@ -366,6 +506,26 @@ pub struct Agent {
     pub failed_command_count: u32,
     /// Count of successful tool completions this session
     pub successful_tool_count: u32,
+
+    // AskUserQuestion tracking (cleared once the agent leaves Attention(Input))
+    /// Question text from the pending AskUserQuestion
+    pub pending_question: Option<String>,
+    /// Option labels for the pending question, in display order
+    pub pending_options: Vec<String>,
+    /// What the pending permission prompt asks to run, e.g. "Bash: rm -rf build"
+    /// (cleared once the agent leaves Attention(Permission))
+    pub pending_permission: Option<String>,
+    /// Tool the pending permission prompt is for, e.g. "Write"
+    pub pending_permission_tool: Option<String>,
+    /// The user's most recent prompt, one line, clipped (`[ui] capture_prompts`)
+    pub last_prompt: Option<String>,
+    /// Free-text annotation from the user (`e`); only the user changes it
+    pub note: Option<String>,
+
+    /// Hook events per bucket, oldest first (newest bucket is last)
+    pub activity: [u64; ACTIVITY_BUCKETS],
+    /// Bucket number (`timestamp / ACTIVITY_BUCKET_SECS`) of the newest bucket
+    pub activity_bucket: i64,
 }
 
 impl Agent {
@@ -374,6 +534,7 @@ impl Agent {
             pane_id,
             project,
             status: Status::Attention(AttentionType::Waiting),
+            created_at: 0,
             start_time: 0,
             last_update: 0,
             last_event: String::new(),
@@ -381,29 +542,45 @@ impl Agent {
             session_id: None,
             current_tool: None,
             pending_tool_start: None,
+            current_tool_since: None,
+            stuck: false,
+            gone_since: None,
             pending_tool_use_id: None,
             last_latency_ms: None,
             avg_latency_ms: None,
             total_tool_calls: 0,
             in_response: false,
+            turn_started: None,
+            turn_start_tool_calls: 0,
+            turns_completed: 0,
+            turn_durations: VecDeque::new(),
             // v0.9.0 Subagent tracking
             subagents: Vec::new(),
+            pending_subagent_parent: None,
             // v0.10.0 Sprite tracking
             is_sprite: false,
             sprite_id: None,
             // v1.2 Agent role classification
             role: AgentRole::General,
             tool_history: VecDeque::with_capacity(10),
+            recent_tool_calls: VecDeque::with_capacity(MAX_RECENT_TOOL_CALLS),
+            tool_latencies: HashMap::new(),
             // v2.0 Per-agent file tracking
             modified_files: HashSet::new(),
             // Claude Code 2.1.x integration fields
             context_usage_percent: None,
             context_remaining_percent: None,
             context_total_tokens: None,
+            tokens_accrued: 0,
             explicit_agent_type: None,
             permission_mode: None,
             cwd: None,
+            git_branch: None,
+            git_branch_checked_at: 0,
+            upstream: None,
+            upstream_checked_at: 0,
             transcript_path: None,
+            socket: None,
             // Claude Code Tasks API integration (v2.2)
             last_task_tool: None,
             current_task_subject: None,
@@ -414,6 +591,7 @@ impl Agent {
             team_agent_id: None,
             team_agent_name: None,
             team_agent_type: None,
+            team_source: None,
             // Claude Code version
             claude_code_version: None,
             // Claude model
@@ -421,7 +599,7 @@ impl Agent {
             // Background task tracking
             has_background_tasks: false,
             // Task dependency tracking
-            tasks: std::collections::HashMap::new(),
+            tasks: HashMap::new(),
             // Tool failure tracking
             last_tool_failed: false,
             failed_tool_name: None,
@@ -433,9 +611,11 @@ impl Agent {
             // Session metadata (v0.9.15)
             session_source: None,
             stop_hook_active: false,
+            last_turn_end: None,
             // Compaction tracking (v0.9.16)
             compaction_count: 0,
             last_compact_trigger: None,
+            compaction_started_at: None,
             // MCP tool tracking (v0.9.16)
             mcp_tool_count: 0,
             // Effort level
@@ -444,6 +624,16 @@ impl Agent {
             last_exit_code: None,
             failed_command_count: 0,
             successful_tool_count: 0,
+            // AskUserQuestion tracking
+            pending_question: None,
+            pending_options: Vec::new(),
+            pending_permission: None,
+            pending_permission_tool: None,
+            last_prompt: None,
+            note: None,
+            // Activity sparkline
+            activity: [0; ACTIVITY_BUCKETS],
+            activity_bucket: 0,
         }
     }
 
@@ -467,19 +657,95 @@ impl Agent {
         now - self.start_time
     }
 
-    /// Format elapsed time for display
-    pub fn elapsed_display(&self) -> String {
-        let secs = self.elapsed_secs();
-        if secs == 0 {
-            return "--".to_string();
+    /// Permission mode worth a badge: anything but `default`
+    pub fn notable_permission_mode(&self) -> Option<&str> {
+        self.permission_mode
+            .as_deref()
+            .filter(|mode| !mode.is_empty() && *mode != "default")
+    }
+
+    /// Running with every permission prompt skipped
+    pub fn bypasses_permissions(&self) -> bool {
+        self.permission_mode.as_deref() == Some(BYPASS_PERMISSIONS)
+    }
+
+    /// Needs attention right after a tool was interrupted
+    ///
+    /// Outranks every other attention type; cleared by the next PreToolUse.
+    pub fn failed_attention(&self) -> bool {
+        matches!(self.status, Status::Attention(_))
+            && self.last_tool_failed
+            && self.failed_tool_interrupt
+    }
+
+    /// Seconds since the agent first appeared (0 if unknown)
+    pub fn lifetime_secs(&self, now: i64) -> i64 {
+        if self.created_at == 0 {
+            return 0;
         }
-        if secs < 60 {
-            format!("{secs}s")
-        } else if secs < 3600 {
-            format!("{}m {:02}s", secs / 60, secs % 60)
-        } else {
-            format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+        (now - self.created_at).max(0)
+    }
+
+    /// Seconds since the agent's last hook event
+    pub fn idle_secs(&self, now: i64) -> i64 {
+        (now - self.last_update).max(0)
+    }
+
+    /// Remaining context percentage, derived from usage on older Claude Code
+    ///
+    /// None when the agent never reported context (sprites, older Claude Code).
+    pub fn context_remaining(&self) -> Option<f64> {
+        self.context_remaining_percent
+            .or_else(|| self.context_usage_percent.map(|used| 100.0 - used))
+    }
+
+    /// Progress of an in-flight compaction, e.g. "~12s remaining"
+    ///
+    /// Uses `typical_secs` (the historical average) when known, otherwise
+    /// falls back to elapsed time.
+    pub fn compaction_progress(&self, typical_secs: Option<u64>) -> Option<String> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        self.compaction_progress_at(now, typical_secs)
+    }
+
+    pub(super) fn compaction_progress_at(
+        &self,
+        now: i64,
+        typical_secs: Option<u64>,
+    ) -> Option<String> {
+        let started = self.compaction_started_at?;
+        let elapsed = u64::try_from(now - started).unwrap_or(0);
+        Some(match typical_secs {
+            Some(typical) if typical > elapsed => format!("~{}s remaining", typical - elapsed),
+            Some(_) => format!("{elapsed}s (longer than usual)"),
+            None => format!("{elapsed}s elapsed"),
+        })
+    }
+
+    /// Shift the activity window so its newest bucket covers `now`
+    pub fn roll_activity(&mut self, now: i64) {
+        let bucket = now / ACTIVITY_BUCKET_SECS;
+        let shift = bucket - self.activity_bucket;
+        if shift <= 0 {
+            return;
         }
+        match usize::try_from(shift) {
+            Ok(n) if n < ACTIVITY_BUCKETS => {
+                self.activity.rotate_left(n);
+                self.activity[ACTIVITY_BUCKETS - n..].fill(0);
+            }
+            _ => self.activity = [0; ACTIVITY_BUCKETS],
+        }
+        self.activity_bucket = bucket;
+    }
+
+    /// Count one hook event in the activity sparkline
+    pub fn record_activity(&mut self, now: i64) {
+        self.roll_activity(now);
+        self.activity[ACTIVITY_BUCKETS - 1] += 1;
     }
 
     /// Record PreToolUse event for latency tracking
@@ -487,7 +753,13 @@ impl Agent {
     /// Called when a tool starts executing. Stores the tool name, timestamp,
     /// and tool_use_id for later correlation with PostToolUse.
     pub fn start_tool(&mut self, tool: &str, tool_use_id: Option<&str>, timestamp: i64) {
+        // Previous call never got a PostToolUse - count it as incomplete
+        if self.pending_tool_start.is_some() {
+            self.record_incomplete_tool();
+        }
         self.current_tool = Some(tool.to_string());
+        self.current_tool_since = Some(timestamp);
+        self.stuck = false;
         self.pending_tool_start = Some(timestamp);
         self.pending_tool_use_id = tool_use_id.map(String::from);
     }
@@ -500,7 +772,9 @@ impl Agent {
     /// # Latency Calculation
     /// - `last_latency_ms`: Time for this specific tool call
     /// - `avg_latency_ms`: Running average across all tool calls this session
-    pub fn end_tool(&mut self, tool_use_id: Option<&str>, timestamp: i64) {
+    ///
+    /// Returns the completed tool and its latency when one was measured.
+    pub fn end_tool(&mut self, tool_use_id: Option<&str>, timestamp: i64) -> Option<(String, u64)> {
         // Verify tool_use_id matches (if both are present)
         if let (Some(pending), Some(incoming)) = (&self.pending_tool_use_id, tool_use_id) {
             if pending != incoming {
@@ -513,10 +787,11 @@ impl Agent {
                     "Tool use ID mismatch - skipping latency, cleaning up pending state"
                 );
                 // Clean up to avoid stale tool state
-                self.current_tool = None;
+                self.record_incomplete_tool();
+                self.clear_current_tool();
                 self.pending_tool_start = None;
                 self.pending_tool_use_id = None;
-                return;
+                return None;
             }
         }
         // Log if we have no pending ID but got one, or vice versa (helpful for debugging)
@@ -529,8 +804,10 @@ impl Agent {
         }
 
         // Calculate latency if we have a start time
+        let mut latency_ms = None;
         if let Some(start) = self.pending_tool_start {
             let latency = ((timestamp - start) * 1000) as u64;
+            latency_ms = Some(latency);
             self.last_latency_ms = Some(latency);
 
             // Update running average
@@ -542,10 +819,109 @@ impl Agent {
             );
         }
 
+        // Retain completed call for the detail view
+        let mut completed = None;
+        if let Some(tool) = self.current_tool.take() {
+            if let Some(latency) = latency_ms {
+                completed = Some((tool.clone(), latency));
+                self.tool_latencies
+                    .entry(tool.clone())
+                    .or_default()
+                    .record(latency);
+            }
+            if self.recent_tool_calls.len() >= MAX_RECENT_TOOL_CALLS {
+                self.recent_tool_calls.pop_front();
+            }
+            self.recent_tool_calls.push_back(ToolCall {
+                name: tool,
+                latency_ms,
+                completed_at: timestamp,
+                failed: self.last_tool_failed,
+            });
+        }
+
         // Clear pending tool state
-        self.current_tool = None;
+        self.clear_current_tool();
         self.pending_tool_start = None;
         self.pending_tool_use_id = None;
+        completed
+    }
+
+    /// Start a turn (UserPromptSubmit)
+    pub fn begin_turn(&mut self, timestamp: i64) {
+        self.turn_started = Some(timestamp);
+        self.turn_start_tool_calls = self.total_tool_calls;
+    }
+
+    /// Finish the turn in progress, if any, ended by hook `reason`
+    pub fn end_turn(&mut self, timestamp: i64, reason: &str) -> Option<TurnRecord> {
+        let started = self.turn_started.take()?;
+        self.turns_completed += 1;
+        let duration_secs = (timestamp - started).max(0);
+        if self.turn_durations.len() >= TURN_HISTORY {
+            self.turn_durations.pop_front();
+        }
+        self.turn_durations.push_back(duration_secs);
+        Some(TurnRecord {
+            iteration: self.turns_completed,
+            duration_secs,
+            tool_calls: self
+                .total_tool_calls
+                .saturating_sub(self.turn_start_tool_calls),
+            completion_reason: reason.to_string(),
+            timestamp,
+            pane_id: self.pane_id.clone(),
+            project: self.project.clone(),
+        })
+    }
+
+    /// Whether recent turns are speeding up or bogging down
+    ///
+    /// None until [`MIN_TREND_TURNS`] turns have completed.
+    pub fn turn_trend(&self) -> Option<TurnTrend> {
+        let n = self.turn_durations.len();
+        if n < MIN_TREND_TURNS {
+            return None;
+        }
+        // Equal halves; the middle turn of an odd count sits out
+        let half = n / 2;
+        let mean = |skip: usize| {
+            let total: i64 = self.turn_durations.iter().skip(skip).take(half).sum();
+            total as f64 / half as f64
+        };
+        let (older, newer) = (mean(0), mean(n - half));
+        Some(if newer > older * 1.25 {
+            TurnTrend::Slower
+        } else if newer < older * 0.8 {
+            TurnTrend::Faster
+        } else {
+            TurnTrend::Steady
+        })
+    }
+
+    /// Forget the running tool, along with its stuck flag
+    fn clear_current_tool(&mut self) {
+        self.current_tool = None;
+        self.current_tool_since = None;
+        self.stuck = false;
+    }
+
+    /// Seconds the current tool has been running, if one is
+    pub fn tool_running_secs(&self, now: i64) -> Option<i64> {
+        self.current_tool
+            .as_ref()
+            .and(self.current_tool_since)
+            .map(|since| now - since)
+    }
+
+    /// Count the pending tool call as incomplete (no PostToolUse received)
+    fn record_incomplete_tool(&mut self) {
+        if let Some(tool) = &self.current_tool {
+            self.tool_latencies
+                .entry(tool.clone())
+                .or_default()
+                .incomplete += 1;
+        }
     }
 
     /// Get display string for tool/latency column
@@ -688,6 +1064,11 @@ impl Agent {
         AgentRole::General
     }
 
+    /// True while the agent is kept on the board after its hooks stopped
+    pub fn is_gone(&self) -> bool {
+        self.gone_since.is_some()
+    }
+
     /// Get task progress as (completed, total)
     ///
     /// Returns (0, 0) if no tasks are tracked.
@@ -702,6 +1083,76 @@ impl Agent {
     }
 }
 
+/// One row of the running-subagent tree
+#[derive(Debug, Clone)]
+pub struct SubagentRow<'a> {
+    pub subagent: &'a Subagent,
+    /// Box-drawing guides for this row (`├─ `, `│  └─ `, ...)
+    pub connector: String,
+    /// Spawned by a subagent that has already finished
+    pub orphan: bool,
+}
+
+impl Agent {
+    /// Running subagents in tree order, children under their spawner
+    ///
+    /// A subagent whose spawner already stopped is drawn at the root and
+    /// flagged as an orphan, so it is never hidden with its finished parent.
+    /// Siblings keep start order.
+    pub fn running_subagent_tree(&self) -> Vec<SubagentRow<'_>> {
+        let running: Vec<&Subagent> = self
+            .subagents
+            .iter()
+            .filter(|s| s.status == "running")
+            .collect();
+        let is_running = |id: &str| running.iter().any(|s| s.id == id);
+        let roots: Vec<(&Subagent, bool)> = running
+            .iter()
+            .filter_map(|s| match &s.parent_subagent_id {
+                None => Some((*s, false)),
+                Some(parent) if !is_running(parent) => Some((*s, true)),
+                Some(_) => None,
+            })
+            .collect();
+
+        let mut rows = Vec::new();
+        // (subagent, orphan, guides inherited from ancestors, is last sibling)
+        let mut stack: Vec<(&Subagent, bool, String, bool)> = roots
+            .iter()
+            .enumerate()
+            .rev()
+            .map(|(i, (sub, orphan))| (*sub, *orphan, String::new(), i == roots.len() - 1))
+            .collect();
+        // Bounded by the running count, so a malformed parent loop cannot spin
+        while let Some((sub, orphan, guides, last)) = stack.pop() {
+            if rows.len() >= running.len() {
+                break;
+            }
+            // └─ closes the sibling list, ├─ continues it
+            let branch = if last {
+                "\u{2514}\u{2500} "
+            } else {
+                "\u{251c}\u{2500} "
+            };
+            rows.push(SubagentRow {
+                subagent: sub,
+                connector: format!("{guides}{branch}"),
+                orphan,
+            });
+            let child_guides = format!("{guides}{}", if last { "   " } else { "\u{2502}  " }); // │
+            let children: Vec<&Subagent> = running
+                .iter()
+                .filter(|c| c.parent_subagent_id.as_deref() == Some(sub.id.as_str()))
+                .copied()
+                .collect();
+            for (i, child) in children.iter().enumerate().rev() {
+                stack.push((child, false, child_guides.clone(), i == children.len() - 1));
+            }
+        }
+        rows
+    }
+}
+
 /// Format MCP tool name for display: `mcp__server__tool` → `MCP:server:tool`
 /// Returns None if the tool is not an MCP tool.
 pub fn format_mcp_tool(tool: &str) -> Option<String> {
@@ -727,7 +1178,7 @@ fn truncate_tool_name(tool: &str) -> String {
 }
 
 /// Format latency for display
-fn format_latency(ms: u64) -> String {
+pub fn format_latency(ms: u64) -> String {
     if ms < 1000 {
         format!("{ms}ms")
     } else {
@@ -739,6 +1190,50 @@ fn format_latency(ms: u64) -> String {
 mod tests {
     use super::*;
 
+    fn subagent(id: &str, parent: Option<&str>, status: &str) -> Subagent {
+        Subagent {
+            id: id.to_string(),
+            description: format!("sub {id}"),
+            status: status.to_string(),
+            duration_ms: None,
+            parent_pane_id: "%0".to_string(),
+            parent_subagent_id: parent.map(str::to_string),
+            depth: 0,
+            role: AgentRole::General,
+            subagent_type: None,
+            transcript_path: None,
+        }
+    }
+
+    #[test]
+    fn test_running_subagent_tree_nests_and_roots_orphans() {
+        let mut agent = Agent::new("%0".to_string(), "test".to_string());
+        agent.subagents = vec![
+            subagent("a", None, "running"),
+            subagent("done", None, "completed"),
+            subagent("b", Some("a"), "running"),
+            subagent("c", Some("b"), "running"),
+            subagent("d", Some("a"), "running"),
+            subagent("e", Some("done"), "running"),
+        ];
+
+        let rows: Vec<(String, &str, bool)> = agent
+            .running_subagent_tree()
+            .into_iter()
+            .map(|r| (r.connector, r.subagent.id.as_str(), r.orphan))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("├─ ".to_string(), "a", false),
+                ("│  ├─ ".to_string(), "b", false),
+                ("│  │  └─ ".to_string(), "c", false),
+                ("│  └─ ".to_string(), "d", false),
+                ("└─ ".to_string(), "e", true),
+            ]
+        );
+    }
+
     #[test]
     fn test_role_classification_general_default() {
         let agent = Agent::new("%0".to_string(), "test".to_string());
@@ -825,6 +1320,29 @@ mod tests {
         assert!(display.starts_with("MCP:github")); // Preserves prefix
     }
 
+    #[test]
+    fn test_end_tool_records_recent_calls() {
+        let mut agent = Agent::new("%0".to_string(), "test".to_string());
+        agent.start_tool("Bash", Some("t1"), 100);
+        agent.end_tool(Some("t1"), 103);
+        agent.start_tool("Read", Some("t2"), 103);
+        agent.last_tool_failed = true;
+        agent.end_tool(Some("t2"), 103);
+
+        assert_eq!(agent.recent_tool_calls.len(), 2);
+        assert_eq!(agent.recent_tool_calls[0].name, "Bash");
+        assert_eq!(agent.recent_tool_calls[0].latency_ms, Some(3000));
+        assert!(agent.recent_tool_calls[1].failed);
+
+        // Bounded to MAX_RECENT_TOOL_CALLS
+        for i in 0..MAX_RECENT_TOOL_CALLS {
+            agent.start_tool("Grep", None, i as i64);
+            agent.end_tool(None, i as i64);
+        }
+        assert_eq!(agent.recent_tool_calls.len(), MAX_RECENT_TOOL_CALLS);
+        assert_eq!(agent.recent_tool_calls[0].name, "Grep");
+    }
+
     #[test]
     fn test_tool_display_mcp() {
         let mut agent = Agent::new("%0".to_string(), "test".to_string());
@@ -836,4 +1354,35 @@ mod tests {
             display
         );
     }
+
+    #[test]
+    fn test_turn_durations_bounded_and_trend() {
+        let mut agent = Agent::new("%0".to_string(), "loop".to_string());
+        let mut now = 1000;
+        let mut turn = |agent: &mut Agent, secs: i64| {
+            agent.begin_turn(now);
+            now += secs;
+            agent.end_turn(now, "Stop");
+        };
+        for secs in [60, 60, 60] {
+            turn(&mut agent, secs);
+        }
+        assert_eq!(agent.turn_trend(), None, "too few turns");
+        turn(&mut agent, 65);
+        assert_eq!(agent.turn_trend(), Some(TurnTrend::Steady));
+
+        for _ in 0..TURN_HISTORY {
+            turn(&mut agent, 300);
+        }
+        assert_eq!(agent.turn_durations.len(), TURN_HISTORY);
+        assert_eq!(agent.turn_trend(), Some(TurnTrend::Steady));
+        for secs in [120, 100, 90, 80, 70, 60] {
+            turn(&mut agent, secs);
+        }
+        assert_eq!(agent.turn_trend(), Some(TurnTrend::Faster));
+        for secs in [400, 500, 600, 700, 800, 900] {
+            turn(&mut agent, secs);
+        }
+        assert_eq!(agent.turn_trend(), Some(TurnTrend::Slower));
+    }
 }
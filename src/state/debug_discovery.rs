@@ -83,7 +83,7 @@ impl DebugDiscovery {
         }
 
         // Sort by modification time descending (newest first)
-        entries.sort_by(|a, b| b.modified.cmp(&a.modified));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.modified));
 
         Ok(entries)
     }
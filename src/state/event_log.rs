@@ -0,0 +1,119 @@
+//! Event log filtering for the full-screen log view (`V`)
+//!
+//! A query is whitespace-separated terms, all of which must match. A term
+//! with a field prefix narrows that field by substring, ignoring case:
+//! `pane:%3`, `event:Stop`, `source:sprite` (the source label from the
+//! connections panel: `local`, `local: <socket>`, `sprite: <id>`). A bare
+//! term is a fuzzy match, as in agent search, against the pane, hook name,
+//! project, source and tool.
+
+use super::{fuzzy_match, AppState, LoggedEvent, SourceKey};
+
+/// One term of an event log query
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Pane(String),
+    Event(String),
+    Source(String),
+    Fuzzy(String),
+}
+
+impl Term {
+    fn parse(term: &str) -> Self {
+        let field = |prefix: &str| {
+            term.strip_prefix(prefix)
+                .filter(|value| !value.is_empty())
+                .map(str::to_lowercase)
+        };
+        if let Some(value) = field("pane:") {
+            Self::Pane(value)
+        } else if let Some(value) = field("event:") {
+            Self::Event(value)
+        } else if let Some(value) = field("source:") {
+            Self::Source(value)
+        } else {
+            Self::Fuzzy(term.to_string())
+        }
+    }
+
+    fn matches(&self, logged: &LoggedEvent) -> bool {
+        let event = &logged.event;
+        let contains = |field: &str, value: &str| field.to_lowercase().contains(value);
+        match self {
+            Self::Pane(value) => contains(&event.pane_id, value),
+            Self::Event(value) => contains(&event.event, value),
+            Self::Source(value) => contains(&SourceKey::of(event).label(), value),
+            Self::Fuzzy(query) => [
+                event.pane_id.as_str(),
+                event.event.as_str(),
+                event.project.as_str(),
+                &SourceKey::of(event).label(),
+                event.tool_name.as_deref().unwrap_or_default(),
+            ]
+            .into_iter()
+            .any(|field| fuzzy_match(query, field).is_some()),
+        }
+    }
+}
+
+impl AppState {
+    /// Logged events matching `query`, newest first
+    pub fn filter_event_log(&self, query: &str) -> Vec<&LoggedEvent> {
+        let terms: Vec<Term> = query.split_whitespace().map(Term::parse).collect();
+        self.events
+            .iter()
+            .filter(|logged| terms.iter().all(|term| term.matches(logged)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{EventSource, HookEvent};
+
+    #[test]
+    fn test_event_log_filters_by_field_and_fuzzy() {
+        let mut state = AppState::new();
+        state.event_log_size = 10;
+        for (name, pane, sprite) in [
+            ("SessionStart", "%1", None),
+            ("PreToolUse", "%1", None),
+            ("Stop", "%12", None),
+            ("Stop", "sp_web", Some("web")),
+        ] {
+            let mut event = HookEvent::internal(name, "working", None, pane, "auth", 1);
+            if let Some(sprite_id) = sprite {
+                event.source = EventSource::Sprite {
+                    sprite_id: sprite_id.to_string(),
+                };
+            }
+            state.log_event(event, None);
+        }
+        let names = |state: &AppState, query: &str| -> Vec<String> {
+            state
+                .filter_event_log(query)
+                .iter()
+                .map(|l| format!("{} {}", l.event.pane_id, l.event.event))
+                .collect()
+        };
+
+        assert_eq!(names(&state, "").len(), 4);
+        assert_eq!(
+            names(&state, "pane:%1"),
+            ["%12 Stop", "%1 PreToolUse", "%1 SessionStart"]
+        );
+        assert_eq!(names(&state, "event:stop source:local"), ["%12 Stop"]);
+        assert_eq!(names(&state, "source:SPRITE"), ["sp_web Stop"]);
+        assert_eq!(names(&state, "pretool"), ["%1 PreToolUse"]);
+        assert!(names(&state, "event:stop pane:%9").is_empty());
+
+        // The cap drops the oldest events first
+        state.event_log_size = 2;
+        state.log_event(
+            HookEvent::internal("Stop", "idle", None, "%2", "auth", 2),
+            None,
+        );
+        assert_eq!(names(&state, ""), ["%2 Stop", "sp_web Stop"]);
+    }
+}
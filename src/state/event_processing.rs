@@ -3,14 +3,85 @@
 //! This module contains the core `process_event()` implementation and helper functions
 //! for handling Claude Code hook events.
 
-use super::{status_to_column, Agent, AgentRole, AppState, AttentionType, Status};
-use crate::config::MAX_EVENTS;
+use super::{
+    status_to_column, Agent, AgentRole, AppState, AttentionType, LatencyBuckets, Status,
+    TeamSource, TurnEnd, MAX_COMPACTION_SAMPLES,
+};
 use crate::event::{EventSource, HookEvent};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::collections::BTreeMap;
 
 /// Tools that require user input and don't fire PostToolUse until the user responds.
 /// Used to detect when an agent is waiting for user input on Stop events.
-const USER_INPUT_TOOLS: &[&str] = &["AskUserQuestion"];
+pub const USER_INPUT_TOOLS: &[&str] = &["AskUserQuestion"];
+
+/// A status change and what caused it
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTransition {
+    /// Status before the event (None for a newly registered agent)
+    pub from: Option<Status>,
+    pub to: Status,
+    /// Tool, notification type, hook name, or "idle timeout"
+    pub reason: String,
+}
+
+impl StatusTransition {
+    /// Event log text, e.g. "Working→Attention(Input) [AskUserQuestion]"
+    pub fn describe(&self) -> String {
+        let from = self
+            .from
+            .as_ref()
+            .map_or_else(|| "New".to_string(), |s| format!("{s:?}"));
+        format!("{from}→{:?} [{}]", self.to, self.reason)
+    }
+}
+
+/// What `process_event` did with an event
+#[derive(Debug, Clone, PartialEq)]
+pub enum EventOutcome {
+    /// The event names an agent that isn't tracked (e.g. a stray heartbeat)
+    AgentMissing,
+    /// The agent exists but nothing visible changed
+    NoChange,
+    /// State was updated; carries the status transition if there was one
+    Updated(Option<StatusTransition>),
+}
+
+impl EventOutcome {
+    /// True if the event requires a re-render
+    pub fn changed(&self) -> bool {
+        matches!(self, EventOutcome::Updated(_))
+    }
+}
+
+/// Event log entry: a hook event and the status transition it caused
+#[derive(Debug, Clone)]
+pub struct LoggedEvent {
+    pub event: HookEvent,
+    pub transition: Option<StatusTransition>,
+}
+
+/// Why an event moved an agent to its current status
+///
+/// Prefers the most specific cause: notification type, the tool the agent is
+/// blocked on, the event's tool, then the hook name.
+fn transition_reason(event: &HookEvent, agent: &Agent) -> String {
+    if event.event == "Notification" {
+        if let Some(ref ntype) = event.notification_type {
+            return ntype.clone();
+        }
+    }
+    if event.event == "Stop" {
+        if let Some(ref tool) = agent.current_tool {
+            if USER_INPUT_TOOLS.contains(&tool.as_str()) {
+                return tool.clone();
+            }
+        }
+    }
+    event
+        .tool_name
+        .clone()
+        .unwrap_or_else(|| event.event.clone())
+}
 
 /// v1.3: Infer agent role from subagent description keywords
 ///
@@ -158,6 +229,114 @@ fn extract_owner(input: &Option<serde_json::Value>) -> Option<String> {
     input.as_ref()?.get("owner")?.as_str().map(String::from)
 }
 
+/// Extract the first question and its option labels from AskUserQuestion tool_input
+/// Example: {"questions": [{"question": "Which DB?", "options": [{"label": "Postgres"}]}]}
+fn extract_question(input: &Option<serde_json::Value>) -> Option<(String, Vec<String>)> {
+    let first = input.as_ref()?.get("questions")?.as_array()?.first()?;
+    let question = first.get("question")?.as_str()?.to_string();
+    let options = first
+        .get("options")
+        .and_then(|v| v.as_array())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|o| o.get("label").and_then(|l| l.as_str()).map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    Some((question, options))
+}
+
+/// Notification type announcing a completed sign-in
+const AUTH_SUCCESS: &str = "auth_success";
+
+/// A sign-in notice: shown briefly, never an attention state
+pub fn is_auth_success(event: &HookEvent) -> bool {
+    event.event == "Notification" && event.notification_type.as_deref() == Some(AUTH_SUCCESS)
+}
+
+/// Longest permission prompt kept on the card
+const MAX_PROMPT_CHARS: usize = 200;
+
+/// tool_input fields that say what a permission prompt is about, most telling first
+const PROMPT_FIELDS: [&str; 6] = [
+    "command",
+    "file_path",
+    "notebook_path",
+    "url",
+    "pattern",
+    "query",
+];
+
+/// Describe what a permission prompt is asking to run
+///
+/// Uses the field a human would look at first (`command`, `file_path`, ...);
+/// falls back to the bare tool name. Escape sequences and control characters
+/// are stripped so a crafted command can't repaint the TUI.
+fn describe_permission(tool: &str, input: &Option<serde_json::Value>) -> String {
+    let detail = input.as_ref().and_then(|input| {
+        PROMPT_FIELDS
+            .iter()
+            .find_map(|key| input.get(key)?.as_str())
+    });
+    let text = match detail {
+        Some(detail) => format!("{tool}: {detail}"),
+        None => tool.to_string(),
+    };
+    let clean = strip_ansi(&text);
+    if clean.chars().count() > MAX_PROMPT_CHARS {
+        let mut clipped: String = clean.chars().take(MAX_PROMPT_CHARS - 1).collect();
+        clipped.push('\u{2026}'); // …
+        clipped
+    } else {
+        clean
+    }
+}
+
+/// Longest user prompt kept on the agent
+const MAX_LAST_PROMPT_CHARS: usize = 300;
+
+/// One-line, clipped copy of a user prompt for the card (None when blank)
+///
+/// Newlines and escape sequences collapse to single spaces like in
+/// [`describe_permission`], so a pasted log can't repaint the TUI.
+fn summarize_prompt(prompt: &str) -> Option<String> {
+    let clean = strip_ansi(prompt);
+    let line = clean.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.is_empty() {
+        return None;
+    }
+    Some(if line.chars().count() > MAX_LAST_PROMPT_CHARS {
+        let mut clipped: String = line.chars().take(MAX_LAST_PROMPT_CHARS - 1).collect();
+        clipped.push('\u{2026}'); // …
+        clipped
+    } else {
+        line
+    })
+}
+
+/// Drop ANSI escape sequences and turn other control characters into spaces
+fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // CSI: ESC [ params final-byte; other escapes are ESC + one char
+            if chars.next_if_eq(&'[').is_some() {
+                while chars.next().is_some_and(|c| !('@'..='~').contains(&c)) {}
+            } else {
+                chars.next();
+            }
+        } else if c.is_control() {
+            if !out.ends_with(' ') {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out.trim().to_string()
+}
+
 /// Get human-readable name for column index
 fn column_name(col: usize) -> &'static str {
     match col {
@@ -168,11 +347,29 @@ fn column_name(col: usize) -> &'static str {
     }
 }
 
-fn current_timestamp() -> i64 {
-    SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|d| d.as_secs() as i64)
-        .unwrap_or(0)
+/// Status transition from `from` to the agent's current status, if it changed
+fn transition_for(
+    from: Option<Status>,
+    event: &HookEvent,
+    agent: &Agent,
+) -> Option<StatusTransition> {
+    (from.as_ref() != Some(&agent.status)).then(|| StatusTransition {
+        from,
+        to: agent.status.clone(),
+        reason: transition_reason(event, agent),
+    })
+}
+
+/// End the agent's pending tool call and add its latency to the metrics totals
+fn end_tool_observed(
+    agent: &mut Agent,
+    totals: &mut BTreeMap<String, LatencyBuckets>,
+    tool_use_id: Option<&str>,
+    timestamp: i64,
+) {
+    if let Some((tool, latency)) = agent.end_tool(tool_use_id, timestamp) {
+        totals.entry(tool).or_default().observe(latency);
+    }
 }
 
 impl AppState {
@@ -184,13 +381,32 @@ impl AppState {
     /// - Activity sparklines
     /// - Session lifecycle (start/end)
     /// - Status count caching (v1.1 optimization)
-    /// - Agent limit with LRU eviction (v1.1 optimization)
+    /// - Agent limit with `[limits] eviction` policy (v1.1 optimization)
     /// - Sprite agent tracking (v0.10.0)
     ///
     /// # Returns
-    /// Returns `true` if the event caused a state change that requires re-render.
+    /// An [`EventOutcome`]; `changed()` tells whether a re-render is needed and
+    /// any status transition is also recorded in the event log.
     #[must_use = "check if state changed to trigger re-render"]
-    pub fn process_event(&mut self, event: HookEvent) -> bool {
+    pub fn process_event(&mut self, mut event: HookEvent) -> EventOutcome {
+        let now = self.now();
+        self.record_source(&event);
+        self.merge_session_fallback(&mut event);
+
+        // Any sign of life brings a gone agent back before counts are touched
+        self.revive_if_gone(&event.pane_id);
+
+        // Heartbeat (see `crate::heartbeat`): liveness only, no status change or log entry
+        if event.event == "Heartbeat" {
+            return match self.agents.get_mut(&event.pane_id) {
+                Some(agent) => {
+                    agent.last_update = now;
+                    EventOutcome::NoChange
+                }
+                None => EventOutcome::AgentMissing,
+            };
+        }
+
         // Phantom agent creation: TeammateIdle/TaskCompleted carry teammate info
         // that should create/update a phantom agent entry instead of updating the leader
         if matches!(event.event.as_str(), "TeammateIdle" | "TaskCompleted") {
@@ -199,23 +415,26 @@ impl AppState {
             {
                 let phantom_id = format!("team:{team_name}:{teammate_name}");
                 let project = event.project.clone();
-                let now = current_timestamp();
 
-                // Map session to team if we have a session_id
+                // Map session to team if we have a session_id, and tell the
+                // session's own agent right away rather than on the next scan
                 if let Some(ref sid) = event.session_id {
                     self.map_session_to_team(sid.clone(), team_name.clone());
+                    self.apply_session_teams();
                 }
 
                 // Create or update phantom agent
                 let is_new = !self.agents.contains_key(&phantom_id);
-                if is_new && self.agents.len() >= crate::config::MAX_AGENTS {
-                    self.evict_oldest_waiting();
+                if is_new && self.agents.len() >= self.max_agents && !self.make_room() {
+                    return EventOutcome::Updated(None);
                 }
 
+                self.revive_if_gone(&phantom_id);
                 let old_col = self
                     .agents
                     .get(&phantom_id)
                     .map(|a| status_to_column(&a.status));
+                let old_status = self.agents.get(&phantom_id).map(|a| a.status.clone());
 
                 let agent = self
                     .agents
@@ -224,6 +443,7 @@ impl AppState {
 
                 agent.project = project;
                 agent.team_name = Some(team_name.clone());
+                agent.team_source = Some(TeamSource::Hook);
                 agent.team_agent_name = Some(teammate_name.clone());
                 agent.last_event = event.event.clone();
                 agent.last_update = now;
@@ -263,13 +483,13 @@ impl AppState {
                     "Phantom agent created/updated from teammate event"
                 );
 
-                // Add to event log
-                self.events.push_front(event);
-                if self.events.len() > MAX_EVENTS {
-                    self.events.pop_back();
-                }
+                let transition = self
+                    .agents
+                    .get(&phantom_id)
+                    .and_then(|agent| transition_for(old_status, &event, agent));
+                self.log_event(event, transition.clone());
 
-                return true;
+                return EventOutcome::Updated(transition);
             }
         }
 
@@ -294,9 +514,9 @@ impl AppState {
             "Processing hook event"
         );
 
-        // Evict oldest waiting agent if at capacity and adding new agent
-        if is_new_agent && self.agents.len() >= crate::config::MAX_AGENTS {
-            self.evict_oldest_waiting();
+        // At capacity, a new agent needs room (or is turned away under `never`)
+        if is_new_agent && self.agents.len() >= self.max_agents && !self.make_room() {
+            return EventOutcome::Updated(None);
         }
 
         // Session-ID to team correlation: look up team BEFORE borrowing agents mutably
@@ -310,6 +530,8 @@ impl AppState {
             .agents
             .get(&pane_id)
             .map(|a| status_to_column(&a.status));
+        let old_status = self.agents.get(&pane_id).map(|a| a.status.clone());
+        let capture_prompts = self.capture_prompts;
 
         // Update or create agent (sprite-aware)
         let agent = self.agents.entry(pane_id.clone()).or_insert_with(|| {
@@ -372,11 +594,17 @@ impl AppState {
                     "permission_prompt" => {
                         agent.status = Status::Attention(AttentionType::Permission);
                     }
+                    // An MCP server asking the user for input is a question too
                     "idle_prompt" | "elicitation_dialog" => {
                         agent.status = Status::Attention(AttentionType::Input);
                     }
+                    // Informational only (the App shows it in the footer):
+                    // the agent keeps whatever it was doing
+                    AUTH_SUCCESS => {
+                        agent.status = old_status.clone().unwrap_or(Status::Working);
+                    }
                     _ => {
-                        // auth_success and others remain as Notification
+                        // Other types remain as Notification
                     }
                 }
             }
@@ -398,7 +626,8 @@ impl AppState {
         }
 
         agent.last_event = event.event.clone();
-        agent.last_update = current_timestamp();
+        agent.last_update = now;
+        agent.record_activity(agent.last_update);
 
         // Get new status column
         let new_status_col = status_to_column(&agent.status);
@@ -433,6 +662,30 @@ impl AppState {
             );
         }
 
+        // Desktop notification throttling: a new attention type or a fresh
+        // prompt resets cooldowns, then the hook's notification is offered
+        if event.event == "UserPromptSubmit" {
+            self.notifications.clear_pane(&pane_id);
+        }
+        if let Status::Attention(attn) = &agent.status {
+            if old_status.as_ref() != Some(&agent.status) {
+                self.notifications.attention_changed(&pane_id, attn);
+            }
+            if let Some(note) = event
+                .desktop_notification
+                .clone()
+                .filter(|_| !is_auth_success(&event))
+            {
+                self.notifications.offer(
+                    &pane_id,
+                    &agent.project,
+                    attn.clone(),
+                    note,
+                    std::time::Instant::now(),
+                );
+            }
+        }
+
         // Update session_id if present (v1.0)
         if let Some(sid) = &event.session_id {
             agent.session_id = Some(sid.clone());
@@ -447,6 +700,8 @@ impl AppState {
                 agent.context_remaining_percent = Some(remaining);
             }
             if let Some(tokens) = ctx.total_tokens {
+                agent.tokens_accrued +=
+                    super::pricing::accrued_delta(agent.context_total_tokens, tokens);
                 agent.context_total_tokens = Some(tokens);
             }
         }
@@ -466,6 +721,10 @@ impl AppState {
             agent.cwd = Some(cwd.clone());
         }
 
+        if let Some(ref socket) = event.socket {
+            agent.socket = Some(socket.clone());
+        }
+
         // Claude Code 2.1.x: Update transcript path
         if let Some(ref transcript) = event.transcript_path {
             agent.transcript_path = Some(transcript.clone());
@@ -474,6 +733,7 @@ impl AppState {
         // TeammateTool env vars (v3.0) - display-only monitoring
         if let Some(ref name) = event.team_name {
             agent.team_name = Some(name.clone());
+            agent.team_source = Some(TeamSource::Hook);
         }
         if let Some(ref id) = event.team_agent_id {
             agent.team_agent_id = Some(id.clone());
@@ -486,9 +746,10 @@ impl AppState {
         }
 
         // Session-ID to team correlation: enrich agent from pre-fetched lookup
-        if agent.team_name.is_none() {
-            if let Some(ref team) = session_team {
+        if agent.team_source != Some(TeamSource::Hook) {
+            if let Some(team) = session_team.filter(|t| agent.team_name.as_ref() != Some(t)) {
                 agent.team_name = Some(team.clone());
+                agent.team_source = Some(TeamSource::Session);
                 tracing::debug!(
                     pane_id = %pane_id,
                     team = %team,
@@ -534,6 +795,7 @@ impl AppState {
         if event.event == "PreCompact" {
             agent.compaction_count += 1;
             agent.last_compact_trigger = event.trigger.clone();
+            agent.compaction_started_at = Some(now);
             tracing::info!(
                 pane_id = %pane_id,
                 count = agent.compaction_count,
@@ -542,6 +804,42 @@ impl AppState {
             );
         }
 
+        // Question answered (or abandoned): drop it from the card
+        if agent.status != Status::Attention(AttentionType::Input) {
+            agent.pending_question = None;
+            agent.pending_options.clear();
+        }
+
+        // Permission prompt: remember what it asks for, from the PermissionRequest
+        // payload or (for a bare permission_prompt notification) the running tool
+        if agent.status == Status::Attention(AttentionType::Permission) {
+            if let Some(ref tool) = event.tool_name {
+                agent.pending_permission = Some(describe_permission(tool, &event.tool_input));
+                agent.pending_permission_tool = Some(tool.clone());
+            } else if agent.pending_permission.is_none() {
+                agent.pending_permission = agent
+                    .current_tool
+                    .as_deref()
+                    .map(|tool| describe_permission(tool, &None));
+                agent.pending_permission_tool = agent.current_tool.clone();
+            }
+        } else {
+            agent.pending_permission = None;
+            agent.pending_permission_tool = None;
+        }
+
+        // Compaction finished: feed its duration into the rolling estimate
+        if agent.status != Status::Compacting {
+            if let Some(started) = agent.compaction_started_at.take() {
+                let secs = u64::try_from(now - started).unwrap_or(0);
+                if self.compaction_durations.len() >= MAX_COMPACTION_SAMPLES {
+                    self.compaction_durations.pop_front();
+                }
+                self.compaction_durations.push_back(secs);
+                tracing::debug!(pane_id = %pane_id, secs, "Compaction finished");
+            }
+        }
+
         // Track tool latency (v1.0) and role classification (v1.2)
         match event.event.as_str() {
             "PreToolUse" => {
@@ -572,6 +870,12 @@ impl AppState {
                         }
                     }
 
+                    // Remember who launched the next subagent; hooks fired inside a
+                    // subagent carry its id, so nested spawns can be linked up
+                    if matches!(tool.as_str(), "Task" | "Agent") {
+                        agent.pending_subagent_parent = Some(event.subagent_id.clone());
+                    }
+
                     // v2.2: Track Claude Code Tasks API usage
                     if is_task_tool(tool) {
                         agent.last_task_tool = Some(tool.clone());
@@ -681,6 +985,7 @@ impl AppState {
                             if let Some(team_name) = extract_team_name_from_tool(&event.tool_input)
                             {
                                 agent.team_name = Some(team_name);
+                                agent.team_source = Some(TeamSource::Hook);
                             }
                         }
                         "SendMessage" => {
@@ -718,6 +1023,10 @@ impl AppState {
                     // (PostToolUse won't fire until user responds)
                     if USER_INPUT_TOOLS.contains(&tool.as_str()) {
                         agent.status = Status::Attention(AttentionType::Input);
+                        if let Some((question, options)) = extract_question(&event.tool_input) {
+                            agent.pending_question = Some(question);
+                            agent.pending_options = options;
+                        }
                         tracing::info!(
                             pane_id = %pane_id,
                             tool = %tool,
@@ -758,7 +1067,12 @@ impl AppState {
                     agent.successful_tool_count += 1;
                 }
 
-                agent.end_tool(event.tool_use_id.as_deref(), event.timestamp);
+                end_tool_observed(
+                    agent,
+                    &mut self.tool_latency_totals,
+                    event.tool_use_id.as_deref(),
+                    event.timestamp,
+                );
                 if let Some(latency) = agent.last_latency_ms {
                     tracing::info!(
                         pane_id = %pane_id,
@@ -776,7 +1090,12 @@ impl AppState {
                 agent.failed_tool_name = tool_name.clone();
                 agent.failed_tool_error = event.error.clone();
                 agent.failed_tool_interrupt = event.is_interrupt.unwrap_or(false);
-                agent.end_tool(event.tool_use_id.as_deref(), event.timestamp);
+                end_tool_observed(
+                    agent,
+                    &mut self.tool_latency_totals,
+                    event.tool_use_id.as_deref(),
+                    event.timestamp,
+                );
                 tracing::warn!(
                     pane_id = %pane_id,
                     tool = ?tool_name,
@@ -799,6 +1118,16 @@ impl AppState {
                     // v0.9.17: Capture subagent type from event (e.g., "Bash", "Explore", "Plan")
                     let subagent_type = event.agent_type.clone();
 
+                    let parent_subagent_id = agent
+                        .pending_subagent_parent
+                        .take()
+                        .flatten()
+                        .filter(|parent| parent != subagent_id);
+                    let depth = parent_subagent_id
+                        .as_ref()
+                        .and_then(|parent| agent.subagents.iter().find(|s| &s.id == parent))
+                        .map_or(0, |parent| parent.depth.saturating_add(1));
+
                     agent.subagents.push(super::Subagent {
                         id: subagent_id.clone(),
                         description: description.clone(),
@@ -806,7 +1135,8 @@ impl AppState {
                         duration_ms: None,
                         // v1.3: Parent-child tracking
                         parent_pane_id: pane_id.clone(),
-                        depth: 0, // Direct child of this agent
+                        parent_subagent_id: parent_subagent_id.clone(),
+                        depth,
                         role,
                         subagent_type: subagent_type.clone(),
                         transcript_path: None,
@@ -817,6 +1147,8 @@ impl AppState {
                         description = %description,
                         role = ?role,
                         subagent_type = ?subagent_type,
+                        parent = ?parent_subagent_id,
+                        depth,
                         "Subagent started"
                     );
                 }
@@ -848,15 +1180,40 @@ impl AppState {
         match event.event.as_str() {
             "UserPromptSubmit" => {
                 agent.in_response = true;
+                agent.begin_turn(event.timestamp);
+                if capture_prompts {
+                    if let Some(prompt) = event.prompt.as_deref().and_then(summarize_prompt) {
+                        agent.last_prompt = Some(prompt);
+                    }
+                }
                 tracing::debug!(pane_id = %pane_id, "Response started");
             }
             "Stop" | "SessionEnd" => {
                 agent.in_response = false;
+                agent.last_turn_end = Some(if agent.failed_tool_interrupt {
+                    TurnEnd::Interrupted
+                } else {
+                    TurnEnd::Completed
+                });
+                if let Some(turn) = agent.end_turn(event.timestamp, &event.event) {
+                    tracing::info!(
+                        pane_id = %pane_id,
+                        iteration = turn.iteration,
+                        duration_secs = turn.duration_secs,
+                        tool_calls = turn.tool_calls,
+                        "Turn completed"
+                    );
+                    self.completed_turns.push(turn);
+                }
                 tracing::debug!(pane_id = %pane_id, "Response ended");
             }
             _ => {}
         }
 
+        if agent.created_at == 0 {
+            agent.created_at = event.timestamp;
+        }
+
         // Set start_time on first event or session start
         if agent.start_time == 0 || event.event == "SessionStart" {
             agent.start_time = event.timestamp;
@@ -876,6 +1233,11 @@ impl AppState {
             }
         }
 
+        let transition = self
+            .agents
+            .get(&pane_id)
+            .and_then(|agent| transition_for(old_status, &event, agent));
+
         // Handle session end - remove agent
         if event.event == "SessionEnd" {
             // Decrement count before removal
@@ -886,19 +1248,25 @@ impl AppState {
             // Clean up sprite tracking
             self.sprite_agent_ids.remove(&pane_id);
             self.agents.remove(&pane_id);
+            self.notifications.clear_pane(&pane_id);
             // Clear stale selection
             if self.selected_pane_id.as_deref() == Some(&pane_id) {
                 self.selected_pane_id = None;
             }
         }
 
-        // Add to event log
-        self.events.push_front(event);
-        if self.events.len() > MAX_EVENTS {
-            self.events.pop_back();
-        }
+        self.log_event(event, transition.clone());
 
-        true // State was modified
+        EventOutcome::Updated(transition)
+    }
+
+    /// Append to the event log, dropping the oldest entries past `event_log_size`
+    pub(super) fn log_event(&mut self, event: HookEvent, transition: Option<StatusTransition>) {
+        if let (Some(delta), Some(transition)) = (self.freeze_delta.as_mut(), transition.as_ref()) {
+            delta.record(&event.pane_id, transition);
+        }
+        self.events.push_front(LoggedEvent { event, transition });
+        self.events.truncate(self.event_log_size);
     }
 }
 
@@ -906,6 +1274,21 @@ impl AppState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_describe_permission_strips_escapes_and_caps() {
+        let input = Some(serde_json::json!({"command": "rm -rf build", "timeout": 5}));
+        assert_eq!(describe_permission("Bash", &input), "Bash: rm -rf build");
+        assert_eq!(describe_permission("Bash", &None), "Bash");
+
+        let input = Some(serde_json::json!({"command": "echo \u{1b}[2J\u{1b}[31mhi\u{1b}[0m\nls"}));
+        assert_eq!(describe_permission("Bash", &input), "Bash: echo hi ls");
+
+        let input = Some(serde_json::json!({"file_path": "x".repeat(500)}));
+        let text = describe_permission("Write", &input);
+        assert_eq!(text.chars().count(), MAX_PROMPT_CHARS);
+        assert!(text.ends_with('\u{2026}'));
+    }
+
     #[test]
     fn test_extract_team_name_from_tool() {
         let input = Some(
@@ -134,24 +134,24 @@ impl FacetDiscovery {
 
         // Sort all by count desc
         let mut helpfulness: Vec<(String, u32)> = helpfulness_counts.into_iter().collect();
-        helpfulness.sort_by(|a, b| b.1.cmp(&a.1));
+        helpfulness.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
 
         let mut top_categories: Vec<(String, u32)> = category_counts.into_iter().collect();
-        top_categories.sort_by(|a, b| b.1.cmp(&a.1));
+        top_categories.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
         top_categories.truncate(10);
 
         let mut satisfaction: Vec<(String, u32)> = satisfaction_counts.into_iter().collect();
-        satisfaction.sort_by(|a, b| b.1.cmp(&a.1));
+        satisfaction.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
 
         let mut friction: Vec<(String, u32)> = friction_counts.into_iter().collect();
-        friction.sort_by(|a, b| b.1.cmp(&a.1));
+        friction.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
         friction.truncate(10);
 
         let mut session_types: Vec<(String, u32)> = session_type_counts.into_iter().collect();
-        session_types.sort_by(|a, b| b.1.cmp(&a.1));
+        session_types.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
 
         let mut success_patterns: Vec<(String, u32)> = success_counts.into_iter().collect();
-        success_patterns.sort_by(|a, b| b.1.cmp(&a.1));
+        success_patterns.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
 
         Ok(SessionQuality {
             total_sessions,
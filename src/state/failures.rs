@@ -0,0 +1,160 @@
+//! Recent tool failures across agents, grouped by error (`E` to view)
+//!
+//! Each agent keeps only its last failure, and a new tool call clears it, so
+//! the groups are the failures still unresolved right now: an agent drops out
+//! of its group as soon as it recovers. Messages are normalised before
+//! grouping (numbers, paths and hex ids masked) so "rate limited, retry in
+//! 12s" and "... in 30s" count as the same error.
+
+use super::Agent;
+use std::collections::HashMap;
+
+/// Longest normalised signature kept (the rest rarely helps grouping)
+const MAX_SIGNATURE_CHARS: usize = 120;
+
+/// Agents failing with the same (normalised) error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureGroup {
+    /// Normalised message shared by the group
+    pub signature: String,
+    /// Raw message of the first affected agent (by pane), for display
+    pub example: String,
+    /// Failing tools, deduplicated
+    pub tools: Vec<String>,
+    /// Affected agents as (pane_id, project), sorted by pane
+    pub agents: Vec<(String, String)>,
+}
+
+/// Mask the parts of an error that vary between occurrences
+///
+/// Paths become `<path>`, runs of digits `#` and long hex ids `<id>`;
+/// whitespace is collapsed and case folded.
+pub fn normalize_error(message: &str) -> String {
+    let words: Vec<String> = message
+        .split_whitespace()
+        .map(|word| {
+            let core = word.trim_matches(|c: char| "\"'`()[]{},;:".contains(c));
+            if core.contains('/') || core.contains('\\') {
+                "<path>".to_string()
+            } else if core.len() >= 8 && core.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+                "<id>".to_string()
+            } else {
+                mask_digits(&word.to_lowercase())
+            }
+        })
+        .collect();
+    words.join(" ").chars().take(MAX_SIGNATURE_CHARS).collect()
+}
+
+fn mask_digits(word: &str) -> String {
+    let mut out = String::with_capacity(word.len());
+    for c in word.chars() {
+        if c.is_ascii_digit() {
+            if !out.ends_with('#') {
+                out.push('#');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Group agents' current failures, largest group first
+///
+/// User interrupts are not errors and are left out.
+pub fn group_failures<'a>(agents: impl Iterator<Item = &'a Agent>) -> Vec<FailureGroup> {
+    let mut groups: HashMap<String, FailureGroup> = HashMap::new();
+    for agent in agents {
+        if !agent.last_tool_failed || agent.failed_tool_interrupt {
+            continue;
+        }
+        let Some(error) = agent.failed_tool_error.as_deref() else {
+            continue;
+        };
+        let signature = normalize_error(error);
+        let group = groups
+            .entry(signature.clone())
+            .or_insert_with(|| FailureGroup {
+                signature,
+                example: error.to_string(),
+                tools: Vec::new(),
+                agents: Vec::new(),
+            });
+        if group.agents.iter().all(|(pane, _)| agent.pane_id < *pane) {
+            group.example = error.to_string();
+        }
+        if let Some(tool) = &agent.failed_tool_name {
+            if !group.tools.contains(tool) {
+                group.tools.push(tool.clone());
+            }
+        }
+        group
+            .agents
+            .push((agent.pane_id.clone(), agent.project.clone()));
+    }
+    let mut groups: Vec<FailureGroup> = groups.into_values().collect();
+    for group in &mut groups {
+        group.agents.sort();
+        group.tools.sort();
+    }
+    groups.sort_by(|a, b| {
+        b.agents
+            .len()
+            .cmp(&a.agents.len())
+            .then_with(|| a.signature.cmp(&b.signature))
+    });
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failing(pane: &str, tool: &str, error: &str) -> Agent {
+        let mut agent = Agent::new(pane.to_string(), "proj".to_string());
+        agent.last_tool_failed = true;
+        agent.failed_tool_name = Some(tool.to_string());
+        agent.failed_tool_error = Some(error.to_string());
+        agent
+    }
+
+    #[test]
+    fn test_normalize_masks_volatile_parts() {
+        assert_eq!(
+            normalize_error("Rate limited: retry in 12s (request 3f9a2c41-77be)"),
+            normalize_error("rate limited: retry in 30s (request 0b1c2d3e-4f50)")
+        );
+        assert_eq!(
+            normalize_error("ENOENT: no such file '/tmp/a/b.rs'"),
+            "enoent: no such file <path>"
+        );
+        assert_ne!(
+            normalize_error("auth failed"),
+            normalize_error("rate limited")
+        );
+    }
+
+    #[test]
+    fn test_groups_by_signature_and_skips_recovered_and_interrupts() {
+        let mut interrupted = failing("%4", "Bash", "Interrupted by user");
+        interrupted.failed_tool_interrupt = true;
+        let mut recovered = failing("%5", "Bash", "rate limited, retry in 9s");
+        recovered.last_tool_failed = false;
+        let agents = [
+            failing("%2", "WebFetch", "rate limited, retry in 12s"),
+            failing("%1", "Bash", "Rate limited, retry in 30s"),
+            failing("%3", "Edit", "File /src/main.rs not read yet"),
+            interrupted,
+            recovered,
+        ];
+
+        let groups = group_failures(agents.iter());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].example, "Rate limited, retry in 30s");
+        assert_eq!(groups[0].tools, vec!["Bash", "WebFetch"]);
+        let panes: Vec<&str> = groups[0].agents.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(panes, vec!["%1", "%2"]);
+        assert_eq!(groups[1].agents.len(), 1);
+    }
+}
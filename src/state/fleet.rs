@@ -0,0 +1,91 @@
+//! Fleet composition: agents and completed turns by model and effort level
+//!
+//! Pure aggregation over the tracked agents for the stats dashboard. Agents
+//! that never reported a model or effort level are grouped as "unknown".
+
+use super::{Agent, AppState};
+use std::collections::HashMap;
+
+/// Group label for agents without a model or effort level
+pub const UNKNOWN: &str = "unknown";
+
+/// One model or effort level across the fleet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FleetRow {
+    pub label: String,
+    /// Agents currently tracked in this group
+    pub agents: usize,
+    /// Turns (prompt → Stop) those agents have completed
+    pub turns: u32,
+}
+
+/// Fleet breakdown for the dashboard
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FleetBreakdown {
+    pub by_model: Vec<FleetRow>,
+    pub by_effort: Vec<FleetRow>,
+    /// Completed turns across all agents (denominator for turn shares)
+    pub total_turns: u32,
+}
+
+/// Group agents by `key`, most agents first (ties by label)
+fn group_by(agents: &[&Agent], key: impl Fn(&Agent) -> Option<&str>) -> Vec<FleetRow> {
+    let mut groups: HashMap<&str, (usize, u32)> = HashMap::new();
+    for agent in agents {
+        let entry = groups.entry(key(agent).unwrap_or(UNKNOWN)).or_default();
+        entry.0 += 1;
+        entry.1 += agent.turns_completed;
+    }
+    let mut rows: Vec<FleetRow> = groups
+        .into_iter()
+        .map(|(label, (agents, turns))| FleetRow {
+            label: label.to_string(),
+            agents,
+            turns,
+        })
+        .collect();
+    rows.sort_by(|a, b| b.agents.cmp(&a.agents).then_with(|| a.label.cmp(&b.label)));
+    rows
+}
+
+impl AppState {
+    /// Agent and turn counts by model and by effort level
+    pub fn fleet_breakdown(&self) -> FleetBreakdown {
+        let agents: Vec<&Agent> = self.agents.values().collect();
+        FleetBreakdown {
+            by_model: group_by(&agents, |a| a.model.as_deref()),
+            by_effort: group_by(&agents, |a| a.effort_level.as_deref()),
+            total_turns: agents.iter().map(|a| a.turns_completed).sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fleet_groups_by_model_and_effort() {
+        let mut state = AppState::new();
+        for (pane, model, effort, turns) in [
+            ("%1", Some("opus"), Some("high"), 6),
+            ("%2", Some("opus"), None, 2),
+            ("%3", None, Some("high"), 2),
+        ] {
+            let mut agent = Agent::new(pane.to_string(), "p".to_string());
+            agent.model = model.map(String::from);
+            agent.effort_level = effort.map(String::from);
+            agent.turns_completed = turns;
+            state.agents.insert(pane.to_string(), agent);
+        }
+
+        let fleet = state.fleet_breakdown();
+        assert_eq!(fleet.total_turns, 10);
+        let model = |label: &str| fleet.by_model.iter().find(|r| r.label == label).cloned();
+        assert_eq!(fleet.by_model[0].label, "opus", "largest group first");
+        assert_eq!(model("opus").map(|r| (r.agents, r.turns)), Some((2, 8)));
+        assert_eq!(model(UNKNOWN).map(|r| (r.agents, r.turns)), Some((1, 2)));
+        assert_eq!(fleet.by_effort[0].label, "high");
+        assert_eq!(fleet.by_effort[0].agents, 2);
+    }
+}
@@ -0,0 +1,118 @@
+//! What changed while the display was frozen (`f`), summarised on unfreeze
+//!
+//! State keeps updating under a freeze, so the board is current once live
+//! again, but the transitions that got it there went by unseen. While frozen,
+//! every logged transition (idle timeouts included) is folded into per-kind
+//! sets of panes; agents that ended are found by comparing against the panes
+//! present when the freeze began.
+
+use super::{Agent, AttentionType, Status, StatusTransition};
+use std::collections::{BTreeSet, HashMap};
+
+/// Agents grouped by what happened to them while frozen
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FreezeDelta {
+    /// Panes on the board when the freeze began
+    before: BTreeSet<String>,
+    /// Entered permission, input or notification attention
+    attention: BTreeSet<String>,
+    /// Finished a turn (went to Waiting)
+    finished: BTreeSet<String>,
+    /// Registered while frozen
+    joined: BTreeSet<String>,
+}
+
+impl FreezeDelta {
+    pub fn new<'a>(panes: impl IntoIterator<Item = &'a String>) -> Self {
+        Self {
+            before: panes.into_iter().cloned().collect(),
+            ..Self::default()
+        }
+    }
+
+    pub fn record(&mut self, pane_id: &str, transition: &StatusTransition) {
+        if transition.from.is_none() {
+            self.joined.insert(pane_id.to_string());
+        }
+        match transition.to {
+            Status::Attention(AttentionType::Waiting) => {
+                self.finished.insert(pane_id.to_string());
+            }
+            Status::Attention(_) => {
+                self.attention.insert(pane_id.to_string());
+            }
+            Status::Working | Status::Compacting => {}
+        }
+    }
+
+    /// "while frozen: 3 agents went to attention, 1 finished, 1 ended"
+    pub fn summary(&self, agents: &HashMap<String, Agent>) -> String {
+        let ended = self
+            .before
+            .iter()
+            .filter(|pane| agents.get(*pane).is_none_or(Agent::is_gone))
+            .count();
+        let agents_word = |n: usize| if n == 1 { "agent" } else { "agents" };
+        let mut parts = Vec::new();
+        if !self.attention.is_empty() {
+            let n = self.attention.len();
+            parts.push(format!("{n} {} went to attention", agents_word(n)));
+        }
+        for (count, what) in [
+            (self.finished.len(), "finished"),
+            (self.joined.len(), "joined"),
+            (ended, "ended"),
+        ] {
+            if count > 0 {
+                parts.push(format!("{count} {what}"));
+            }
+        }
+        if parts.is_empty() {
+            "while frozen: nothing changed".to_string()
+        } else {
+            format!("while frozen: {}", parts.join(", "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transition(from: Option<Status>, to: Status) -> StatusTransition {
+        StatusTransition {
+            from,
+            to,
+            reason: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_freeze_delta_summarises_distinct_agents() {
+        let mut agents: HashMap<String, Agent> = ["%1", "%2", "%3"]
+            .into_iter()
+            .map(|pane| {
+                (
+                    pane.to_string(),
+                    Agent::new(pane.to_string(), "p".to_string()),
+                )
+            })
+            .collect();
+        let mut delta = FreezeDelta::new(agents.keys());
+        assert_eq!(delta.summary(&agents), "while frozen: nothing changed");
+
+        let permission = Status::Attention(AttentionType::Permission);
+        let waiting = Status::Attention(AttentionType::Waiting);
+        delta.record("%1", &transition(Some(Status::Working), permission.clone()));
+        delta.record("%1", &transition(Some(Status::Working), permission.clone()));
+        delta.record("%2", &transition(Some(Status::Working), permission));
+        delta.record("%2", &transition(Some(Status::Working), waiting));
+        delta.record("%4", &transition(None, Status::Working));
+        agents.remove("%3");
+
+        assert_eq!(
+            delta.summary(&agents),
+            "while frozen: 2 agents went to attention, 1 finished, 1 joined, 1 ended"
+        );
+    }
+}
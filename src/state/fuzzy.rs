@@ -0,0 +1,171 @@
+//! Fuzzy agent search: subsequence matching with a small scoring model
+//!
+//! A query matches a field when its characters appear in order, ignoring
+//! case ("authtest" matches "auth-test-worker"). Scoring favours matches
+//! that start words and run consecutively, and penalises skipped
+//! characters, so the tightest field wins when several match. Whitespace
+//! in the query is ignored.
+
+use super::Agent;
+
+const MATCH: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 12;
+const WORD_START_BONUS: i32 = 10;
+const GAP_PENALTY: i32 = 1;
+
+/// Which agent field a hit was found in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    Project,
+    PaneId,
+    Team,
+    AgentName,
+    Model,
+    Socket,
+}
+
+/// Best match of a query against an agent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchHit {
+    pub score: i32,
+    pub field: SearchField,
+    /// Char indices of the matched characters within that field
+    pub positions: Vec<usize>,
+}
+
+fn is_word_start(chars: &[char], i: usize) -> bool {
+    i == 0
+        || !chars[i - 1].is_alphanumeric()
+        || (chars[i].is_uppercase() && chars[i - 1].is_lowercase())
+}
+
+/// Score `query` against `candidate`, or None when it is not a subsequence
+///
+/// Greedy: each query char takes the first word start or consecutive
+/// position after the previous match that still leaves room for the rest of
+/// the query, else the first occurrence. Not optimal, but cheap enough to run
+/// on every keystroke and good at preferring "auth-test" over "a-u-t-h".
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let chars: Vec<char> = candidate.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut positions = Vec::with_capacity(query.len());
+    let mut score = 0;
+    let mut next = 0;
+    for (qi, &qc) in query.iter().enumerate() {
+        let remaining = &query[qi + 1..];
+        let mut chosen = None;
+        for i in next..lower.len() {
+            if lower[i] != qc {
+                continue;
+            }
+            // The rest of the query must still fit after this position
+            if !is_subsequence(remaining, &lower[i + 1..]) {
+                break;
+            }
+            let consecutive = positions.last().is_some_and(|&p: &usize| p + 1 == i);
+            if chosen.is_none() {
+                chosen = Some(i);
+            }
+            if consecutive || is_word_start(&chars, i) {
+                chosen = Some(i);
+                break;
+            }
+        }
+        let i = chosen?;
+        score += MATCH;
+        if positions.last().is_some_and(|&p| p + 1 == i) {
+            score += CONSECUTIVE_BONUS;
+        } else if let Some(&p) = positions.last() {
+            score -= GAP_PENALTY * (i - p - 1) as i32;
+        }
+        if is_word_start(&chars, i) {
+            score += WORD_START_BONUS;
+        }
+        positions.push(i);
+        next = i + 1;
+    }
+    // Prefer shorter fields for equal matches
+    score -= (chars.len() - positions.len()) as i32 / 4;
+    Some((score, positions))
+}
+
+fn is_subsequence(needle: &[char], haystack: &[char]) -> bool {
+    let mut hay = haystack.iter();
+    needle.iter().all(|c| hay.any(|h| h == c))
+}
+
+/// Best-scoring field of `agent` for `query`
+pub fn search_agent(query: &str, agent: &Agent) -> Option<SearchHit> {
+    let fields = [
+        (SearchField::Project, Some(agent.project.as_str())),
+        (SearchField::PaneId, Some(agent.pane_id.as_str())),
+        (SearchField::Team, agent.team_name.as_deref()),
+        (SearchField::AgentName, agent.team_agent_name.as_deref()),
+        (SearchField::Model, agent.model.as_deref()),
+        (SearchField::Socket, agent.socket.as_deref()),
+    ];
+    fields
+        .into_iter()
+        .filter_map(|(field, value)| {
+            let (score, positions) = fuzzy_match(query, value?)?;
+            Some(SearchHit {
+                score,
+                field,
+                positions,
+            })
+        })
+        .max_by_key(|hit| hit.score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subsequence_matches_rank_tight_and_word_start_first() {
+        let (_, positions) = fuzzy_match("authtest", "auth-test-worker").unwrap();
+        assert_eq!(positions, vec![0, 1, 2, 3, 5, 6, 7, 8]);
+        assert!(fuzzy_match("AuthTest", "auth-test-worker").is_some());
+        assert!(fuzzy_match("tsetx", "auth-test-worker").is_none());
+        assert_eq!(fuzzy_match("", "anything"), Some((0, Vec::new())));
+
+        // Word starts are taken over earlier mid-word occurrences
+        let (_, positions) = fuzzy_match("tw", "auth-test-worker").unwrap();
+        assert_eq!(positions, vec![5, 10]);
+
+        let score = |q, c| fuzzy_match(q, c).unwrap().0;
+        assert!(score("api", "api-server") > score("api", "a-pretty-idea"));
+        assert!(score("api", "api") > score("api", "api-server-with-long-name"));
+    }
+
+    #[test]
+    fn test_search_agent_picks_best_field() {
+        let mut agent = Agent::new("%12".to_string(), "billing".to_string());
+        agent.model = Some("claude-opus-4-6".to_string());
+        agent.team_name = Some("auth-squad".to_string());
+
+        let hit = search_agent("opus", &agent).unwrap();
+        assert_eq!(hit.field, SearchField::Model);
+        assert_eq!(
+            search_agent("asq", &agent).unwrap().field,
+            SearchField::Team
+        );
+        assert_eq!(
+            search_agent("%12", &agent).unwrap().field,
+            SearchField::PaneId
+        );
+        assert!(search_agent("zzz", &agent).is_none());
+    }
+}
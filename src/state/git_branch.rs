@@ -0,0 +1,195 @@
+//! Git branch detection for agent cards
+//!
+//! Reads `HEAD` directly instead of shelling out to `git`, so refreshing every
+//! agent from `tick()` costs a couple of small file reads. Worktrees (where
+//! `.git` is a `gitdir:` file) are followed. Detached HEADs and directories
+//! outside a repository yield no branch.
+//!
+//! Ahead/behind counts against the upstream do need `git rev-list`, so they
+//! are refreshed less often and never on the UI thread: `tick()` collects
+//! the due directories with [`AppState::upstream_refresh_due`], the App runs
+//! [`upstream_counts_for`] on a blocking thread, and the results come back
+//! as `Event::GitUpstream`.
+
+use super::{current_timestamp, AppState};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Seconds before an agent's branch is re-read
+const BRANCH_REFRESH_SECS: i64 = 30;
+
+/// Seconds before an agent's ahead/behind counts are recomputed
+const UPSTREAM_REFRESH_SECS: i64 = 60;
+
+/// Locate the git dir for `start`, walking up to the repository root
+fn find_git_dir(start: &Path) -> Option<PathBuf> {
+    for dir in start.ancestors() {
+        let dot_git = dir.join(".git");
+        if dot_git.is_dir() {
+            return Some(dot_git);
+        }
+        if dot_git.is_file() {
+            // Worktree or submodule: ".git" contains "gitdir: <path>"
+            let content = std::fs::read_to_string(&dot_git).ok()?;
+            let target = content.strip_prefix("gitdir:")?.trim();
+            return Some(dir.join(target));
+        }
+    }
+    None
+}
+
+/// Current branch for a working directory (None if detached or not a repo)
+pub fn detect_branch(cwd: &Path) -> Option<String> {
+    let head = std::fs::read_to_string(find_git_dir(cwd)?.join("HEAD")).ok()?;
+    head.trim()
+        .strip_prefix("ref: refs/heads/")
+        .map(String::from)
+}
+
+/// Commits `HEAD` is (ahead, behind) its upstream in `cwd`
+///
+/// None when the branch has no upstream, `cwd` is not a repository, or git
+/// is missing.
+pub fn upstream_counts(cwd: &Path) -> Option<(u32, u32)> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(cwd)
+        .args(["rev-list", "--left-right", "--count", "@{u}...HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_left_right(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `rev-list --left-right --count @{u}...HEAD` ("behind\tahead")
+fn parse_left_right(output: &str) -> Option<(u32, u32)> {
+    let mut counts = output.split_whitespace().map(str::parse::<u32>);
+    let behind = counts.next()?.ok()?;
+    let ahead = counts.next()?.ok()?;
+    Some((ahead, behind))
+}
+
+/// Ahead/behind counts for each due (pane_id, cwd), running git once per directory
+///
+/// Blocking: call from `spawn_blocking`.
+pub fn upstream_counts_for(due: Vec<(String, String)>) -> Vec<(String, Option<(u32, u32)>)> {
+    let mut by_cwd: std::collections::HashMap<String, Option<(u32, u32)>> =
+        std::collections::HashMap::new();
+    due.into_iter()
+        .map(|(pane_id, cwd)| {
+            let counts = *by_cwd
+                .entry(cwd)
+                .or_insert_with_key(|cwd| upstream_counts(Path::new(cwd)));
+            (pane_id, counts)
+        })
+        .collect()
+}
+
+impl AppState {
+    /// Agents on a branch whose ahead/behind counts are stale, as (pane_id, cwd)
+    ///
+    /// Marks them checked, so a slow git run is not queued twice.
+    pub fn upstream_refresh_due(&mut self) -> Vec<(String, String)> {
+        let now = current_timestamp();
+        let mut due = Vec::new();
+        for (pane_id, agent) in &mut self.agents {
+            let Some(cwd) = agent.cwd.as_ref() else {
+                continue;
+            };
+            if agent.git_branch.is_none() {
+                agent.upstream = None;
+                continue;
+            }
+            if agent.is_gone() || now - agent.upstream_checked_at < UPSTREAM_REFRESH_SECS {
+                continue;
+            }
+            agent.upstream_checked_at = now;
+            due.push((pane_id.clone(), cwd.clone()));
+        }
+        due
+    }
+
+    /// Store ahead/behind counts computed by [`upstream_counts_for`]
+    pub fn apply_upstream(&mut self, counts: Vec<(String, Option<(u32, u32)>)>) {
+        for (pane_id, upstream) in counts {
+            if let Some(agent) = self.agents.get_mut(&pane_id) {
+                agent.upstream = upstream;
+            }
+        }
+    }
+
+    /// Re-read git branches for agents whose last check is stale
+    ///
+    /// Called from `App::tick()`; each agent is checked at most every
+    /// `BRANCH_REFRESH_SECS`.
+    pub fn refresh_git_branches(&mut self) {
+        let now = current_timestamp();
+        for agent in self.agents.values_mut() {
+            if now - agent.git_branch_checked_at < BRANCH_REFRESH_SECS {
+                continue;
+            }
+            agent.git_branch_checked_at = now;
+            agent.git_branch = agent
+                .cwd
+                .as_deref()
+                .and_then(|cwd| detect_branch(Path::new(cwd)));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_branch_variants() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        let nested = repo.join("src");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(&nested).unwrap();
+
+        std::fs::write(repo.join(".git/HEAD"), "ref: refs/heads/feature/x\n").unwrap();
+        assert_eq!(detect_branch(&nested).as_deref(), Some("feature/x"));
+
+        // Detached HEAD shows nothing
+        std::fs::write(repo.join(".git/HEAD"), "3f2a9c1e\n").unwrap();
+        assert_eq!(detect_branch(&repo), None);
+
+        // Worktree: .git file points at the real git dir
+        let worktree = dir.path().join("wt");
+        let wt_git = repo.join(".git/worktrees/wt");
+        std::fs::create_dir_all(&worktree).unwrap();
+        std::fs::create_dir_all(&wt_git).unwrap();
+        std::fs::write(wt_git.join("HEAD"), "ref: refs/heads/wt-branch\n").unwrap();
+        std::fs::write(
+            worktree.join(".git"),
+            format!("gitdir: {}\n", wt_git.display()),
+        )
+        .unwrap();
+        assert_eq!(detect_branch(&worktree).as_deref(), Some("wt-branch"));
+    }
+
+    #[test]
+    fn test_parse_left_right_counts() {
+        assert_eq!(parse_left_right("0\t2\n"), Some((2, 0)));
+        assert_eq!(parse_left_right("3\t1"), Some((1, 3)));
+        assert_eq!(parse_left_right(""), None);
+        assert_eq!(parse_left_right("fatal: no upstream"), None);
+    }
+
+    #[test]
+    fn test_upstream_counts_none_outside_repo() {
+        let dir = tempfile::tempdir().unwrap();
+        let counts = upstream_counts_for(vec![
+            ("%1".to_string(), dir.path().display().to_string()),
+            ("%2".to_string(), dir.path().display().to_string()),
+        ]);
+        assert_eq!(
+            counts,
+            vec![("%1".to_string(), None), ("%2".to_string(), None)]
+        );
+    }
+}
@@ -89,7 +89,7 @@ impl HistoryDiscovery {
             .collect();
 
         // Sort by timestamp descending (newest first)
-        entries.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.timestamp));
 
         // Cap at 500 entries
         entries.truncate(500);
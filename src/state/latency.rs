@@ -0,0 +1,128 @@
+//! Tool latency distribution across agents
+//!
+//! Each agent keeps a bounded sample of Pre→Post durations per tool name
+//! (correlated via `tool_use_id`). The stats dashboard aggregates these into
+//! p50/p95/p99 so slow tools (e.g. WebFetch vs Bash) stand out.
+//!
+//! Calls that never receive a PostToolUse (missing hook, interrupted session)
+//! are counted as "incomplete" instead of producing a bogus duration.
+
+use super::AppState;
+use std::collections::{HashMap, VecDeque};
+
+/// Samples retained per tool per agent (oldest dropped first)
+const MAX_SAMPLES_PER_TOOL: usize = 200;
+
+/// Latency samples for a single tool on a single agent
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    /// Completed call durations in milliseconds
+    pub samples: VecDeque<u64>,
+    /// Calls that started but never completed
+    pub incomplete: u32,
+}
+
+impl LatencyHistogram {
+    /// Record a completed call duration
+    pub fn record(&mut self, latency_ms: u64) {
+        if self.samples.len() >= MAX_SAMPLES_PER_TOOL {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(latency_ms);
+    }
+}
+
+/// Aggregated latency row for one tool across all agents
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatencyRow {
+    pub tool: String,
+    /// Number of completed calls sampled
+    pub count: usize,
+    pub p50_ms: u64,
+    pub p95_ms: u64,
+    pub p99_ms: u64,
+    /// Calls without a matching PostToolUse
+    pub incomplete: u32,
+}
+
+/// Nearest-rank percentile over an ascending-sorted slice
+fn percentile(sorted: &[u64], pct: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+impl AppState {
+    /// Per-tool latency percentiles across all agents, sorted by p95 descending
+    pub fn tool_latency_table(&self) -> Vec<LatencyRow> {
+        let mut merged: HashMap<&str, (Vec<u64>, u32)> = HashMap::new();
+        for agent in self.agents.values() {
+            for (tool, hist) in &agent.tool_latencies {
+                let entry = merged.entry(tool.as_str()).or_default();
+                entry.0.extend(hist.samples.iter().copied());
+                entry.1 += hist.incomplete;
+            }
+        }
+
+        let mut rows: Vec<LatencyRow> = merged
+            .into_iter()
+            .map(|(tool, (mut samples, incomplete))| {
+                samples.sort_unstable();
+                LatencyRow {
+                    tool: tool.to_string(),
+                    count: samples.len(),
+                    p50_ms: percentile(&samples, 50.0),
+                    p95_ms: percentile(&samples, 95.0),
+                    p99_ms: percentile(&samples, 99.0),
+                    incomplete,
+                }
+            })
+            .collect();
+        rows.sort_by(|a, b| b.p95_ms.cmp(&a.p95_ms).then_with(|| a.tool.cmp(&b.tool)));
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Agent;
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let samples: Vec<u64> = (1..=100).collect();
+        assert_eq!(percentile(&samples, 50.0), 50);
+        assert_eq!(percentile(&samples, 95.0), 95);
+        assert_eq!(percentile(&samples, 99.0), 99);
+        assert_eq!(percentile(&[], 50.0), 0);
+        assert_eq!(percentile(&[7], 99.0), 7);
+    }
+
+    #[test]
+    fn test_latency_table_merges_agents_and_sorts_by_p95() {
+        let mut state = AppState::new();
+
+        let mut a = Agent::new("%1".to_string(), "proj".to_string());
+        a.start_tool("Bash", Some("b1"), 100);
+        a.end_tool(Some("b1"), 103);
+        a.start_tool("WebFetch", Some("w1"), 103);
+        a.end_tool(Some("w1"), 115);
+        state.agents.insert("%1".to_string(), a);
+
+        let mut b = Agent::new("%2".to_string(), "proj".to_string());
+        b.start_tool("Bash", Some("b2"), 100);
+        // Next PreToolUse without a PostToolUse → previous call is incomplete
+        b.start_tool("Bash", Some("b3"), 105);
+        b.end_tool(Some("b3"), 106);
+        state.agents.insert("%2".to_string(), b);
+
+        let table = state.tool_latency_table();
+        assert_eq!(table[0].tool, "WebFetch");
+        assert_eq!(table[0].p95_ms, 12_000);
+        assert_eq!(table[1].tool, "Bash");
+        assert_eq!(table[1].count, 2);
+        assert_eq!(table[1].incomplete, 1);
+    }
+}
@@ -0,0 +1,174 @@
+//! Prometheus exposition of board state (`--metrics-port`)
+//!
+//! Metric names are a stable interface (documented in ARCHITECTURE.md):
+//! rename only with a deprecation period. Agent counts come from the same
+//! folded column counts the header shows, so dashboards match the TUI.
+//!
+//! Tool latencies are accumulated separately from the per-agent samples used
+//! by the stats dashboard: those are bounded and vanish with their agent,
+//! while Prometheus histograms must only ever grow.
+
+use super::AppState;
+use std::fmt::Write;
+
+/// Upper bounds (milliseconds) of the tool latency histogram buckets
+///
+/// Hook timestamps have one-second resolution, so sub-second buckets would
+/// only ever see zero.
+pub const LATENCY_BUCKETS_MS: [u64; 8] =
+    [1000, 2000, 5000, 10_000, 30_000, 60_000, 120_000, 300_000];
+
+/// Cumulative latency histogram for one tool
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyBuckets {
+    /// Calls at or below each bound in `LATENCY_BUCKETS_MS` (cumulative)
+    pub buckets: [u64; LATENCY_BUCKETS_MS.len()],
+    pub count: u64,
+    pub sum_ms: u64,
+}
+
+impl LatencyBuckets {
+    /// Record one completed tool call
+    pub fn observe(&mut self, latency_ms: u64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(LATENCY_BUCKETS_MS) {
+            if latency_ms <= bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum_ms += latency_ms;
+    }
+}
+
+/// Escape a label value per the exposition format
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+impl AppState {
+    /// Render all metrics in Prometheus text exposition format (version 0.0.4)
+    pub fn prometheus_metrics(&self) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_agents Agents on the board by status column"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_agents gauge");
+        for (column, count) in self.column_counts() {
+            let _ = writeln!(
+                out,
+                "rehoboam_agents{{status=\"{}\"}} {count}",
+                column.label()
+            );
+        }
+
+        let _ = writeln!(out, "# HELP rehoboam_agents_total Agents on the board");
+        let _ = writeln!(out, "# TYPE rehoboam_agents_total gauge");
+        let _ = writeln!(out, "rehoboam_agents_total {}", self.agents.len());
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_sprite_agents Agents running on remote sprites"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_sprite_agents gauge");
+        let _ = writeln!(out, "rehoboam_sprite_agents {}", self.sprite_agent_count());
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_sprites_connected Sprites with a live WebSocket connection"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_sprites_connected gauge");
+        let _ = writeln!(
+            out,
+            "rehoboam_sprites_connected {}",
+            self.connected_sprite_count()
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_events_dropped_total Tool events dropped because the event channel was full"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_events_dropped_total counter");
+        let _ = writeln!(out, "rehoboam_events_dropped_total {}", self.dropped_events);
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_webhook_failures_total Webhook deliveries that failed every retry"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_webhook_failures_total counter");
+        let _ = writeln!(
+            out,
+            "rehoboam_webhook_failures_total {}",
+            self.webhook_failures
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP rehoboam_tool_latency_ms Tool call duration (PreToolUse to PostToolUse)"
+        );
+        let _ = writeln!(out, "# TYPE rehoboam_tool_latency_ms histogram");
+        for (tool, hist) in &self.tool_latency_totals {
+            let tool = escape_label(tool);
+            for (bound, count) in LATENCY_BUCKETS_MS.iter().zip(hist.buckets) {
+                let _ = writeln!(
+                    out,
+                    "rehoboam_tool_latency_ms_bucket{{tool=\"{tool}\",le=\"{bound}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "rehoboam_tool_latency_ms_bucket{{tool=\"{tool}\",le=\"+Inf\"}} {}",
+                hist.count
+            );
+            let _ = writeln!(
+                out,
+                "rehoboam_tool_latency_ms_sum{{tool=\"{tool}\"}} {}",
+                hist.sum_ms
+            );
+            let _ = writeln!(
+                out,
+                "rehoboam_tool_latency_ms_count{{tool=\"{tool}\"}} {}",
+                hist.count
+            );
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_match_header_and_accumulate_latency() {
+        let mut state = AppState::default();
+        state.agents.insert(
+            "%0".to_string(),
+            super::super::Agent::new("%0".to_string(), "p".to_string()),
+        );
+        state.status_counts[0] = 1;
+        let hist = state
+            .tool_latency_totals
+            .entry("Bash \"x\"".to_string())
+            .or_default();
+        hist.observe(1000);
+        hist.observe(7000);
+
+        let text = state.prometheus_metrics();
+        assert!(text.contains("rehoboam_agents{status=\"attention\"} 1\n"));
+        assert!(text.contains("rehoboam_agents{status=\"working\"} 0\n"));
+        assert!(text.contains("rehoboam_agents_total 1\n"));
+        assert!(text.contains("rehoboam_events_dropped_total 0\n"));
+        assert!(text
+            .contains("rehoboam_tool_latency_ms_bucket{tool=\"Bash \\\"x\\\"\",le=\"1000\"} 1\n"));
+        assert!(text
+            .contains("rehoboam_tool_latency_ms_bucket{tool=\"Bash \\\"x\\\"\",le=\"10000\"} 2\n"));
+        assert!(text.contains("rehoboam_tool_latency_ms_sum{tool=\"Bash \\\"x\\\"\"} 8000\n"));
+        assert!(text.contains("rehoboam_tool_latency_ms_count{tool=\"Bash \\\"x\\\"\"} 2\n"));
+    }
+}
@@ -7,30 +7,75 @@
 
 mod agent;
 mod debug_discovery;
+mod event_log;
 mod event_processing;
 mod facet_discovery;
+mod failures;
+mod fleet;
+mod freeze_delta;
+mod fuzzy;
+mod git_branch;
 mod history_discovery;
 mod insights_discovery;
+mod latency;
+mod metrics;
+mod notifications;
+mod persistence;
+mod pricing;
+mod recording;
+mod session_merge;
+mod snapshot;
+mod sources;
 mod stats_discovery;
 mod task_discovery;
+mod task_graph;
 mod team_discovery;
+mod transcript;
+mod turns;
 
-pub use agent::{Agent, AgentRole, AttentionType, Status, Subagent, TaskInfo, TaskStatus};
+pub use agent::{
+    format_latency, Agent, AgentRole, AttentionType, Status, Subagent, TaskInfo, TaskStatus,
+    TeamSource, TurnEnd, ACTIVITY_BUCKETS, BYPASS_PERMISSIONS,
+};
 pub use debug_discovery::DebugLogEntry;
+pub use event_processing::{
+    is_auth_success, EventOutcome, LoggedEvent, StatusTransition, USER_INPUT_TOOLS,
+};
 pub use facet_discovery::SessionQuality;
+pub use failures::FailureGroup;
+pub use fleet::{FleetBreakdown, FleetRow};
+pub use freeze_delta::FreezeDelta;
+pub use fuzzy::{fuzzy_match, SearchField, SearchHit};
+pub use git_branch::upstream_counts_for;
 pub use history_discovery::HistoryEntry;
 pub use insights_discovery::{InsightsBar, InsightsReport};
+pub use metrics::LatencyBuckets;
+pub use notifications::NotificationThrottle;
+pub use persistence::BoardPersister;
+pub use pricing::{format_cost, FleetCost, Pricing};
+pub use recording::{load_frames, BoardFrame, BoardRecorder};
+pub use snapshot::{snapshot_to_csv, AgentSnapshot};
+pub use sources::{SourceHealth, SourceKey, SourceStats, STREAMING_SECS};
 pub use stats_discovery::StatsCache;
 pub use task_discovery::{FsTaskList, TaskDiscovery};
+pub use task_graph::TaskReadiness;
 pub use team_discovery::TeamDiscovery;
+pub use transcript::{TranscriptMessage, TranscriptRole, TranscriptTail};
+pub use turns::TurnCsvWriter;
 
+use crate::config::{
+    CardSort, EvictionPolicy, GroupBy, IdleTimeoutRules, StatusColumn, MAX_AGENTS, MAX_EVENTS,
+};
 use crate::event::HookEvent;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Number of status categories (Attention, Working, Compacting)
 pub const NUM_COLUMNS: usize = 3;
 
+/// Start of the footer warning shown while `never` eviction turns agents away
+const AGENT_LIMIT_WARNING: &str = "agent limit reached";
+
 /// A task with contextual metadata for display in the task board
 #[derive(Debug, Clone)]
 #[allow(dead_code)] // Fields used by UI renderer and tests
@@ -40,37 +85,106 @@ pub struct TaskWithContext {
     pub status: TaskStatus,
     pub blocked_by: Vec<String>,
     pub blocks: Vec<String>,
+    /// `blocked_by` with each blocker's status (None if not on the board)
+    pub blockers: Vec<(String, Option<TaskStatus>)>,
+    pub readiness: TaskReadiness,
     pub owner_name: String,
     pub team_name: Option<String>,
     pub description: String,
     pub active_form: Option<String>,
 }
 
+/// One project's summed activity sparkline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectActivity {
+    pub project: String,
+    /// Agents contributing to the sum
+    pub agent_count: usize,
+    /// Hook events per bucket, oldest first
+    pub activity: [u64; ACTIVITY_BUCKETS],
+}
+
+/// Local and sprite agents rolled up per project (the swarm line)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectSummary {
+    pub project: String,
+    pub total: usize,
+    pub local: usize,
+    /// Agents running on sprites (`sprite_agent_ids`)
+    pub sprite: usize,
+    pub attention: usize,
+    /// Turns completed across the project's agents
+    pub turns: u32,
+}
+
+/// How close an agent is to running out of context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextLevel {
+    Normal,
+    /// Remaining context below `[ui] context_warn_percent`
+    Warn,
+    /// Remaining context below `[ui] context_critical_percent`
+    Critical,
+}
+
 /// Application state
 #[derive(Debug)]
 pub struct AppState {
     /// Active agents indexed by pane_id
     pub agents: HashMap<String, Agent>,
-    /// Recent events for the event log
-    pub events: VecDeque<HookEvent>,
+    /// Recent events for the event log, newest first
+    pub events: VecDeque<LoggedEvent>,
+    /// Most events kept in `events` (`[ui] event_log_size`)
+    pub event_log_size: usize,
     /// Currently selected agent by pane_id (identity-based navigation)
     pub selected_pane_id: Option<String>,
     /// Cached status counts: [attention, working, compacting]
     pub status_counts: [usize; NUM_COLUMNS],
     /// Set of selected pane_ids for bulk operations
     pub selected_agents: HashSet<String>,
+    /// Teams collapsed to a single header line in the team view (persisted)
+    pub collapsed_teams: BTreeSet<String>,
+    /// Pinned pane_ids, sorted to the top of their team (persisted)
+    ///
+    /// Kept when the agent goes away, so a pane that comes back is pinned again.
+    pub pinned: BTreeSet<String>,
     /// Set of sprite agent IDs (for quick lookup)
     pub sprite_agent_ids: HashSet<String>,
     /// Set of currently connected sprite IDs
     pub connected_sprites: HashSet<String>,
+    /// Events delivered per socket and sprite
+    pub sources: BTreeMap<SourceKey, SourceStats>,
+    /// Transitions collected while the display is frozen (None when live)
+    pub freeze_delta: Option<FreezeDelta>,
     /// Health warning message (hooks.log size issue)
     pub health_warning: Option<String>,
+    /// Hook events dropped because the event channel was full (since startup)
+    pub dropped_events: u64,
+    /// Webhook deliveries that failed every retry (copied from the App)
+    pub webhook_failures: u64,
     /// Configurable timeout: Working → Attention(Waiting) transition (seconds)
     pub idle_timeout_secs: i64,
-    /// Configurable timeout: removing stale sessions (seconds)
+    /// Per-project overrides of `idle_timeout_secs`
+    pub idle_rules: IdleTimeoutRules,
+    /// Configurable timeout: marking silent sessions gone (seconds)
     pub stale_timeout_secs: i64,
+    /// Configurable grace: gone agents stay this long before removal (seconds)
+    pub gone_grace_secs: i64,
+    /// Turns finished since the App last drained them (for `--metrics-csv`)
+    pub completed_turns: Vec<turns::TurnRecord>,
+    /// Configurable threshold: one tool running this long flags the agent STUCK (seconds)
+    pub stuck_tool_secs: i64,
+    /// Configurable threshold: idle agents the manual sweep offers to remove (seconds)
+    pub sweep_idle_secs: i64,
+    /// Most agents tracked at once (`[limits] max_agents`)
+    pub max_agents: usize,
+    /// How room is made at `max_agents` (`[limits] eviction`)
+    pub eviction: EvictionPolicy,
     /// Session ID → team name mapping for cross-event correlation
     pub session_to_team: HashMap<String, String>,
+    /// Session ID → real pane it last reported from (merges session-id
+    /// fallback cards, see `session_merge.rs`)
+    pub session_panes: HashMap<String, String>,
     /// Last filesystem team scan timestamp (throttled to every 30s)
     pub last_team_scan: i64,
     /// Filesystem task lists from ~/.claude/tasks/ (ground truth)
@@ -95,6 +209,32 @@ pub struct AppState {
     pub last_debug_scan: i64,
     /// Last insights scan timestamp (throttled to 120s)
     pub last_insights_scan: i64,
+    /// Visible status columns in display order (hidden ones fold into Working)
+    pub columns: Vec<StatusColumn>,
+    /// Secondary ordering of agents within a status group
+    pub card_sort: CardSort,
+    /// What the team view groups agents under
+    pub group_by: GroupBy,
+    /// Desktop notification cooldowns and pending burst
+    pub notifications: NotificationThrottle,
+    /// Recent compaction durations in seconds (oldest first)
+    pub compaction_durations: VecDeque<u64>,
+    /// Working agents at each recent tick, oldest first (the header's fleet pulse)
+    pub fleet_pulse: VecDeque<u64>,
+    /// Ticks kept in `fleet_pulse`
+    pub fleet_pulse_ticks: usize,
+    /// Remaining-context percentage below which cards warn
+    pub context_warn_percent: f64,
+    /// Remaining-context percentage below which cards turn critical
+    pub context_critical_percent: f64,
+    /// Per-model token rates for cost estimates (`[pricing]`)
+    pub pricing: Pricing,
+    /// Store each agent's latest prompt text (`[ui] capture_prompts`)
+    pub capture_prompts: bool,
+    /// Cumulative tool latency histograms for `--metrics-port`
+    pub tool_latency_totals: BTreeMap<String, LatencyBuckets>,
+    /// Recorded time a `--replay` has reached; `None` reads the wall clock
+    pub clock: Option<i64>,
 }
 
 impl Default for AppState {
@@ -102,15 +242,30 @@ impl Default for AppState {
         Self {
             agents: HashMap::new(),
             events: VecDeque::new(),
+            event_log_size: MAX_EVENTS,
             selected_pane_id: None,
             status_counts: [0; NUM_COLUMNS],
             selected_agents: HashSet::new(),
+            collapsed_teams: BTreeSet::new(),
+            pinned: BTreeSet::new(),
             sprite_agent_ids: HashSet::new(),
             connected_sprites: HashSet::new(),
+            sources: BTreeMap::new(),
+            freeze_delta: None,
             health_warning: None,
+            dropped_events: 0,
+            webhook_failures: 0,
             idle_timeout_secs: 60,
+            idle_rules: IdleTimeoutRules::default(),
             stale_timeout_secs: 300,
+            gone_grace_secs: 300,
+            completed_turns: Vec::new(),
+            stuck_tool_secs: 300,
+            sweep_idle_secs: 600,
+            max_agents: MAX_AGENTS,
+            eviction: EvictionPolicy::OldestWaiting,
             session_to_team: HashMap::new(),
+            session_panes: HashMap::new(),
             last_team_scan: 0,
             fs_task_lists: HashMap::new(),
             last_task_scan: 0,
@@ -123,7 +278,57 @@ impl Default for AppState {
             last_history_scan: 0,
             last_debug_scan: 0,
             last_insights_scan: 0,
+            columns: vec![
+                StatusColumn::Attention,
+                StatusColumn::Working,
+                StatusColumn::Compacting,
+            ],
+            card_sort: CardSort::Project,
+            group_by: GroupBy::Team,
+            notifications: NotificationThrottle::default(),
+            compaction_durations: VecDeque::new(),
+            fleet_pulse: VecDeque::new(),
+            fleet_pulse_ticks: 60,
+            context_warn_percent: 20.0,
+            context_critical_percent: 10.0,
+            pricing: Pricing::default(),
+            capture_prompts: true,
+            tool_latency_totals: BTreeMap::new(),
+            clock: None,
+        }
+    }
+}
+
+/// Compaction durations kept for the rolling estimate
+pub(crate) const MAX_COMPACTION_SAMPLES: usize = 20;
+
+/// View filter applied to the team list and j/k navigation
+///
+/// The default filter matches every agent. When `attention_only` is set, only
+/// agents needing attention are shown. A non-empty `query` narrows either view
+/// by fuzzy match against project, pane ID, team, agent name, model and
+/// socket (see [`fuzzy`]), and ranks the matches by score.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AgentFilter<'a> {
+    pub attention_only: bool,
+    pub query: &'a str,
+}
+
+impl AgentFilter<'_> {
+    /// Check if an agent passes the filter
+    pub fn matches(&self, agent: &Agent) -> bool {
+        if self.attention_only && !matches!(agent.status, Status::Attention(_)) {
+            return false;
         }
+        self.query.trim().is_empty() || fuzzy::search_agent(self.query, agent).is_some()
+    }
+
+    /// Search hit for `agent`, None when there is no query or no match
+    pub fn hit(&self, agent: &Agent) -> Option<SearchHit> {
+        if self.query.trim().is_empty() {
+            return None;
+        }
+        fuzzy::search_agent(self.query, agent)
     }
 }
 
@@ -136,6 +341,26 @@ pub fn status_to_column(status: &Status) -> usize {
     }
 }
 
+/// Attention sub-priority (0 for non-attention agents)
+/// Attention sort rank (lower first): an interrupted tool failure outranks
+/// every attention type
+fn attention_priority(agent: &Agent) -> u8 {
+    match &agent.status {
+        _ if agent.failed_attention() => 0,
+        Status::Attention(attn) => attn.priority() + 1,
+        _ => 0,
+    }
+}
+
+/// Map status to its natural display column (before folding hidden columns)
+fn status_to_status_column(status: &Status) -> StatusColumn {
+    match status {
+        Status::Attention(_) => StatusColumn::Attention,
+        Status::Working => StatusColumn::Working,
+        Status::Compacting => StatusColumn::Compacting,
+    }
+}
+
 impl AppState {
     #[allow(dead_code)] // Used in tests; production uses with_timeouts()
     pub fn new() -> Self {
@@ -153,63 +378,338 @@ impl AppState {
 
     // NOTE: process_event() is defined in event_processing.rs
 
-    /// Evict the oldest waiting agent to make room for new ones
-    fn evict_oldest_waiting(&mut self) {
-        // Find oldest Attention(Waiting) agent by last_update
-        let oldest_waiting = self
+    /// Current time for timeouts and event stamps: the replay clock when set
+    pub fn now(&self) -> i64 {
+        self.clock.unwrap_or_else(current_timestamp)
+    }
+
+    /// Move the replay clock to a recorded timestamp, then run the timeouts
+    /// that would have fired live before the next replayed event
+    pub fn advance_clock(&mut self, timestamp: i64) {
+        if self.clock.is_some_and(|clock| timestamp <= clock) {
+            return;
+        }
+        self.clock = Some(timestamp);
+        self.tick();
+    }
+
+    /// Column a status is displayed under, folding hidden columns into Working
+    pub fn display_column(&self, status: &Status) -> StatusColumn {
+        let column = status_to_status_column(status);
+        if self.columns.contains(&column) {
+            column
+        } else {
+            StatusColumn::Working
+        }
+    }
+
+    /// Average of recent compaction durations (None until one has finished)
+    pub fn typical_compaction_secs(&self) -> Option<u64> {
+        if self.compaction_durations.is_empty() {
+            return None;
+        }
+        let total: u64 = self.compaction_durations.iter().sum();
+        Some(total / self.compaction_durations.len() as u64)
+    }
+
+    /// Live agents per non-default permission mode, most common first
+    ///
+    /// `bypassPermissions` always leads so the header shows it even when
+    /// other modes outnumber it.
+    pub fn permission_mode_counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for agent in self.agents.values().filter(|a| !a.is_gone()) {
+            if let Some(mode) = agent.notable_permission_mode() {
+                *counts.entry(mode).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+        counts.sort_by_key(|&(mode, count)| (mode != BYPASS_PERMISSIONS, std::cmp::Reverse(count)));
+        counts
+    }
+
+    /// Live agents needing attention per project, most first (ties by name)
+    pub fn attention_by_project(&self) -> Vec<(&str, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for agent in self.agents.values().filter(|a| !a.is_gone()) {
+            if matches!(agent.status, Status::Attention(_)) {
+                *counts.entry(agent.project.as_str()).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+        counts.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        counts
+    }
+
+    /// Mean lifetime of the agents on the board (None when none has reported)
+    pub fn average_lifetime_secs(&self, now: i64) -> Option<i64> {
+        let lifetimes: Vec<i64> = self
             .agents
-            .iter()
-            .filter(|(_, a)| matches!(a.status, Status::Attention(AttentionType::Waiting)))
-            .min_by_key(|(_, a)| a.last_update)
-            .map(|(id, _)| id.clone());
+            .values()
+            .filter(|a| a.created_at > 0)
+            .map(|a| a.lifetime_secs(now))
+            .collect();
+        if lifetimes.is_empty() {
+            return None;
+        }
+        Some(lifetimes.iter().sum::<i64>() / lifetimes.len() as i64)
+    }
 
-        if let Some(pane_id) = oldest_waiting {
-            self.status_counts[0] = self.status_counts[0].saturating_sub(1); // Attention is column 0
-            self.agents.remove(&pane_id);
-            self.selected_agents.remove(&pane_id);
-            self.sprite_agent_ids.remove(&pane_id);
-            if self.selected_pane_id.as_deref() == Some(&pane_id) {
-                self.selected_pane_id = None;
+    /// Agent counts per visible column, in configured display order
+    pub fn column_counts(&self) -> Vec<(StatusColumn, usize)> {
+        let mut counts: Vec<(StatusColumn, usize)> =
+            self.columns.iter().map(|&column| (column, 0)).collect();
+        let statuses = [
+            Status::Attention(AttentionType::Waiting),
+            Status::Working,
+            Status::Compacting,
+        ];
+        for status in &statuses {
+            let column = self.display_column(status);
+            let count = self.status_counts[status_to_column(status)];
+            if let Some(entry) = counts.iter_mut().find(|(c, _)| *c == column) {
+                entry.1 += count;
             }
-        } else {
-            // No idle agents, evict oldest of any status
-            let oldest = self
-                .agents
+        }
+        counts
+    }
+
+    /// Make room for one more agent under the `[limits] eviction` policy
+    ///
+    /// Returns false when the policy is `never`: the new agent is not
+    /// tracked and the footer warns instead.
+    pub(crate) fn make_room(&mut self) -> bool {
+        if self.eviction == EvictionPolicy::Never {
+            let warning = format!(
+                "{AGENT_LIMIT_WARNING} ({}): new agents not shown, raise [limits] max_agents",
+                self.max_agents
+            );
+            if self.health_warning.as_ref() != Some(&warning) {
+                tracing::warn!(
+                    max_agents = self.max_agents,
+                    "Agent limit reached, new agent not tracked"
+                );
+                self.health_warning = Some(warning);
+            }
+            return false;
+        }
+        if let Some(pane_id) = self.eviction_victim() {
+            if let Some(agent) = self.forget_agent(&pane_id) {
+                tracing::info!(pane_id = %pane_id, project = %agent.project, "Evicted agent at capacity");
+            }
+        }
+        true
+    }
+
+    /// Agent to evict: gone agents first (already on their way out), then
+    /// the oldest waiting one (`oldest-waiting`), then the oldest of any status
+    fn eviction_victim(&self) -> Option<String> {
+        let oldest = |keep: fn(&Agent) -> bool| {
+            self.agents
                 .iter()
+                .filter(|(_, a)| keep(a))
                 .min_by_key(|(_, a)| a.last_update)
-                .map(|(id, a)| (id.clone(), status_to_column(&a.status)));
-
-            if let Some((pane_id, col)) = oldest {
-                self.status_counts[col] = self.status_counts[col].saturating_sub(1);
-                self.agents.remove(&pane_id);
-                self.selected_agents.remove(&pane_id);
-                self.sprite_agent_ids.remove(&pane_id);
-                if self.selected_pane_id.as_deref() == Some(&pane_id) {
-                    self.selected_pane_id = None;
+                .map(|(id, _)| id.clone())
+        };
+        oldest(Agent::is_gone)
+            .or_else(|| {
+                if self.eviction == EvictionPolicy::OldestWaiting {
+                    oldest(|a| matches!(a.status, Status::Attention(AttentionType::Waiting)))
+                } else {
+                    None
                 }
+            })
+            .or_else(|| oldest(|_| true))
+    }
+
+    /// Take an agent off the board, keeping counts and selection consistent
+    fn forget_agent(&mut self, pane_id: &str) -> Option<Agent> {
+        let agent = self.agents.remove(pane_id)?;
+        // Gone agents were already taken out of the status counts
+        if !agent.is_gone() {
+            let col = status_to_column(&agent.status);
+            self.status_counts[col] = self.status_counts[col].saturating_sub(1);
+        }
+        self.selected_agents.remove(pane_id);
+        self.notifications.clear_pane(pane_id);
+        self.sprite_agent_ids.remove(pane_id);
+        if self.selected_pane_id.as_deref() == Some(pane_id) {
+            self.selected_pane_id = None;
+        }
+        Some(agent)
+    }
+
+    /// Bring a gone agent back in place when it reports again
+    ///
+    /// Restores its status count; everything else on the card was kept.
+    pub(crate) fn revive_if_gone(&mut self, pane_id: &str) {
+        let Some(agent) = self.agents.get_mut(pane_id) else {
+            return;
+        };
+        if agent.gone_since.take().is_some() {
+            self.status_counts[status_to_column(&agent.status)] += 1;
+            tracing::info!(pane_id = %pane_id, project = %agent.project, "Gone agent revived");
+        }
+    }
+
+    /// Agents the manual sweep would remove, oldest first
+    ///
+    /// Gone agents, plus agents idle after their turn (Attention(Waiting))
+    /// with no events for `sweep_idle_secs`. Agents asking for permission or
+    /// input are never offered: someone may still answer them.
+    pub fn sweep_candidates(&self) -> Vec<String> {
+        let now = self.now();
+        let mut candidates: Vec<&Agent> = self
+            .agents
+            .values()
+            .filter(|a| {
+                a.is_gone()
+                    || (matches!(a.status, Status::Attention(AttentionType::Waiting))
+                        && now - a.last_update >= self.sweep_idle_secs)
+            })
+            .collect();
+        candidates.sort_by_key(|a| (a.last_update, a.pane_id.clone()));
+        candidates.into_iter().map(|a| a.pane_id.clone()).collect()
+    }
+
+    /// Agents waiting on a permission prompt for `tool` (any tool if None),
+    /// sorted by pane
+    pub fn pending_permissions(&self, tool: Option<&str>) -> Vec<String> {
+        let mut panes: Vec<String> = self
+            .agents
+            .values()
+            .filter(|a| a.status == Status::Attention(AttentionType::Permission))
+            .filter(|a| tool.is_none() || a.pending_permission_tool.as_deref() == tool)
+            .map(|a| a.pane_id.clone())
+            .collect();
+        panes.sort();
+        panes
+    }
+
+    /// Tools with permission prompts waiting and how many, most first
+    ///
+    /// Prompts whose tool is unknown only count towards "all".
+    pub fn pending_permission_tools(&self) -> Vec<(String, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for agent in self.agents.values() {
+            if agent.status != Status::Attention(AttentionType::Permission) {
+                continue;
+            }
+            if let Some(ref tool) = agent.pending_permission_tool {
+                *counts.entry(tool.as_str()).or_default() += 1;
             }
         }
+        let mut tools: Vec<(String, usize)> = counts
+            .into_iter()
+            .map(|(tool, count)| (tool.to_string(), count))
+            .collect();
+        tools.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        tools
+    }
+
+    /// Remove agents from the board (only from rehoboam's view; panes are
+    /// left alone), returning the removed agents' "project (pane)" labels
+    ///
+    /// Panes that are no longer sweep candidates (an event revived them since
+    /// the list was shown) are kept.
+    pub fn sweep(&mut self, pane_ids: &[String]) -> Vec<String> {
+        let eligible = self.sweep_candidates();
+        let mut removed = Vec::new();
+        for pane_id in pane_ids.iter().filter(|p| eligible.contains(p)) {
+            let Some(agent) = self.forget_agent(pane_id) else {
+                continue;
+            };
+            tracing::info!(pane_id = %pane_id, project = %agent.project, "Swept agent");
+            removed.push(format!("{} ({pane_id})", agent.project));
+        }
+        removed
+    }
+
+    /// Append this tick's working-agent count to the fleet pulse
+    fn record_fleet_pulse(&mut self) {
+        let working = self
+            .agents
+            .values()
+            .filter(|a| !a.is_gone() && a.status == Status::Working)
+            .count();
+        self.fleet_pulse.push_back(working as u64);
+        while self.fleet_pulse.len() > self.fleet_pulse_ticks {
+            self.fleet_pulse.pop_front();
+        }
     }
 
     /// Periodic tick for timeout-based state transitions
     ///
     /// Handles:
     /// - Working → Attention(Waiting) after idle_timeout_secs of no events
-    /// - Remove stale sessions after stale_timeout_secs of no events
+    ///   (or the project's `[timeouts.per_project]` override)
+    /// - Mark sessions gone after stale_timeout_secs of no events, and remove
+    ///   them once gone_grace_secs more have passed
+    /// - Flag Working agents whose current tool has run past stuck_tool_secs
     pub fn tick(&mut self) {
-        let now = current_timestamp();
+        self.record_fleet_pulse();
+        let now = self.now();
+        let stuck_tool_secs = self.stuck_tool_secs;
+        for agent in self.agents.values_mut() {
+            // Age activity sparklines even for silent agents
+            agent.roll_activity(now);
+
+            // Stuck is a warning only: the agent keeps its Working status
+            let stuck = !agent.is_gone()
+                && matches!(agent.status, Status::Working)
+                && agent
+                    .tool_running_secs(now)
+                    .is_some_and(|secs| secs > stuck_tool_secs);
+            if stuck && !agent.stuck {
+                tracing::warn!(
+                    pane_id = %agent.pane_id,
+                    project = %agent.project,
+                    tool = ?agent.current_tool,
+                    threshold_secs = stuck_tool_secs,
+                    "Tool running too long: flagged as stuck"
+                );
+            }
+            agent.stuck = stuck;
+        }
+        // The agent-limit warning lasts only while the board is full
+        if self.agents.len() < self.max_agents
+            && self
+                .health_warning
+                .as_deref()
+                .is_some_and(|w| w.starts_with(AGENT_LIMIT_WARNING))
+        {
+            self.health_warning = None;
+        }
+
         let mut to_remove: Vec<String> = Vec::new();
+        let mut to_mark_gone: Vec<String> = Vec::new();
         let mut waiting_transitions: Vec<String> = Vec::new();
 
-        let idle_timeout = self.idle_timeout_secs;
         let stale_timeout = self.stale_timeout_secs;
+        let gone_grace = self.gone_grace_secs;
 
         for (pane_id, agent) in &self.agents {
             let elapsed = now - agent.last_update;
+            let idle_timeout = self
+                .idle_rules
+                .for_project(&agent.project)
+                .unwrap_or(self.idle_timeout_secs);
+
+            // Gone agents only wait out their grace period
+            if let Some(since) = agent.gone_since {
+                if now - since > gone_grace {
+                    to_remove.push(pane_id.clone());
+                }
+                continue;
+            }
 
-            // Remove stale sessions
+            // Stale sessions: remove outright without a grace period, else mark gone
             if elapsed > stale_timeout {
-                to_remove.push(pane_id.clone());
+                if gone_grace == 0 {
+                    to_remove.push(pane_id.clone());
+                } else {
+                    to_mark_gone.push(pane_id.clone());
+                }
                 continue;
             }
 
@@ -240,7 +740,8 @@ impl AppState {
         for pane_id in waiting_transitions {
             if let Some(agent) = self.agents.get_mut(&pane_id) {
                 let old_col = status_to_column(&agent.status);
-                agent.status = Status::Attention(AttentionType::Waiting);
+                let from =
+                    std::mem::replace(&mut agent.status, Status::Attention(AttentionType::Waiting));
                 let new_col = status_to_column(&agent.status);
 
                 // Update status counts
@@ -253,23 +754,57 @@ impl AppState {
                     elapsed_secs = %(now - agent.last_update),
                     "Timeout: Working → Attention(Waiting)"
                 );
+
+                let event = HookEvent::internal(
+                    "IdleTimeout",
+                    "attention",
+                    Some("waiting"),
+                    &pane_id,
+                    &agent.project,
+                    now,
+                );
+                let transition = StatusTransition {
+                    from: Some(from),
+                    to: agent.status.clone(),
+                    reason: "idle timeout".to_string(),
+                };
+                self.log_event(event, Some(transition));
             }
         }
 
-        // Remove stale sessions
-        for pane_id in to_remove {
-            if let Some(agent) = self.agents.get(&pane_id) {
+        // Grey out stale sessions; they leave the header counts now
+        for pane_id in to_mark_gone {
+            if let Some(agent) = self.agents.get_mut(&pane_id) {
                 let col = status_to_column(&agent.status);
                 self.status_counts[col] = self.status_counts[col].saturating_sub(1);
+                agent.gone_since = Some(now);
+                agent.stuck = false;
                 tracing::info!(
                     pane_id = %pane_id,
                     project = %agent.project,
                     stale_timeout_secs = stale_timeout,
+                    "Stale session marked gone"
+                );
+            }
+        }
+
+        // Remove stale sessions
+        for pane_id in to_remove {
+            if let Some(agent) = self.agents.get(&pane_id) {
+                if !agent.is_gone() {
+                    let col = status_to_column(&agent.status);
+                    self.status_counts[col] = self.status_counts[col].saturating_sub(1);
+                }
+                tracing::info!(
+                    pane_id = %pane_id,
+                    project = %agent.project,
+                    gone_grace_secs = gone_grace,
                     "Removed stale session"
                 );
             }
             self.agents.remove(&pane_id);
             self.selected_agents.remove(&pane_id);
+            self.notifications.clear_pane(&pane_id);
             self.sprite_agent_ids.remove(&pane_id);
             if self.selected_pane_id.as_deref() == Some(&pane_id) {
                 self.selected_pane_id = None;
@@ -280,32 +815,83 @@ impl AppState {
     /// Get agents grouped by team name
     ///
     /// Returns a vector of (team_name, agents) tuples.
-    /// Agents within each team are sorted: leads first, then by status priority.
-    /// "Independent" group (agents with no team) is always last.
+    /// Agents within each team are sorted: pinned first, then leads, then by
+    /// status priority (attention type priority within Attention), then by
+    /// `card_sort`. Teams holding a pinned agent come first; otherwise the
+    /// "Independent" group (agents with no team) is last. With a search
+    /// query, agents and teams are ranked by match score first.
     pub fn agents_by_team(&self) -> Vec<(String, Vec<&Agent>)> {
+        self.agents_by_team_filtered(&AgentFilter::default())
+    }
+
+    /// Get agents grouped by team, keeping only those matching `filter`
+    ///
+    /// Teams with no matching agents are omitted.
+    pub fn agents_by_team_filtered(&self, filter: &AgentFilter) -> Vec<(String, Vec<&Agent>)> {
+        self.group_agents_by(filter, |a| a.team_name.as_deref(), GroupBy::Team.fallback())
+    }
+
+    /// Agents matching `filter` grouped under `group_by`, in display order
+    ///
+    /// This is what the team view, navigation and the minimap show.
+    pub fn agents_grouped(&self, filter: &AgentFilter) -> Vec<(String, Vec<&Agent>)> {
+        let fallback = self.group_by.fallback();
+        match self.group_by {
+            GroupBy::Team => self.agents_by_team_filtered(filter),
+            GroupBy::Model => self.group_agents_by(filter, |a| a.model.as_deref(), fallback),
+            GroupBy::PermissionMode => {
+                self.group_agents_by(filter, |a| a.permission_mode.as_deref(), fallback)
+            }
+        }
+    }
+
+    /// Group agents matching `filter` by `key`, sorted like the team view
+    ///
+    /// Agents without a key go under `fallback`, which is listed last.
+    fn group_agents_by(
+        &self,
+        filter: &AgentFilter,
+        key: impl Fn(&Agent) -> Option<&str>,
+        fallback: &str,
+    ) -> Vec<(String, Vec<&Agent>)> {
         let mut teams: HashMap<String, Vec<&Agent>> = HashMap::new();
-        for agent in self.agents.values() {
-            let team_key = agent
-                .team_name
-                .clone()
-                .unwrap_or_else(|| "Independent".to_string());
+        for agent in self.agents.values().filter(|a| filter.matches(a)) {
+            let team_key = key(agent).unwrap_or(fallback).to_string();
             teams.entry(team_key).or_default().push(agent);
         }
-        // Sort: leads first within team, then by status priority
+        // Search results rank by score before anything else
+        let score = |agent: &Agent| filter.hit(agent).map_or(0, |hit| hit.score);
+        let pinned = |agent: &Agent| self.pinned.contains(&agent.pane_id);
+        // Sort: pinned, then leads first within team, then by status priority, then card_sort
+        let card_sort = self.card_sort;
         for agents in teams.values_mut() {
             agents.sort_by(|a, b| {
                 let a_lead = a.team_agent_type.as_deref() == Some("lead");
                 let b_lead = b.team_agent_type.as_deref() == Some("lead");
-                b_lead
-                    .cmp(&a_lead)
+                score(b)
+                    .cmp(&score(a))
+                    .then_with(|| pinned(b).cmp(&pinned(a)))
+                    .then_with(|| b_lead.cmp(&a_lead))
                     .then_with(|| a.status.priority().cmp(&b.status.priority()))
+                    .then_with(|| attention_priority(a).cmp(&attention_priority(b)))
+                    .then_with(|| match card_sort {
+                        CardSort::Project => a.project.cmp(&b.project),
+                        CardSort::Elapsed => a.last_update.cmp(&b.last_update),
+                        CardSort::Recent => b.last_update.cmp(&a.last_update),
+                    })
+                    .then_with(|| a.pane_id.cmp(&b.pane_id))
             });
         }
-        // "Independent" always last, otherwise alphabetical
+        // Best search match first; then teams with pins, the fallback group
+        // ("Independent") last, otherwise alphabetical
+        let best = |agents: &[&Agent]| agents.first().map_or(0, |a| score(a));
+        let has_pin = |agents: &[&Agent]| agents.iter().any(|a| pinned(a));
         let mut result: Vec<_> = teams.into_iter().collect();
         result.sort_by(|a, b| {
-            (a.0 == "Independent")
-                .cmp(&(b.0 == "Independent"))
+            best(&b.1)
+                .cmp(&best(&a.1))
+                .then_with(|| has_pin(&b.1).cmp(&has_pin(&a.1)))
+                .then_with(|| (a.0 == fallback).cmp(&(b.0 == fallback)))
                 .then_with(|| a.0.cmp(&b.0))
         });
         result
@@ -315,45 +901,166 @@ impl AppState {
     ///
     /// Traverses agents in `agents_by_team()` order (matches rendered order).
     /// Wraps around at the end.
+    #[allow(dead_code)] // Used in tests; keyboard navigation uses next_agent_filtered()
     pub fn next_agent(&mut self) {
-        let flat: Vec<String> = self
-            .agents_by_team()
+        self.next_agent_filtered(&AgentFilter::default());
+    }
+
+    /// Move to next agent visible under `filter`
+    pub fn next_agent_filtered(&mut self, filter: &AgentFilter) {
+        self.step_selection(filter, true);
+    }
+
+    /// Move the selection one visible agent forward or back, wrapping around
+    ///
+    /// Agents in collapsed teams are skipped. When the selection itself is
+    /// hidden (its team was just collapsed), stepping starts from its position.
+    fn step_selection(&mut self, filter: &AgentFilter, forward: bool) {
+        let flat: Vec<(String, bool)> = self
+            .agents_grouped(filter)
             .iter()
-            .flat_map(|(_, agents)| agents.iter().map(|a| a.pane_id.clone()))
+            .flat_map(|(team, agents)| {
+                let hidden = self.collapsed_teams.contains(team);
+                agents.iter().map(move |a| (a.pane_id.clone(), hidden))
+            })
             .collect();
         if flat.is_empty() {
             self.selected_pane_id = None;
             return;
         }
+        let len = flat.len();
         let idx = self
             .selected_pane_id
             .as_ref()
-            .and_then(|id| flat.iter().position(|p| p == id))
+            .and_then(|id| flat.iter().position(|(p, _)| p == id))
             .unwrap_or(0);
-        self.selected_pane_id = Some(flat[(idx + 1) % flat.len()].clone());
+        for step in 1..=len {
+            let i = if forward {
+                (idx + step) % len
+            } else {
+                (idx + len - step) % len
+            };
+            if !flat[i].1 {
+                self.selected_pane_id = Some(flat[i].0.clone());
+                return;
+            }
+        }
+        // Every team is collapsed: keep the selection so `z` can reopen its team
     }
 
-    /// Move to previous agent in flat order (across all teams)
+    /// Agents visible under `filter` outside collapsed teams, in display order
+    pub fn visible_agents(&self, filter: &AgentFilter) -> Vec<&Agent> {
+        self.agents_grouped(filter)
+            .into_iter()
+            .filter(|(team, _)| !self.collapsed_teams.contains(team))
+            .flat_map(|(_, agents)| agents)
+            .collect()
+    }
+
+    /// Move the selection `offset` visible agents away, stopping at either end
     ///
-    /// Traverses agents in `agents_by_team()` order (matches rendered order).
-    /// Wraps around at the beginning.
-    pub fn prev_agent(&mut self) {
-        let flat: Vec<String> = self
-            .agents_by_team()
+    /// The minimap moves a whole row at a time this way. With no visible
+    /// selection, the first visible agent is selected.
+    pub fn offset_selection(&mut self, filter: &AgentFilter, offset: isize) {
+        let visible = self.visible_agents(filter);
+        let Some(last) = visible.len().checked_sub(1) else {
+            return;
+        };
+        let target = match self
+            .selected_pane_id
+            .as_ref()
+            .and_then(|id| visible.iter().position(|a| &a.pane_id == id))
+        {
+            Some(idx) => idx.saturating_add_signed(offset).min(last),
+            None => 0,
+        };
+        self.selected_pane_id = Some(visible[target].pane_id.clone());
+    }
+
+    /// Collapse or expand a team in the team view
+    pub fn toggle_team_collapsed(&mut self, team: &str) {
+        if !self.collapsed_teams.remove(team) {
+            self.collapsed_teams.insert(team.to_string());
+        }
+    }
+
+    /// Pin or unpin an agent, returning whether it is now pinned
+    pub fn toggle_pinned(&mut self, pane_id: &str) -> bool {
+        if self.pinned.remove(pane_id) {
+            return false;
+        }
+        self.pinned.insert(pane_id.to_string());
+        true
+    }
+
+    /// Set an agent's note, flattened to one line; a blank note clears it.
+    /// Returns false if the agent is gone from the board.
+    pub fn set_note(&mut self, pane_id: &str, note: &str) -> bool {
+        let Some(agent) = self.agents.get_mut(pane_id) else {
+            return false;
+        };
+        let note = note.split_whitespace().collect::<Vec<_>>().join(" ");
+        agent.note = (!note.is_empty()).then_some(note);
+        true
+    }
+
+    /// Group the agent is displayed under (matches `agents_grouped`)
+    pub fn display_group<'a>(&self, agent: &'a Agent) -> &'a str {
+        let value = match self.group_by {
+            GroupBy::Team => agent.team_name.as_deref(),
+            GroupBy::Model => agent.model.as_deref(),
+            GroupBy::PermissionMode => agent.permission_mode.as_deref(),
+        };
+        value.unwrap_or(self.group_by.fallback())
+    }
+
+    /// Select the next agent needing attention, highest priority first
+    ///
+    /// Cycles through Attention agents visible under `filter`, ordered by
+    /// attention priority then display order, wrapping around. Working and
+    /// Compacting agents are skipped. Agents in collapsed teams are still
+    /// visited (their team header shows as selected) so nothing urgent hides.
+    /// Returns `false` (selection unchanged) when no agent needs attention.
+    pub fn next_attention_agent(&mut self, filter: &AgentFilter) -> bool {
+        let mut attention: Vec<(u8, usize, String)> = self
+            .agents_grouped(filter)
             .iter()
-            .flat_map(|(_, agents)| agents.iter().map(|a| a.pane_id.clone()))
+            .flat_map(|(_, agents)| agents.iter())
+            .enumerate()
+            .filter_map(|(order, agent)| match &agent.status {
+                _ if agent.is_gone() => None,
+                Status::Attention(_) => {
+                    Some((attention_priority(agent), order, agent.pane_id.clone()))
+                }
+                _ => None,
+            })
             .collect();
-        if flat.is_empty() {
-            self.selected_pane_id = None;
-            return;
+        if attention.is_empty() {
+            return false;
         }
-        let idx = self
+        attention.sort();
+
+        let next = self
             .selected_pane_id
             .as_ref()
-            .and_then(|id| flat.iter().position(|p| p == id))
-            .unwrap_or(0);
-        let prev_idx = if idx == 0 { flat.len() - 1 } else { idx - 1 };
-        self.selected_pane_id = Some(flat[prev_idx].clone());
+            .and_then(|id| attention.iter().position(|(_, _, p)| p == id))
+            .map_or(0, |idx| (idx + 1) % attention.len());
+        self.selected_pane_id = Some(attention[next].2.clone());
+        true
+    }
+
+    /// Move to previous agent in flat order (across all teams)
+    ///
+    /// Traverses agents in `agents_by_team()` order (matches rendered order).
+    /// Wraps around at the beginning.
+    #[allow(dead_code)] // Used in tests; keyboard navigation uses prev_agent_filtered()
+    pub fn prev_agent(&mut self) {
+        self.prev_agent_filtered(&AgentFilter::default());
+    }
+
+    /// Move to previous agent visible under `filter`
+    pub fn prev_agent_filtered(&mut self, filter: &AgentFilter) {
+        self.step_selection(filter, false);
     }
 
     /// Get currently selected agent
@@ -383,6 +1090,101 @@ impl AppState {
         self.connected_sprites.len()
     }
 
+    /// Activity sparklines summed per project, sorted by project name
+    pub fn activity_by_project(&self) -> Vec<ProjectActivity> {
+        let mut by_project: BTreeMap<&str, ProjectActivity> = BTreeMap::new();
+        for agent in self.agents.values() {
+            let entry =
+                by_project
+                    .entry(agent.project.as_str())
+                    .or_insert_with(|| ProjectActivity {
+                        project: agent.project.clone(),
+                        agent_count: 0,
+                        activity: [0; ACTIVITY_BUCKETS],
+                    });
+            entry.agent_count += 1;
+            for (sum, count) in entry.activity.iter_mut().zip(agent.activity) {
+                *sum += count;
+            }
+        }
+        by_project.into_values().collect()
+    }
+
+    /// Agent rollups per project, sorted by project name
+    ///
+    /// Gone agents are left out: the line describes the live swarm.
+    pub fn project_summaries(&self) -> Vec<ProjectSummary> {
+        let mut by_project: BTreeMap<&str, ProjectSummary> = BTreeMap::new();
+        for agent in self.agents.values().filter(|a| !a.is_gone()) {
+            let entry =
+                by_project
+                    .entry(agent.project.as_str())
+                    .or_insert_with(|| ProjectSummary {
+                        project: agent.project.clone(),
+                        total: 0,
+                        local: 0,
+                        sprite: 0,
+                        attention: 0,
+                        turns: 0,
+                    });
+            entry.total += 1;
+            if self.sprite_agent_ids.contains(&agent.pane_id) {
+                entry.sprite += 1;
+            } else {
+                entry.local += 1;
+            }
+            if matches!(agent.status, Status::Attention(_)) {
+                entry.attention += 1;
+            }
+            entry.turns += agent.turns_completed;
+        }
+        by_project.into_values().collect()
+    }
+
+    /// Unresolved tool failures grouped by normalised error, largest first
+    pub fn failure_groups(&self) -> Vec<FailureGroup> {
+        failures::group_failures(self.agents.values())
+    }
+
+    /// Agent counts per socket source, sorted by label
+    ///
+    /// Empty unless agents arrived on more than one `--socket`.
+    pub fn agents_by_socket(&self) -> Vec<(&str, usize)> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for agent in self.agents.values() {
+            if let Some(ref socket) = agent.socket {
+                *counts.entry(socket.as_str()).or_default() += 1;
+            }
+        }
+        counts.into_iter().collect()
+    }
+
+    /// Context warning level for an agent (Normal if it never reported context)
+    pub fn context_level(&self, agent: &Agent) -> ContextLevel {
+        match agent.context_remaining() {
+            Some(left) if left < self.context_critical_percent => ContextLevel::Critical,
+            Some(left) if left < self.context_warn_percent => ContextLevel::Warn,
+            _ => ContextLevel::Normal,
+        }
+    }
+
+    /// Agents with the most context used, highest first (candidates for `/compact`)
+    ///
+    /// Agents without context data are left out.
+    pub fn top_context_usage(&self, limit: usize) -> Vec<&Agent> {
+        let mut agents: Vec<(&Agent, f64)> = self
+            .agents
+            .values()
+            .filter_map(|a| a.context_remaining().map(|left| (a, left)))
+            .collect();
+        agents.sort_by(|(a, left_a), (b, left_b)| {
+            left_a
+                .total_cmp(left_b)
+                .then_with(|| a.pane_id.cmp(&b.pane_id))
+        });
+        agents.into_iter().take(limit).map(|(a, _)| a).collect()
+    }
+
     /// Map a session ID to a team name for cross-event correlation
     pub fn map_session_to_team(&mut self, session_id: String, team_name: String) {
         self.session_to_team.insert(session_id, team_name);
@@ -394,11 +1196,47 @@ impl AppState {
         self.session_to_team.get(session_id).map(|s| s.as_str())
     }
 
-    /// Periodically scan ~/.claude/teams/ and enrich agents with discovered team membership
+    /// Give agents the team their session was seen with on teammate events
+    ///
+    /// Overrides filesystem-derived teams but never the agent's own hook
+    /// payload (see [`TeamSource`]). Returns the number of agents updated.
+    pub fn apply_session_teams(&mut self) -> usize {
+        let mut updated = 0;
+        for agent in self.agents.values_mut() {
+            if agent.team_source == Some(TeamSource::Hook) {
+                continue;
+            }
+            let Some(team) = agent
+                .session_id
+                .as_ref()
+                .and_then(|sid| self.session_to_team.get(sid))
+            else {
+                continue;
+            };
+            if agent.team_name.as_ref() != Some(team) {
+                tracing::debug!(
+                    pane_id = %agent.pane_id,
+                    team = %team,
+                    replaced = ?agent.team_name,
+                    "Enriched agent with team from session correlation"
+                );
+                agent.team_name = Some(team.clone());
+                updated += 1;
+            }
+            agent.team_source = Some(TeamSource::Session);
+        }
+        updated
+    }
+
+    /// Enrich agents with team membership from sessions and ~/.claude/teams/
     ///
-    /// Throttled to every 30s. Matches agents to teams by team_agent_name or team_agent_id
+    /// Session correlation runs on every call, so an agent joins its team on
+    /// the first team-tagged event. The filesystem scan is throttled to every
+    /// 30s and matches agents by team_agent_name, team_agent_id or tmux pane
     /// from `~/.claude/teams/*/config.json` members.
     pub fn refresh_team_metadata(&mut self) {
+        self.apply_session_teams();
+
         let now = current_timestamp();
         if self.last_team_scan != 0 && now - self.last_team_scan < 30 {
             return;
@@ -427,6 +1265,7 @@ impl AppState {
 
                             if name_match || id_match || pane_match {
                                 agent.team_name = Some(config.team_name.clone());
+                                agent.team_source = Some(TeamSource::Filesystem);
                                 agent.team_agent_type = Some(member.agent_type.clone());
                                 if agent.team_agent_name.is_none() {
                                     agent.team_agent_name = Some(member.name.clone());
@@ -586,6 +1425,7 @@ impl AppState {
     /// Merges filesystem tasks (ground truth) with hook-captured tasks from
     /// agent.tasks. Deduplicates by task_id (filesystem wins for status).
     /// Includes phantom agent current_task_subject as in_progress tasks.
+    /// Annotates blocker status and readiness (see `task_graph`).
     /// Sorts: blocked tasks to end, then alphabetical by subject.
     pub fn tasks_by_team(&self) -> Vec<(String, [Vec<TaskWithContext>; 3])> {
         let mut team_tasks: HashMap<String, HashMap<String, TaskWithContext>> = HashMap::new();
@@ -627,6 +1467,8 @@ impl AppState {
                         status: TaskStatus::from_str(&fs_task.status),
                         blocked_by: fs_task.blocked_by.clone(),
                         blocks: fs_task.blocks.clone(),
+                        blockers: Vec::new(),
+                        readiness: TaskReadiness::Free,
                         owner_name: String::new(),
                         team_name: Some(team_name.clone()),
                         description: fs_task.description.clone(),
@@ -654,6 +1496,8 @@ impl AppState {
                             status: task_info.status,
                             blocked_by: task_info.blocked_by.clone(),
                             blocks: task_info.blocks.clone(),
+                            blockers: Vec::new(),
+                            readiness: TaskReadiness::Free,
                             owner_name: agent.team_agent_name.clone().unwrap_or_default(),
                             team_name: Some(team_name.clone()),
                             description: String::new(),
@@ -685,6 +1529,8 @@ impl AppState {
                                 status: TaskStatus::InProgress,
                                 blocked_by: Vec::new(),
                                 blocks: Vec::new(),
+                                blockers: Vec::new(),
+                                readiness: TaskReadiness::Free,
                                 owner_name: agent.team_agent_name.clone().unwrap_or_default(),
                                 team_name: Some(team_name.clone()),
                                 description: String::new(),
@@ -699,7 +1545,8 @@ impl AppState {
         // Step 4: Group by team, sort within each status column
         let mut result: Vec<(String, [Vec<TaskWithContext>; 3])> = team_tasks
             .into_iter()
-            .map(|(team_name, tasks_map)| {
+            .map(|(team_name, mut tasks_map)| {
+                task_graph::annotate(&mut tasks_map);
                 let mut columns: [Vec<TaskWithContext>; 3] = Default::default();
                 for task in tasks_map.into_values() {
                     let col = match task.status {
@@ -712,8 +1559,8 @@ impl AppState {
                 // Sort each column: blocked tasks to end, then alphabetical
                 for col in &mut columns {
                     col.sort_by(|a, b| {
-                        let a_blocked = !a.blocked_by.is_empty();
-                        let b_blocked = !b.blocked_by.is_empty();
+                        let a_blocked = a.readiness.is_blocked();
+                        let b_blocked = b.readiness.is_blocked();
                         a_blocked
                             .cmp(&b_blocked)
                             .then_with(|| a.subject.cmp(&b.subject))
@@ -734,7 +1581,8 @@ impl AppState {
     }
 }
 
-fn current_timestamp() -> i64 {
+/// Current Unix time in seconds
+pub fn current_timestamp() -> i64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs() as i64)
@@ -743,7 +1591,7 @@ fn current_timestamp() -> i64 {
 
 #[cfg(test)]
 mod tests {
-    use super::event_processing::infer_role_from_description;
+    use super::event_processing::{infer_role_from_description, EventOutcome};
     use super::task_discovery::FsTask;
     use super::*;
     use crate::event::HookEvent;
@@ -753,45 +1601,10 @@ mod tests {
         HookEvent {
             event: event.to_string(),
             status: status.to_string(),
-            attention_type: None,
             pane_id: pane_id.to_string(),
             project: project.to_string(),
             timestamp: current_timestamp(),
-            session_id: None,
-            tool_name: None,
-            tool_input: None,
-            tool_use_id: None,
-            reason: None,
-            notification_type: None,
-            notification_title: None,
-            error: None,
-            is_interrupt: None,
-            prompt: None,
-            subagent_id: None,
-            description: None,
-            subagent_duration_ms: None,
-            source: crate::event::EventSource::Local,
-            context_window: None,
-            agent_type: None,
-            permission_mode: None,
-            cwd: None,
-            transcript_path: None,
-            team_name: None,
-            team_agent_id: None,
-            team_agent_name: None,
-            team_agent_type: None,
-            claude_code_version: None,
-            model: None,
-            session_source: None,
-            stop_hook_active: None,
-            agent_transcript_path: None,
-            trigger: None,
-            effort_level: None,
-            teammate_name: None,
-            task_id: None,
-            task_subject: None,
-            task_description: None,
-            tool_response: None,
+            ..HookEvent::default()
         }
     }
 
@@ -800,7 +1613,7 @@ mod tests {
         let mut state = AppState::new();
         let event = make_event("SessionStart", "working", "%0", "test-project");
 
-        let changed = state.process_event(event);
+        let changed = state.process_event(event).changed();
 
         assert!(changed);
         assert_eq!(state.agents.len(), 1);
@@ -826,6 +1639,24 @@ mod tests {
         assert_eq!(state.status_counts[0], 1); // Attention (includes Waiting) now 1
     }
 
+    #[test]
+    fn test_hidden_compacting_folds_into_working() {
+        let mut state = AppState::new();
+        state.columns = vec![StatusColumn::Attention, StatusColumn::Working];
+
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "test"));
+        let _ = state.process_event(make_event("PreCompact", "compacting", "%1", "test"));
+
+        assert_eq!(
+            state.display_column(&Status::Compacting),
+            StatusColumn::Working
+        );
+        assert_eq!(
+            state.column_counts(),
+            vec![(StatusColumn::Attention, 0), (StatusColumn::Working, 2)]
+        );
+    }
+
     #[test]
     fn test_agent_eviction_at_capacity() {
         use crate::config::MAX_AGENTS;
@@ -860,6 +1691,108 @@ mod tests {
         assert!(!state.agents.contains_key("%0"));
     }
 
+    #[test]
+    fn test_eviction_policies_keep_counts_consistent() {
+        let fill = |eviction: EvictionPolicy| {
+            let mut state = AppState::new();
+            state.max_agents = 3;
+            state.eviction = eviction;
+            let mut waiting = make_event("Stop", "attention", "%waiting", "p");
+            waiting.attention_type = Some("waiting".to_string());
+            let _ = state.process_event(waiting);
+            let _ = state.process_event(make_event("PreToolUse", "working", "%old", "p"));
+            let _ = state.process_event(make_event("PreToolUse", "working", "%mid", "p"));
+            // %old has the oldest activity; %waiting is newer than it
+            state.agents.get_mut("%old").unwrap().last_update -= 100;
+            state.agents.get_mut("%waiting").unwrap().last_update -= 50;
+            let _ = state.process_event(make_event("PreToolUse", "working", "%new", "p"));
+            let counted: usize = state.status_counts.iter().sum();
+            assert_eq!(counted, state.agents.len(), "{eviction:?}");
+            state
+        };
+
+        let state = fill(EvictionPolicy::OldestWaiting);
+        assert!(!state.agents.contains_key("%waiting"));
+        assert!(state.agents.contains_key("%new"));
+
+        let state = fill(EvictionPolicy::OldestAny);
+        assert!(!state.agents.contains_key("%old"));
+        assert!(state.agents.contains_key("%waiting"));
+
+        let mut state = fill(EvictionPolicy::Never);
+        assert_eq!(state.agents.len(), 3);
+        assert!(!state.agents.contains_key("%new"));
+        assert!(state
+            .health_warning
+            .as_deref()
+            .is_some_and(|w| w.contains("max_agents")));
+
+        // Cleared once there is room again
+        state.agents.remove("%mid");
+        state.tick();
+        assert!(state.health_warning.is_none());
+    }
+
+    #[test]
+    fn test_lifetime_survives_new_session() {
+        let mut state = AppState::new();
+        let mut first = make_event("SessionStart", "working", "%1", "proj");
+        first.timestamp -= 3600;
+        let _ = state.process_event(first);
+        let mut other = make_event("SessionStart", "working", "%2", "proj");
+        other.timestamp -= 600;
+        let _ = state.process_event(other);
+
+        // /clear starts a new session in the same pane
+        let _ = state.process_event(make_event("SessionStart", "working", "%1", "proj"));
+        let now = current_timestamp();
+        let agent = &state.agents["%1"];
+        assert!(agent.elapsed_secs() < 60, "session clock restarted");
+        assert!((3600..3602).contains(&agent.lifetime_secs(now)));
+        let average = state.average_lifetime_secs(now).unwrap();
+        assert!((2100..2102).contains(&average));
+    }
+
+    #[test]
+    fn test_permission_mode_counts_lead_with_bypass() {
+        let mut state = AppState::new();
+        for (pane, mode) in [
+            ("%1", "plan"),
+            ("%2", "plan"),
+            ("%3", "bypassPermissions"),
+            ("%4", "default"),
+        ] {
+            let mut event = make_event("SessionStart", "working", pane, "proj");
+            event.permission_mode = Some(mode.to_string());
+            let _ = state.process_event(event);
+        }
+        let _ = state.process_event(make_event("SessionStart", "working", "%5", "proj"));
+        assert_eq!(
+            state.permission_mode_counts(),
+            vec![("bypassPermissions", 1), ("plan", 2)]
+        );
+        assert!(state.agents["%3"].bypasses_permissions());
+        assert_eq!(state.agents["%4"].notable_permission_mode(), None);
+    }
+
+    #[test]
+    fn test_attention_by_project_most_blocked_first() {
+        let mut state = AppState::new();
+        for (pane, status, project) in [
+            ("%1", "attention", "api"),
+            ("%2", "attention", "auth"),
+            ("%3", "attention", "auth"),
+            ("%4", "working", "web"),
+            ("%5", "attention", "web"),
+        ] {
+            let _ = state.process_event(make_event("SessionStart", status, pane, project));
+        }
+        assert_eq!(
+            state.attention_by_project(),
+            vec![("auth", 2), ("api", 1), ("web", 1)]
+        );
+    }
+
     #[test]
     fn test_session_end_removes_agent() {
         let mut state = AppState::new();
@@ -911,6 +1844,241 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_activity_rolls_and_sums_by_project() {
+        let mut agent = Agent::new("%0".to_string(), "a".to_string());
+        agent.record_activity(1000);
+        agent.record_activity(1005);
+        assert_eq!(agent.activity[ACTIVITY_BUCKETS - 1], 2);
+
+        // Two buckets later the counts have shifted left
+        agent.record_activity(1020);
+        assert_eq!(agent.activity[ACTIVITY_BUCKETS - 3..], [2, 0, 1]);
+
+        // A long silence empties the window
+        agent.roll_activity(1020 + 10 * ACTIVITY_BUCKETS as i64);
+        assert!(agent.activity.iter().all(|&n| n == 0));
+
+        let mut state = AppState::new();
+        for (pane, project) in [("%1", "a"), ("%2", "a"), ("%3", "b")] {
+            let _ = state.process_event(make_event("PreToolUse", "working", pane, project));
+        }
+        let grouped = state.activity_by_project();
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].project, "a");
+        assert_eq!(grouped[0].agent_count, 2);
+        assert_eq!(grouped[0].activity.iter().sum::<u64>(), 2);
+        assert_eq!(grouped[1].activity.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn test_heartbeat_only_bumps_last_update() {
+        let mut state = AppState::new();
+        // Unknown pane: ignored, no agent created
+        assert_eq!(
+            state.process_event(make_event("Heartbeat", "working", "%9", "test")),
+            EventOutcome::AgentMissing
+        );
+        assert!(state.agents.is_empty());
+
+        let _ = state.process_event(make_event("PostToolUse", "working", "%0", "test"));
+        let events_before = state.events.len();
+        let agent = state.agents.get_mut("%0").unwrap();
+        agent.status = Status::Attention(AttentionType::Waiting);
+        agent.last_update = 0;
+
+        assert_eq!(
+            state.process_event(make_event("Heartbeat", "working", "%0", "test")),
+            EventOutcome::NoChange
+        );
+        let agent = &state.agents["%0"];
+        assert!(agent.last_update > 0);
+        assert_eq!(agent.status, Status::Attention(AttentionType::Waiting));
+        assert_eq!(state.events.len(), events_before);
+    }
+
+    #[test]
+    fn test_project_summaries_split_local_and_sprite() {
+        let mut state = AppState::new();
+        for (pane, project) in [("%1", "api"), ("%2", "api"), ("s1", "api"), ("%3", "web")] {
+            let _ = state.process_event(make_event("PreToolUse", "working", pane, project));
+        }
+        state.sprite_agent_ids.insert("s1".to_string());
+        let agent = state.agents.get_mut("%2").unwrap();
+        agent.status = Status::Attention(AttentionType::Permission);
+        agent.turns_completed = 3;
+        state.agents.get_mut("s1").unwrap().turns_completed = 2;
+        state.agents.get_mut("%3").unwrap().gone_since = Some(0);
+
+        let summaries = state.project_summaries();
+        assert_eq!(
+            summaries,
+            vec![ProjectSummary {
+                project: "api".to_string(),
+                total: 3,
+                local: 2,
+                sprite: 1,
+                attention: 1,
+                turns: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_agents_grouped_by_socket_source() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("PreToolUse", "working", "%0", "a"));
+        assert!(state.agents_by_socket().is_empty());
+
+        for (pane, socket) in [("%1", "work"), ("%2", "work"), ("%3", "personal")] {
+            let mut event = make_event("PreToolUse", "working", pane, "a");
+            event.socket = Some(socket.to_string());
+            let _ = state.process_event(event);
+        }
+        assert_eq!(state.agents_by_socket(), vec![("personal", 1), ("work", 2)]);
+
+        let filter = AgentFilter {
+            attention_only: true,
+            query: "pers",
+        };
+        let mut agent = state.agents["%3"].clone();
+        agent.status = Status::Attention(AttentionType::Input);
+        assert!(filter.matches(&agent));
+    }
+
+    #[test]
+    fn test_event_log_records_transition_reasons() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("UserPromptSubmit", "working", "%3", "app"));
+
+        let mut ask = make_event("PreToolUse", "working", "%3", "app");
+        ask.tool_name = Some("AskUserQuestion".to_string());
+        let outcome = state.process_event(ask);
+        let EventOutcome::Updated(Some(transition)) = outcome else {
+            panic!("expected a transition, got {outcome:?}");
+        };
+        assert_eq!(
+            transition.describe(),
+            "Working→Attention(Input) [AskUserQuestion]"
+        );
+        assert_eq!(state.events[0].transition, Some(transition));
+
+        // Same status again: updated, but no transition logged
+        let outcome = state.process_event(make_event("Notification", "attention", "%3", "app"));
+        assert_eq!(outcome, EventOutcome::Updated(None));
+
+        // Idle timeouts are logged with their own reason
+        let _ = state.process_event(make_event("UserPromptSubmit", "working", "%3", "app"));
+        let agent = state.agents.get_mut("%3").unwrap();
+        agent.in_response = false;
+        agent.current_tool = None;
+        agent.last_update = current_timestamp() - state.idle_timeout_secs - 1;
+        state.tick();
+        assert_eq!(state.events[0].event.event, "IdleTimeout");
+        assert_eq!(
+            state.events[0]
+                .transition
+                .as_ref()
+                .map(StatusTransition::describe),
+            Some("Working→Attention(Waiting) [idle timeout]".to_string())
+        );
+    }
+
+    #[test]
+    fn test_long_running_tool_flagged_stuck_but_stays_working() {
+        let mut state = AppState::new();
+        let mut event = make_event("PreToolUse", "working", "%4", "app");
+        event.tool_name = Some("Bash".to_string());
+        event.tool_use_id = Some("t1".to_string());
+        let _ = state.process_event(event);
+
+        state.tick();
+        assert!(!state.agents["%4"].stuck);
+
+        let agent = state.agents.get_mut("%4").unwrap();
+        agent.current_tool_since = Some(current_timestamp() - state.stuck_tool_secs - 1);
+        state.tick();
+        let agent = &state.agents["%4"];
+        assert!(agent.stuck);
+        assert_eq!(agent.status, Status::Working);
+
+        // PostToolUse clears the flag along with the tool
+        let mut event = make_event("PostToolUse", "working", "%4", "app");
+        event.tool_name = Some("Bash".to_string());
+        event.tool_use_id = Some("t1".to_string());
+        let _ = state.process_event(event);
+        let agent = &state.agents["%4"];
+        assert!(!agent.stuck);
+        assert_eq!(agent.current_tool_since, None);
+    }
+
+    #[test]
+    fn test_turns_recorded_from_prompt_to_stop() {
+        let mut state = AppState::new();
+        let mut event = make_event("UserPromptSubmit", "working", "%5", "app");
+        event.timestamp = 1000;
+        let _ = state.process_event(event);
+        for (name, timestamp) in [("PreToolUse", 1005), ("PostToolUse", 1010)] {
+            let mut event = make_event(name, "working", "%5", "app");
+            event.tool_name = Some("Read".to_string());
+            event.timestamp = timestamp;
+            let _ = state.process_event(event);
+        }
+        let mut event = make_event("Stop", "attention", "%5", "app");
+        event.timestamp = 1030;
+        let _ = state.process_event(event);
+
+        let turns = std::mem::take(&mut state.completed_turns);
+        assert_eq!(turns.len(), 1);
+        assert_eq!(turns[0].iteration, 1);
+        assert_eq!(turns[0].duration_secs, 30);
+        assert_eq!(turns[0].tool_calls, 1);
+        assert_eq!(turns[0].completion_reason, "Stop");
+
+        // A Stop without a prompt in flight is not a turn
+        let _ = state.process_event(make_event("Stop", "attention", "%5", "app"));
+        assert!(state.completed_turns.is_empty());
+        assert_eq!(state.agents["%5"].last_turn_end, Some(TurnEnd::Completed));
+
+        // Interrupting a tool ends the turn as interrupted
+        let mut event = make_event("PostToolUseFailure", "working", "%5", "app");
+        event.is_interrupt = Some(true);
+        let _ = state.process_event(event);
+        let _ = state.process_event(make_event("Stop", "attention", "%5", "app"));
+        assert_eq!(state.agents["%5"].last_turn_end, Some(TurnEnd::Interrupted));
+    }
+
+    #[test]
+    fn test_ask_user_question_options_until_answered() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "test"));
+
+        let mut event = make_event("PreToolUse", "working", "%0", "test");
+        event.tool_name = Some("AskUserQuestion".to_string());
+        event.tool_input = Some(serde_json::json!({
+            "questions": [{
+                "question": "Which database?",
+                "options": [{"label": "Postgres"}, {"label": "SQLite"}]
+            }]
+        }));
+        let _ = state.process_event(event);
+
+        let agent = &state.agents["%0"];
+        assert_eq!(agent.pending_question.as_deref(), Some("Which database?"));
+        assert_eq!(agent.pending_options, vec!["Postgres", "SQLite"]);
+
+        // Still waiting: a notification keeps the question
+        let _ = state.process_event(make_event("Notification", "attention", "%0", "test"));
+        assert!(state.agents["%0"].pending_question.is_some());
+
+        let mut event = make_event("PostToolUse", "working", "%0", "test");
+        event.tool_name = Some("AskUserQuestion".to_string());
+        let _ = state.process_event(event);
+        let agent = &state.agents["%0"];
+        assert!(agent.pending_question.is_none());
+        assert!(agent.pending_options.is_empty());
+    }
+
     #[test]
     fn test_subagent_start_does_not_override_attention() {
         let mut state = AppState::new();
@@ -988,6 +2156,58 @@ mod tests {
         assert!(teams.is_empty(), "empty state should return empty vec");
     }
 
+    #[test]
+    fn test_agents_by_team_card_sort() {
+        let mut state = AppState::new();
+        for (pane, project, last_update) in [("%0", "b", 300), ("%1", "a", 100), ("%2", "c", 200)] {
+            let mut agent = Agent::new(pane.to_string(), project.to_string());
+            agent.status = Status::Working;
+            agent.last_update = last_update;
+            state.agents.insert(pane.to_string(), agent);
+        }
+        let order = |state: &AppState| -> Vec<String> {
+            state.agents_by_team()[0]
+                .1
+                .iter()
+                .map(|a| a.pane_id.clone())
+                .collect()
+        };
+
+        assert_eq!(order(&state), ["%1", "%0", "%2"], "project: a, b, c");
+
+        state.card_sort = CardSort::Elapsed;
+        assert_eq!(order(&state), ["%1", "%2", "%0"], "elapsed: oldest first");
+
+        state.card_sort = CardSort::Recent;
+        assert_eq!(order(&state), ["%0", "%2", "%1"], "recent: newest first");
+
+        // Attention priority still wins over the secondary key
+        state.agents.get_mut("%0").unwrap().status = Status::Attention(AttentionType::Waiting);
+        state.agents.get_mut("%2").unwrap().status = Status::Attention(AttentionType::Permission);
+        assert_eq!(order(&state), ["%2", "%0", "%1"]);
+    }
+
+    #[test]
+    fn test_pinned_agents_sort_first() {
+        let mut state = AppState::new();
+        for (pane, team) in [("%1", Some("alpha")), ("%2", None), ("%3", None)] {
+            let mut agent = Agent::new(pane.to_string(), "proj".to_string());
+            agent.status = Status::Working;
+            agent.team_name = team.map(str::to_string);
+            state.agents.insert(pane.to_string(), agent);
+        }
+        // Pinned beats attention and pulls "Independent" above other teams
+        state.agents.get_mut("%2").unwrap().status = Status::Attention(AttentionType::Permission);
+        assert!(state.toggle_pinned("%3"));
+        let teams = state.agents_by_team();
+        assert_eq!(teams[0].0, "Independent");
+        let panes: Vec<&str> = teams[0].1.iter().map(|a| a.pane_id.as_str()).collect();
+        assert_eq!(panes, ["%3", "%2"]);
+
+        assert!(!state.toggle_pinned("%3"));
+        assert_eq!(state.agents_by_team()[0].0, "alpha");
+    }
+
     // v1.3: Tests for parent-child tracking and role inference from description
 
     #[test]
@@ -1039,6 +2259,33 @@ mod tests {
         assert_eq!(subagent.role, AgentRole::Planner); // "Explore" -> Planner
     }
 
+    #[test]
+    fn test_nested_subagent_links_to_spawning_subagent() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "test"));
+
+        // Main agent launches sub-a, which launches sub-b
+        let spawn = |state: &mut AppState, from: Option<&str>, id: &str| {
+            let mut event = make_event("PreToolUse", "working", "%0", "test");
+            event.tool_name = Some("Task".to_string());
+            event.subagent_id = from.map(str::to_string);
+            let _ = state.process_event(event);
+            let mut event = make_event("SubagentStart", "working", "%0", "test");
+            event.subagent_id = Some(id.to_string());
+            let _ = state.process_event(event);
+        };
+        spawn(&mut state, None, "sub-a");
+        spawn(&mut state, Some("sub-a"), "sub-b");
+
+        let agent = state.agents.get("%0").unwrap();
+        assert_eq!(agent.subagents[0].parent_subagent_id, None);
+        assert_eq!(
+            agent.subagents[1].parent_subagent_id.as_deref(),
+            Some("sub-a")
+        );
+        assert_eq!(agent.subagents[1].depth, 1);
+    }
+
     // =========================================================================
     // v0.9.16 feature tests
     // =========================================================================
@@ -1085,6 +2332,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_notification_elicitation_dialog_becomes_attention_input() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "test"));
+
+        // An MCP elicitation asks the user a question, like idle_prompt
+        let mut event = make_event("Notification", "attention", "%0", "test");
+        event.attention_type = Some("notification".to_string());
+        event.notification_type = Some("elicitation_dialog".to_string());
+        let _ = state.process_event(event);
+
+        let agent = state.agents.get("%0").unwrap();
+        assert!(
+            matches!(agent.status, Status::Attention(AttentionType::Input)),
+            "elicitation_dialog notification should become Attention(Input), got {:?}",
+            agent.status
+        );
+    }
+
+    #[test]
+    fn test_notification_auth_success_keeps_previous_status() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "test"));
+
+        // auth_success is informational: no attention, counts unchanged
+        let mut event = make_event("Notification", "attention", "%0", "test");
+        event.attention_type = Some("notification".to_string());
+        event.notification_type = Some("auth_success".to_string());
+        assert!(is_auth_success(&event));
+        let _ = state.process_event(event);
+
+        let agent = state.agents.get("%0").unwrap();
+        assert!(
+            matches!(agent.status, Status::Working),
+            "auth_success notification should leave the agent Working, got {:?}",
+            agent.status
+        );
+        assert_eq!(
+            agent.last_notification_type.as_deref(),
+            Some("auth_success")
+        );
+        assert_eq!(state.status_counts, [0, 1, 0]);
+    }
+
     #[test]
     fn test_post_tool_use_failure_error_forwarding() {
         let mut state = AppState::new();
@@ -1233,6 +2524,44 @@ mod tests {
         assert_eq!(agent.context_total_tokens, Some(150000));
     }
 
+    #[test]
+    fn test_context_levels_and_top_usage() {
+        let mut state = AppState::new();
+        for (pane, remaining) in [("%0", Some(8.0)), ("%1", Some(15.0)), ("%2", Some(60.0))] {
+            let mut event = make_event("PreToolUse", "working", pane, "test");
+            event.context_window = remaining.map(|r| crate::event::ContextWindow {
+                used_percentage: None,
+                remaining_percentage: Some(r),
+                total_tokens: None,
+            });
+            let _ = state.process_event(event);
+        }
+        // Older Claude Code reports usage only
+        let mut event = make_event("PreToolUse", "working", "%3", "test");
+        event.context_window = Some(crate::event::ContextWindow {
+            used_percentage: Some(50.0),
+            remaining_percentage: None,
+            total_tokens: None,
+        });
+        let _ = state.process_event(event);
+        // No context data at all (sprites, very old Claude Code)
+        let _ = state.process_event(make_event("PreToolUse", "working", "%4", "test"));
+
+        let level = |pane: &str| state.context_level(&state.agents[pane]);
+        assert_eq!(level("%0"), ContextLevel::Critical);
+        assert_eq!(level("%1"), ContextLevel::Warn);
+        assert_eq!(level("%2"), ContextLevel::Normal);
+        assert_eq!(level("%4"), ContextLevel::Normal);
+
+        let top: Vec<&str> = state
+            .top_context_usage(3)
+            .iter()
+            .map(|a| a.pane_id.as_str())
+            .collect();
+        assert_eq!(top, vec!["%0", "%1", "%3"]);
+        assert_eq!(state.top_context_usage(10).len(), 4);
+    }
+
     #[test]
     fn test_effort_level_captured_from_event() {
         let mut state = AppState::new();
@@ -1274,6 +2603,49 @@ mod tests {
         assert_eq!(state.get_team_for_session("session-unknown"), None);
     }
 
+    #[test]
+    fn test_teammate_event_assigns_session_team_immediately() {
+        let mut state = AppState::new();
+        let mut event = make_event("PreToolUse", "working", "%1", "proj");
+        event.session_id = Some("s1".to_string());
+        let _ = state.process_event(event);
+        let mut event = make_event("PreToolUse", "working", "%2", "proj");
+        event.session_id = Some("s2".to_string());
+        let _ = state.process_event(event);
+        // %2 was matched by the filesystem scan to a stale team
+        let agent = state.agents.get_mut("%2").unwrap();
+        agent.team_name = Some("old-team".to_string());
+        agent.team_source = Some(TeamSource::Filesystem);
+
+        for sid in ["s1", "s2"] {
+            let mut event = make_event("TeammateIdle", "working", "%9", "proj");
+            event.session_id = Some(sid.to_string());
+            event.team_name = Some("my-team".to_string());
+            event.teammate_name = Some("researcher".to_string());
+            let _ = state.process_event(event);
+        }
+        assert_eq!(state.agents["%1"].team_name.as_deref(), Some("my-team"));
+        assert_eq!(
+            state.agents["%2"].team_name.as_deref(),
+            Some("my-team"),
+            "session correlation beats the filesystem"
+        );
+    }
+
+    #[test]
+    fn test_own_hook_team_beats_session_correlation() {
+        let mut state = AppState::new();
+        let mut event = make_event("PreToolUse", "working", "%1", "proj");
+        event.session_id = Some("s1".to_string());
+        event.team_name = Some("env-team".to_string());
+        let _ = state.process_event(event);
+
+        state.map_session_to_team("s1".to_string(), "other-team".to_string());
+        assert_eq!(state.apply_session_teams(), 0);
+        assert_eq!(state.agents["%1"].team_name.as_deref(), Some("env-team"));
+        assert_eq!(state.agents["%1"].team_source, Some(TeamSource::Hook));
+    }
+
     #[test]
     fn test_phantom_agent_creation() {
         let mut state = AppState::new();
@@ -1496,6 +2868,77 @@ mod tests {
     // Identity-based navigation tests
     // =========================================================================
 
+    #[test]
+    fn test_attention_filter_composes_with_query() {
+        let mut state = AppState::new();
+        let _ = state.process_event(make_event("SessionStart", "working", "%0", "auth-api"));
+        let _ = state.process_event(make_event("Stop", "idle", "%1", "auth-web"));
+        let _ = state.process_event(make_event("Stop", "idle", "%2", "billing"));
+
+        let all = AgentFilter::default();
+        assert_eq!(state.agents.values().filter(|a| all.matches(a)).count(), 3);
+
+        let attention = AgentFilter {
+            attention_only: true,
+            query: "",
+        };
+        let teams = state.agents_by_team_filtered(&attention);
+        assert_eq!(teams[0].1.len(), 2, "working agent hidden");
+
+        let narrowed = AgentFilter {
+            attention_only: true,
+            query: "AUTH",
+        };
+        let teams = state.agents_by_team_filtered(&narrowed);
+        assert_eq!(teams[0].1.len(), 1);
+        assert_eq!(teams[0].1[0].pane_id, "%1");
+
+        // Navigation stays within the filtered set
+        state.selected_pane_id = Some("%1".to_string());
+        state.next_agent_filtered(&narrowed);
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%1"));
+    }
+
+    #[test]
+    fn test_agents_grouped_by_model_and_permission_mode() {
+        let mut state = AppState::new();
+        for (pane, model, mode) in [
+            ("%1", Some("opus"), Some("bypassPermissions")),
+            ("%2", None, Some("plan")),
+            ("%3", Some("opus"), None),
+        ] {
+            let mut agent = Agent::new(pane.to_string(), "p".to_string());
+            agent.model = model.map(String::from);
+            agent.permission_mode = mode.map(String::from);
+            state.agents.insert(pane.to_string(), agent);
+        }
+        let names = |state: &AppState| {
+            state
+                .agents_grouped(&AgentFilter::default())
+                .into_iter()
+                .map(|(group, agents)| (group, agents.len()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(&state), [("Independent".to_string(), 3)]);
+        state.group_by = GroupBy::Model;
+        assert_eq!(
+            names(&state),
+            [("opus".to_string(), 2), ("unknown".to_string(), 1)]
+        );
+        state.group_by = GroupBy::PermissionMode;
+        assert_eq!(
+            names(&state),
+            [
+                ("bypassPermissions".to_string(), 1),
+                ("plan".to_string(), 1),
+                ("default".to_string(), 1)
+            ],
+            "agents without a mode are listed last"
+        );
+        assert_eq!(state.display_group(&state.agents["%3"]), "default");
+    }
+
     #[test]
     fn test_next_agent_identity_based() {
         let mut state = AppState::new();
@@ -1604,4 +3047,302 @@ mod tests {
         // selected_agent on empty returns None
         assert!(state.selected_agent().is_none(), "no agent in empty state");
     }
+
+    #[test]
+    fn test_flapping_agent_notifies_once() {
+        let mut state = AppState::new();
+        let note = crate::event::DesktopNotification {
+            title: "Claude Done".to_string(),
+            message: "proj: Complete".to_string(),
+            sound: None,
+            urgent: false,
+        };
+
+        for _ in 0..3 {
+            let _ = state.process_event(make_event("PreToolUse", "working", "%1", "proj"));
+            let mut stop = make_event("Stop", "attention", "%1", "proj");
+            stop.attention_type = Some("waiting".to_string());
+            stop.desktop_notification = Some(note.clone());
+            let _ = state.process_event(stop);
+        }
+
+        let later = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        assert_eq!(state.notifications.flush(later), Some(note));
+        assert!(state.notifications.flush(later).is_none());
+    }
+
+    #[test]
+    fn test_compaction_duration_feeds_estimate() {
+        let mut state = AppState::new();
+        assert!(state.typical_compaction_secs().is_none());
+
+        let _ = state.process_event(make_event("PreCompact", "compacting", "%1", "test"));
+        let agent = state.agents.get_mut("%1").unwrap();
+        assert!(agent.compaction_started_at.is_some());
+        // Pretend compaction started 40s ago
+        agent.compaction_started_at = Some(current_timestamp() - 40);
+        assert_eq!(
+            agent
+                .compaction_progress_at(current_timestamp(), None)
+                .as_deref(),
+            Some("40s elapsed")
+        );
+
+        let _ = state.process_event(make_event("PostToolUse", "working", "%1", "test"));
+        assert!(state.agents["%1"].compaction_started_at.is_none());
+        assert_eq!(state.typical_compaction_secs(), Some(40));
+
+        let _ = state.process_event(make_event("PreCompact", "compacting", "%1", "test"));
+        let typical = state.typical_compaction_secs();
+        let agent = state.agents.get_mut("%1").unwrap();
+        agent.compaction_started_at = Some(current_timestamp() - 10);
+        assert_eq!(
+            agent
+                .compaction_progress_at(current_timestamp(), typical)
+                .as_deref(),
+            Some("~30s remaining")
+        );
+    }
+
+    #[test]
+    fn test_tick_uses_per_project_idle_timeout() {
+        let mut config = crate::config::TimeoutConfig::default();
+        config.per_project.insert("build-*".to_string(), 180);
+        config.per_project.insert("build-slow*".to_string(), 600);
+        config.per_project.insert("chat-*".to_string(), 30);
+        let rules = IdleTimeoutRules::compile(&config);
+        assert_eq!(rules.for_project("build-api"), Some(180));
+        // Most specific glob wins
+        assert_eq!(rules.for_project("build-slow-ci"), Some(600));
+        assert_eq!(rules.for_project("other"), None);
+
+        let mut state = AppState::with_timeouts(60, 3600);
+        state.idle_rules = rules;
+        for (pane, project) in [("%1", "build-api"), ("%2", "chat-ui"), ("%3", "other")] {
+            let _ = state.process_event(make_event("PostToolUse", "working", pane, project));
+            state.agents.get_mut(pane).unwrap().last_update = current_timestamp() - 90;
+        }
+
+        state.tick();
+        // 90s idle: past chat (30s) and global (60s), not build (180s)
+        assert_eq!(state.agents["%1"].status, Status::Working);
+        assert_eq!(
+            state.agents["%2"].status,
+            Status::Attention(AttentionType::Waiting)
+        );
+        assert_eq!(
+            state.agents["%3"].status,
+            Status::Attention(AttentionType::Waiting)
+        );
+    }
+
+    #[test]
+    fn test_fleet_pulse_counts_working_agents_within_window() {
+        let mut state = AppState::new();
+        state.fleet_pulse_ticks = 3;
+        let _ = state.process_event(make_event("PostToolUse", "working", "%1", "p"));
+        let _ = state.process_event(make_event("PostToolUse", "working", "%2", "p"));
+        state.tick();
+        let _ = state.process_event(make_event("Stop", "attention", "%2", "p"));
+        for _ in 0..3 {
+            state.tick();
+        }
+        assert_eq!(state.fleet_pulse, [1, 1, 1]);
+    }
+
+    #[test]
+    fn test_stale_agent_goes_gone_then_revives_or_is_removed() {
+        let mut state = AppState::with_timeouts(60, 300);
+        state.gone_grace_secs = 120;
+        for pane in ["%1", "%2"] {
+            let _ = state.process_event(make_event("PostToolUse", "working", pane, "p"));
+            state.agents.get_mut(pane).unwrap().last_update = current_timestamp() - 301;
+        }
+
+        state.tick();
+        assert!(state.agents["%1"].is_gone());
+        assert!(state.agents["%2"].is_gone());
+        // Gone agents leave the header counts
+        assert_eq!(state.status_counts, [0; NUM_COLUMNS]);
+
+        // A hook event revives in place, keeping the card's history
+        state.agents.get_mut("%1").unwrap().total_tool_calls = 7;
+        let _ = state.process_event(make_event("PreToolUse", "working", "%1", "p"));
+        assert!(!state.agents["%1"].is_gone());
+        assert_eq!(state.agents["%1"].total_tool_calls, 7);
+        assert_eq!(state.status_counts[1], 1);
+
+        // The other one outlives its grace period and is removed
+        state.agents.get_mut("%2").unwrap().gone_since = Some(current_timestamp() - 121);
+        state.tick();
+        assert!(!state.agents.contains_key("%2"));
+        assert_eq!(state.status_counts[1], 1);
+    }
+
+    #[test]
+    fn test_sweep_removes_gone_and_long_idle_agents_only() {
+        let mut state = AppState::with_timeouts(60, 300);
+        state.sweep_idle_secs = 600;
+        let _ = state.process_event(make_event("PostToolUse", "working", "%1", "busy"));
+        for (pane, project) in [("%2", "fresh"), ("%3", "idle"), ("%4", "gone")] {
+            let mut stop = make_event("Stop", "attention", pane, project);
+            stop.attention_type = Some("waiting".to_string());
+            let _ = state.process_event(stop);
+        }
+        let mut permission = make_event("PermissionRequest", "attention", "%5", "asking");
+        permission.attention_type = Some("permission".to_string());
+        let _ = state.process_event(permission);
+        let long_ago = current_timestamp() - 601;
+        for pane in ["%3", "%5"] {
+            state.agents.get_mut(pane).unwrap().last_update = long_ago;
+        }
+        state.agents.get_mut("%4").unwrap().last_update = long_ago - 1;
+        state.agents.get_mut("%4").unwrap().gone_since = Some(current_timestamp());
+        state.status_counts[0] -= 1; // %4 left the counts when it went gone
+        state.selected_pane_id = Some("%3".to_string());
+
+        let candidates = state.sweep_candidates();
+        assert_eq!(candidates, vec!["%4".to_string(), "%3".to_string()]);
+
+        // %3 reports again before the sweep is confirmed: it stays
+        let _ = state.process_event(make_event("UserPromptSubmit", "working", "%3", "idle"));
+        let removed = state.sweep(&candidates);
+        assert_eq!(removed, vec!["gone (%4)".to_string()]);
+        assert!(state.agents.contains_key("%3"));
+        assert!(!state.agents.contains_key("%4"));
+        assert_eq!(state.agents.len(), 4);
+        let counted: usize = state.status_counts.iter().sum();
+        assert_eq!(counted, 4, "counts untouched for the gone agent");
+    }
+
+    #[test]
+    fn test_pending_permissions_grouped_by_tool() {
+        let mut state = AppState::new();
+        for (pane, tool) in [("%1", "Write"), ("%2", "Bash"), ("%3", "Write")] {
+            let mut permission = make_event("PermissionRequest", "attention", pane, "proj");
+            permission.attention_type = Some("permission".to_string());
+            permission.tool_name = Some(tool.to_string());
+            let _ = state.process_event(permission);
+        }
+        let _ = state.process_event(make_event("PreToolUse", "working", "%4", "proj"));
+
+        assert_eq!(
+            state.pending_permission_tools(),
+            [("Write".to_string(), 2), ("Bash".to_string(), 1)]
+        );
+        assert_eq!(state.pending_permissions(Some("Write")), ["%1", "%3"]);
+        assert_eq!(state.pending_permissions(None), ["%1", "%2", "%3"]);
+
+        let _ = state.process_event(make_event("PostToolUse", "working", "%1", "proj"));
+        assert!(state.agents["%1"].pending_permission_tool.is_none());
+        assert_eq!(state.pending_permissions(Some("Write")), ["%3"]);
+    }
+
+    #[test]
+    fn test_permission_prompt_kept_until_answered() {
+        let mut state = AppState::new();
+        let mut permission = make_event("PermissionRequest", "attention", "%1", "proj");
+        permission.attention_type = Some("permission".to_string());
+        permission.tool_name = Some("Bash".to_string());
+        permission.tool_input = Some(serde_json::json!({"command": "cargo publish"}));
+        let _ = state.process_event(permission);
+        assert_eq!(
+            state.agents["%1"].pending_permission.as_deref(),
+            Some("Bash: cargo publish")
+        );
+
+        let mut done = make_event("PostToolUse", "working", "%1", "proj");
+        done.tool_name = Some("Bash".to_string());
+        let _ = state.process_event(done);
+        assert!(state.agents["%1"].pending_permission.is_none());
+    }
+
+    #[test]
+    fn test_last_prompt_kept_as_one_line_unless_disabled() {
+        let mut state = AppState::new();
+        let mut prompt = make_event("UserPromptSubmit", "working", "%1", "proj");
+        prompt.prompt = Some("Fix the parser\n\n  then \u{1b}[31mrun\u{1b}[0m tests".to_string());
+        let _ = state.process_event(prompt);
+        assert_eq!(
+            state.agents["%1"].last_prompt.as_deref(),
+            Some("Fix the parser then run tests")
+        );
+
+        // Only the latest is kept, clipped
+        let mut long = make_event("UserPromptSubmit", "working", "%1", "proj");
+        long.prompt = Some("x".repeat(1000));
+        let _ = state.process_event(long);
+        let last = state.agents["%1"].last_prompt.clone().unwrap();
+        assert!(last.chars().count() < 1000 && last.ends_with('\u{2026}'));
+
+        let mut private = AppState::new();
+        private.capture_prompts = false;
+        let mut prompt = make_event("UserPromptSubmit", "working", "%1", "proj");
+        prompt.prompt = Some("secret plans".to_string());
+        let _ = private.process_event(prompt);
+        assert!(private.agents["%1"].last_prompt.is_none());
+    }
+
+    #[test]
+    fn test_next_attention_agent_cycles_by_priority() {
+        let mut state = AppState::new();
+        let filter = AgentFilter::default();
+        let _ = state.process_event(make_event("PreToolUse", "working", "%1", "a"));
+        assert!(!state.next_attention_agent(&filter));
+
+        let mut waiting = make_event("Stop", "attention", "%2", "b");
+        waiting.attention_type = Some("waiting".to_string());
+        let _ = state.process_event(waiting);
+        let mut permission = make_event("PermissionRequest", "attention", "%3", "c");
+        permission.attention_type = Some("permission".to_string());
+        let _ = state.process_event(permission);
+
+        state.selected_pane_id = Some("%1".to_string());
+        assert!(state.next_attention_agent(&filter));
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%3"));
+        assert!(state.next_attention_agent(&filter));
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%2"));
+        assert!(state.next_attention_agent(&filter));
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%3"));
+    }
+
+    #[test]
+    fn test_interrupted_failure_outranks_attention() {
+        let mut state = AppState::new();
+        let mut permission = make_event("PermissionRequest", "attention", "%1", "a");
+        permission.attention_type = Some("permission".to_string());
+        let _ = state.process_event(permission);
+        let mut waiting = make_event("Stop", "attention", "%2", "b");
+        waiting.attention_type = Some("waiting".to_string());
+        let _ = state.process_event(waiting);
+
+        let _ = state.process_event(make_event("PreToolUse", "working", "%3", "c"));
+        let mut failure = make_event("PostToolUseFailure", "working", "%3", "c");
+        failure.is_interrupt = Some(true);
+        let _ = state.process_event(failure);
+        let mut stop = make_event("Stop", "attention", "%3", "c");
+        stop.attention_type = Some("waiting".to_string());
+        let _ = state.process_event(stop);
+
+        let order = |state: &AppState| -> Vec<String> {
+            state.agents_by_team()[0]
+                .1
+                .iter()
+                .map(|a| a.pane_id.clone())
+                .collect()
+        };
+        assert!(state.agents["%3"].failed_attention());
+        assert_eq!(order(&state), ["%3", "%1", "%2"]);
+        state.selected_pane_id = None;
+        assert!(state.next_attention_agent(&AgentFilter::default()));
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%3"));
+
+        // The next tool call clears the failure, and with it the rank
+        let _ = state.process_event(make_event("PreToolUse", "working", "%3", "c"));
+        let mut stop = make_event("Stop", "attention", "%3", "c");
+        stop.attention_type = Some("waiting".to_string());
+        let _ = state.process_event(stop);
+        assert!(!state.agents["%3"].failed_attention());
+        assert_eq!(order(&state), ["%1", "%2", "%3"]);
+    }
 }
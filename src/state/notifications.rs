@@ -0,0 +1,206 @@
+//! Desktop notification throttling
+//!
+//! Hooks hand their notification to the TUI, which decides whether to show it:
+//! - **Cooldown**: a repeat for the same `(pane_id, attention type)` within
+//!   `cooldown` is dropped, so agents flapping Working ↔ Waiting stay quiet.
+//!   Entering a different attention type clears the pane's cooldowns.
+//! - **Coalescing**: notifications queued within `coalesce` of the first are
+//!   merged into one "N agents need attention" summary.
+
+use super::AttentionType;
+use crate::config::NotifyConfig;
+use crate::event::DesktopNotification;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// A notification waiting for the coalesce window to close
+#[derive(Debug, Clone)]
+struct Pending {
+    project: String,
+    notification: DesktopNotification,
+    queued_at: Instant,
+}
+
+/// Per-agent cooldown and burst coalescing for desktop notifications
+#[derive(Debug, Clone)]
+pub struct NotificationThrottle {
+    cooldown: Duration,
+    coalesce: Duration,
+    /// Last time each (pane_id, attention type) was notified
+    last_sent: HashMap<(String, AttentionType), Instant>,
+    /// Queued notifications, oldest first
+    pending: Vec<Pending>,
+}
+
+impl Default for NotificationThrottle {
+    fn default() -> Self {
+        Self::new(&NotifyConfig::default())
+    }
+}
+
+impl NotificationThrottle {
+    /// Create a throttle from config
+    pub fn new(config: &NotifyConfig) -> Self {
+        Self {
+            cooldown: Duration::from_secs(config.cooldown_secs),
+            coalesce: Duration::from_secs(config.coalesce_secs),
+            last_sent: HashMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Queue a notification unless the agent is still in cooldown for it
+    ///
+    /// Returns `true` if queued.
+    pub fn offer(
+        &mut self,
+        pane_id: &str,
+        project: &str,
+        attention: AttentionType,
+        notification: DesktopNotification,
+        now: Instant,
+    ) -> bool {
+        let key = (pane_id.to_string(), attention);
+        if let Some(last) = self.last_sent.get(&key) {
+            if now.duration_since(*last) < self.cooldown {
+                tracing::debug!(pane_id, "Notification suppressed (cooldown)");
+                return false;
+            }
+        }
+        self.last_sent.insert(key, now);
+        self.pending.push(Pending {
+            project: project.to_string(),
+            notification,
+            queued_at: now,
+        });
+        true
+    }
+
+    /// Agent entered a new attention type: forget cooldowns for its other types
+    pub fn attention_changed(&mut self, pane_id: &str, attention: &AttentionType) {
+        self.last_sent
+            .retain(|(pane, attn), _| pane != pane_id || attn == attention);
+    }
+
+    /// Drop all cooldowns for an agent (e.g. the user sent a new prompt)
+    pub fn clear_pane(&mut self, pane_id: &str) {
+        self.last_sent.retain(|(pane, _), _| pane != pane_id);
+    }
+
+    /// Take the notification to show once the coalesce window has closed
+    pub fn flush(&mut self, now: Instant) -> Option<DesktopNotification> {
+        let first = self.pending.first()?;
+        if now.duration_since(first.queued_at) < self.coalesce {
+            return None;
+        }
+
+        let mut pending = std::mem::take(&mut self.pending);
+        if pending.len() == 1 {
+            return pending.pop().map(|p| p.notification);
+        }
+
+        let mut projects: Vec<&str> = pending.iter().map(|p| p.project.as_str()).collect();
+        projects.sort_unstable();
+        projects.dedup();
+        // Loudest sound wins: the first permission/input prompt's, if any
+        let urgent = pending.iter().any(|p| p.notification.urgent);
+        let sound = pending
+            .iter()
+            .filter(|p| p.notification.urgent == urgent)
+            .find_map(|p| p.notification.sound.clone())
+            .or_else(|| Some("default".to_string()));
+        Some(DesktopNotification {
+            title: "Claude Needs Attention".to_string(),
+            message: format!(
+                "{} agents need attention: {}",
+                pending.len(),
+                projects.join(", ")
+            ),
+            sound,
+            urgent,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(msg: &str) -> DesktopNotification {
+        DesktopNotification {
+            title: "t".to_string(),
+            message: msg.to_string(),
+            sound: None,
+            urgent: false,
+        }
+    }
+
+    fn throttle() -> NotificationThrottle {
+        NotificationThrottle::new(&NotifyConfig {
+            cooldown_secs: 30,
+            coalesce_secs: 2,
+            ..NotifyConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_cooldown_suppresses_repeats_until_attention_changes() {
+        let mut t = throttle();
+        let now = Instant::now();
+        assert!(t.offer("%1", "p", AttentionType::Waiting, note("a"), now));
+        assert!(!t.offer("%1", "p", AttentionType::Waiting, note("b"), now));
+        // Different type is a different key
+        assert!(t.offer("%1", "p", AttentionType::Permission, note("c"), now));
+
+        t.attention_changed("%1", &AttentionType::Permission);
+        assert!(t.offer("%1", "p", AttentionType::Waiting, note("d"), now));
+
+        // Other agents are independent; cooldown expires
+        assert!(t.offer("%2", "p", AttentionType::Waiting, note("e"), now));
+        let later = now + Duration::from_secs(31);
+        assert!(t.offer("%2", "p", AttentionType::Waiting, note("f"), later));
+    }
+
+    #[test]
+    fn test_flush_coalesces_burst() {
+        let mut t = throttle();
+        let now = Instant::now();
+        for (i, project) in ["a", "b", "c", "d", "e"].iter().enumerate() {
+            t.offer(
+                &format!("%{i}"),
+                project,
+                AttentionType::Input,
+                note("x"),
+                now,
+            );
+        }
+        assert!(t.flush(now + Duration::from_secs(1)).is_none());
+
+        let summary = t.flush(now + Duration::from_secs(2)).unwrap();
+        assert_eq!(summary.message, "5 agents need attention: a, b, c, d, e");
+        assert!(t.flush(now + Duration::from_secs(3)).is_none());
+
+        // The permission prompt's sound wins over the completion's
+        let done = DesktopNotification {
+            sound: Some("Glass".to_string()),
+            ..note("done")
+        };
+        let prompt = DesktopNotification {
+            sound: Some("Sosumi".to_string()),
+            urgent: true,
+            ..note("prompt")
+        };
+        t.offer("%5", "f", AttentionType::Waiting, done, now);
+        t.offer("%6", "g", AttentionType::Permission, prompt, now);
+        let summary = t.flush(now + Duration::from_secs(2)).unwrap();
+        assert_eq!(summary.sound.as_deref(), Some("Sosumi"));
+        assert!(summary.urgent);
+
+        // A lone notification passes through untouched
+        t.offer("%9", "z", AttentionType::Input, note("solo"), now);
+        assert_eq!(
+            t.flush(now + Duration::from_secs(5)).unwrap().message,
+            "solo"
+        );
+    }
+}
@@ -0,0 +1,400 @@
+//! Board persistence across TUI restarts
+//!
+//! Without this, restarting rehoboam blanks the board until each agent fires
+//! its next hook, which can take minutes for idle sessions. The board is
+//! written to `~/.cache/rehoboam/state.json` on a throttled interval and
+//! reloaded on startup.
+//!
+//! Only durable session metadata is persisted, plus notes, the collapsed-team
+//! layout and pins. Runtime-only data is rebuilt from subsequent hook events
+//! instead:
+//! - In-flight tool state (`current_tool`, `pending_tool_*`, `in_response`),
+//!   since the tool almost certainly finished while we were down
+//! - Subagents, tool history, modified files, and tasks
+//! - Last tool failure details
+
+use super::snapshot::attention_type_str;
+use super::{status_to_column, Agent, AppState, Status};
+use crate::config::PersistenceConfig;
+use color_eyre::eyre;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+/// Bump when `PersistedAgent` changes incompatibly; mismatched files are ignored
+pub const STATE_SCHEMA_VERSION: u32 = 1;
+
+/// On-disk board file
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedBoard {
+    version: u32,
+    /// Unix timestamp when the file was written
+    saved_at: i64,
+    agents: Vec<PersistedAgent>,
+    /// Team view layout (absent in older files)
+    #[serde(default)]
+    collapsed_teams: Vec<String>,
+    /// Pinned pane_ids, including agents not on the board right now
+    #[serde(default)]
+    pinned: Vec<String>,
+}
+
+/// Durable subset of `Agent`
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedAgent {
+    pane_id: String,
+    project: String,
+    status: String,
+    attention_type: Option<String>,
+    /// Absent in older files (restored from `start_time`)
+    #[serde(default)]
+    created_at: i64,
+    start_time: i64,
+    last_update: i64,
+    last_event: String,
+    session_id: Option<String>,
+    is_sprite: bool,
+    total_tool_calls: u32,
+    avg_latency_ms: Option<u64>,
+    context_usage_percent: Option<f64>,
+    context_remaining_percent: Option<f64>,
+    explicit_agent_type: Option<String>,
+    permission_mode: Option<String>,
+    cwd: Option<String>,
+    transcript_path: Option<String>,
+    task_list_id: Option<String>,
+    team_name: Option<String>,
+    team_agent_id: Option<String>,
+    team_agent_name: Option<String>,
+    team_agent_type: Option<String>,
+    claude_code_version: Option<String>,
+    model: Option<String>,
+    effort_level: Option<String>,
+    compaction_count: u32,
+    /// Absent in older files
+    #[serde(default)]
+    last_prompt: Option<String>,
+    /// Absent in older files
+    #[serde(default)]
+    note: Option<String>,
+}
+
+impl PersistedAgent {
+    fn from_agent(agent: &Agent) -> Self {
+        let (status, attention_type) = match &agent.status {
+            Status::Attention(attn) => ("attention", Some(attention_type_str(attn))),
+            Status::Working => ("working", None),
+            Status::Compacting => ("compacting", None),
+        };
+        Self {
+            pane_id: agent.pane_id.clone(),
+            project: agent.project.clone(),
+            status: status.to_string(),
+            attention_type: attention_type.map(String::from),
+            created_at: agent.created_at,
+            start_time: agent.start_time,
+            last_update: agent.last_update,
+            last_event: agent.last_event.clone(),
+            session_id: agent.session_id.clone(),
+            is_sprite: agent.is_sprite,
+            total_tool_calls: agent.total_tool_calls,
+            avg_latency_ms: agent.avg_latency_ms,
+            context_usage_percent: agent.context_usage_percent,
+            context_remaining_percent: agent.context_remaining_percent,
+            explicit_agent_type: agent.explicit_agent_type.clone(),
+            permission_mode: agent.permission_mode.clone(),
+            cwd: agent.cwd.clone(),
+            transcript_path: agent.transcript_path.clone(),
+            task_list_id: agent.task_list_id.clone(),
+            team_name: agent.team_name.clone(),
+            team_agent_id: agent.team_agent_id.clone(),
+            team_agent_name: agent.team_agent_name.clone(),
+            team_agent_type: agent.team_agent_type.clone(),
+            claude_code_version: agent.claude_code_version.clone(),
+            model: agent.model.clone(),
+            effort_level: agent.effort_level.clone(),
+            compaction_count: agent.compaction_count,
+            last_prompt: agent.last_prompt.clone(),
+            note: agent.note.clone(),
+        }
+    }
+
+    fn into_agent(self) -> Agent {
+        let mut agent = if self.is_sprite {
+            Agent::new_sprite(self.pane_id, self.project)
+        } else {
+            Agent::new(self.pane_id, self.project)
+        };
+        agent.status = Status::from_str(&self.status, self.attention_type.as_deref());
+        agent.created_at = if self.created_at == 0 {
+            self.start_time
+        } else {
+            self.created_at
+        };
+        agent.start_time = self.start_time;
+        agent.last_update = self.last_update;
+        agent.last_event = self.last_event;
+        agent.session_id = self.session_id;
+        agent.total_tool_calls = self.total_tool_calls;
+        agent.avg_latency_ms = self.avg_latency_ms;
+        agent.context_usage_percent = self.context_usage_percent;
+        agent.context_remaining_percent = self.context_remaining_percent;
+        agent.explicit_agent_type = self.explicit_agent_type;
+        agent.permission_mode = self.permission_mode;
+        agent.cwd = self.cwd;
+        agent.transcript_path = self.transcript_path;
+        agent.task_list_id = self.task_list_id;
+        agent.team_name = self.team_name;
+        agent.team_agent_id = self.team_agent_id;
+        agent.team_agent_name = self.team_agent_name;
+        agent.team_agent_type = self.team_agent_type;
+        agent.claude_code_version = self.claude_code_version;
+        agent.model = self.model;
+        agent.effort_level = self.effort_level;
+        agent.compaction_count = self.compaction_count;
+        agent.last_prompt = self.last_prompt;
+        agent.note = self.note;
+        agent
+    }
+}
+
+/// Default location of the persisted board
+pub fn state_file_path() -> PathBuf {
+    directories::BaseDirs::new().map_or_else(
+        || PathBuf::from("/tmp/rehoboam/state.json"),
+        |dirs| dirs.cache_dir().join("rehoboam").join("state.json"),
+    )
+}
+
+impl AppState {
+    /// Write the board to `path` (atomic via temp file + rename)
+    pub fn save_board(&self, path: &Path) -> eyre::Result<()> {
+        let board = PersistedBoard {
+            version: STATE_SCHEMA_VERSION,
+            saved_at: super::current_timestamp(),
+            agents: self
+                .agents
+                .values()
+                .map(PersistedAgent::from_agent)
+                .collect(),
+            collapsed_teams: self.collapsed_teams.iter().cloned().collect(),
+            pinned: self.pinned.iter().cloned().collect(),
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_vec(&board)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Load a previously saved board, returning the number of agents restored
+    ///
+    /// Agents idle longer than `stale_timeout_secs` are dropped, and at most
+    /// `max_agents` are restored, most recently active first. Missing,
+    /// unreadable, or version-mismatched files restore nothing.
+    pub fn restore_board(&mut self, path: &Path) -> usize {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                tracing::debug!(error = %e, path = %path.display(), "No saved board to restore");
+                return 0;
+            }
+        };
+
+        // Check version before full deserialize so schema changes can't fail loudly
+        let version = serde_json::from_str::<serde_json::Value>(&content)
+            .ok()
+            .and_then(|v| v.get("version").and_then(serde_json::Value::as_u64));
+        if version != Some(u64::from(STATE_SCHEMA_VERSION)) {
+            tracing::info!(
+                found = ?version,
+                expected = STATE_SCHEMA_VERSION,
+                "Ignoring saved board with different schema version"
+            );
+            return 0;
+        }
+
+        let board: PersistedBoard = match serde_json::from_str(&content) {
+            Ok(board) => board,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to parse saved board, ignoring");
+                return 0;
+            }
+        };
+
+        self.collapsed_teams.extend(board.collapsed_teams);
+        self.pinned.extend(board.pinned);
+
+        let now = super::current_timestamp();
+        let mut agents = board.agents;
+        agents.sort_by_key(|persisted| std::cmp::Reverse(persisted.last_update));
+        let mut restored = 0;
+        let mut over_limit = 0;
+        for persisted in agents {
+            if now - persisted.last_update > self.stale_timeout_secs {
+                continue;
+            }
+            if self.agents.contains_key(&persisted.pane_id) {
+                continue;
+            }
+            if self.agents.len() >= self.max_agents {
+                over_limit += 1;
+                continue;
+            }
+            let mut agent = persisted.into_agent();
+            if !self.capture_prompts {
+                agent.last_prompt = None;
+            }
+            self.status_counts[status_to_column(&agent.status)] += 1;
+            if agent.is_sprite {
+                self.sprite_agent_ids.insert(agent.pane_id.clone());
+            }
+            self.agents.insert(agent.pane_id.clone(), agent);
+            restored += 1;
+        }
+
+        if restored > 0 {
+            tracing::info!(restored, "Restored agents from saved board");
+        }
+        if over_limit > 0 {
+            tracing::warn!(
+                skipped = over_limit,
+                max_agents = self.max_agents,
+                "Saved board exceeds the agent limit, oldest agents not restored"
+            );
+        }
+        restored
+    }
+}
+
+/// Timer-gated board writer, driven from `App::tick()`
+pub struct BoardPersister {
+    /// Where to read/write the board (None = persistence disabled)
+    path: Option<PathBuf>,
+    /// Seconds between saves
+    interval_secs: u64,
+    /// Last save time
+    last_save: Instant,
+}
+
+impl BoardPersister {
+    /// Create a persister from config
+    pub fn new(config: &PersistenceConfig) -> Self {
+        Self {
+            path: config.enabled.then(state_file_path),
+            interval_secs: config.interval_secs,
+            last_save: Instant::now(),
+        }
+    }
+
+    /// Restore the saved board into `state` (no-op when disabled)
+    pub fn restore(&self, state: &mut AppState) {
+        if let Some(path) = &self.path {
+            state.restore_board(path);
+        }
+    }
+
+    /// Check if a save is due (timer-gated)
+    pub fn should_run(&self) -> bool {
+        self.path.is_some() && self.last_save.elapsed().as_secs() >= self.interval_secs
+    }
+
+    /// Save the board now, logging rather than propagating failures
+    pub fn save(&mut self, state: &AppState) {
+        self.last_save = Instant::now();
+        if let Some(path) = &self.path {
+            if let Err(e) = state.save_board(path) {
+                tracing::warn!(error = %e, path = %path.display(), "Failed to save board");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::AttentionType;
+
+    #[test]
+    fn test_round_trip_restores_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        let mut state = AppState::new();
+        let mut agent = Agent::new("%1".to_string(), "proj".to_string());
+        agent.status = Status::Attention(AttentionType::Permission);
+        agent.last_update = super::super::current_timestamp();
+        agent.model = Some("opus".to_string());
+        agent.current_tool = Some("Bash".to_string());
+        state.agents.insert("%1".to_string(), agent);
+        state.set_note("%1", "waiting on review");
+        state.toggle_team_collapsed("Independent");
+        // Pins outlive the agent: %9 is not on the board
+        state.toggle_pinned("%9");
+        state.save_board(&path).unwrap();
+
+        let mut restored = AppState::new();
+        assert_eq!(restored.restore_board(&path), 1);
+        assert!(restored.collapsed_teams.contains("Independent"));
+        assert!(restored.pinned.contains("%9"));
+        let agent = &restored.agents["%1"];
+        assert_eq!(agent.status, Status::Attention(AttentionType::Permission));
+        assert_eq!(agent.model.as_deref(), Some("opus"));
+        assert_eq!(agent.note.as_deref(), Some("waiting on review"));
+        // Runtime-only state is not carried over
+        assert!(agent.current_tool.is_none());
+        assert_eq!(restored.status_counts[0], 1);
+    }
+
+    #[test]
+    fn test_restore_drops_stale_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        let mut state = AppState::new();
+        let mut agent = Agent::new("%1".to_string(), "proj".to_string());
+        agent.last_update = super::super::current_timestamp() - state.stale_timeout_secs - 1;
+        state.agents.insert("%1".to_string(), agent);
+        state.save_board(&path).unwrap();
+
+        let mut restored = AppState::new();
+        assert_eq!(restored.restore_board(&path), 0);
+        assert!(restored.agents.is_empty());
+    }
+
+    #[test]
+    fn test_restore_stops_at_agent_limit() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        let mut state = AppState::new();
+        let now = super::super::current_timestamp();
+        for (i, pane) in ["%1", "%2", "%3"].iter().enumerate() {
+            let mut agent = Agent::new(pane.to_string(), "proj".to_string());
+            agent.last_update = now - 10 + i as i64;
+            state.agents.insert(pane.to_string(), agent);
+        }
+        state.save_board(&path).unwrap();
+
+        let mut restored = AppState::new();
+        restored.max_agents = 2;
+        assert_eq!(restored.restore_board(&path), 2);
+        assert!(!restored.agents.contains_key("%1"), "least recent left out");
+        assert_eq!(restored.status_counts.iter().sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn test_restore_ignores_other_schema_versions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+        std::fs::write(&path, r#"{"version":999,"agents":[{"bogus":true}]}"#).unwrap();
+
+        let mut state = AppState::new();
+        assert_eq!(state.restore_board(&path), 0);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(state.restore_board(&path), 0);
+    }
+}
@@ -0,0 +1,199 @@
+//! Rough cost estimates from model and context tokens
+//!
+//! `context_window.total_tokens` is a snapshot of the context, not a meter,
+//! so each agent accrues only growth: the first snapshot counts in full, a
+//! rise counts its delta, and a drop (compaction, `/clear`) just lowers the
+//! baseline. Accrued tokens are priced by the agent's model using a per-model
+//! rate in USD per million tokens: the built-in rates below, overridden or
+//! extended by `[pricing]` in config.toml. A key matches when it appears in
+//! the model name (case-insensitive); the longest matching key wins, so
+//! `"claude-sonnet-4"` can refine `"sonnet"`. Models with no matching key
+//! have no estimate ("n/a").
+//!
+//! Output tokens and cache discounts are invisible in the snapshot, so this
+//! is a ballpark for spotting an expensive agent, not a bill.
+
+use super::{Agent, AppState};
+use std::collections::BTreeMap;
+
+/// Built-in USD per million tokens (input list price)
+const DEFAULT_PRICES: [(&str, f64); 3] = [("opus", 15.0), ("sonnet", 3.0), ("haiku", 0.8)];
+
+/// Per-model token rates, keys lowercased
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pricing {
+    rates: BTreeMap<String, f64>,
+}
+
+impl Default for Pricing {
+    fn default() -> Self {
+        Self::new(&BTreeMap::new())
+    }
+}
+
+impl Pricing {
+    /// Built-in rates with `overrides` (`[pricing]`) on top
+    pub fn new(overrides: &BTreeMap<String, f64>) -> Self {
+        let mut rates: BTreeMap<String, f64> = DEFAULT_PRICES
+            .iter()
+            .map(|(model, rate)| (model.to_string(), *rate))
+            .collect();
+        for (model, rate) in overrides {
+            rates.insert(model.to_lowercase(), *rate);
+        }
+        Self { rates }
+    }
+
+    /// USD per million tokens for `model`, if any key matches
+    pub fn rate(&self, model: &str) -> Option<f64> {
+        let model = model.to_lowercase();
+        self.rates
+            .iter()
+            .filter(|(key, _)| model.contains(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, rate)| *rate)
+    }
+
+    /// Estimated cost of `tokens` on `model`
+    pub fn cost(&self, model: Option<&str>, tokens: u64) -> Option<f64> {
+        self.rate(model?)
+            .map(|rate| tokens as f64 / 1_000_000.0 * rate)
+    }
+}
+
+/// Tokens a new `total_tokens` snapshot adds to what the agent has accrued
+pub(super) fn accrued_delta(previous: Option<u64>, total: u64) -> u64 {
+    total.saturating_sub(previous.unwrap_or(0))
+}
+
+/// One model's share of the fleet estimate
+#[derive(Debug, Clone, PartialEq)]
+pub struct CostRow {
+    pub model: String,
+    pub agents: usize,
+    pub tokens: u64,
+    /// `None` when the model has no price
+    pub cost: Option<f64>,
+}
+
+/// Fleet-wide cost estimate for the dashboard
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FleetCost {
+    /// Costliest model first; unpriced models last
+    pub by_model: Vec<CostRow>,
+    /// Sum over priced models
+    pub total: f64,
+    /// Accrued tokens on models without a price
+    pub unpriced_tokens: u64,
+}
+
+impl AppState {
+    /// Estimated cost of an agent's accrued tokens, `None` without a price
+    pub fn agent_cost(&self, agent: &Agent) -> Option<f64> {
+        self.pricing
+            .cost(agent.model.as_deref(), agent.tokens_accrued)
+    }
+
+    /// Estimated cost across all tracked agents, grouped by model
+    pub fn fleet_cost(&self) -> FleetCost {
+        let mut groups: BTreeMap<&str, (usize, u64)> = BTreeMap::new();
+        for agent in self.agents.values().filter(|a| a.tokens_accrued > 0) {
+            let entry = groups
+                .entry(agent.model.as_deref().unwrap_or(super::fleet::UNKNOWN))
+                .or_default();
+            entry.0 += 1;
+            entry.1 += agent.tokens_accrued;
+        }
+        let mut fleet = FleetCost::default();
+        for (model, (agents, tokens)) in groups {
+            let cost = self.pricing.cost(Some(model), tokens);
+            match cost {
+                Some(cost) => fleet.total += cost,
+                None => fleet.unpriced_tokens += tokens,
+            }
+            fleet.by_model.push(CostRow {
+                model: model.to_string(),
+                agents,
+                tokens,
+                cost,
+            });
+        }
+        fleet.by_model.sort_by(|a, b| {
+            b.cost
+                .unwrap_or(-1.0)
+                .total_cmp(&a.cost.unwrap_or(-1.0))
+                .then_with(|| a.model.cmp(&b.model))
+        });
+        fleet
+    }
+}
+
+/// "~$0.40", or "n/a" without a price
+pub fn format_cost(cost: Option<f64>) -> String {
+    match cost {
+        Some(cost) if cost < 0.01 => "~$0.00".to_string(),
+        Some(cost) => format!("~${cost:.2}"),
+        None => "n/a".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{ContextWindow, HookEvent};
+
+    #[test]
+    fn test_pricing_longest_key_wins() {
+        let overrides = BTreeMap::from([
+            ("Claude-Sonnet-4-5".to_string(), 4.0),
+            ("opus".to_string(), 5.0),
+        ]);
+        let pricing = Pricing::new(&overrides);
+        assert_eq!(pricing.rate("claude-opus-4-6"), Some(5.0));
+        assert_eq!(pricing.rate("claude-sonnet-4-5-20250929"), Some(4.0));
+        assert_eq!(pricing.rate("claude-sonnet-4"), Some(3.0));
+        assert_eq!(pricing.rate("gpt-5"), None);
+        assert_eq!(format_cost(pricing.cost(Some("opus"), 80_000)), "~$0.40");
+        assert_eq!(format_cost(pricing.cost(None, 80_000)), "n/a");
+    }
+
+    #[test]
+    fn test_cost_accrues_context_growth_only() {
+        let mut state = AppState::new();
+        let mut snapshot = |tokens: u64| {
+            let mut event = HookEvent::internal(
+                "PostToolUse",
+                "working",
+                None,
+                "%1",
+                "proj",
+                super::super::current_timestamp(),
+            );
+            event.model = Some("claude-opus-4-6".to_string());
+            event.context_window = Some(ContextWindow {
+                total_tokens: Some(tokens),
+                ..ContextWindow::default()
+            });
+            let _ = state.process_event(event);
+        };
+        snapshot(20_000);
+        snapshot(50_000);
+        // Compaction shrinks the context; growth after it counts again
+        snapshot(10_000);
+        snapshot(40_000);
+
+        let agent = &state.agents["%1"];
+        assert_eq!(agent.tokens_accrued, 80_000);
+        assert_eq!(format_cost(state.agent_cost(agent)), "~$1.20");
+
+        let mut other = Agent::new("%2".to_string(), "proj".to_string());
+        other.model = Some("mystery".to_string());
+        other.tokens_accrued = 1_000;
+        state.agents.insert("%2".to_string(), other);
+        let fleet = state.fleet_cost();
+        assert!((fleet.total - 1.2).abs() < 1e-9);
+        assert_eq!(fleet.unpriced_tokens, 1_000);
+        assert_eq!(fleet.by_model[0].model, "claude-opus-4-6");
+        assert_eq!(fleet.by_model[1].cost, None);
+    }
+}
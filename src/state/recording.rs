@@ -0,0 +1,169 @@
+//! Board recordings for retrospectives (`--record-board` / `--playback`)
+//!
+//! Every `[recording] interval_secs` the TUI appends the whole board as one
+//! JSON line: a timestamp plus the same [`AgentSnapshot`] list
+//! `export-state` returns. Recording stops after `max_duration_mins`, so the
+//! interval and duration together bound the file size. `--playback` loads
+//! the frames into a read-only timeline that can be scrubbed frame by frame.
+
+use super::{current_timestamp, AgentSnapshot, AppState};
+use crate::config::RecordingConfig;
+use color_eyre::eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The board at one moment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardFrame {
+    /// Unix timestamp of the capture
+    pub timestamp: i64,
+    pub agents: Vec<AgentSnapshot>,
+}
+
+/// Appends a board frame to a file on an interval
+#[derive(Debug)]
+pub struct BoardRecorder {
+    writer: BufWriter<File>,
+    interval: Duration,
+    max_duration: Duration,
+    started: Instant,
+    /// Last frame written (None until the first)
+    last_frame: Option<Instant>,
+    /// The duration cap was reached and logged
+    finished: bool,
+}
+
+impl BoardRecorder {
+    /// Open `path` for appending (created if missing)
+    pub fn open(path: &Path, config: &RecordingConfig) -> Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open board recording {}", path.display()))?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+            interval: Duration::from_secs(config.interval_secs.max(1)),
+            max_duration: Duration::from_secs(config.max_duration_mins.saturating_mul(60)),
+            started: Instant::now(),
+            last_frame: None,
+            finished: false,
+        })
+    }
+
+    /// Write a frame if the interval has passed, until the duration cap
+    pub fn record_due(&mut self, state: &AppState) {
+        if self.finished {
+            return;
+        }
+        if self.started.elapsed() >= self.max_duration {
+            self.finished = true;
+            tracing::info!(
+                max_secs = self.max_duration.as_secs(),
+                "Board recording reached its duration cap"
+            );
+            return;
+        }
+        if self
+            .last_frame
+            .is_some_and(|at| at.elapsed() < self.interval)
+        {
+            return;
+        }
+        self.last_frame = Some(Instant::now());
+        self.write(&BoardFrame {
+            timestamp: current_timestamp(),
+            agents: state.snapshot(),
+        });
+    }
+
+    /// Write one frame; flushed immediately so a crash loses at most one
+    fn write(&mut self, frame: &BoardFrame) {
+        let result = serde_json::to_writer(&mut self.writer, frame)
+            .map_err(std::io::Error::from)
+            .and_then(|()| self.writer.write_all(b"\n"))
+            .and_then(|()| self.writer.flush());
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "Failed to record board frame");
+        }
+    }
+}
+
+/// Read a board recording, skipping lines that do not parse
+pub fn load_frames(path: &Path) -> Result<Vec<BoardFrame>> {
+    let content = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("Failed to read board recording {}", path.display()))?;
+    let mut frames: Vec<BoardFrame> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str(line) {
+            Ok(frame) => Some(frame),
+            Err(e) => {
+                tracing::warn!("Skipping unparseable board frame: {}", e);
+                None
+            }
+        })
+        .collect();
+    // Appending to an old recording can interleave sessions; play in time order
+    frames.sort_by_key(|frame| frame.timestamp);
+    Ok(frames)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Agent;
+
+    #[test]
+    fn test_recorded_frames_load_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("board.jsonl");
+        let config = RecordingConfig {
+            interval_secs: 60,
+            max_duration_mins: 10,
+        };
+
+        let mut state = AppState::new();
+        state.agents.insert(
+            "%1".to_string(),
+            Agent::new("%1".to_string(), "proj".to_string()),
+        );
+        let mut recorder = BoardRecorder::open(&path, &config).unwrap();
+        recorder.record_due(&state);
+        // Inside the interval: no second frame
+        recorder.record_due(&state);
+        recorder.write(&BoardFrame {
+            timestamp: 1,
+            agents: Vec::new(),
+        });
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"not json\n")
+            .unwrap();
+
+        let frames = load_frames(&path).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].timestamp, 1);
+        assert_eq!(frames[1].agents.len(), 1);
+        assert_eq!(frames[1].agents[0].pane_id, "%1");
+    }
+
+    #[test]
+    fn test_recording_stops_at_duration_cap() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("board.jsonl");
+        let config = RecordingConfig {
+            interval_secs: 1,
+            max_duration_mins: 0,
+        };
+
+        let mut recorder = BoardRecorder::open(&path, &config).unwrap();
+        recorder.record_due(&AppState::new());
+        assert!(load_frames(&path).unwrap().is_empty());
+    }
+}
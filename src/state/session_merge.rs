@@ -0,0 +1,133 @@
+//! Merging cards split by the session-id fallback
+//!
+//! A hook that finds no tmux, WezTerm or Kitty pane names its agent by the
+//! session id's prefix ([`terminal::session_fallback_pane`]). When the same
+//! session later reports from a real pane (the pane variable reached Claude
+//! Code after all, or a resumed session moved into tmux), the board would
+//! show two cards for one agent. Instead the fallback card is renamed to the
+//! real pane, keeping its status, history and note; once a session has a real
+//! pane, fallback-id events from it are routed to that pane's card.
+
+use super::{status_to_column, AppState};
+use crate::event::{EventSource, HookEvent};
+use crate::terminal;
+
+impl AppState {
+    /// Fold a fallback-id card into the real pane reporting the same session,
+    /// and point fallback-id events at the pane the session already has
+    pub(super) fn merge_session_fallback(&mut self, event: &mut HookEvent) {
+        if !matches!(event.source, EventSource::Local) {
+            return;
+        }
+        let Some(session_id) = event.session_id.clone() else {
+            return;
+        };
+        let fallback = terminal::session_fallback_pane(&session_id);
+        if event.event == "SessionEnd" {
+            // Route this last event, then forget the session
+            if let Some(pane_id) = self.session_panes.remove(&session_id) {
+                if event.pane_id == fallback && self.agents.contains_key(&pane_id) {
+                    event.pane_id = pane_id;
+                }
+            }
+            return;
+        }
+        if event.pane_id == fallback {
+            if let Some(pane_id) = self
+                .session_panes
+                .get(&session_id)
+                .filter(|pane_id| self.agents.contains_key(*pane_id))
+            {
+                event.pane_id = pane_id.clone();
+            }
+            return;
+        }
+
+        self.session_panes
+            .insert(session_id.clone(), event.pane_id.clone());
+        let split = self
+            .agents
+            .get(&fallback)
+            .is_some_and(|a| a.session_id.as_deref() == Some(session_id.as_str()));
+        if split {
+            self.adopt_pane(&fallback, &event.pane_id);
+        }
+    }
+
+    /// Move the card at `old` to `new`; if `new` already has a card, the one
+    /// at `old` is the duplicate and goes (its note carried over)
+    fn adopt_pane(&mut self, old: &str, new: &str) {
+        let Some(mut agent) = self.agents.remove(old) else {
+            return;
+        };
+        tracing::info!(from = %old, to = %new, project = %agent.project, "Merged session fallback card");
+        if let Some(existing) = self.agents.get_mut(new) {
+            if existing.note.is_none() {
+                existing.note = agent.note.take();
+            }
+            if !agent.is_gone() {
+                let col = status_to_column(&agent.status);
+                self.status_counts[col] = self.status_counts[col].saturating_sub(1);
+            }
+        } else {
+            agent.pane_id = new.to_string();
+            self.agents.insert(new.to_string(), agent);
+        }
+
+        if self.selected_agents.remove(old) {
+            self.selected_agents.insert(new.to_string());
+        }
+        if self.pinned.remove(old) {
+            self.pinned.insert(new.to_string());
+        }
+        if self.selected_pane_id.as_deref() == Some(old) {
+            self.selected_pane_id = Some(new.to_string());
+        }
+        self.notifications.clear_pane(old);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str, status: &str, pane_id: &str) -> HookEvent {
+        let mut event = HookEvent::internal(
+            name,
+            status,
+            None,
+            pane_id,
+            "proj",
+            super::super::current_timestamp(),
+        );
+        event.session_id = Some("4f2a9c01-77aa-4b1e-9f00-123456789abc".to_string());
+        event
+    }
+
+    #[test]
+    fn test_fallback_card_adopts_real_pane() {
+        let mut state = AppState::new();
+        let _ = state.process_event(event("UserPromptSubmit", "working", "4f2a9c01"));
+        state.selected_pane_id = Some("4f2a9c01".to_string());
+        state.toggle_pinned("4f2a9c01");
+        state.set_note("4f2a9c01", "check later");
+
+        let _ = state.process_event(event("PreToolUse", "working", "%7"));
+        assert_eq!(state.agents.len(), 1);
+        let agent = &state.agents["%7"];
+        assert_eq!(agent.pane_id, "%7");
+        assert_eq!(agent.note.as_deref(), Some("check later"));
+        assert_eq!(state.selected_pane_id.as_deref(), Some("%7"));
+        assert!(state.pinned.contains("%7"));
+        assert_eq!(state.status_counts.iter().sum::<usize>(), 1);
+
+        // A straggler under the fallback id lands on the real card
+        let _ = state.process_event(event("Stop", "attention", "4f2a9c01"));
+        assert_eq!(state.agents.len(), 1);
+        assert!(matches!(
+            state.agents["%7"].status,
+            super::super::Status::Attention(_)
+        ));
+        assert_eq!(state.status_counts.iter().sum::<usize>(), 1);
+    }
+}
@@ -0,0 +1,158 @@
+//! Point-in-time board snapshots for external tooling
+//!
+//! `rehoboam export-state` asks the running TUI for a snapshot over the
+//! control socket. Field names here are a stable contract: downstream
+//! consumers (dashboards, scripts) key on them, so rename with care.
+
+use super::{Agent, AppState, AttentionType, Status};
+use serde::{Deserialize, Serialize};
+
+/// Serializable view of a single agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSnapshot {
+    pub pane_id: String,
+    pub project: String,
+    /// "attention", "working", "compacting", or "gone" (silent past the
+    /// stale timeout, greyed out and left out of the header counts)
+    pub status: String,
+    /// "permission", "input", "notification", or "waiting" (attention only)
+    pub attention_type: Option<String>,
+    pub current_tool: Option<String>,
+    /// Seconds since session start (0 if unknown)
+    pub elapsed_secs: i64,
+    pub team_name: Option<String>,
+    /// Unix timestamp of the last hook event
+    pub last_update: i64,
+    /// Seconds since the agent first appeared, across sessions (0 if unknown)
+    #[serde(default)]
+    pub lifetime_secs: i64,
+    /// Unix timestamp the agent was marked gone (gone agents only)
+    #[serde(default)]
+    pub gone_since: Option<i64>,
+}
+
+impl AgentSnapshot {
+    /// CSV column order (matches `to_csv_row`)
+    pub const CSV_HEADER: &'static str =
+        "pane_id,project,status,attention_type,current_tool,elapsed_secs,team_name,last_update,lifetime_secs,gone_since";
+
+    fn from_agent(agent: &Agent, now: i64) -> Self {
+        let (status, attention_type) = match &agent.status {
+            _ if agent.is_gone() => ("gone", None),
+            Status::Attention(attn) => ("attention", Some(attention_type_str(attn))),
+            Status::Working => ("working", None),
+            Status::Compacting => ("compacting", None),
+        };
+        Self {
+            pane_id: agent.pane_id.clone(),
+            project: agent.project.clone(),
+            status: status.to_string(),
+            attention_type: attention_type.map(String::from),
+            current_tool: agent.current_tool.clone(),
+            elapsed_secs: agent.elapsed_secs(),
+            team_name: agent.team_name.clone(),
+            last_update: agent.last_update,
+            lifetime_secs: agent.lifetime_secs(now),
+            gone_since: agent.gone_since,
+        }
+    }
+
+    /// Render as a single CSV row (RFC 4180 quoting)
+    pub fn to_csv_row(&self) -> String {
+        [
+            csv_field(&self.pane_id),
+            csv_field(&self.project),
+            csv_field(&self.status),
+            csv_field(self.attention_type.as_deref().unwrap_or("")),
+            csv_field(self.current_tool.as_deref().unwrap_or("")),
+            self.elapsed_secs.to_string(),
+            csv_field(self.team_name.as_deref().unwrap_or("")),
+            self.last_update.to_string(),
+            self.lifetime_secs.to_string(),
+            self.gone_since.map(|t| t.to_string()).unwrap_or_default(),
+        ]
+        .join(",")
+    }
+}
+
+pub(super) fn attention_type_str(attn: &AttentionType) -> &'static str {
+    match attn {
+        AttentionType::Permission => "permission",
+        AttentionType::Input => "input",
+        AttentionType::Notification => "notification",
+        AttentionType::Waiting => "waiting",
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+pub(super) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render a full snapshot as CSV (header + one row per agent)
+pub fn snapshot_to_csv(agents: &[AgentSnapshot]) -> String {
+    let mut out = String::from(AgentSnapshot::CSV_HEADER);
+    out.push('\n');
+    for agent in agents {
+        out.push_str(&agent.to_csv_row());
+        out.push('\n');
+    }
+    out
+}
+
+impl AppState {
+    /// Capture every agent in rendered (team) order
+    pub fn snapshot(&self) -> Vec<AgentSnapshot> {
+        let now = self.now();
+        self.agents_by_team()
+            .iter()
+            .flat_map(|(_, agents)| agents.iter().map(|a| AgentSnapshot::from_agent(a, now)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_status_fields() {
+        let mut state = AppState::new();
+        let mut agent = Agent::new("%1".to_string(), "proj".to_string());
+        agent.status = Status::Attention(AttentionType::Permission);
+        agent.current_tool = Some("Bash".to_string());
+        state.agents.insert("%1".to_string(), agent);
+
+        let snap = state.snapshot();
+        assert_eq!(snap.len(), 1);
+        assert_eq!(snap[0].status, "attention");
+        assert_eq!(snap[0].attention_type.as_deref(), Some("permission"));
+        assert_eq!(snap[0].current_tool.as_deref(), Some("Bash"));
+        assert_eq!(snap[0].gone_since, None);
+
+        // A gone agent is exported as gone, as the header counts treat it
+        state.agents.get_mut("%1").unwrap().gone_since = Some(1_000);
+        let snap = state.snapshot();
+        assert_eq!(snap[0].status, "gone");
+        assert_eq!(snap[0].attention_type, None);
+        assert_eq!(snap[0].gone_since, Some(1_000));
+        assert!(snap[0].to_csv_row().ends_with(",1000"));
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_snapshot_to_csv_header() {
+        let csv = snapshot_to_csv(&[]);
+        assert_eq!(csv.trim_end(), AgentSnapshot::CSV_HEADER);
+    }
+}
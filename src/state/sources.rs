@@ -0,0 +1,163 @@
+//! Per-source traffic: events delivered by each socket and sprite (`N` to view)
+//!
+//! A connected sprite only proves the WebSocket is up; whether hooks on the
+//! far side actually reach us shows in its event count and last-event time.
+//! A source that reported within [`STREAMING_SECS`] is streaming; one that
+//! has gone quiet since (or never sent anything) is silent.
+
+use super::{current_timestamp, AppState};
+use crate::event::{EventSource, HookEvent};
+
+/// A source that reported this recently counts as streaming
+pub const STREAMING_SECS: i64 = 60;
+
+/// Where events come from: a local socket or a sprite
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SourceKey {
+    /// Local socket, labelled when listening on several `--socket` paths
+    Local(Option<String>),
+    Sprite(String),
+}
+
+impl SourceKey {
+    pub fn of(event: &HookEvent) -> Self {
+        match &event.source {
+            EventSource::Local => Self::Local(event.socket.clone()),
+            EventSource::Sprite { sprite_id } => Self::Sprite(sprite_id.clone()),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            Self::Local(None) => "local".to_string(),
+            Self::Local(Some(socket)) => format!("local: {socket}"),
+            Self::Sprite(sprite_id) => format!("sprite: {sprite_id}"),
+        }
+    }
+}
+
+/// Traffic seen from one source
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SourceStats {
+    /// Events received, heartbeats included
+    pub events: u64,
+    /// Unix time of the last event (None before the first)
+    pub last_event: Option<i64>,
+}
+
+/// Whether a source is delivering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceHealth {
+    Streaming,
+    /// Up (or local) but nothing within [`STREAMING_SECS`]
+    Silent,
+    /// Sprite whose WebSocket is down
+    Disconnected,
+}
+
+impl SourceHealth {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Streaming => "streaming",
+            Self::Silent => "silent",
+            Self::Disconnected => "disconnected",
+        }
+    }
+}
+
+/// One row of the connections panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceRow {
+    pub key: SourceKey,
+    pub stats: SourceStats,
+    pub health: SourceHealth,
+}
+
+impl AppState {
+    /// Count an event against the source it arrived from
+    pub(super) fn record_source(&mut self, event: &HookEvent) {
+        let stats = self.sources.entry(SourceKey::of(event)).or_default();
+        stats.events += 1;
+        stats.last_event = Some(current_timestamp());
+    }
+
+    /// Every source seen, local sockets first, with its health at `now`
+    ///
+    /// Connected sprites are listed before their first event so a sprite
+    /// that connects and then says nothing still shows up.
+    pub fn source_rows(&self, now: i64) -> Vec<SourceRow> {
+        let mut sources = self.sources.clone();
+        for sprite_id in &self.connected_sprites {
+            sources
+                .entry(SourceKey::Sprite(sprite_id.clone()))
+                .or_default();
+        }
+        sources
+            .into_iter()
+            .map(|(key, stats)| {
+                let disconnected = match &key {
+                    SourceKey::Sprite(sprite_id) => !self.connected_sprites.contains(sprite_id),
+                    SourceKey::Local(_) => false,
+                };
+                let health = if disconnected {
+                    SourceHealth::Disconnected
+                } else if stats
+                    .last_event
+                    .is_some_and(|last| now - last < STREAMING_SECS)
+                {
+                    SourceHealth::Streaming
+                } else {
+                    SourceHealth::Silent
+                };
+                SourceRow { key, stats, health }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_rows_split_streaming_silent_and_disconnected() {
+        let mut state = AppState::new();
+        let mut event = HookEvent::internal(
+            "PreToolUse",
+            "working",
+            None,
+            "%1",
+            "p",
+            current_timestamp(),
+        );
+        let _ = state.process_event(event.clone());
+        event.source = EventSource::Sprite {
+            sprite_id: "busy".to_string(),
+        };
+        event.pane_id = "sprite-1".to_string();
+        let _ = state.process_event(event.clone());
+        let _ = state.process_event(event);
+        state.sprite_connected("busy");
+        state.sprite_connected("quiet");
+
+        let now = current_timestamp();
+        let rows = state.source_rows(now);
+        let summary: Vec<_> = rows
+            .iter()
+            .map(|row| (row.key.label(), row.stats.events, row.health))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("local".to_string(), 1, SourceHealth::Streaming),
+                ("sprite: busy".to_string(), 2, SourceHealth::Streaming),
+                ("sprite: quiet".to_string(), 0, SourceHealth::Silent),
+            ]
+        );
+
+        state.sprite_disconnected("busy");
+        let rows = state.source_rows(now + STREAMING_SECS);
+        assert_eq!(rows[0].health, SourceHealth::Silent);
+        assert_eq!(rows[1].health, SourceHealth::Disconnected);
+    }
+}
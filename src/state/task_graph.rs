@@ -0,0 +1,148 @@
+//! Task dependency graph: blocker status, readiness, and cycle detection
+//!
+//! `blocked_by` edges come from agents and the task files, so nothing stops
+//! them from forming a loop. Readiness is computed from the direct blockers
+//! only, and cycles are found with a visited set, so a malformed graph is
+//! flagged instead of recursed into forever.
+
+use super::{TaskStatus, TaskWithContext};
+use std::collections::{HashMap, HashSet};
+
+/// Whether a task can start, judged by its blockers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskReadiness {
+    /// No blockers
+    #[default]
+    Free,
+    /// Every blocker is completed: can start now
+    Ready,
+    /// At least one blocker is unfinished (or unknown)
+    Blocked,
+    /// Part of a blocked_by cycle: can never become ready on its own
+    Cycle,
+}
+
+impl TaskReadiness {
+    /// True when the task should sort after actionable ones
+    pub fn is_blocked(self) -> bool {
+        matches!(self, Self::Blocked | Self::Cycle)
+    }
+}
+
+/// Task ids that can reach themselves through `blocked_by`
+fn tasks_in_cycles(edges: &HashMap<&str, Vec<&str>>) -> HashSet<String> {
+    let mut in_cycle = HashSet::new();
+    for &start in edges.keys() {
+        let mut stack: Vec<&str> = edges[start].clone();
+        let mut seen: HashSet<&str> = HashSet::new();
+        while let Some(id) = stack.pop() {
+            if id == start {
+                in_cycle.insert(start.to_string());
+                break;
+            }
+            if seen.insert(id) {
+                if let Some(next) = edges.get(id) {
+                    stack.extend(next.iter().copied());
+                }
+            }
+        }
+    }
+    in_cycle
+}
+
+/// Fill in `blockers` and `readiness` for one team's tasks (keyed by task id)
+pub(super) fn annotate(tasks: &mut HashMap<String, TaskWithContext>) {
+    let statuses: HashMap<String, TaskStatus> = tasks
+        .iter()
+        .map(|(id, task)| (id.clone(), task.status))
+        .collect();
+    let cycles = {
+        let edges: HashMap<&str, Vec<&str>> = tasks
+            .iter()
+            .map(|(id, task)| {
+                let blockers = task.blocked_by.iter().map(String::as_str).collect();
+                (id.as_str(), blockers)
+            })
+            .collect();
+        tasks_in_cycles(&edges)
+    };
+
+    for (id, task) in tasks.iter_mut() {
+        task.blockers = task
+            .blocked_by
+            .iter()
+            .map(|blocker| (blocker.clone(), statuses.get(blocker).copied()))
+            .collect();
+        task.readiness = if task.blocked_by.is_empty() {
+            TaskReadiness::Free
+        } else if cycles.contains(id) {
+            TaskReadiness::Cycle
+        } else if task
+            .blockers
+            .iter()
+            .all(|(_, status)| *status == Some(TaskStatus::Completed))
+        {
+            TaskReadiness::Ready
+        } else {
+            TaskReadiness::Blocked
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, status: TaskStatus, blocked_by: &[&str]) -> (String, TaskWithContext) {
+        (
+            id.to_string(),
+            TaskWithContext {
+                task_id: id.to_string(),
+                subject: format!("task {id}"),
+                status,
+                blocked_by: blocked_by.iter().map(|s| s.to_string()).collect(),
+                blocks: Vec::new(),
+                blockers: Vec::new(),
+                readiness: TaskReadiness::Free,
+                owner_name: String::new(),
+                team_name: None,
+                description: String::new(),
+                active_form: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_readiness_and_cycles() {
+        let mut tasks: HashMap<String, TaskWithContext> = [
+            task("1", TaskStatus::Completed, &[]),
+            task("2", TaskStatus::Pending, &["1"]),
+            task("3", TaskStatus::Pending, &["1", "2"]),
+            task("4", TaskStatus::Pending, &["missing"]),
+            // 5 → 6 → 5, and 7 waits on the loop without being part of it
+            task("5", TaskStatus::Pending, &["6"]),
+            task("6", TaskStatus::InProgress, &["5"]),
+            task("7", TaskStatus::Pending, &["5"]),
+        ]
+        .into_iter()
+        .collect();
+
+        annotate(&mut tasks);
+        let readiness = |id: &str| tasks[id].readiness;
+        assert_eq!(readiness("1"), TaskReadiness::Free);
+        assert_eq!(readiness("2"), TaskReadiness::Ready);
+        assert_eq!(readiness("3"), TaskReadiness::Blocked);
+        assert_eq!(readiness("4"), TaskReadiness::Blocked);
+        assert_eq!(readiness("5"), TaskReadiness::Cycle);
+        assert_eq!(readiness("6"), TaskReadiness::Cycle);
+        assert_eq!(readiness("7"), TaskReadiness::Blocked);
+        assert_eq!(
+            tasks["3"].blockers,
+            vec![
+                ("1".to_string(), Some(TaskStatus::Completed)),
+                ("2".to_string(), Some(TaskStatus::Pending)),
+            ]
+        );
+        assert_eq!(tasks["4"].blockers, vec![("missing".to_string(), None)]);
+    }
+}
@@ -0,0 +1,302 @@
+//! Live tail of an agent's transcript `.jsonl` (`O` to view)
+//!
+//! Claude Code appends one JSON object per message to `transcript_path`. The
+//! tail reads only what was appended since the last refresh, so it is cheap
+//! enough to run every tick while the view is open, and it keeps working
+//! after the terminal has scrolled the output away. Only the last
+//! [`MAX_MESSAGES`] messages are kept.
+//!
+//! The file may not exist yet (a fresh session), may be replaced (`/clear`
+//! starts a new one under the same agent) or truncated; any of these restart
+//! the tail from the top of the new file.
+
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+/// Messages kept for display
+pub const MAX_MESSAGES: usize = 50;
+
+/// On first open, skip anything older than this many bytes from the end
+const INITIAL_READ_BYTES: u64 = 256 * 1024;
+
+/// Who produced a transcript entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptRole {
+    /// The human (or a stop hook) prompting
+    User,
+    /// Claude's text reply
+    Assistant,
+    /// A tool call Claude made
+    ToolUse,
+    /// What the tool returned
+    ToolResult,
+}
+
+/// One rendered transcript entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptMessage {
+    pub role: TranscriptRole,
+    pub text: String,
+}
+
+/// Incremental reader for one transcript file
+#[derive(Debug)]
+pub struct TranscriptTail {
+    pub path: PathBuf,
+    /// Most recent messages, oldest first
+    pub messages: VecDeque<TranscriptMessage>,
+    /// False until the file has been opened at least once
+    pub found: bool,
+    /// Bytes consumed so far
+    offset: u64,
+    /// Trailing partial line (the writer hasn't finished it yet)
+    partial: Vec<u8>,
+    /// Started mid-file: the first line read is a fragment
+    skip_first: bool,
+    /// Identity of the file being read, to notice replacement
+    file_id: Option<(u64, u64)>,
+}
+
+impl TranscriptTail {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            messages: VecDeque::new(),
+            found: false,
+            offset: 0,
+            partial: Vec::new(),
+            skip_first: false,
+            file_id: None,
+        }
+    }
+
+    /// Whether this tail follows `path`
+    pub fn follows(&self, path: &Path) -> bool {
+        self.path == path
+    }
+
+    /// Read whatever was appended since the last call
+    ///
+    /// Returns true when new messages arrived (or the file went away).
+    pub fn refresh(&mut self) -> bool {
+        let Ok(mut file) = std::fs::File::open(&self.path) else {
+            let changed = self.found;
+            self.reset();
+            return changed;
+        };
+        let Ok(meta) = file.metadata() else {
+            return false;
+        };
+        let id = file_id(&meta);
+        let mut changed = false;
+        if !self.found || self.file_id != Some(id) || meta.len() < self.offset {
+            // New, replaced or truncated: start over, skipping old history
+            changed = !self.messages.is_empty();
+            self.reset();
+            self.found = true;
+            self.file_id = Some(id);
+            self.offset = meta.len().saturating_sub(INITIAL_READ_BYTES);
+            self.skip_first = self.offset > 0;
+        }
+        if meta.len() == self.offset {
+            return changed;
+        }
+
+        let mut appended = Vec::new();
+        if file.seek(SeekFrom::Start(self.offset)).is_err()
+            || file.read_to_end(&mut appended).is_err()
+        {
+            return changed;
+        }
+        self.offset += appended.len() as u64;
+        self.ingest(&appended) || changed
+    }
+
+    /// Parse complete lines from `chunk`, keeping any unfinished tail
+    ///
+    /// Works on bytes so a multi-byte character split across two writes
+    /// is decoded once it is whole.
+    fn ingest(&mut self, chunk: &[u8]) -> bool {
+        let mut buffer = std::mem::take(&mut self.partial);
+        buffer.extend_from_slice(chunk);
+        let Some(end) = buffer.iter().rposition(|&b| b == b'\n') else {
+            self.partial = buffer;
+            return false;
+        };
+        self.partial = buffer.split_off(end + 1);
+        let complete = String::from_utf8_lossy(&buffer);
+        // Starting mid-file, the first line is almost certainly cut
+        let lines = complete.lines().skip(usize::from(self.skip_first));
+        self.skip_first = false;
+        let mut added = false;
+        for line in lines {
+            for message in parse_line(line) {
+                if self.messages.len() >= MAX_MESSAGES {
+                    self.messages.pop_front();
+                }
+                self.messages.push_back(message);
+                added = true;
+            }
+        }
+        added
+    }
+
+    fn reset(&mut self) {
+        self.messages.clear();
+        self.partial.clear();
+        self.skip_first = false;
+        self.found = false;
+        self.offset = 0;
+        self.file_id = None;
+    }
+}
+
+#[cfg(unix)]
+fn file_id(meta: &std::fs::Metadata) -> (u64, u64) {
+    use std::os::unix::fs::MetadataExt;
+    (meta.dev(), meta.ino())
+}
+
+#[cfg(not(unix))]
+fn file_id(_meta: &std::fs::Metadata) -> (u64, u64) {
+    (0, 0)
+}
+
+/// Messages in one transcript line (an assistant line can hold text and tool calls)
+///
+/// Lines that aren't user/assistant messages (summaries, snapshots) and
+/// anything unparseable are skipped.
+fn parse_line(line: &str) -> Vec<TranscriptMessage> {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(line) else {
+        return Vec::new();
+    };
+    let role = match json.get("type").and_then(|t| t.as_str()) {
+        Some("user") => TranscriptRole::User,
+        Some("assistant") => TranscriptRole::Assistant,
+        _ => return Vec::new(),
+    };
+    let Some(content) = json.get("message").and_then(|m| m.get("content")) else {
+        return Vec::new();
+    };
+    if let Some(text) = content.as_str() {
+        return text_message(role, text).into_iter().collect();
+    }
+    let Some(blocks) = content.as_array() else {
+        return Vec::new();
+    };
+    blocks
+        .iter()
+        .filter_map(|block| match block.get("type")?.as_str()? {
+            "text" => text_message(role, block.get("text")?.as_str()?),
+            "tool_use" => {
+                let name = block.get("name")?.as_str()?;
+                let input = block.get("input");
+                let detail = ["command", "file_path", "pattern", "url", "description"]
+                    .iter()
+                    .find_map(|key| input?.get(key)?.as_str());
+                Some(TranscriptMessage {
+                    role: TranscriptRole::ToolUse,
+                    text: match detail {
+                        Some(detail) => format!("{name}: {detail}"),
+                        None => name.to_string(),
+                    },
+                })
+            }
+            "tool_result" => {
+                let result = block.get("content")?;
+                let text = result.as_str().map(String::from).or_else(|| {
+                    let parts: Vec<&str> = result
+                        .as_array()?
+                        .iter()
+                        .filter_map(|part| part.get("text")?.as_str())
+                        .collect();
+                    Some(parts.join("\n"))
+                })?;
+                text_message(TranscriptRole::ToolResult, &text)
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn text_message(role: TranscriptRole, text: &str) -> Option<TranscriptMessage> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| TranscriptMessage {
+        role,
+        text: text.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    const USER: &str = r#"{"type":"user","message":{"role":"user","content":"fix the tests"}}"#;
+    const ASSISTANT: &str = r#"{"type":"assistant","message":{"role":"assistant","content":[{"type":"text","text":"Running them."},{"type":"tool_use","name":"Bash","input":{"command":"cargo test"}}]}}"#;
+    const RESULT: &str = r#"{"type":"user","message":{"role":"user","content":[{"type":"tool_result","content":"ok. 3 passed"}]}}"#;
+
+    fn texts(tail: &TranscriptTail) -> Vec<(TranscriptRole, &str)> {
+        tail.messages
+            .iter()
+            .map(|m| (m.role, m.text.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parses_messages_and_waits_for_complete_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.jsonl");
+        let mut tail = TranscriptTail::new(&path);
+        assert!(!tail.refresh(), "missing file is not an error");
+        assert!(!tail.found);
+
+        let mut file = std::fs::File::create(&path).unwrap();
+        writeln!(file, "{USER}\n{ASSISTANT}").unwrap();
+        // Half-written line stays pending
+        write!(file, "{}", &RESULT[..20]).unwrap();
+        assert!(tail.refresh());
+        assert_eq!(
+            texts(&tail),
+            vec![
+                (TranscriptRole::User, "fix the tests"),
+                (TranscriptRole::Assistant, "Running them."),
+                (TranscriptRole::ToolUse, "Bash: cargo test"),
+            ]
+        );
+
+        writeln!(file, "{}", &RESULT[20..]).unwrap();
+        assert!(tail.refresh());
+        assert_eq!(
+            tail.messages.back().unwrap().text,
+            "ok. 3 passed",
+            "finished line parsed"
+        );
+        assert!(!tail.refresh(), "nothing new");
+    }
+
+    #[test]
+    fn test_restarts_when_file_is_replaced_or_removed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.jsonl");
+        std::fs::write(&path, format!("{USER}\n{ASSISTANT}\n")).unwrap();
+        let mut tail = TranscriptTail::new(&path);
+        tail.refresh();
+        assert_eq!(tail.messages.len(), 3);
+
+        // Rotated: a new, shorter file under the same name
+        std::fs::remove_file(&path).unwrap();
+        std::fs::write(&path, format!("{RESULT}\n")).unwrap();
+        assert!(tail.refresh());
+        assert_eq!(
+            texts(&tail),
+            vec![(TranscriptRole::ToolResult, "ok. 3 passed")]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+        assert!(tail.refresh());
+        assert!(tail.messages.is_empty());
+        assert!(!tail.found);
+    }
+}
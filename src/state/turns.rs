@@ -0,0 +1,129 @@
+//! Per-turn activity log for post-hoc analysis (`--metrics-csv`)
+//!
+//! A turn runs from `UserPromptSubmit` to `Stop` (or `SessionEnd`). Each
+//! completed turn becomes one CSV row, so iteration durations can be charted
+//! across a long run. The tracing log keeps its human-readable lines.
+//!
+//! Rows are written with a single `write` on an append-mode file, so a crash
+//! mid-run can at worst lose the row in flight, never interleave two.
+
+use super::snapshot::csv_field;
+use color_eyre::eyre::{Result, WrapErr};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// One completed agent turn
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurnRecord {
+    /// 1-based turn number for this agent
+    pub iteration: u32,
+    pub duration_secs: i64,
+    /// Tool calls completed during the turn
+    pub tool_calls: u32,
+    /// Hook that ended the turn (`Stop` or `SessionEnd`)
+    pub completion_reason: String,
+    /// When the turn ended (Unix seconds)
+    pub timestamp: i64,
+    pub pane_id: String,
+    pub project: String,
+}
+
+impl TurnRecord {
+    /// CSV column order (matches `to_csv_row`)
+    pub const CSV_HEADER: &'static str =
+        "iteration,duration_secs,tool_calls,completion_reason,timestamp,pane_id,project";
+
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.iteration,
+            self.duration_secs,
+            self.tool_calls,
+            csv_field(&self.completion_reason),
+            self.timestamp,
+            csv_field(&self.pane_id),
+            csv_field(&self.project),
+        )
+    }
+}
+
+/// Appends completed turns to a CSV file
+#[derive(Debug)]
+pub struct TurnCsvWriter {
+    file: File,
+}
+
+impl TurnCsvWriter {
+    /// Open `path` for appending, writing the header only if the file is new or empty
+    pub fn open(path: &Path) -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)
+            .wrap_err_with(|| format!("Failed to open metrics CSV {}", path.display()))?;
+        let len = file.metadata()?.len();
+        if len == 0 {
+            file.write_all(format!("{}\n", TurnRecord::CSV_HEADER).as_bytes())?;
+        } else {
+            // Terminate a row left partial by a crash so the next one starts clean
+            let mut last = [0u8; 1];
+            file.seek(SeekFrom::End(-1))?;
+            file.read_exact(&mut last)?;
+            if last[0] != b'\n' {
+                file.write_all(b"\n")?;
+            }
+        }
+        Ok(Self { file })
+    }
+
+    /// Append one row in a single write
+    pub fn write(&mut self, turn: &TurnRecord) {
+        let row = format!("{}\n", turn.to_csv_row());
+        if let Err(e) = self.file.write_all(row.as_bytes()) {
+            tracing::warn!(error = %e, "Failed to append turn to metrics CSV");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(iteration: u32) -> TurnRecord {
+        TurnRecord {
+            iteration,
+            duration_secs: 42,
+            tool_calls: 3,
+            completion_reason: "Stop".to_string(),
+            timestamp: 1_760_000_000,
+            pane_id: "%3".to_string(),
+            project: "my,app".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_header_written_once_and_partial_rows_terminated() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("turns.csv");
+
+        TurnCsvWriter::open(&path).unwrap().write(&turn(1));
+        // Simulate a crash mid-row
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"2,1")
+            .unwrap();
+        TurnCsvWriter::open(&path).unwrap().write(&turn(3));
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines[0], TurnRecord::CSV_HEADER);
+        assert_eq!(lines[1], "1,42,3,Stop,1760000000,%3,\"my,app\"");
+        assert_eq!(lines[2], "2,1");
+        assert_eq!(lines[3], "3,42,3,Stop,1760000000,%3,\"my,app\"");
+        assert_eq!(lines.len(), 4);
+    }
+}
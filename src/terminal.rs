@@ -0,0 +1,118 @@
+//! Pane ids across terminals, and focusing a pane (`Enter`)
+//!
+//! The hook names an agent by the pane it runs in: tmux's `$TMUX_PANE`
+//! (`%3`) when there is one, otherwise WezTerm's `$WEZTERM_PANE` or Kitty's
+//! `$KITTY_WINDOW_ID`. Both of those are bare numbers, so they are stored
+//! with a scheme prefix (`wezterm:3`, `kitty:3`) to keep them apart from
+//! each other and from the session-id fallback used when there is no pane at
+//! all. `Enter` runs the matching `[terminal]` command with `{pane}` replaced
+//! by the terminal's own id.
+
+use crate::config::TerminalConfig;
+use color_eyre::eyre::{bail, Result, WrapErr};
+use std::process::Command;
+
+/// Length of the session_id prefix used as a fallback pane id
+pub const SESSION_ID_PREFIX_LEN: usize = 8;
+
+/// Pane id for an agent outside any known terminal: its session id's prefix
+pub fn session_fallback_pane(session_id: &str) -> String {
+    session_id.chars().take(SESSION_ID_PREFIX_LEN).collect()
+}
+
+/// Terminal that owns a pane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaneScheme {
+    Tmux,
+    WezTerm,
+    Kitty,
+}
+
+impl PaneScheme {
+    /// Scheme and terminal-native id of a pane id, None for phantom and
+    /// session-id agents (nothing to focus)
+    pub fn detect(pane_id: &str) -> Option<(Self, &str)> {
+        let numeric = |id: &str| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit());
+        if pane_id.starts_with('%') {
+            Some((Self::Tmux, pane_id))
+        } else if let Some(id) = pane_id.strip_prefix("wezterm:").filter(|id| numeric(id)) {
+            Some((Self::WezTerm, id))
+        } else if let Some(id) = pane_id.strip_prefix("kitty:").filter(|id| numeric(id)) {
+            Some((Self::Kitty, id))
+        } else {
+            None
+        }
+    }
+
+    fn activate_template(self, config: &TerminalConfig) -> &str {
+        match self {
+            Self::Tmux => &config.tmux,
+            Self::WezTerm => &config.wezterm,
+            Self::Kitty => &config.kitty,
+        }
+    }
+}
+
+/// Pane id for an agent outside tmux, from WezTerm's or Kitty's environment
+pub fn foreign_pane_from_env() -> Option<String> {
+    let var = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+    var("WEZTERM_PANE")
+        .map(|id| format!("wezterm:{id}"))
+        .or_else(|| var("KITTY_WINDOW_ID").map(|id| format!("kitty:{id}")))
+}
+
+/// Focus `pane_id` with its terminal's `[terminal]` command
+pub fn activate_pane(config: &TerminalConfig, pane_id: &str) -> Result<()> {
+    let Some((scheme, id)) = PaneScheme::detect(pane_id) else {
+        bail!("{pane_id} is not a terminal pane");
+    };
+    let command = expand_activate_command(scheme.activate_template(config), id);
+    let output = Command::new("sh")
+        .arg("-c")
+        .arg(&command)
+        .output()
+        .wrap_err_with(|| format!("Failed to run {command}"))?;
+    if !output.status.success() {
+        bail!(
+            "{command} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+fn expand_activate_command(template: &str, pane: &str) -> String {
+    let quoted = format!("'{}'", pane.replace('\'', "'\\''"));
+    template.replace("{pane}", &quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pane_scheme_detection_and_commands() {
+        assert_eq!(PaneScheme::detect("%12"), Some((PaneScheme::Tmux, "%12")));
+        assert_eq!(
+            PaneScheme::detect("wezterm:7"),
+            Some((PaneScheme::WezTerm, "7"))
+        );
+        assert_eq!(
+            PaneScheme::detect("kitty:3"),
+            Some((PaneScheme::Kitty, "3"))
+        );
+        assert_eq!(PaneScheme::detect("kitty:x"), None);
+        assert_eq!(PaneScheme::detect("team:alpha/bob"), None);
+        assert_eq!(PaneScheme::detect("4f2a9c01"), None);
+
+        let config = TerminalConfig::default();
+        assert_eq!(
+            expand_activate_command(&config.wezterm, "7"),
+            "wezterm cli activate-pane --pane-id '7'"
+        );
+        assert_eq!(
+            expand_activate_command(&config.kitty, "3"),
+            "kitty @ focus-window --match id:'3'"
+        );
+    }
+}
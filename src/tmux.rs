@@ -4,6 +4,7 @@
 //! - Sending input to agents (y/n approval, custom text)
 //! - Checking pane health (alive/dead detection)
 //! - Creating new panes for agent spawning
+//! - Capturing pane output with scrollback
 //!
 //! Key patterns from ecosystem research:
 //! - Enter must be a separate argument to send-keys
@@ -47,10 +48,30 @@ impl TmuxController {
         Ok(())
     }
 
+    /// Send a single key to a tmux pane without pressing Enter
+    ///
+    /// Used for menu-style prompts (e.g. AskUserQuestion) that react to a
+    /// keypress directly.
+    pub fn send_key(pane_id: &str, key: &str) -> Result<()> {
+        let status = Command::new("tmux")
+            .args(["send-keys", "-t", pane_id, key])
+            .status()
+            .wrap_err("Failed to execute tmux send-keys")?;
+
+        if !status.success() {
+            bail!("tmux send-keys failed with status: {}", status);
+        }
+
+        tracing::debug!(pane_id = %pane_id, key = %key, "Sent key to pane");
+        Ok(())
+    }
+
     /// Send multi-line content via tmux buffer
     ///
     /// Uses load-buffer + paste-buffer to avoid escaping issues with long prompts.
-    /// This is the recommended method for sending prompts > 1 line.
+    /// This is the recommended method for sending prompts > 1 line: `-p` pastes
+    /// with bracketed-paste markers when the pane asked for them (Claude Code
+    /// does), so embedded newlines stay in one prompt instead of each submitting.
     ///
     /// # Arguments
     /// * `pane_id` - Tmux pane identifier
@@ -84,9 +105,18 @@ impl TmuxController {
             bail!("tmux load-buffer failed");
         }
 
-        // Paste named buffer to target pane, -d deletes buffer after paste
+        // Paste named buffer to target pane, -d deletes buffer after paste,
+        // -p brackets the paste
         let status = Command::new("tmux")
-            .args(["paste-buffer", "-t", pane_id, "-b", &buffer_name, "-d"])
+            .args([
+                "paste-buffer",
+                "-p",
+                "-t",
+                pane_id,
+                "-b",
+                &buffer_name,
+                "-d",
+            ])
             .status()
             .wrap_err("Failed to execute tmux paste-buffer")?;
 
@@ -139,4 +169,34 @@ impl TmuxController {
         tracing::info!(pane_id = %pane_id, cwd = %cwd, "Created new tmux pane");
         Ok(pane_id)
     }
+
+    /// Capture a pane's visible output plus `scrollback` lines above it
+    ///
+    /// `-e` keeps color escapes and `-J` joins lines tmux wrapped, so the
+    /// text renders at any width.
+    pub fn capture_pane(pane_id: &str, scrollback: usize) -> Result<String> {
+        let start = format!("-{scrollback}");
+        let output = Command::new("tmux")
+            .args([
+                "capture-pane",
+                "-p",
+                "-e",
+                "-J",
+                "-t",
+                pane_id,
+                "-S",
+                &start,
+            ])
+            .output()
+            .wrap_err("Failed to execute tmux capture-pane")?;
+
+        if !output.status.success() {
+            bail!(
+                "tmux capture-pane failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
 }
@@ -4,7 +4,7 @@
 //! for safe cleanup on exit or panic.
 
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -16,7 +16,8 @@ pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 
 /// Initialize terminal for TUI mode
 ///
-/// Sets up raw mode, alternate screen, and mouse capture.
+/// Sets up raw mode, alternate screen, mouse capture, and bracketed paste
+/// (so a pasted multi-line prompt arrives whole instead of as Enter presses).
 /// Returns a configured terminal ready for rendering.
 ///
 /// # Errors
@@ -24,17 +25,28 @@ pub type Tui = Terminal<CrosstermBackend<Stdout>>;
 pub fn init() -> io::Result<Tui> {
     enable_raw_mode()?;
     let mut stdout = stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     Terminal::new(CrosstermBackend::new(stdout))
 }
 
 /// Restore terminal to normal state
 ///
-/// Disables raw mode, exits alternate screen, and disables mouse capture.
+/// Disables raw mode, exits alternate screen, and disables mouse capture
+/// and bracketed paste.
 /// Safe to call multiple times.
 pub fn restore() -> io::Result<()> {
     disable_raw_mode()?;
-    execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    execute!(
+        stdout(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste
+    )?;
     Ok(())
 }
 
@@ -1,5 +1,6 @@
 //! UI helper functions
 
+use crate::config::TimeFormat;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 /// Truncate a string to max_len with ellipsis
@@ -14,21 +15,63 @@ pub fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
-/// Format a Unix timestamp as HH:MM:SS
-pub fn format_timestamp(ts: i64) -> String {
-    use std::time::{Duration, UNIX_EPOCH};
-    let datetime = UNIX_EPOCH + Duration::from_secs(ts as u64);
-    let now = std::time::SystemTime::now();
-
-    // Simple HH:MM:SS format
-    if let Ok(duration) = now.duration_since(datetime) {
-        let secs = duration.as_secs();
-        let hours = (secs / 3600) % 24;
-        let mins = (secs / 60) % 60;
-        let secs = secs % 60;
-        format!("{hours:02}:{mins:02}:{secs:02}")
-    } else {
-        "??:??:??".to_string()
+/// Format a Unix timestamp (seconds) in the configured [`TimeFormat`]
+///
+/// The one place event-log, audit and card times are formatted, so every
+/// view reads the same way.
+pub fn format_timestamp(ts: i64, format: TimeFormat) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    format_timestamp_at(ts, now, format)
+}
+
+/// [`format_timestamp`] against a fixed `now`
+///
+/// Times ahead of `now` (clock skew from a remote sprite) read "just now".
+pub fn format_timestamp_at(ts: i64, now: i64, format: TimeFormat) -> String {
+    let age = now - ts;
+    if age < 0 {
+        return "just now".to_string();
+    }
+    match format {
+        TimeFormat::Compact => {
+            if age < 60 {
+                format!("{age}s")
+            } else if age < 3600 {
+                format!("{}m", age / 60)
+            } else if age < 86400 {
+                format!("{}h {:02}m", age / 3600, (age % 3600) / 60)
+            } else {
+                format!("{}d", age / 86400)
+            }
+        }
+        TimeFormat::Verbose => {
+            let (count, unit) = match age {
+                0..=9 => return "just now".to_string(),
+                10..=59 => (age, "second"),
+                60..=3599 => (age / 60, "minute"),
+                3600..=86399 => (age / 3600, "hour"),
+                _ => (age / 86400, "day"),
+            };
+            let plural = if count == 1 { "" } else { "s" };
+            format!("{count} {unit}{plural} ago")
+        }
+        TimeFormat::Clock => {
+            use chrono::{Local, TimeZone};
+            let (Some(time), Some(today)) = (
+                Local.timestamp_opt(ts, 0).single(),
+                Local.timestamp_opt(now, 0).single(),
+            ) else {
+                return "--:--:--".to_string();
+            };
+            if time.date_naive() == today.date_naive() {
+                time.format("%H:%M:%S").to_string()
+            } else {
+                time.format("%b %d %H:%M").to_string()
+            }
+        }
     }
 }
 
@@ -52,3 +95,47 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_formats() {
+        let now = 1_700_000_000;
+        let cases = [
+            (5, "5s", "just now"),
+            (45, "45s", "45 seconds ago"),
+            (120, "2m", "2 minutes ago"),
+            (3600 + 5 * 60, "1h 05m", "1 hour ago"),
+            (3 * 86400, "3d", "3 days ago"),
+        ];
+        for (age, compact, verbose) in cases {
+            assert_eq!(
+                format_timestamp_at(now - age, now, TimeFormat::Compact),
+                compact
+            );
+            assert_eq!(
+                format_timestamp_at(now - age, now, TimeFormat::Verbose),
+                verbose
+            );
+        }
+    }
+
+    #[test]
+    fn test_future_times_read_just_now() {
+        let now = 1_700_000_000;
+        for format in [TimeFormat::Compact, TimeFormat::Verbose, TimeFormat::Clock] {
+            assert_eq!(format_timestamp_at(now + 30, now, format), "just now");
+        }
+    }
+
+    #[test]
+    fn test_clock_shows_time_of_day_then_date() {
+        let now = 1_700_000_000;
+        let recent = format_timestamp_at(now, now, TimeFormat::Clock);
+        assert_eq!(recent.len(), 8, "HH:MM:SS, got {recent}");
+        let old = format_timestamp_at(now - 3 * 86400, now, TimeFormat::Clock);
+        assert_eq!(old.len(), TimeFormat::Clock.width(), "got {old}");
+    }
+}
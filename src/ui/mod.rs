@@ -9,9 +9,14 @@ mod views;
 
 use crate::app::{App, InputMode};
 use crate::config::colors;
+use crate::state::{Agent, ContextLevel};
 use modals::{
-    render_debug_viewer, render_event_log, render_help, render_history_viewer,
-    render_insights_viewer, render_plan_viewer, render_spawn_dialog, render_stats_viewer,
+    render_agent_detail, render_audit_log, render_bulk_approve, render_checkpoint_viewer,
+    render_copy_menu, render_debug_viewer, render_event_log, render_failures, render_help,
+    render_history_viewer, render_input_dialog, render_insights_viewer, render_palette,
+    render_pane_output, render_plan_viewer, render_quit_confirm, render_sources,
+    render_spawn_dialog, render_stats_viewer, render_sweep_confirm, render_transcript,
+    render_workspace_menu,
 };
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
@@ -22,21 +27,46 @@ use ratatui::{
 };
 use views::render_team_view;
 
+/// Agents listed on the context usage line
+const CONTEXT_LINE_AGENTS: usize = 5;
+
+/// Projects named in the header's attention summary before "+N"
+const TOP_ATTENTION_PROJECTS: usize = 3;
+
 /// Main render function
 pub fn render(f: &mut Frame, app: &mut App) {
-    // Create layout: 3 zones (header, team view, footer)
+    // Create layout: 6 zones (header, context line, swarm line, activity sparklines,
+    // team view, footer)
+    let activity_height = if app.state.agents.is_empty() { 0 } else { 4 };
+    let context_top = app.state.top_context_usage(CONTEXT_LINE_AGENTS);
+    let context_height = if context_top.is_empty() { 0 } else { 1 };
+    // The swarm line only adds information once some agents run on sprites
+    let swarm_height = u16::from(app.state.sprite_agent_count() > 0);
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3), // Header
-            Constraint::Min(12),   // Team view (always rendered)
-            Constraint::Length(1), // Footer
+            Constraint::Length(3),               // Header
+            Constraint::Length(context_height),  // Top agents by context usage
+            Constraint::Length(swarm_height),    // Local + sprite agents per project
+            Constraint::Length(activity_height), // Activity sparklines
+            Constraint::Min(12),                 // Team view (always rendered)
+            Constraint::Length(1),               // Footer
         ])
         .split(f.area());
 
     render_header(f, chunks[0], app);
-    render_team_view(f, chunks[1], app);
-    render_footer(f, chunks[2], app);
+    render_context_line(f, chunks[1], app, &context_top);
+    if swarm_height > 0 {
+        render_swarm_line(f, chunks[2], app);
+    }
+    views::render_activity(f, chunks[3], app);
+    if app.show_minimap {
+        app.minimap_columns = views::minimap_columns(chunks[4].width);
+        views::render_minimap(f, chunks[4], app);
+    } else {
+        render_team_view(f, chunks[4], app);
+    }
+    render_footer(f, chunks[5], app);
 
     // Render event log if in debug mode
     if app.debug_mode && !app.state.events.is_empty() {
@@ -50,6 +80,38 @@ pub fn render(f: &mut Frame, app: &mut App) {
         views::render_task_board(f, area, app);
     }
 
+    // Render audit trail overlay if active
+    if app.show_audit_log {
+        render_audit_log(f, app);
+    }
+
+    // Render connections panel if active
+    if app.show_sources {
+        render_sources(f, app);
+    }
+
+    // Render recent errors overlay if active
+    if app.show_failures {
+        render_failures(f, app);
+    }
+
+    // Render transcript tail docked on the right if active
+    if app.show_transcript {
+        render_transcript(f, app);
+    }
+
+    // Render pane output docked on the right if active
+    if app.show_pane_output {
+        app.output_page = usize::from(f.area().height.saturating_sub(2) / 2).max(1);
+        render_pane_output(f, app);
+    }
+
+    // Render agent detail overlay if active
+    if app.show_agent_detail {
+        let area = helpers::centered_rect(70, 80, f.area());
+        render_agent_detail(f, area, app);
+    }
+
     // Render plan viewer overlay if active
     if app.show_plan_viewer {
         let area = helpers::centered_rect(85, 85, f.area());
@@ -80,33 +142,82 @@ pub fn render(f: &mut Frame, app: &mut App) {
         render_insights_viewer(f, area, app);
     }
 
+    // Render sprite checkpoint timeline overlay if active
+    if app.show_checkpoint_viewer {
+        let area = helpers::centered_rect(70, 70, f.area());
+        render_checkpoint_viewer(f, area, app);
+    }
+
+    // Render board recording playback if active
+    if let Some(ref playback) = app.playback {
+        let area = helpers::centered_rect(90, 90, f.area());
+        modals::render_playback(f, area, playback);
+    }
+
+    // Render the full-screen event log if active
+    if app.input_mode == InputMode::EventLog {
+        modals::render_event_log_view(f, app);
+    }
+
     // Render help popup if active (always on top)
     if app.show_help {
-        render_help(f);
+        render_help(f, &app.keymap);
     }
 
     // Render spawn dialog if in spawn mode (always on top)
     if app.input_mode == InputMode::Spawn {
         render_spawn_dialog(f, &app.spawn_state);
     }
+
+    // Render input dialog if in input mode (always on top)
+    if app.input_mode == InputMode::Input {
+        render_input_dialog(f, app);
+    }
+
+    // Render copy menu if choosing what to copy (always on top)
+    if app.input_mode == InputMode::CopyMenu {
+        render_copy_menu(f, app);
+    }
+
+    // Render bulk approve menu (always on top)
+    if app.input_mode == InputMode::BulkApprove {
+        render_bulk_approve(f, app);
+    }
+
+    // Render workspace menu (always on top)
+    if app.input_mode == InputMode::WorkspaceMenu {
+        render_workspace_menu(f, app);
+    }
+
+    // Render sweep confirmation (always on top)
+    if app.input_mode == InputMode::SweepConfirm {
+        render_sweep_confirm(f, app);
+    }
+
+    // Render quit confirmation (always on top)
+    if app.input_mode == InputMode::QuitConfirm {
+        render_quit_confirm(f, app);
+    }
+
+    // Render command palette (always on top)
+    if app.input_mode == InputMode::Palette {
+        render_palette(f, app);
+    }
 }
 
 fn render_header(f: &mut Frame, area: Rect, app: &App) {
-    // Use cached status counts (O(1) instead of O(3n))
-    let [attention, working, compacting] = app.state.status_counts;
+    // Use cached status counts (O(1) instead of O(3n)), folded to visible columns
     let total = app.state.agents.len();
     let sprite_count = app.state.sprite_agent_count();
 
     // Build status summary
-    let status_parts: Vec<String> = [
-        (attention, "attention"),
-        (working, "working"),
-        (compacting, "compacting"),
-    ]
-    .iter()
-    .filter(|(count, _)| *count > 0)
-    .map(|(count, label)| format!("{count} {label}"))
-    .collect();
+    let status_parts: Vec<String> = app
+        .state
+        .column_counts()
+        .iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(column, count)| format!("{count} {}", column.label()))
+        .collect();
 
     // Show Claude Code version from any active agent (they should all be the same)
     let cc_version = app
@@ -139,34 +250,203 @@ fn render_header(f: &mut Frame, area: Rect, app: &App) {
     } else {
         String::new()
     };
+    // Active view filter indicator
+    let filter_indicator = match (app.attention_only, app.search_query.is_empty()) {
+        (false, true) => String::new(),
+        (false, false) => format!(" [SEARCH: {}]", app.search_query),
+        (true, true) => " [ATTENTION]".to_string(),
+        (true, false) => format!(" [ATTENTION: {}]", app.search_query),
+    };
+    // Per-socket agent counts when listening on several sockets
+    let socket_indicator = {
+        let sockets = app.state.agents_by_socket();
+        if sockets.is_empty() {
+            String::new()
+        } else {
+            let parts: Vec<String> = sockets
+                .iter()
+                .map(|(label, count)| format!("{label}: {count}"))
+                .collect();
+            format!(" [{}]", parts.join(" \u{2502} "))
+        }
+    };
+    // Mirror mode indicator
+    let mirror_indicator = if app.read_only { " [READ-ONLY]" } else { "" };
+    let freeze_indicator = app.freeze.label();
+    let auto_indicator = app.auto_accept.label();
+    let follow_indicator = if app.focus_follows_attention {
+        " [FOLLOW]"
+    } else {
+        ""
+    };
+    let dnd_indicator = app.do_not_disturb.label(app.in_quiet_hours());
     let title = if total == 0 {
-        "Rehoboam".to_string()
+        format!(
+            "Rehoboam{mirror_indicator}{freeze_indicator}{auto_indicator}{dnd_indicator}{follow_indicator}{filter_indicator}"
+        )
     } else {
         format!(
-            "Rehoboam ({} agents: {}){}{}",
+            "Rehoboam ({} agents: {}){}{}{}{}{}{}{}{}{}",
             total,
             status_parts.join(", "),
             cc_version,
             sprite_indicator,
+            socket_indicator,
+            mirror_indicator,
+            freeze_indicator,
+            auto_indicator,
+            dnd_indicator,
+            follow_indicator,
+            filter_indicator,
         )
     };
 
+    // Agents per non-default permission mode; any bypass turns it red
+    let modes = app.state.permission_mode_counts();
+    let mode_indicator = if modes.is_empty() {
+        String::new()
+    } else {
+        let parts: Vec<String> = modes
+            .iter()
+            .map(|(mode, count)| format!("{count} {mode}"))
+            .collect();
+        format!(" [{}]", parts.join(", "))
+    };
+    let mode_style = if modes
+        .first()
+        .is_some_and(|&(mode, _)| mode == crate::state::BYPASS_PERMISSIONS)
+    {
+        Style::default().fg(colors::error())
+    } else {
+        Style::default()
+    };
+
+    // Projects with the most agents waiting on a human, top three
+    let blocked = app.state.attention_by_project();
+    let attention_indicator = if blocked.is_empty() {
+        String::new()
+    } else {
+        let mut parts: Vec<String> = blocked
+            .iter()
+            .take(TOP_ATTENTION_PROJECTS)
+            .map(|(project, count)| format!("{project}({count})"))
+            .collect();
+        if blocked.len() > TOP_ATTENTION_PROJECTS {
+            parts.push(format!("+{}", blocked.len() - TOP_ATTENTION_PROJECTS));
+        }
+        format!(" [{}]", parts.join(" "))
+    };
+
+    let title = Line::from(vec![
+        Span::raw(title),
+        Span::styled(
+            attention_indicator,
+            Style::default().fg(colors::attention()),
+        ),
+        Span::styled(mode_indicator, mode_style),
+    ]);
+
     let header = Paragraph::new(title)
-        .style(Style::default().fg(colors::FG).add_modifier(Modifier::BOLD))
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::BORDER))
-                .border_type(ratatui::widgets::BorderType::Rounded),
-        );
-    f.render_widget(header, area);
+        .style(
+            Style::default()
+                .fg(colors::fg())
+                .add_modifier(Modifier::BOLD),
+        )
+        .alignment(Alignment::Center);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::border()))
+        .border_type(ratatui::widgets::BorderType::Rounded);
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    // Fleet pulse on the right, once there's room for it beside the title
+    let pulse_width = views::fleet_pulse_width(app, inner.width);
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(pulse_width)])
+        .split(inner);
+    f.render_widget(header, chunks[0]);
+    if pulse_width > 0 {
+        views::render_fleet_pulse(f, chunks[1], app);
+    }
+}
+
+/// Show the agents closest to running out of context, most used first
+fn render_context_line(f: &mut Frame, area: Rect, app: &App, agents: &[&Agent]) {
+    if agents.is_empty() {
+        return;
+    }
+    let mut spans = vec![Span::styled("ctx ", Style::default().fg(colors::idle()))];
+    for (i, agent) in agents.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(
+                " \u{2502} ", // │
+                Style::default().fg(colors::idle()),
+            ));
+        }
+        let used = 100.0 - agent.context_remaining().unwrap_or(100.0);
+        let color = match app.state.context_level(agent) {
+            ContextLevel::Critical => colors::error(),
+            ContextLevel::Warn => colors::compacting(),
+            ContextLevel::Normal => colors::fg(),
+        };
+        let name = agent.team_agent_name.as_deref().unwrap_or(&agent.pane_id);
+        spans.push(Span::styled(
+            format!("{} {} {:.0}%", agent.project, name, used),
+            Style::default().fg(color),
+        ));
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+        area,
+    );
+}
+
+/// One rollup per project: "api 5 (3 local, 2 ☁) 1🔔 12 turns"
+fn render_swarm_line(f: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::styled("swarm ", Style::default().fg(colors::idle()))];
+    for (i, summary) in app.state.project_summaries().iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(
+                " \u{2502} ", // │
+                Style::default().fg(colors::idle()),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(
+                "{} {} ({} local, {} \u{2601})", // ☁
+                summary.project, summary.total, summary.local, summary.sprite
+            ),
+            Style::default().fg(colors::fg()),
+        ));
+        if summary.attention > 0 {
+            spans.push(Span::styled(
+                format!(" {}\u{1f514}", summary.attention), // 🔔
+                Style::default().fg(colors::attention()),
+            ));
+        }
+        spans.push(Span::styled(
+            format!(
+                " {} turn{}",
+                summary.turns,
+                if summary.turns == 1 { "" } else { "s" }
+            ),
+            Style::default().fg(colors::idle()),
+        ));
+    }
+    f.render_widget(
+        Paragraph::new(Line::from(spans)).alignment(Alignment::Center),
+        area,
+    );
 }
 
 fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     // Health warning takes highest priority (persistent red text)
     if let Some(ref warning) = app.state.health_warning {
-        let style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+        let style = Style::default()
+            .fg(colors::error())
+            .add_modifier(Modifier::BOLD);
         let msg = Paragraph::new(warning.as_str())
             .style(style)
             .alignment(Alignment::Center);
@@ -178,14 +458,39 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     if app.input_mode == InputMode::Search {
         let search_text = format!("Search: {}|", app.search_query);
         let footer = Paragraph::new(search_text)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(colors::warning()))
+            .alignment(Alignment::Center);
+        f.render_widget(footer, area);
+        return;
+    }
+
+    // Transient status message (e.g. "No agents need attention")
+    if let Some((ref message, _)) = app.status_message {
+        let msg = Paragraph::new(message.as_str())
+            .style(Style::default().fg(colors::warning()))
+            .alignment(Alignment::Center);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    // Marked agents: show bulk input hint
+    if !app.state.selected_agents.is_empty() && !app.read_only {
+        let text = format!(
+            "{} marked  Space:mark/unmark  c:send input  Esc:clear marks",
+            app.state.selected_agents.len()
+        );
+        let footer = Paragraph::new(text)
+            .style(Style::default().fg(colors::warning()))
             .alignment(Alignment::Center);
         f.render_widget(footer, area);
         return;
     }
 
     // Context-aware help based on selection state
-    let help = if let Some(_agent) = app.state.selected_agent() {
+    let help = if app.read_only {
+        // Mirroring a remote board - no local tmux actions
+        "read-only mirror  j/k:nav  i:detail  T:tasks  S:stats  ?:help  q:quit".to_string()
+    } else if let Some(_agent) = app.state.selected_agent() {
         // Single agent selected - show relevant commands
         let debug = if app.debug_mode { "[debug] " } else { "" };
         format!("{debug}Enter:jump  T:tasks  P:plans  S:stats  L:log  D:debug  I:insights  ?:help")
@@ -196,7 +501,7 @@ fn render_footer(f: &mut Frame, area: Rect, app: &App) {
     };
 
     let footer = Paragraph::new(help)
-        .style(Style::default().fg(colors::IDLE))
+        .style(Style::default().fg(colors::idle()))
         .alignment(Alignment::Center);
 
     f.render_widget(footer, area);
@@ -0,0 +1,224 @@
+//! Agent detail modal - inspect one agent without leaving rehoboam
+
+use crate::app::App;
+use crate::config::{colors, TimeFormat};
+use crate::state::{format_cost, format_latency, Agent};
+use crate::ui::helpers::{format_timestamp, format_timestamp_at, truncate};
+use ratatui::{
+    prelude::*,
+    style::Modifier,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+/// Render the selected agent's metadata and recent tool calls
+pub fn render_agent_detail(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Agent Detail ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::highlight()))
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title_bottom(Line::from(" i/Esc:close ").centered())
+        .style(Style::default().bg(colors::bg()));
+
+    let Some(agent) = app.state.selected_agent() else {
+        let empty = Paragraph::new("No agent selected (j/k to select)")
+            .style(Style::default().fg(colors::idle()))
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(empty, area);
+        return;
+    };
+
+    let now = crate::state::current_timestamp();
+    let lines = detail_lines(
+        agent,
+        area.width.saturating_sub(4) as usize,
+        app.time_format,
+        now,
+        app.state.average_lifetime_secs(now),
+        app.state.agent_cost(agent),
+    );
+    let detail = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(block);
+    f.render_widget(detail, area);
+}
+
+/// How the last turn ended, and whether a stop hook sent Claude back to work
+fn last_turn(agent: &Agent) -> String {
+    let Some(end) = agent.last_turn_end else {
+        return "-".to_string();
+    };
+    let mut text = end.label().to_string();
+    if agent.turns_completed > 0 {
+        text.push_str(&format!(" (turn {})", agent.turns_completed));
+    }
+    if agent.stop_hook_active {
+        text.push_str(", continued by stop hook");
+    }
+    text
+}
+
+/// "3h 05m, idle 2m (board avg 1h 10m)"
+fn lifetime(agent: &Agent, now: i64, average: Option<i64>) -> String {
+    if agent.created_at == 0 {
+        return "-".to_string();
+    }
+    let span = |secs: i64| format_timestamp_at(now - secs, now, TimeFormat::Compact);
+    let mut text = format!(
+        "{}, idle {}",
+        span(agent.lifetime_secs(now)),
+        span(agent.idle_secs(now))
+    );
+    if let Some(average) = average {
+        text.push_str(&format!(" (board avg {})", span(average)));
+    }
+    text
+}
+
+fn detail_lines(
+    agent: &Agent,
+    width: usize,
+    time_format: TimeFormat,
+    now: i64,
+    average_lifetime: Option<i64>,
+    cost: Option<f64>,
+) -> Vec<Line<'static>> {
+    let label = Style::default().fg(colors::idle());
+    let value = Style::default().fg(colors::fg());
+    let heading = Style::default()
+        .fg(colors::highlight())
+        .add_modifier(Modifier::BOLD);
+
+    let field = |name: &str, text: String| {
+        Line::from(vec![
+            Span::styled(format!("  {name:<12}"), label),
+            Span::styled(text, value),
+        ])
+    };
+
+    let name = agent
+        .team_agent_name
+        .clone()
+        .unwrap_or_else(|| agent.pane_id.clone());
+    let context = match (agent.context_usage_percent, agent.context_total_tokens) {
+        (Some(pct), Some(tokens)) => format!("{pct:.0}% ({tokens} tokens)"),
+        (Some(pct), None) => format!("{pct:.0}%"),
+        _ => "-".to_string(),
+    };
+    let avg_latency = agent
+        .avg_latency_ms
+        .map_or_else(|| "-".to_string(), format_latency);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("  {name} — {}", agent.project),
+            heading,
+        )),
+        Line::from(""),
+        field("Pane", agent.pane_id.clone()),
+        field("Status", format!("{:?}", agent.status)),
+        field("Model", agent.model.clone().unwrap_or_else(|| "-".into())),
+        field("Context", context),
+        field(
+            "Cost",
+            format!(
+                "{} ({} tokens accrued)",
+                format_cost(cost),
+                agent.tokens_accrued
+            ),
+        ),
+        field("Elapsed", format_timestamp(agent.start_time, time_format)),
+        field("Lifetime", lifetime(agent, now, average_lifetime)),
+        field("Last turn", last_turn(agent)),
+        field(
+            "Tool calls",
+            format!("{} (avg {avg_latency})", agent.total_tool_calls),
+        ),
+        field(
+            "Transcript",
+            agent
+                .transcript_path
+                .clone()
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        field(
+            "Last prompt",
+            agent.last_prompt.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        field(
+            "Note",
+            agent.note.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+
+    if let Some(prompt) = &agent.pending_permission {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  \u{1f512} Asking to run {prompt}"), // 🔒
+            Style::default()
+                .fg(colors::attention())
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    if agent.last_tool_failed {
+        let tool = agent.failed_tool_name.as_deref().unwrap_or("tool");
+        let error = agent
+            .failed_tool_error
+            .as_deref()
+            .unwrap_or(if agent.failed_tool_interrupt {
+                "interrupted"
+            } else {
+                "unknown error"
+            });
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  ✗ {tool} failed: {error}"),
+            Style::default().fg(colors::error()),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("  Recent tools", heading)));
+    if let Some(tool) = &agent.current_tool {
+        lines.push(Line::from(Span::styled(
+            format!("  ▶ {tool} (running)"),
+            Style::default().fg(colors::working()),
+        )));
+    }
+    if agent.recent_tool_calls.is_empty() && agent.current_tool.is_none() {
+        lines.push(Line::from(Span::styled("  (none yet)", label)));
+    }
+    // Newest first
+    for call in agent.recent_tool_calls.iter().rev() {
+        let latency = call
+            .latency_ms
+            .map_or_else(|| "?".to_string(), format_latency);
+        let (marker, style) = if call.failed {
+            ("✗", Style::default().fg(colors::error()))
+        } else {
+            ("✓", value)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ", format_timestamp(call.completed_at, time_format)),
+                label,
+            ),
+            Span::styled(format!("{marker} "), style),
+            Span::styled(
+                format!(
+                    "{:<24}",
+                    truncate(&call.name, width.saturating_sub(30).max(8))
+                ),
+                style,
+            ),
+            Span::styled(format!("{latency:>7}"), label),
+        ]));
+    }
+
+    lines
+}
@@ -0,0 +1,54 @@
+//! Bulk approve menu: approve pending permission prompts, all or by tool
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+pub fn render_bulk_approve(f: &mut Frame, app: &App) {
+    let area = centered_rect(40, 40, f.area());
+    let total = app.state.pending_permissions(None).len();
+
+    let choice = |key: String, label: String, count: usize| {
+        Line::from(vec![
+            Span::styled(
+                format!(" [{key}] {label:<16}"),
+                Style::default().fg(colors::highlight()),
+            ),
+            Span::styled(
+                format!("{count} waiting"),
+                Style::default().fg(colors::fg()),
+            ),
+        ])
+    };
+    let mut lines = vec![choice("a".to_string(), "all tools".to_string(), total)];
+    lines.extend(
+        app.bulk_approve_tools
+            .iter()
+            .take(9)
+            .enumerate()
+            .map(|(i, (tool, count))| choice((i + 1).to_string(), tool.clone(), *count)),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [Esc] Cancel",
+        Style::default().fg(colors::idle()),
+    )));
+
+    let menu = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Approve pending prompts ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::attention()))
+            .border_type(ratatui::widgets::BorderType::Double)
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(menu, area);
+}
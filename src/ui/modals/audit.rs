@@ -0,0 +1,60 @@
+//! Audit trail modal: actions taken from this board, newest first
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use super::super::helpers::{centered_rect, format_timestamp, truncate};
+
+pub fn render_audit_log(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, f.area());
+    let rows = area.height.saturating_sub(2) as usize;
+
+    let items: Vec<ListItem> = if app.audit.is_empty() {
+        vec![ListItem::new("  No approvals, answers or input sent yet")
+            .style(Style::default().fg(colors::idle()))]
+    } else {
+        app.audit
+            .newest_first()
+            .take(rows)
+            .map(|entry| {
+                let outcome = if entry.ok { "" } else { " (failed)" };
+                // Input text is arbitrary: cut on chars, not bytes
+                let detail: String = entry.detail.replace('\n', " ").chars().take(40).collect();
+                let line = format!(
+                    "{:>width$} │ {:12} │ {:6} │ {:15} │ {}{}",
+                    format_timestamp(entry.timestamp, app.time_format),
+                    entry.action.label(),
+                    entry.pane_id,
+                    truncate(&entry.project, 15),
+                    detail,
+                    outcome,
+                    width = app.time_format.width()
+                );
+                let color = if entry.ok {
+                    colors::fg()
+                } else {
+                    colors::attention()
+                };
+                ListItem::new(line).style(Style::default().fg(color))
+            })
+            .collect()
+    };
+
+    let title = format!(" Audit Trail ({}) ", app.audit.len());
+    let list = List::new(items).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::border()))
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(list, area);
+}
@@ -0,0 +1,95 @@
+//! Sprite checkpoint timeline modal
+//!
+//! Lists the selected sprite's checkpoints (newest first). Enter asks for a
+//! y/N confirmation before rolling the sprite back.
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph},
+    Frame,
+};
+
+/// Render the checkpoint timeline overlay
+pub fn render_checkpoint_viewer(f: &mut Frame, area: Rect, app: &App) {
+    f.render_widget(Clear, area);
+
+    let viewer = &app.checkpoint_viewer;
+    let title = format!(
+        " Checkpoints: {} ({}) ",
+        viewer.sprite_id,
+        viewer.records.len()
+    );
+    let footer = if viewer.confirm_restore {
+        let id = viewer
+            .records
+            .get(viewer.selected_index)
+            .map_or("?", |r| r.id.as_str());
+        format!(
+            " Restore {} to {id}? This discards later changes. [y/N] ",
+            viewer.sprite_id
+        )
+    } else {
+        " [Enter] Restore  r:refresh  Esc:close ".to_string()
+    };
+    let footer_style = if viewer.confirm_restore {
+        Style::default()
+            .fg(colors::attention())
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(colors::idle())
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::highlight()))
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title_bottom(Line::from(footer).style(footer_style).centered())
+        .style(Style::default().bg(colors::bg()));
+
+    let placeholder = if let Some(ref error) = viewer.error {
+        Some(format!("Failed to load checkpoints: {error}"))
+    } else if viewer.records.is_empty() {
+        Some(if viewer.loading {
+            "Loading checkpoints...".to_string()
+        } else {
+            "No checkpoints for this sprite.".to_string()
+        })
+    } else {
+        None
+    };
+    if let Some(text) = placeholder {
+        let msg = Paragraph::new(text)
+            .style(Style::default().fg(colors::idle()))
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let items: Vec<ListItem> = viewer
+        .records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let is_selected = i == viewer.selected_index;
+            let marker = if is_selected { "\u{25b6} " } else { "  " };
+            let line = format!(
+                "{marker}{:<6} {:<16} {}",
+                record.id,
+                record.created_at.as_deref().unwrap_or("-"),
+                record.comment.as_deref().unwrap_or("")
+            );
+            let style = if is_selected {
+                Style::default()
+                    .fg(colors::highlight())
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(colors::fg())
+            };
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    f.render_widget(List::new(items).block(block), area);
+}
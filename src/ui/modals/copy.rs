@@ -0,0 +1,48 @@
+//! Copy menu modal (pick which agent detail goes to the clipboard)
+
+use crate::app::{copy_choices, App};
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::{centered_rect, truncate};
+
+pub fn render_copy_menu(f: &mut Frame, app: &App) {
+    let Some(agent) = app.state.selected_agent() else {
+        return;
+    };
+    let area = centered_rect(60, 30, f.area());
+
+    let mut lines: Vec<Line> = copy_choices(agent)
+        .into_iter()
+        .map(|(key, label, value)| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" [{key}] {label:<11}"),
+                    Style::default().fg(colors::highlight()),
+                ),
+                Span::styled(truncate(&value, 60), Style::default().fg(colors::fg())),
+            ])
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " [Esc] Cancel",
+        Style::default().fg(colors::idle()),
+    )));
+
+    let menu = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Copy from {} ", agent.pane_id))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::highlight()))
+            .border_type(ratatui::widgets::BorderType::Double)
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(menu, area);
+}
@@ -36,15 +36,15 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
 
     if entries.is_empty() {
         let msg = Paragraph::new("No debug logs found in ~/.claude/debug/")
-            .style(Style::default().fg(colors::IDLE))
+            .style(Style::default().fg(colors::idle()))
             .block(
                 Block::default()
                     .title(title)
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(colors::HIGHLIGHT))
+                    .border_style(Style::default().fg(colors::highlight()))
                     .border_type(ratatui::widgets::BorderType::Rounded)
                     .title_bottom(Line::from(" Esc:close ").centered())
-                    .style(Style::default().bg(colors::BG)),
+                    .style(Style::default().bg(colors::bg())),
             );
         f.render_widget(msg, area);
         return;
@@ -89,10 +89,10 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(colors::HIGHLIGHT)
+                    .fg(colors::highlight())
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(colors::FG)
+                Style::default().fg(colors::fg())
             };
 
             ListItem::new(Line::from(line)).style(style)
@@ -103,10 +103,10 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors::HIGHLIGHT))
+            .border_style(Style::default().fg(colors::highlight()))
             .border_type(ratatui::widgets::BorderType::Rounded)
             .title_bottom(Line::from(" j/k:nav  Enter:read  Esc:close ").centered())
-            .style(Style::default().bg(colors::BG)),
+            .style(Style::default().bg(colors::bg())),
     );
 
     f.render_widget(list, area);
@@ -123,8 +123,8 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
         let mut state = ScrollbarState::new(max_scroll).position(offset);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut state,
         );
@@ -155,15 +155,15 @@ fn render_reader(f: &mut Frame, area: Rect, app: &mut App) {
         .lines()
         .map(|line| {
             let style = if line.contains("[ERROR]") {
-                Style::default().fg(Color::Red)
+                Style::default().fg(colors::error())
             } else if line.contains("[WARN]") {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(colors::warning())
             } else if line.contains("[INFO]") {
                 Style::default().fg(Color::Cyan)
             } else if line.contains("[DEBUG]") {
-                Style::default().fg(colors::IDLE)
+                Style::default().fg(colors::idle())
             } else {
-                Style::default().fg(colors::FG)
+                Style::default().fg(colors::fg())
             };
             Line::from(Span::styled(line, style))
         })
@@ -187,12 +187,12 @@ fn render_reader(f: &mut Frame, area: Rect, app: &mut App) {
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title_bottom(
                     Line::from(" j/k:scroll  d/u:page  g/G:top/bot  Esc:back ").centered(),
                 )
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
 
     f.render_widget(paragraph, area);
@@ -209,8 +209,8 @@ fn render_reader(f: &mut Frame, area: Rect, app: &mut App) {
             .position(scroll_pos as usize);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut state,
         );
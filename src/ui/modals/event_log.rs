@@ -1,10 +1,11 @@
-//! Event log modal
+//! Event log: the small debug-mode box and the full-screen view (`V`)
 
 use crate::app::App;
-use crate::config::colors;
+use crate::config::{colors, TimeFormat};
+use crate::state::{LoggedEvent, SourceKey};
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
     Frame,
 };
 
@@ -18,15 +19,17 @@ pub fn render_event_log(f: &mut Frame, app: &App) {
         .events
         .iter()
         .take(15)
-        .map(|event| {
+        .map(|logged| {
+            let event = &logged.event;
             let line = format!(
-                "{} │ {:12} │ {:15} │ {}",
-                format_timestamp(event.timestamp),
+                "{:>width$} │ {:12} │ {:15} │ {}",
+                format_timestamp(event.timestamp, app.time_format),
                 event.event,
                 truncate(&event.project, 15),
-                event.status
+                detail(logged),
+                width = app.time_format.width()
             );
-            ListItem::new(line).style(Style::default().fg(colors::FG))
+            ListItem::new(line).style(Style::default().fg(colors::fg()))
         })
         .collect();
 
@@ -34,11 +37,105 @@ pub fn render_event_log(f: &mut Frame, app: &App) {
         Block::default()
             .title(" Event Log ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors::BORDER))
+            .border_style(Style::default().fg(colors::border()))
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .style(Style::default().bg(colors::BG)),
+            .style(Style::default().bg(colors::bg())),
     );
 
     f.render_widget(ratatui::widgets::Clear, area);
     f.render_widget(list, area);
 }
+
+/// "%3 Working→Attention(Input) [AskUserQuestion]" when the status moved
+fn detail(logged: &LoggedEvent) -> String {
+    let event = &logged.event;
+    logged.transition.as_ref().map_or_else(
+        || event.status.clone(),
+        |t| format!("{} {}", event.pane_id, t.describe()),
+    )
+}
+
+fn log_line(logged: &LoggedEvent, time_format: TimeFormat) -> String {
+    let event = &logged.event;
+    format!(
+        "{:>width$} │ {:10} │ {:17} │ {:16} │ {:15} │ {}",
+        format_timestamp(event.timestamp, time_format),
+        truncate(&event.pane_id, 10),
+        truncate(&event.event, 17),
+        truncate(&SourceKey::of(event).label(), 16),
+        truncate(&event.project, 15),
+        detail(logged),
+        width = time_format.width()
+    )
+}
+
+/// Full-screen, scrollable event log with its filter on top
+pub fn render_event_log_view(f: &mut Frame, app: &App) {
+    let area = f.area();
+    f.render_widget(ratatui::widgets::Clear, area);
+
+    let entries = app.state.filter_event_log(&app.event_log.query);
+    let log = &app.event_log;
+    let title = if log.query.is_empty() {
+        format!(
+            " Event Log ({} of last {}) ",
+            entries.len(),
+            app.state.event_log_size
+        )
+    } else {
+        format!(
+            " Event Log ({} of {} match) ",
+            entries.len(),
+            app.state.events.len()
+        )
+    };
+    let hint = if log.editing {
+        " pane:%3  event:Stop  source:sprite  words fuzzy-match  Enter:keep  Esc:clear "
+    } else {
+        " /:filter  j/k:scroll  d/u:page  g/G:newest/oldest  Esc:close "
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::border()))
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title_bottom(Line::from(hint).centered())
+        .style(Style::default().bg(colors::bg()));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(inner);
+
+    let cursor = if log.editing { "\u{2588}" } else { "" }; // █
+    let prompt_style = if log.editing || !log.query.is_empty() {
+        Style::default().fg(colors::fg())
+    } else {
+        Style::default().fg(colors::idle())
+    };
+    let prompt = Paragraph::new(format!(" / {}{cursor}", log.query)).style(prompt_style);
+    f.render_widget(prompt, chunks[0]);
+
+    if entries.is_empty() {
+        let empty =
+            Paragraph::new("   No matching events").style(Style::default().fg(colors::idle()));
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+    let items: Vec<ListItem> = entries
+        .iter()
+        .skip(log.scroll)
+        .take(usize::from(chunks[1].height))
+        .map(|logged| {
+            let style = if logged.transition.is_some() {
+                Style::default().fg(colors::fg())
+            } else {
+                Style::default().fg(colors::idle())
+            };
+            ListItem::new(log_line(logged, app.time_format)).style(style)
+        })
+        .collect();
+    f.render_widget(List::new(items), chunks[1]);
+}
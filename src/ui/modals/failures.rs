@@ -0,0 +1,74 @@
+//! Recent errors modal: unresolved tool failures grouped across agents
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+/// Cut to `width` chars (error text is arbitrary, so not on bytes)
+fn clip(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut clipped: String = text.chars().take(width.saturating_sub(1)).collect();
+    clipped.push('\u{2026}'); // …
+    clipped
+}
+
+pub fn render_failures(f: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, f.area());
+    let width = area.width.saturating_sub(4) as usize;
+    let groups = app.state.failure_groups();
+
+    let items: Vec<ListItem> = if groups.is_empty() {
+        vec![ListItem::new("  No failing tools right now")
+            .style(Style::default().fg(colors::idle()))]
+    } else {
+        groups
+            .iter()
+            .map(|group| {
+                let message = group.example.replace('\n', " ");
+                let headline = format!("{}\u{d7} {}", group.agents.len(), message); // ×
+                let agents: Vec<String> = group
+                    .agents
+                    .iter()
+                    .map(|(pane, project)| format!("{pane} ({project})"))
+                    .collect();
+                let affected = format!(
+                    "    {} \u{2502} {}",
+                    group.tools.join(", "),
+                    agents.join(", ")
+                );
+                ListItem::new(vec![
+                    Line::styled(
+                        clip(&headline, width),
+                        Style::default()
+                            .fg(colors::attention())
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Line::styled(clip(&affected, width), Style::default().fg(colors::idle())),
+                ])
+            })
+            .collect()
+    };
+
+    let affected: usize = groups.iter().map(|g| g.agents.len()).sum();
+    let title = format!(" Recent Errors ({affected} agents) ");
+    let list = List::new(items).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::border()))
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title_bottom(Line::from(" clears as agents recover \u{2502} E:close ").centered())
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(list, area);
+}
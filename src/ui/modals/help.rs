@@ -1,5 +1,6 @@
 //! Help modal
 
+use crate::app::keymap::{Action, Keymap, Section};
 use crate::config::colors;
 use ratatui::{
     prelude::*,
@@ -9,54 +10,87 @@ use ratatui::{
 
 use super::super::helpers::centered_rect;
 
-pub fn render_help(f: &mut Frame) {
-    let area = centered_rect(55, 80, f.area());
-
-    let help_text = r"
-  Views (uppercase)
-  T            Task board
-  P            Plan viewer
-  S            Stats dashboard
-  L            History log
-  D            Debug viewer
-  I            Insights report
-  ?, H         This help
+/// One "  keys  description" row per action in `section`
+fn section_rows(keymap: &Keymap, section: Section) -> String {
+    Action::ALL
+        .into_iter()
+        .filter(|action| action.section() == section)
+        .map(|action| format!("  {:<12} {}\n", keymap.label(action), action.description()))
+        .collect()
+}
 
+/// Help text for the active bindings (defaults plus `[keymap]`)
+fn help_text(keymap: &Keymap) -> String {
+    format!(
+        r"
+  Views
+{views}
   Navigation
-  j/k, Up/Dn   Move between agents
-  Enter        Jump to agent's terminal
-  /            Search agents
-
+{navigation}
   Actions
-  s            Spawn agent
+{actions}  1-9          Answer selected agent's question
+               (jump/spawn/input/auto-accept/1-9 disabled when mirroring)
 
   Search Mode
   Esc          Cancel search
-  Enter        Confirm / jump to match
+  Enter        Jump to best match (Esc clears)
   Type         Filter agents
 
+  Checkpoints
+  Enter        Restore selected (asks y/N)
+  r            Refresh timeline
+
   Spawn Mode
   Tab / Dn     Next field
   Shift+Tab/Up Previous field
   Enter        Submit / toggle
   Esc          Cancel
 
-  q, Esc       Quit (Esc closes modals first)
+{application}  Esc          Close modals, clear search/marks
   Ctrl+C       Force quit
-";
+",
+        views = section_rows(keymap, Section::Views),
+        navigation = section_rows(keymap, Section::Navigation),
+        actions = section_rows(keymap, Section::Actions),
+        application = section_rows(keymap, Section::Application),
+    )
+}
+
+pub fn render_help(f: &mut Frame, keymap: &Keymap) {
+    let area = centered_rect(55, 80, f.area());
+    let help_text = help_text(keymap);
 
     let help = Paragraph::new(help_text)
-        .style(Style::default().fg(colors::FG))
+        .style(Style::default().fg(colors::fg()))
         .block(
             Block::default()
                 .title(" Help ")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Double)
                 .title_bottom(Line::from(" ?:close ").centered())
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
 
     f.render_widget(ratatui::widgets::Clear, area);
     f.render_widget(help, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::KeyBinding;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_help_lists_active_bindings() {
+        let text = help_text(&Keymap::default());
+        assert!(text.contains("  j, Down      Next agent\n"));
+        assert!(text.contains("  ?, H         This help\n"));
+
+        let config = BTreeMap::from([("quit".to_string(), KeyBinding::One("Q".to_string()))]);
+        let text = help_text(&Keymap::from_config(&config));
+        assert!(text.contains("  Q            Quit"));
+        assert!(!text.contains("  q            Quit"));
+    }
+}
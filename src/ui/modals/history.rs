@@ -24,15 +24,15 @@ pub fn render_history_viewer(f: &mut Frame, area: Rect, app: &mut App) {
         let msg = ratatui::widgets::Paragraph::new(
             "No history found. Use Claude Code to generate history.",
         )
-        .style(Style::default().fg(colors::IDLE))
+        .style(Style::default().fg(colors::idle()))
         .block(
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title_bottom(Line::from(" Esc:close ").centered())
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
         f.render_widget(msg, area);
         return;
@@ -90,10 +90,10 @@ pub fn render_history_viewer(f: &mut Frame, area: Rect, app: &mut App) {
 
             let style = if is_selected {
                 Style::default()
-                    .fg(colors::HIGHLIGHT)
+                    .fg(colors::highlight())
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(colors::FG)
+                Style::default().fg(colors::fg())
             };
 
             let line = format!("{marker}{ts}  {project}{paste_indicator}{display}");
@@ -105,10 +105,10 @@ pub fn render_history_viewer(f: &mut Frame, area: Rect, app: &mut App) {
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors::HIGHLIGHT))
+            .border_style(Style::default().fg(colors::highlight()))
             .border_type(ratatui::widgets::BorderType::Rounded)
             .title_bottom(Line::from(" j/k:scroll  Esc:close ").centered())
-            .style(Style::default().bg(colors::BG)),
+            .style(Style::default().bg(colors::bg())),
     );
 
     f.render_widget(list, area);
@@ -125,8 +125,8 @@ pub fn render_history_viewer(f: &mut Frame, area: Rect, app: &mut App) {
         let mut state = ScrollbarState::new(max_scroll).position(scroll_offset);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut state,
         );
@@ -0,0 +1,131 @@
+//! Input dialog modal (send text to marked or selected agents, edit the
+//! selected agent's note, or name a workspace)
+//!
+//! The field wraps long lines and scrolls to keep the cursor (`█`) in view.
+
+use crate::app::{App, InputPurpose, TextInput};
+use crate::config::colors;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    prelude::*,
+    style::Modifier,
+    widgets::{Block, Borders, Paragraph, Wrap},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+pub fn render_input_dialog(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 40, f.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(3),    // Text field
+            Constraint::Length(1), // Instructions
+        ])
+        .margin(1)
+        .split(area);
+
+    let targets = app.input_targets();
+    let lines = app.input_buffer.as_str().split('\n').count();
+    let line_count = if lines > 1 {
+        format!("({lines} lines) ")
+    } else {
+        String::new()
+    };
+    let title = match &app.input_purpose {
+        InputPurpose::Note(pane_id) => format!(" Note for {pane_id} "),
+        InputPurpose::Workspace => " Save layout as ".to_string(),
+        InputPurpose::Send if targets.len() == 1 => {
+            format!(" Send to {} {line_count}", targets[0])
+        }
+        InputPurpose::Send => format!(" Send to {} agents {line_count}", targets.len()),
+    };
+
+    let width = chunks[0].width.saturating_sub(2).max(1) as usize;
+    let height = chunks[0].height.saturating_sub(2).max(1) as usize;
+    let scroll = cursor_row(&app.input_buffer, width).saturating_sub(height - 1);
+    let field = Paragraph::new(field_lines(&app.input_buffer))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll as u16, 0))
+        .style(
+            Style::default()
+                .fg(colors::highlight())
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(colors::highlight())),
+        );
+
+    let (heading, instructions) = match app.input_purpose {
+        InputPurpose::Note(_) => (" Agent Note ", "[Enter] Save (empty clears)  [Esc] Cancel"),
+        InputPurpose::Workspace => (" Workspace ", "[Enter] Save  [Esc] Cancel"),
+        InputPurpose::Send => (
+            " Send Input ",
+            "[Enter] Send  [Alt+Enter] New line  [Esc] Cancel",
+        ),
+    };
+    let instructions = Paragraph::new(instructions)
+        .style(
+            Style::default()
+                .fg(colors::idle())
+                .add_modifier(Modifier::DIM),
+        )
+        .alignment(Alignment::Center);
+
+    let dialog = Block::default()
+        .title(heading)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::highlight()))
+        .border_type(ratatui::widgets::BorderType::Double)
+        .style(Style::default().bg(colors::bg()));
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(dialog, area);
+    f.render_widget(field, chunks[0]);
+    f.render_widget(instructions, chunks[1]);
+}
+
+/// The text as lines, with `█` drawn at the cursor
+fn field_lines(input: &TextInput) -> Vec<Line<'static>> {
+    let (before, after) = input.split_at_cursor();
+    format!("{before}\u{2588}{after}")
+        .split('\n')
+        .map(|line| Line::raw(line.to_string()))
+        .collect()
+}
+
+/// Wrapped row the cursor sits on, assuming `width` columns per row
+fn cursor_row(input: &TextInput, width: usize) -> usize {
+    let (before, _) = input.split_at_cursor();
+    let mut lines = before.split('\n').peekable();
+    let mut row = 0;
+    while let Some(line) = lines.next() {
+        let chars = line.chars().count();
+        if lines.peek().is_some() {
+            // Full line: at least one row, plus one per extra `width` chars
+            row += chars.saturating_sub(1) / width + 1;
+        } else {
+            row += chars / width;
+        }
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_row_counts_wrapped_lines() {
+        let mut input = TextInput::default();
+        input.insert_str("abcdef\n\nxy");
+        assert_eq!(cursor_row(&input, 4), 3, "abcd/ef, blank, then xy");
+        input.insert_str("zzz");
+        assert_eq!(cursor_row(&input, 4), 4, "xyzz wrapped, cursor on next row");
+    }
+}
@@ -23,15 +23,15 @@ pub fn render_insights_viewer(f: &mut Frame, area: Rect, app: &mut App) {
             let msg = Paragraph::new(
                 "No insights report found.\nRun /insights in Claude Code to generate one.",
             )
-            .style(Style::default().fg(colors::IDLE))
+            .style(Style::default().fg(colors::idle()))
             .block(
                 Block::default()
                     .title(" Claude Code Insights ")
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(colors::HIGHLIGHT))
+                    .border_style(Style::default().fg(colors::highlight()))
                     .border_type(ratatui::widgets::BorderType::Rounded)
                     .title_bottom(Line::from(" Esc:close ").centered())
-                    .style(Style::default().bg(colors::BG)),
+                    .style(Style::default().bg(colors::bg())),
             );
             f.render_widget(msg, area);
             return;
@@ -63,10 +63,10 @@ pub fn render_insights_viewer(f: &mut Frame, area: Rect, app: &mut App) {
     // Tab bar
     let tabs = Tabs::new(tab_titles)
         .select(active)
-        .style(Style::default().fg(colors::FG))
+        .style(Style::default().fg(colors::fg()))
         .highlight_style(
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )
         .divider(" | ")
@@ -74,9 +74,9 @@ pub fn render_insights_viewer(f: &mut Frame, area: Rect, app: &mut App) {
             Block::default()
                 .title(" Claude Code Insights ")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
     f.render_widget(tabs, chunks[0]);
 
@@ -102,7 +102,7 @@ fn render_section(
     lines.push(Line::from(Span::styled(
         title,
         Style::default()
-            .fg(colors::HIGHLIGHT)
+            .fg(colors::highlight())
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
@@ -144,12 +144,12 @@ fn render_section(
             let label = format!("{:>width$}", bar.label, width = max_label);
 
             lines.push(Line::from(vec![
-                Span::styled(format!("  {label} "), Style::default().fg(colors::FG)),
-                Span::styled("█".repeat(filled.max(1)), Style::default().fg(colors::WORKING)),
+                Span::styled(format!("  {label} "), Style::default().fg(colors::fg())),
+                Span::styled("█".repeat(filled.max(1)), Style::default().fg(colors::working())),
                 Span::raw(" ".repeat(empty)),
                 Span::styled(
                     format!(" {}", bar.value),
-                    Style::default().fg(colors::IDLE),
+                    Style::default().fg(colors::idle()),
                 ),
             ]));
         }
@@ -169,12 +169,12 @@ fn render_section(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::BORDER))
+                .border_style(Style::default().fg(colors::border()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title_bottom(
                     Line::from(" Tab:sections  j/k:scroll  Esc:close ").centered(),
                 )
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
 
     f.render_widget(paragraph, area);
@@ -190,8 +190,8 @@ fn render_section(
         let mut state = ScrollbarState::new(max_scroll as usize).position(scroll_pos as usize);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut state,
         );
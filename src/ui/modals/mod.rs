@@ -2,19 +2,49 @@
 //!
 //! This module contains all modal/popup rendering functions.
 
+mod agent_detail;
+mod approve;
+mod audit;
+mod checkpoints;
+mod copy;
 mod debug;
 mod event_log;
+mod failures;
 mod help;
 mod history;
+mod input;
 mod insights;
+mod palette;
+mod pane_output;
 mod plans;
+mod playback;
+mod quit;
+mod sources;
 mod spawn;
 mod stats;
+mod sweep;
+mod transcript;
+mod workspaces;
+pub use agent_detail::render_agent_detail;
+pub use approve::render_bulk_approve;
+pub use audit::render_audit_log;
+pub use checkpoints::render_checkpoint_viewer;
+pub use copy::render_copy_menu;
 pub use debug::render_debug_viewer;
-pub use event_log::render_event_log;
+pub use event_log::{render_event_log, render_event_log_view};
+pub use failures::render_failures;
 pub use help::render_help;
 pub use history::render_history_viewer;
+pub use input::render_input_dialog;
 pub use insights::render_insights_viewer;
+pub use palette::render_palette;
+pub use pane_output::render_pane_output;
 pub use plans::render_plan_viewer;
+pub use playback::render_playback;
+pub use quit::render_quit_confirm;
+pub use sources::render_sources;
 pub use spawn::render_spawn_dialog;
 pub use stats::render_stats_viewer;
+pub use sweep::render_sweep_confirm;
+pub use transcript::render_transcript;
+pub use workspaces::render_workspace_menu;
@@ -0,0 +1,82 @@
+//! Command palette modal: filter box over every action and its keys
+
+use crate::app::{App, PaletteEntry};
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    style::Modifier,
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+/// Description with matched characters underlined, then the keys
+fn entry_line(entry: &PaletteEntry) -> Line<'static> {
+    let color = if entry.available {
+        colors::fg()
+    } else {
+        colors::idle()
+    };
+    let base = Style::default().fg(color);
+    let mut spans: Vec<Span> = entry
+        .action
+        .description()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            let style = if entry.positions.contains(&i) {
+                base.add_modifier(Modifier::UNDERLINED)
+            } else {
+                base
+            };
+            Span::styled(c.to_string(), style)
+        })
+        .collect();
+    spans.push(Span::styled(
+        format!("  {}", entry.keys),
+        Style::default().fg(colors::highlight()),
+    ));
+    Line::from(spans)
+}
+
+pub fn render_palette(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, f.area());
+    f.render_widget(ratatui::widgets::Clear, area);
+
+    let block = Block::default()
+        .title(" Commands ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::highlight()))
+        .border_type(ratatui::widgets::BorderType::Double)
+        .title_bottom(Line::from(" Enter:run  \u{2191}\u{2193}:move  Esc:close ").centered())
+        .style(Style::default().bg(colors::bg()));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(inner);
+
+    let prompt = Paragraph::new(format!(" > {}\u{2588}", app.palette_query)) // █ cursor
+        .style(Style::default().fg(colors::fg()));
+    f.render_widget(prompt, chunks[0]);
+
+    let entries = app.palette_entries();
+    if entries.is_empty() {
+        let empty =
+            Paragraph::new("   No matching commands").style(Style::default().fg(colors::idle()));
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+    let items: Vec<ListItem> = entries
+        .iter()
+        .map(|entry| ListItem::new(entry_line(entry)))
+        .collect();
+    let list = List::new(items)
+        .highlight_symbol(" \u{25b8} ") // ▸
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(app.palette_selected));
+    f.render_stateful_widget(list, chunks[1], &mut state);
+}
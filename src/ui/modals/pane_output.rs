@@ -0,0 +1,72 @@
+//! Pane output: the selected agent's tmux pane, colors and scrollback kept
+//!
+//! Docked on the right half like the transcript tail. The newest line sits at
+//! the bottom while following; scrolling back freezes the capture.
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::transcript::docked_right;
+
+pub fn render_pane_output(f: &mut Frame, app: &App) {
+    let area = docked_right(f.area());
+    let height = area.height.saturating_sub(2) as usize;
+
+    let title = match app.state.selected_agent() {
+        Some(agent) => format!(" Output: {} ({}) ", agent.project, agent.pane_id),
+        None => " Output ".to_string(),
+    };
+    let idle = |text: String| Line::styled(text, Style::default().fg(colors::idle()));
+    let (lines, position) = match app.pane_output.as_ref() {
+        None => (vec![idle("  No agent selected".to_string())], String::new()),
+        Some(output) if output.lines.is_empty() => {
+            let text = match output.error.as_deref() {
+                Some(error) => format!("  {error}"),
+                None => "  Capturing\u{2026}".to_string(), // …
+            };
+            (vec![idle(text)], String::new())
+        }
+        Some(output) => {
+            let mut lines = output.visible(height).to_vec();
+            if let Some(error) = output.error.as_deref() {
+                // Keep the last good capture; say why it stopped updating
+                if lines.len() >= height && !lines.is_empty() {
+                    lines.remove(0);
+                }
+                lines.push(Line::styled(
+                    format!("  {error}"),
+                    Style::default().fg(colors::attention()),
+                ));
+            }
+            let position = if output.following() {
+                " following \u{2502}".to_string() // │
+            } else {
+                format!(
+                    " paused, {} lines back \u{2502} End:follow \u{2502}",
+                    output.scroll
+                )
+            };
+            (lines, position)
+        }
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::border()))
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title_bottom(
+                Line::from(format!("{position} PgUp/PgDn:scroll \u{2502} W:close ")).centered(),
+            )
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(paragraph, area);
+}
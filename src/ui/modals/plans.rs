@@ -44,10 +44,10 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
             };
             let style = if i == app.plan_viewer.selected_index {
                 Style::default()
-                    .fg(colors::HIGHLIGHT)
+                    .fg(colors::highlight())
                     .add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(colors::FG)
+                Style::default().fg(colors::fg())
             };
 
             // Truncate name to fit, right-align age and size
@@ -80,10 +80,10 @@ fn render_list(f: &mut Frame, area: Rect, app: &App) {
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title_bottom(Line::from(" j/k:nav  Enter:read  Esc:close ").centered())
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
 
     f.render_widget(list, area);
@@ -125,13 +125,13 @@ fn render_reader(f: &mut Frame, area: Rect, app: &mut App) {
             Block::default()
                 .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
                 .title_bottom(
                     Line::from(" j/k:scroll  d/u:page  g/G:top/bot  n/p:next  Esc:back ")
                         .centered(),
                 )
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
 
     f.render_widget(paragraph, area);
@@ -148,8 +148,8 @@ fn render_reader(f: &mut Frame, area: Rect, app: &mut App) {
             .position(scroll_pos as usize);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut scrollbar_state,
         );
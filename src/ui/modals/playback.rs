@@ -0,0 +1,162 @@
+//! Board recording playback (`--playback`)
+//!
+//! Shows one recorded frame at a time: a scrubber across the whole
+//! recording, status counts, and the agents as they stood at that moment.
+
+use crate::app::PlaybackState;
+use crate::config::{colors, TimeFormat};
+use crate::state::AgentSnapshot;
+use crate::ui::helpers::format_timestamp_at;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Render the playback timeline
+pub fn render_playback(f: &mut Frame, area: Rect, playback: &PlaybackState) {
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(
+            " Board playback ({} frames) ",
+            playback.frames.len()
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::highlight()))
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title_bottom(
+            Line::from(" h/l:step  PgUp/PgDn:10  g/G:start/end  q:quit ")
+                .style(Style::default().fg(colors::idle()))
+                .centered(),
+        )
+        .style(Style::default().bg(colors::bg()));
+
+    let (Some(frame), Some(first)) = (playback.current(), playback.frames.first()) else {
+        let msg = Paragraph::new("Recording has no frames.")
+            .style(Style::default().fg(colors::idle()))
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    };
+
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                format!(
+                    "frame {}/{}  ",
+                    playback.position + 1,
+                    playback.frames.len()
+                ),
+                Style::default()
+                    .fg(colors::highlight())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format_timestamp_at(frame.timestamp, frame.timestamp, TimeFormat::Clock),
+                Style::default().fg(colors::fg()),
+            ),
+            Span::styled(
+                format!("  +{}", elapsed(frame.timestamp - first.timestamp)),
+                Style::default().fg(colors::idle()),
+            ),
+        ]),
+        Line::from(Span::styled(
+            scrubber(playback.position, playback.frames.len(), inner_width),
+            Style::default().fg(colors::highlight()),
+        )),
+        status_counts(&frame.agents),
+        Line::raw(""),
+    ];
+    lines.extend(frame.agents.iter().map(agent_line));
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// "1h 02m" style offset from the start of the recording
+fn elapsed(secs: i64) -> String {
+    let secs = secs.max(0);
+    if secs < 3600 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// "├───●──────┤" with the knob at `position` of `count` frames
+fn scrubber(position: usize, count: usize, width: usize) -> String {
+    let track = width.saturating_sub(2).max(1);
+    let knob = if count > 1 {
+        position * (track - 1) / (count - 1)
+    } else {
+        0
+    };
+    let mut bar = String::from("\u{251c}"); // ├
+    for i in 0..track {
+        bar.push(if i == knob { '\u{25cf}' } else { '\u{2500}' }); // ● ─
+    }
+    bar.push('\u{2524}'); // ┤
+    bar
+}
+
+fn status_counts(agents: &[AgentSnapshot]) -> Line<'static> {
+    let count = |status: &str| agents.iter().filter(|a| a.status == status).count();
+    Line::from(vec![
+        Span::styled(
+            format!("{} attention", count("attention")),
+            Style::default().fg(colors::attention()),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!("{} working", count("working")),
+            Style::default().fg(colors::working()),
+        ),
+        Span::raw("  "),
+        Span::styled(
+            format!("{} compacting", count("compacting")),
+            Style::default().fg(colors::compacting()),
+        ),
+    ])
+}
+
+fn agent_line(agent: &AgentSnapshot) -> Line<'static> {
+    let color = match agent.status.as_str() {
+        "attention" => colors::attention(),
+        "compacting" => colors::compacting(),
+        "gone" => colors::idle(),
+        _ => colors::working(),
+    };
+    let status = agent
+        .attention_type
+        .as_deref()
+        .unwrap_or(agent.status.as_str());
+    let team = agent
+        .team_name
+        .as_deref()
+        .map(|t| format!(" [{t}]"))
+        .unwrap_or_default();
+    Line::from(vec![
+        Span::styled(format!("{:<13}", status), Style::default().fg(color)),
+        Span::styled(
+            format!("{:<6} {}{team}", agent.pane_id, agent.project),
+            Style::default().fg(colors::fg()),
+        ),
+        Span::styled(
+            format!("  {}", agent.current_tool.as_deref().unwrap_or("")),
+            Style::default().fg(colors::idle()),
+        ),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scrubber_knob_spans_track() {
+        assert_eq!(scrubber(0, 5, 7), "├●────┤");
+        assert_eq!(scrubber(4, 5, 7), "├────●┤");
+        assert_eq!(scrubber(0, 1, 4), "├●─┤");
+    }
+}
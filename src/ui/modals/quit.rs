@@ -0,0 +1,45 @@
+//! Quit confirmation: `q` while agents are still working or compacting
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+pub fn render_quit_confirm(f: &mut Frame, app: &App) {
+    let area = centered_rect(40, 20, f.area());
+    let busy = app.busy_agent_count();
+
+    let lines = vec![
+        Line::from(""),
+        Line::styled(
+            format!(
+                "  {busy} agent{} still working \u{2014} quit anyway?", // —
+                if busy == 1 { " is" } else { "s are" }
+            ),
+            Style::default().fg(colors::fg()),
+        ),
+        Line::from(""),
+        Line::styled(
+            "  Agents keep running in tmux; only the board closes.",
+            Style::default().fg(colors::idle()),
+        ),
+    ];
+
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Quit? ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::attention()))
+            .border_type(ratatui::widgets::BorderType::Double)
+            .title_bottom(Line::from(" y:quit  any other key:cancel ").centered())
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(dialog, area);
+}
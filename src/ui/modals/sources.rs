@@ -0,0 +1,59 @@
+//! Connections modal: events delivered per local socket and sprite
+
+use crate::app::App;
+use crate::config::colors;
+use crate::state::{current_timestamp, SourceHealth, STREAMING_SECS};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, List, ListItem},
+    Frame,
+};
+
+use super::super::helpers::{centered_rect, format_timestamp_at, truncate};
+
+pub fn render_sources(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 50, f.area());
+    let now = current_timestamp();
+    let rows = app.state.source_rows(now);
+
+    let items: Vec<ListItem> = if rows.is_empty() {
+        vec![ListItem::new("  No events received yet").style(Style::default().fg(colors::idle()))]
+    } else {
+        rows.iter()
+            .map(|row| {
+                let last = row.stats.last_event.map_or_else(
+                    || "never".to_string(),
+                    |ts| format_timestamp_at(ts, now, app.time_format),
+                );
+                let line = format!(
+                    "  {:24} │ {:12} │ {:>8} events │ last {}",
+                    truncate(&row.key.label(), 24),
+                    row.health.label(),
+                    row.stats.events,
+                    last,
+                );
+                let color = match row.health {
+                    SourceHealth::Streaming => colors::working(),
+                    SourceHealth::Silent => colors::attention(),
+                    SourceHealth::Disconnected => colors::idle(),
+                };
+                ListItem::new(line).style(Style::default().fg(color))
+            })
+            .collect()
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(" Connections ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::border()))
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title_bottom(
+                Line::from(format!(" silent: nothing in the last {STREAMING_SECS}s ")).centered(),
+            )
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(list, area);
+}
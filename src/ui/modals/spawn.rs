@@ -33,19 +33,19 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     let field_style = |active: bool| {
         if active {
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(colors::FG)
+            Style::default().fg(colors::fg())
         }
     };
     let border_style = |active: bool| {
         if active {
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(colors::BORDER)
+            Style::default().fg(colors::border())
         }
     };
 
@@ -78,12 +78,17 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     } else {
         field_style(spawn_state.active_field == 0)
     };
-    let project_widget = Paragraph::new(display_text).style(text_style).block(
-        Block::default()
-            .title(field_title)
-            .borders(Borders::ALL)
-            .border_style(border_style(spawn_state.active_field == 0)),
-    );
+    let mut project_block = Block::default()
+        .title(field_title)
+        .borders(Borders::ALL)
+        .border_style(border_style(spawn_state.active_field == 0));
+    if let Some(hint) = spawn_state.recent_hint() {
+        project_block =
+            project_block.title_bottom(Line::from(hint).style(Style::default().fg(colors::idle())));
+    }
+    let project_widget = Paragraph::new(display_text)
+        .style(text_style)
+        .block(project_block);
 
     // Prompt field (1)
     let prompt_cursor = if spawn_state.active_field == 1 {
@@ -91,7 +96,7 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     } else {
         ""
     };
-    let prompt_placeholder = "e.g. Build a REST API with authentication...";
+    let prompt_placeholder = "e.g. Build a REST API... (or @tasks.txt: one agent per line)";
     let prompt_display = if spawn_state.prompt.is_empty() && spawn_state.active_field != 1 {
         prompt_placeholder.to_string()
     } else {
@@ -131,7 +136,9 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
         .style(if spawn_state.use_sprite {
             field_style(spawn_state.active_field == 3)
         } else {
-            Style::default().fg(colors::FG).add_modifier(Modifier::DIM)
+            Style::default()
+                .fg(colors::fg())
+                .add_modifier(Modifier::DIM)
         })
         .alignment(Alignment::Center)
         .block(
@@ -142,7 +149,7 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
                     border_style(spawn_state.active_field == 3)
                 } else {
                     Style::default()
-                        .fg(colors::BORDER)
+                        .fg(colors::border())
                         .add_modifier(Modifier::DIM)
                 }),
         );
@@ -150,7 +157,11 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     // Validation error display
     let error_widget = if let Some(ref error) = spawn_state.validation_error {
         Paragraph::new(format!("⚠ {}", error))
-            .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .style(
+                Style::default()
+                    .fg(colors::error())
+                    .add_modifier(Modifier::BOLD),
+            )
             .alignment(Alignment::Center)
     } else {
         Paragraph::new("")
@@ -162,7 +173,7 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     )
     .style(
         Style::default()
-            .fg(colors::IDLE)
+            .fg(colors::idle())
             .add_modifier(Modifier::DIM),
     )
     .alignment(Alignment::Center);
@@ -171,9 +182,9 @@ pub fn render_spawn_dialog(f: &mut Frame, spawn_state: &SpawnState) {
     let dialog = Block::default()
         .title(" Spawn New Agent ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(colors::HIGHLIGHT))
+        .border_style(Style::default().fg(colors::highlight()))
         .border_type(ratatui::widgets::BorderType::Double)
-        .style(Style::default().bg(colors::BG));
+        .style(Style::default().bg(colors::bg()));
 
     f.render_widget(ratatui::widgets::Clear, area);
     f.render_widget(dialog, area);
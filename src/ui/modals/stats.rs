@@ -1,10 +1,13 @@
-//! Stats dashboard modal — Overview, Models, Activity, Quality tabs
+//! Stats dashboard modal — Overview, Models, Activity, Quality, Latency tabs
 //!
 //! Renders a tabbed overlay with Claude Code usage statistics from
-//! stats-cache.json and facet data.
+//! stats-cache.json and facet data, plus live tool latency and fleet
+//! composition (agents by model and effort level) and a running cost
+//! estimate from hook events.
 
 use crate::app::App;
 use crate::config::colors;
+use crate::state::{format_cost, format_latency, FleetBreakdown, FleetCost, FleetRow};
 use ratatui::{
     prelude::*,
     widgets::{
@@ -17,7 +20,7 @@ use ratatui::{
 pub fn render_stats_viewer(f: &mut Frame, area: Rect, app: &mut App) {
     f.render_widget(Clear, area);
 
-    let tab_titles = vec!["Overview", "Models", "Activity", "Quality"];
+    let tab_titles = vec!["Overview", "Models", "Activity", "Quality", "Latency"];
     let active_tab = app.stats_viewer.active_tab;
 
     // Split into tab bar + content
@@ -29,10 +32,10 @@ pub fn render_stats_viewer(f: &mut Frame, area: Rect, app: &mut App) {
     // Tab bar
     let tabs = Tabs::new(tab_titles)
         .select(active_tab)
-        .style(Style::default().fg(colors::FG))
+        .style(Style::default().fg(colors::fg()))
         .highlight_style(
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )
         .divider(" | ")
@@ -40,9 +43,9 @@ pub fn render_stats_viewer(f: &mut Frame, area: Rect, app: &mut App) {
             Block::default()
                 .title(" Stats Dashboard ")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(colors::HIGHLIGHT))
+                .border_style(Style::default().fg(colors::highlight()))
                 .border_type(ratatui::widgets::BorderType::Rounded)
-                .style(Style::default().bg(colors::BG)),
+                .style(Style::default().bg(colors::bg())),
         );
     f.render_widget(tabs, chunks[0]);
 
@@ -53,6 +56,7 @@ pub fn render_stats_viewer(f: &mut Frame, area: Rect, app: &mut App) {
         1 => render_models(f, content_area, app),
         2 => render_activity(f, content_area, app),
         3 => render_quality(f, content_area, app),
+        4 => render_latency(f, content_area, app),
         _ => {}
     }
 }
@@ -62,7 +66,7 @@ fn render_overview(f: &mut Frame, area: Rect, app: &mut App) {
         Some(s) => s,
         None => {
             let msg = Paragraph::new("No stats data found. Run Claude Code to generate stats.")
-                .style(Style::default().fg(colors::IDLE))
+                .style(Style::default().fg(colors::idle()))
                 .block(content_block(" Overview ", " Tab:switch  j/k:scroll  Esc:close "));
             f.render_widget(msg, area);
             return;
@@ -75,7 +79,7 @@ fn render_overview(f: &mut Frame, area: Rect, app: &mut App) {
     lines.push(Line::from(Span::styled(
         "SUMMARY",
         Style::default()
-            .fg(colors::HIGHLIGHT)
+            .fg(colors::highlight())
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
@@ -109,7 +113,7 @@ fn render_overview(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "ACTIVITY (last 14 days)",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -140,7 +144,7 @@ fn render_overview(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "HOUR OF DAY",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -175,35 +179,33 @@ fn render_overview(f: &mut Frame, area: Rect, app: &mut App) {
 }
 
 fn render_models(f: &mut Frame, area: Rect, app: &mut App) {
-    let stats = match &app.state.stats_cache {
-        Some(s) => s,
-        None => {
-            let msg = Paragraph::new("No model usage data available.")
-                .style(Style::default().fg(colors::IDLE))
-                .block(content_block(" Models ", " Tab:switch  Esc:close "));
-            f.render_widget(msg, area);
-            return;
-        }
-    };
-
-    let mut lines: Vec<Line> = Vec::new();
-    lines.push(Line::from(Span::styled(
-        "TOKEN USAGE BY MODEL",
-        Style::default()
-            .fg(colors::HIGHLIGHT)
-            .add_modifier(Modifier::BOLD),
-    )));
+    // Live fleet first: it needs no stats-cache.json
+    let mut lines: Vec<Line> = fleet_lines(&app.state.fleet_breakdown());
     lines.push(Line::from(""));
+    lines.extend(cost_lines(&app.state.fleet_cost()));
+    lines.push(Line::from(""));
+    lines.push(section_heading("TOKEN USAGE BY MODEL"));
+    lines.push(Line::from(""));
+
+    let model_usage = match &app.state.stats_cache {
+        Some(stats) => stats.model_usage.as_slice(),
+        None => &[],
+    };
+    if model_usage.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No model usage data available.",
+            Style::default().fg(colors::idle()),
+        )));
+    }
 
-    let max_cache = stats
-        .model_usage
+    let max_cache = model_usage
         .iter()
         .map(|m| m.cache_read)
         .max()
         .unwrap_or(1)
         .max(1);
 
-    for model in &stats.model_usage {
+    for model in model_usage {
         let bar_width = (model.cache_read as f64 / max_cache as f64 * 20.0) as usize;
         let bar: String = "█".repeat(bar_width.max(1));
         let padding = " ".repeat(20usize.saturating_sub(bar_width));
@@ -216,8 +218,8 @@ fn render_models(f: &mut Frame, area: Rect, app: &mut App) {
         };
 
         lines.push(Line::from(vec![
-            Span::styled(format!("  {name} "), Style::default().fg(colors::FG)),
-            Span::styled(bar, Style::default().fg(colors::WORKING)),
+            Span::styled(format!("  {name} "), Style::default().fg(colors::fg())),
+            Span::styled(bar, Style::default().fg(colors::working())),
             Span::raw(padding),
             Span::styled(
                 format!(
@@ -226,7 +228,7 @@ fn render_models(f: &mut Frame, area: Rect, app: &mut App) {
                     format_tokens(model.input),
                     format_tokens(model.output),
                 ),
-                Style::default().fg(colors::IDLE),
+                Style::default().fg(colors::idle()),
             ),
         ]));
     }
@@ -249,7 +251,7 @@ fn render_activity(f: &mut Frame, area: Rect, app: &mut App) {
         Some(s) => s,
         None => {
             let msg = Paragraph::new("No activity data available.")
-                .style(Style::default().fg(colors::IDLE))
+                .style(Style::default().fg(colors::idle()))
                 .block(content_block(" Activity ", " Tab:switch  Esc:close "));
             f.render_widget(msg, area);
             return;
@@ -263,7 +265,7 @@ fn render_activity(f: &mut Frame, area: Rect, app: &mut App) {
         Span::styled(
             format!("  {:<12} {:>8} {:>10} {:>8}", "DATE", "SESSIONS", "MESSAGES", "TOOLS"),
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         ),
     ]));
@@ -302,7 +304,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         Some(q) => q,
         None => {
             let msg = Paragraph::new("No session quality data available.")
-                .style(Style::default().fg(colors::IDLE))
+                .style(Style::default().fg(colors::idle()))
                 .block(content_block(" Quality ", " Tab:switch  Esc:close "));
             f.render_widget(msg, area);
             return;
@@ -323,7 +325,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
     lines.push(Line::from(Span::styled(
         format!("OUTCOMES ({total} sessions)"),
         Style::default()
-            .fg(colors::HIGHLIGHT)
+            .fg(colors::highlight())
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(Line::from(""));
@@ -333,9 +335,9 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
     let empty = 20usize.saturating_sub(filled);
     lines.push(Line::from(vec![
         Span::raw("  "),
-        Span::styled("█".repeat(filled), Style::default().fg(colors::WORKING)),
-        Span::styled("░".repeat(empty), Style::default().fg(colors::BORDER)),
-        Span::styled(format!(" {pct:.0}%"), Style::default().fg(colors::FG)),
+        Span::styled("█".repeat(filled), Style::default().fg(colors::working())),
+        Span::styled("░".repeat(empty), Style::default().fg(colors::border())),
+        Span::styled(format!(" {pct:.0}%"), Style::default().fg(colors::fg())),
     ]));
     lines.push(Line::from(format!(
         "  fully: {}, mostly: {}, partially: {}, not achieved: {}, other: {}",
@@ -352,7 +354,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "HELPFULNESS",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -367,7 +369,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "TOP GOALS",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -382,7 +384,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "SATISFACTION",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -397,7 +399,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "TOP FRICTION",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -412,7 +414,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "SESSION MIX",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -427,7 +429,7 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
         lines.push(Line::from(Span::styled(
             "SUCCESS PATTERNS",
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(""));
@@ -451,14 +453,214 @@ fn render_quality(f: &mut Frame, area: Rect, app: &mut App) {
     render_scrollbar(f, area, max_scroll, app.stats_viewer.scroll_offset);
 }
 
+fn render_latency(f: &mut Frame, area: Rect, app: &mut App) {
+    let rows = app.state.tool_latency_table();
+    if rows.is_empty() {
+        let msg = Paragraph::new("No tool calls recorded yet.")
+            .style(Style::default().fg(colors::idle()))
+            .block(content_block(" Latency ", " Tab:switch  Esc:close "));
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(Span::styled(
+        format!(
+            "  {:<20} {:>6} {:>8} {:>8} {:>8} {:>10}",
+            "TOOL", "CALLS", "P50", "P95", "P99", "INCOMPLETE"
+        ),
+        Style::default()
+            .fg(colors::highlight())
+            .add_modifier(Modifier::BOLD),
+    )));
+    lines.push(Line::from(format!("  {}", "-".repeat(65))));
+
+    for row in &rows {
+        let name = if row.tool.chars().count() > 20 {
+            format!("{}…", row.tool.chars().take(19).collect::<String>())
+        } else {
+            row.tool.clone()
+        };
+        let pct = |ms: u64| {
+            if row.count == 0 {
+                "-".to_string()
+            } else {
+                format_latency(ms)
+            }
+        };
+        let incomplete_style = if row.incomplete > 0 {
+            Style::default().fg(colors::attention())
+        } else {
+            Style::default().fg(colors::idle())
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!(
+                    "  {:<20} {:>6} {:>8} {:>8} {:>8}",
+                    name,
+                    row.count,
+                    pct(row.p50_ms),
+                    pct(row.p95_ms),
+                    pct(row.p99_ms)
+                ),
+                Style::default().fg(colors::fg()),
+            ),
+            Span::styled(format!(" {:>10}", row.incomplete), incomplete_style),
+        ]));
+    }
+
+    let total_lines = lines.len() as u16;
+    let inner_height = area.height.saturating_sub(4);
+    let max_scroll = total_lines.saturating_sub(inner_height);
+    if app.stats_viewer.scroll_offset > max_scroll {
+        app.stats_viewer.scroll_offset = max_scroll;
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .scroll((app.stats_viewer.scroll_offset, 0))
+        .block(content_block(
+            " Latency ",
+            " Tab:switch  j/k:scroll  Esc:close ",
+        ));
+    f.render_widget(paragraph, area);
+
+    render_scrollbar(f, area, max_scroll, app.stats_viewer.scroll_offset);
+}
+
+fn section_heading(text: &'static str) -> Line<'static> {
+    Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(colors::highlight())
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Agents per model and per effort level, with each group's share of turns
+fn fleet_lines(fleet: &FleetBreakdown) -> Vec<Line<'static>> {
+    let mut lines = vec![section_heading("FLEET BY MODEL"), Line::from("")];
+    if fleet.by_model.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No agents tracked.",
+            Style::default().fg(colors::idle()),
+        )));
+        return lines;
+    }
+    lines.extend(fleet_rows(&fleet.by_model, fleet.total_turns));
+    lines.push(Line::from(""));
+    lines.push(section_heading("FLEET BY EFFORT"));
+    lines.push(Line::from(""));
+    lines.extend(fleet_rows(&fleet.by_effort, fleet.total_turns));
+    lines
+}
+
+fn fleet_rows(rows: &[FleetRow], total_turns: u32) -> Vec<Line<'static>> {
+    let max_agents = rows.iter().map(|r| r.agents).max().unwrap_or(1).max(1);
+    rows.iter()
+        .map(|row| {
+            let bar_width = (row.agents * 20).div_ceil(max_agents);
+            let name: String = if row.label.chars().count() > 14 {
+                row.label
+                    .chars()
+                    .take(13)
+                    .chain(std::iter::once('…'))
+                    .collect()
+            } else {
+                format!("{:<14}", row.label)
+            };
+            let share = if total_turns == 0 {
+                String::new()
+            } else {
+                format!(
+                    " ({:.0}%)",
+                    f64::from(row.turns) * 100.0 / f64::from(total_turns)
+                )
+            };
+            Line::from(vec![
+                Span::styled(format!("  {name} "), Style::default().fg(colors::fg())),
+                Span::styled(
+                    "█".repeat(bar_width),
+                    Style::default().fg(colors::working()),
+                ),
+                Span::raw(" ".repeat(20 - bar_width)),
+                Span::styled(
+                    format!(" {:>3} agents  {:>4} turns{share}", row.agents, row.turns),
+                    Style::default().fg(colors::idle()),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// Running cost estimate from `[pricing]` and accrued context tokens
+fn cost_lines(fleet: &FleetCost) -> Vec<Line<'static>> {
+    let dim = Style::default().fg(colors::idle());
+    let mut lines = vec![section_heading("COST ESTIMATE"), Line::from("")];
+    if fleet.by_model.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No token usage reported yet.",
+            dim,
+        )));
+        return lines;
+    }
+    for row in &fleet.by_model {
+        let name: String = if row.model.chars().count() > 14 {
+            row.model
+                .chars()
+                .take(13)
+                .chain(std::iter::once('…'))
+                .collect()
+        } else {
+            format!("{:<14}", row.model)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {name} "), Style::default().fg(colors::fg())),
+            Span::styled(
+                format!("{:>9}", format_cost(row.cost)),
+                Style::default().fg(colors::working()),
+            ),
+            Span::styled(
+                format!(
+                    "  {:>3} agents  {:>6} tokens",
+                    row.agents,
+                    format_tokens(row.tokens)
+                ),
+                dim,
+            ),
+        ]));
+    }
+    let unpriced = if fleet.unpriced_tokens == 0 {
+        String::new()
+    } else {
+        format!(
+            "  (+{} tokens on unpriced models)",
+            format_tokens(fleet.unpriced_tokens)
+        )
+    };
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("  {:<14} {:>9}", "fleet", format_cost(Some(fleet.total))),
+            Style::default()
+                .fg(colors::highlight())
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(unpriced, dim),
+    ]));
+    lines.push(Line::from(Span::styled(
+        "  Context growth × [pricing] rate; a ballpark, not a bill.",
+        dim,
+    )));
+    lines
+}
+
 fn content_block<'a>(title: &'a str, footer: &'a str) -> Block<'a> {
     Block::default()
         .title(title)
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(colors::BORDER))
+        .border_style(Style::default().fg(colors::border()))
         .border_type(ratatui::widgets::BorderType::Rounded)
         .title_bottom(Line::from(footer).centered())
-        .style(Style::default().bg(colors::BG))
+        .style(Style::default().bg(colors::bg()))
 }
 
 fn render_scrollbar(f: &mut Frame, area: Rect, max_scroll: u16, scroll_offset: u16) {
@@ -473,8 +675,8 @@ fn render_scrollbar(f: &mut Frame, area: Rect, max_scroll: u16, scroll_offset: u
             ScrollbarState::new(max_scroll as usize).position(scroll_offset as usize);
         f.render_stateful_widget(
             Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .thumb_style(Style::default().fg(colors::HIGHLIGHT))
-                .track_style(Style::default().fg(colors::BORDER)),
+                .thumb_style(Style::default().fg(colors::highlight()))
+                .track_style(Style::default().fg(colors::border())),
             scrollbar_area,
             &mut state,
         );
@@ -0,0 +1,65 @@
+//! Sweep confirmation: which gone/idle agents `X` is about to remove
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::{centered_rect, format_timestamp};
+
+/// Agents listed by name before collapsing to "… and N more"
+const MAX_LISTED: usize = 8;
+
+pub fn render_sweep_confirm(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, f.area());
+    let pending = &app.sweep_pending;
+
+    let mut lines: Vec<Line> = vec![Line::from("")];
+    for pane_id in pending.iter().take(MAX_LISTED) {
+        let Some(agent) = app.state.agents.get(pane_id) else {
+            continue;
+        };
+        let why = if agent.is_gone() { "gone" } else { "idle" };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} ({pane_id})", agent.project),
+                Style::default().fg(colors::fg()),
+            ),
+            Span::styled(
+                format!(
+                    "  {why} \u{b7} {}", // ·
+                    format_timestamp(agent.last_update, app.time_format)
+                ),
+                Style::default().fg(colors::idle()),
+            ),
+        ]));
+    }
+    if pending.len() > MAX_LISTED {
+        lines.push(Line::styled(
+            format!("  \u{2026} and {} more", pending.len() - MAX_LISTED), // …
+            Style::default().fg(colors::idle()),
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::styled(
+        "  Only removed from the board; tmux panes are not touched.",
+        Style::default().fg(colors::idle()),
+    ));
+
+    let title = format!(" Sweep {} agents? ", pending.len());
+    let dialog = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::attention()))
+            .border_type(ratatui::widgets::BorderType::Double)
+            .title_bottom(Line::from(" y:remove  any other key:cancel ").centered())
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(dialog, area);
+}
@@ -0,0 +1,124 @@
+//! Transcript tail: the selected agent's last messages, read from its `.jsonl`
+//!
+//! Docked on the right half so the board stays visible beside it. Newest
+//! messages sit at the bottom; older ones scroll off the top.
+
+use crate::app::App;
+use crate::config::colors;
+use crate::state::{TranscriptMessage, TranscriptRole};
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Lines shown per message before it is cut with "…"
+const MAX_LINES_PER_MESSAGE: usize = 4;
+
+/// Right half of the screen
+pub(super) fn docked_right(area: Rect) -> Rect {
+    let width = area.width - area.width / 2;
+    Rect {
+        x: area.x + area.width / 2,
+        width,
+        ..area
+    }
+}
+
+fn role_style(role: TranscriptRole) -> (&'static str, Style) {
+    match role {
+        TranscriptRole::User => (
+            "you",
+            Style::default()
+                .fg(colors::highlight())
+                .add_modifier(Modifier::BOLD),
+        ),
+        TranscriptRole::Assistant => ("claude", Style::default().fg(colors::working())),
+        TranscriptRole::ToolUse => ("\u{2192} tool", Style::default().fg(colors::compacting())), // →
+        TranscriptRole::ToolResult => ("\u{2190} result", Style::default().fg(colors::idle())), // ←
+    }
+}
+
+/// One message as display lines: role header, then up to a few clipped lines
+fn message_lines(message: &TranscriptMessage, width: usize) -> Vec<Line<'static>> {
+    let (label, style) = role_style(message.role);
+    let mut lines = vec![Line::styled(label, style)];
+    let text_style = if message.role == TranscriptRole::ToolResult {
+        Style::default().fg(colors::idle())
+    } else {
+        Style::default().fg(colors::fg())
+    };
+    let body: Vec<&str> = message
+        .text
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .collect();
+    for (i, line) in body.iter().take(MAX_LINES_PER_MESSAGE).enumerate() {
+        let cut = i + 1 == MAX_LINES_PER_MESSAGE && body.len() > MAX_LINES_PER_MESSAGE;
+        let mut text: String = line.chars().take(width.saturating_sub(3)).collect();
+        if cut || text.chars().count() < line.chars().count() {
+            text.push('\u{2026}'); // …
+        }
+        lines.push(Line::styled(format!("  {text}"), text_style));
+    }
+    lines
+}
+
+pub fn render_transcript(f: &mut Frame, app: &App) {
+    let area = docked_right(f.area());
+    let width = area.width.saturating_sub(2) as usize;
+    let height = area.height.saturating_sub(2) as usize;
+
+    let title = match app.state.selected_agent() {
+        Some(agent) => format!(" Transcript: {} ", agent.project),
+        None => " Transcript ".to_string(),
+    };
+    let lines: Vec<Line> = match app.transcript.as_ref() {
+        None => vec![Line::styled(
+            "  Selected agent has no transcript",
+            Style::default().fg(colors::idle()),
+        )],
+        Some(tail) if !tail.found => vec![
+            Line::styled(
+                "  Transcript not found yet",
+                Style::default().fg(colors::idle()),
+            ),
+            Line::styled(
+                "  (new session, or the agent runs on another host)",
+                Style::default().fg(colors::idle()),
+            ),
+        ],
+        Some(tail) if tail.messages.is_empty() => vec![Line::styled(
+            "  No messages yet",
+            Style::default().fg(colors::idle()),
+        )],
+        Some(tail) => {
+            // Build newest-first until the panel is full, then flip
+            let mut lines: Vec<Line> = Vec::new();
+            for message in tail.messages.iter().rev() {
+                let mut block = message_lines(message, width);
+                if !lines.is_empty() && lines.len() + block.len() > height {
+                    break;
+                }
+                block.reverse();
+                lines.extend(block);
+            }
+            lines.reverse();
+            let skip = lines.len().saturating_sub(height);
+            lines.into_iter().skip(skip).collect()
+        }
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::border()))
+            .border_type(ratatui::widgets::BorderType::Rounded)
+            .title_bottom(Line::from(" follows selection \u{2502} O:close ").centered())
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,78 @@
+//! Workspace menu: load a saved layout or save the current one
+
+use crate::app::workspace::Workspace;
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::super::helpers::centered_rect;
+
+pub fn render_workspace_menu(f: &mut Frame, app: &App) {
+    let area = centered_rect(50, 40, f.area());
+
+    let key_style = Style::default().fg(colors::highlight());
+    let mut lines: Vec<Line> = app
+        .workspaces
+        .iter()
+        .take(9)
+        .enumerate()
+        .map(|(i, (name, workspace))| {
+            Line::from(vec![
+                Span::styled(format!(" [{}] {name:<14}", i + 1), key_style),
+                Span::styled(summary(workspace), Style::default().fg(colors::idle())),
+            ])
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(Line::from(Span::styled(
+            " No saved workspaces",
+            Style::default().fg(colors::idle()),
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled(" [s] ", key_style),
+        Span::styled("Save current layout", Style::default().fg(colors::fg())),
+    ]));
+    lines.push(Line::from(Span::styled(
+        " [Esc] Cancel",
+        Style::default().fg(colors::idle()),
+    )));
+
+    let menu = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Workspaces ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(colors::highlight()))
+            .border_type(ratatui::widgets::BorderType::Double)
+            .style(Style::default().bg(colors::bg())),
+    );
+
+    f.render_widget(ratatui::widgets::Clear, area);
+    f.render_widget(menu, area);
+}
+
+/// Short description of what a workspace sets, e.g. "minimap, attention, /api"
+fn summary(workspace: &Workspace) -> String {
+    let mut parts = Vec::new();
+    if workspace.minimap == Some(true) {
+        parts.push("minimap".to_string());
+    }
+    if workspace.attention_only == Some(true) {
+        parts.push("attention".to_string());
+    }
+    if let Some(search) = workspace.search.as_deref().filter(|s| !s.is_empty()) {
+        parts.push(format!("/{search}"));
+    }
+    if workspace.pane_output == Some(true) {
+        parts.push("output".to_string());
+    }
+    if let Some(project) = &workspace.project {
+        parts.push(project.clone());
+    }
+    parts.join(", ")
+}
@@ -0,0 +1,126 @@
+//! Activity sparkline row
+//!
+//! One sparkline per agent while the board is small; above
+//! [`AGGREGATE_THRESHOLD`] agents the per-agent lines get too narrow to read,
+//! so activity is summed per project instead.
+
+use crate::app::App;
+use crate::config::colors;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    prelude::*,
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+/// Agent count above which sparklines are grouped by project
+pub const AGGREGATE_THRESHOLD: usize = 8;
+
+/// Narrowest sparkline worth drawing (label + a few bars)
+const MIN_SPARKLINE_WIDTH: u16 = 12;
+
+/// Render the sparkline row
+pub fn render_activity(f: &mut Frame, area: Rect, app: &App) {
+    let mut series: Vec<(String, Vec<u64>)> = if app.state.agents.len() > AGGREGATE_THRESHOLD {
+        app.state
+            .activity_by_project()
+            .into_iter()
+            .map(|p| {
+                (
+                    format!("{} ({})", p.project, p.agent_count),
+                    p.activity.to_vec(),
+                )
+            })
+            .collect()
+    } else {
+        let mut agents: Vec<_> = app.state.agents.values().collect();
+        agents.sort_by(|a, b| a.project.cmp(&b.project).then(a.pane_id.cmp(&b.pane_id)));
+        agents
+            .into_iter()
+            .map(|a| {
+                let label = a.team_agent_name.as_deref().unwrap_or(&a.project);
+                (label.to_string(), a.activity.to_vec())
+            })
+            .collect()
+    };
+    if series.is_empty() {
+        return;
+    }
+
+    // Drop the quietest series if the row can't fit them all
+    let max_series = usize::from((area.width / MIN_SPARKLINE_WIDTH).max(1));
+    if series.len() > max_series {
+        series.sort_by_key(|(_, data)| std::cmp::Reverse(data.iter().sum::<u64>()));
+        series.truncate(max_series);
+    }
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Ratio(1, series.len() as u32);
+            series.len()
+        ])
+        .split(area);
+
+    for ((label, data), chunk) in series.iter().zip(chunks.iter()) {
+        let sparkline = Sparkline::default()
+            .block(
+                Block::default()
+                    .title(format!(" {label} "))
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(colors::border())),
+            )
+            .data(data)
+            .style(Style::default().fg(colors::working()));
+        f.render_widget(sparkline, *chunk);
+    }
+}
+
+/// Header width for the fleet pulse: its window plus a label, or nothing
+/// when the header is too narrow to spare a quarter of itself
+pub fn fleet_pulse_width(app: &App, header_width: u16) -> u16 {
+    let wanted = u16::try_from(app.state.fleet_pulse_ticks)
+        .unwrap_or(u16::MAX)
+        .saturating_add(PULSE_LABEL.len() as u16 + 1);
+    if app.state.fleet_pulse.is_empty() || wanted > header_width / 4 {
+        0
+    } else {
+        wanted
+    }
+}
+
+/// Label before the fleet pulse
+const PULSE_LABEL: &str = "working";
+
+/// Render the fleet pulse: working agents per tick, newest on the right
+pub fn render_fleet_pulse(f: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Length(PULSE_LABEL.len() as u16 + 1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+    f.render_widget(
+        Paragraph::new(PULSE_LABEL).style(Style::default().fg(colors::idle())),
+        chunks[0],
+    );
+
+    // Pad the front so the newest tick always sits at the right edge
+    let pulse = &app.state.fleet_pulse;
+    let width = usize::from(chunks[1].width);
+    let data: Vec<u64> = std::iter::repeat_n(0, width.saturating_sub(pulse.len()))
+        .chain(
+            pulse
+                .iter()
+                .skip(pulse.len().saturating_sub(width))
+                .copied(),
+        )
+        .collect();
+    let peak = data.iter().copied().max().unwrap_or(0).max(1);
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .max(peak)
+        .style(Style::default().fg(colors::working()));
+    f.render_widget(sparkline, chunks[1]);
+}
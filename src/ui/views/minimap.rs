@@ -0,0 +1,147 @@
+//! Minimap - one colored cell per agent, for fleets too big for cards
+//!
+//! Cells follow the team view's display order (collapsed teams left out, as
+//! in navigation) and wrap into a grid. Attention cells blink; the selected
+//! cell is bracketed and summarised on the last line.
+
+use crate::app::App;
+use crate::config::{colors, StatusColumn};
+use crate::state::{Agent, Status};
+use ratatui::{
+    prelude::*,
+    style::Modifier,
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+/// Columns one cell takes, including the gap after it
+const CELL_WIDTH: u16 = 3;
+
+/// Lines below the grid: blank spacer and the selected agent's summary
+const SUMMARY_LINES: u16 = 2;
+
+/// Cells per grid row inside a minimap of `width` columns (borders included)
+pub fn minimap_columns(width: u16) -> usize {
+    (width.saturating_sub(2) / CELL_WIDTH).max(1) as usize
+}
+
+/// Cell color by display status (hidden columns fold into Working)
+fn cell_color(app: &App, agent: &Agent) -> Color {
+    if agent.is_gone() {
+        return colors::idle();
+    }
+    match &agent.status {
+        _ if app.state.display_column(&agent.status) == StatusColumn::Working => colors::working(),
+        Status::Attention(_) => colors::attention(),
+        Status::Working => colors::working(),
+        Status::Compacting => colors::compacting(),
+    }
+}
+
+fn cell(app: &App, agent: &Agent, selected: bool) -> Span<'static> {
+    let mut style = Style::default().fg(cell_color(app, agent));
+    if matches!(agent.status, Status::Attention(_)) && !agent.is_gone() {
+        style = style.add_modifier(Modifier::SLOW_BLINK);
+    }
+    if selected {
+        let style = Style::default()
+            .fg(colors::highlight())
+            .bg(cell_color(app, agent))
+            .add_modifier(Modifier::BOLD);
+        Span::styled("[]", style)
+    } else if agent.is_gone() {
+        Span::styled("\u{2591}\u{2591}", style) // ░░
+    } else {
+        Span::styled("\u{2588}\u{2588}", style) // ██
+    }
+}
+
+/// "▶ %3 myproject  Permission  Bash" for the selected cell
+fn summary(agent: &Agent) -> Line<'static> {
+    let status = match &agent.status {
+        _ if agent.is_gone() => "gone".to_string(),
+        Status::Attention(kind) => format!("{kind:?}").to_lowercase(),
+        Status::Working => "working".to_string(),
+        Status::Compacting => "compacting".to_string(),
+    };
+    let name = agent.team_agent_name.as_deref().unwrap_or(&agent.pane_id);
+    Line::from(vec![
+        Span::styled(
+            format!("\u{25b6} {name} {}", agent.project), // ▶
+            Style::default()
+                .fg(colors::highlight())
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!("  {status}"), Style::default().fg(colors::fg())),
+        Span::styled(
+            format!("  {}", agent.tool_display()),
+            Style::default().fg(colors::idle()),
+        ),
+    ])
+}
+
+/// Render every visible agent as a grid of status-colored cells
+pub fn render_minimap(f: &mut Frame, area: Rect, app: &App) {
+    let agents = app.state.visible_agents(&app.agent_filter());
+    let selected_pane_id = app.state.selected_agent().map(|a| a.pane_id.as_str());
+
+    let block = Block::default()
+        .title(format!(" Minimap ({} agents) ", agents.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(colors::border()))
+        .border_type(ratatui::widgets::BorderType::Rounded)
+        .title_bottom(
+            Line::from(" h/l:cell  j/k:row  Enter:jump  M:cards ")
+                .style(Style::default().fg(colors::idle()))
+                .centered(),
+        );
+    if agents.is_empty() {
+        let msg = Paragraph::new("No agents")
+            .style(Style::default().fg(colors::idle()))
+            .alignment(Alignment::Center)
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let columns = minimap_columns(area.width);
+    let selected = agents
+        .iter()
+        .position(|a| selected_pane_id == Some(a.pane_id.as_str()));
+    let mut rows: Vec<Line> = agents
+        .chunks(columns)
+        .enumerate()
+        .map(|(row, cells)| {
+            let spans = cells.iter().enumerate().flat_map(|(col, agent)| {
+                let is_selected = selected == Some(row * columns + col);
+                [cell(app, agent, is_selected), Span::raw(" ")]
+            });
+            Line::from(spans.collect::<Vec<_>>())
+        })
+        .collect();
+
+    // Scroll so the selected row stays in view above the summary
+    let grid_height = area.height.saturating_sub(2 + SUMMARY_LINES).max(1) as usize;
+    let selected_row = selected.map_or(0, |idx| idx / columns);
+    let first_row = (selected_row + 1).saturating_sub(grid_height);
+    let mut lines: Vec<Line> = rows.drain(first_row..).take(grid_height).collect();
+    if let Some(agent) = selected.map(|idx| agents[idx]) {
+        lines.resize(grid_height, Line::raw(""));
+        lines.push(Line::raw(""));
+        lines.push(summary(agent));
+    }
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimap_columns_fit_inside_borders() {
+        assert_eq!(minimap_columns(32), 10);
+        assert_eq!(minimap_columns(33), 10);
+        assert_eq!(minimap_columns(2), 1, "never zero columns");
+    }
+}
@@ -1,7 +1,11 @@
 //! View rendering modules
 
+mod activity;
+mod minimap;
 mod task;
 mod team;
 
+pub use activity::{fleet_pulse_width, render_activity, render_fleet_pulse};
+pub use minimap::{minimap_columns, render_minimap};
 pub use task::render_task_board;
 pub use team::render_team_view;
@@ -1,7 +1,11 @@
 //! Task board view - tasks grouped by team in Pending/In Progress/Completed columns
+//!
+//! Each card lists its blockers with their status and the tasks it blocks;
+//! pending tasks whose blockers are all done are highlighted as ready.
 
 use crate::app::App;
 use crate::config::colors;
+use crate::state::{TaskReadiness, TaskStatus};
 use ratatui::{
     layout::{Constraint, Direction, Layout},
     prelude::*,
@@ -17,12 +21,12 @@ pub fn render_task_board(f: &mut Frame, area: Rect, app: &App) {
     if teams.is_empty() {
         let empty = Paragraph::new("No tasks tracked. Press T or Esc to close.")
             .alignment(Alignment::Center)
-            .style(Style::default().fg(colors::IDLE))
+            .style(Style::default().fg(colors::idle()))
             .block(
                 Block::default()
                     .title(" Task Board ")
                     .borders(Borders::ALL)
-                    .border_style(Style::default().fg(colors::BORDER))
+                    .border_style(Style::default().fg(colors::border()))
                     .border_type(ratatui::widgets::BorderType::Rounded),
             );
         f.render_widget(empty, area);
@@ -76,7 +80,7 @@ fn render_team_section(
     let header_widget = Paragraph::new(header)
         .style(
             Style::default()
-                .fg(colors::HIGHLIGHT)
+                .fg(colors::highlight())
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Left);
@@ -93,9 +97,9 @@ fn render_team_section(
         .split(chunks[1]);
 
     let col_configs = [
-        ("Pending", colors::ATTENTION, &columns[0]),
-        ("In Progress", colors::WORKING, &columns[1]),
-        ("Completed", colors::IDLE, &columns[2]),
+        ("Pending", colors::attention(), &columns[0]),
+        ("In Progress", colors::working(), &columns[1]),
+        ("Completed", colors::idle(), &columns[2]),
     ];
 
     for (j, (title, color, tasks)) in col_configs.iter().enumerate() {
@@ -103,24 +107,53 @@ fn render_team_section(
             .iter()
             .map(|task| {
                 let indicator = task.status.indicator();
-                let line1 = format!("{} {}", indicator, task.subject);
+                let ready =
+                    task.status == TaskStatus::Pending && task.readiness == TaskReadiness::Ready;
+                let line1 = if ready {
+                    format!("{} {} \u{25b6} ready", indicator, task.subject) // ▶
+                } else {
+                    format!("{} {}", indicator, task.subject)
+                };
+                let line1_style = if ready {
+                    Style::default()
+                        .fg(colors::working())
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(*color)
+                };
 
                 let mut meta_parts: Vec<String> = Vec::new();
                 if !task.owner_name.is_empty() {
                     meta_parts.push(format!("@{}", task.owner_name));
                 }
-                if !task.blocked_by.is_empty() {
-                    meta_parts.push(format!("blocked:{}", task.blocked_by.len()));
+                if task.readiness == TaskReadiness::Cycle {
+                    meta_parts.push("\u{27f3} cycle".to_string()); // ⟳
+                }
+                if !task.blockers.is_empty() {
+                    // Each blocker carries its own status so a stale block is obvious
+                    let blockers: Vec<String> = task
+                        .blockers
+                        .iter()
+                        .map(|(id, status)| {
+                            format!("#{}{}", id, status.map_or("?", |s| s.indicator()))
+                        })
+                        .collect();
+                    meta_parts.push(format!("blocked by: {}", blockers.join(" ")));
+                }
+                if !task.blocks.is_empty() {
+                    let blocks: Vec<String> =
+                        task.blocks.iter().map(|id| format!("#{id}")).collect();
+                    meta_parts.push(format!("blocks: {}", blocks.join(" ")));
                 }
 
                 let lines = if meta_parts.is_empty() {
-                    vec![Line::from(Span::styled(line1, Style::default().fg(*color)))]
+                    vec![Line::from(Span::styled(line1, line1_style))]
                 } else {
                     vec![
-                        Line::from(Span::styled(line1, Style::default().fg(*color))),
+                        Line::from(Span::styled(line1, line1_style)),
                         Line::from(Span::styled(
                             format!("  {}", meta_parts.join(" ")),
-                            Style::default().fg(colors::IDLE),
+                            Style::default().fg(colors::idle()),
                         )),
                     ]
                 };
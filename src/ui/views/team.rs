@@ -1,37 +1,95 @@
 //! Team view - agents grouped by team with hierarchy
 
 use crate::app::App;
-use crate::config::colors;
-use crate::state::{AttentionType, Status};
+use crate::config::{colors, Density, GroupBy, StatusColumn, TimeFormat};
+use crate::state::{format_cost, AttentionType, ContextLevel, SearchField, Status};
+use crate::ui::helpers::{format_timestamp, format_timestamp_at};
 use ratatui::{
     prelude::*,
     style::Modifier,
-    widgets::{Block, Borders, List, ListItem},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
     Frame,
 };
 
+/// Characters of the last prompt shown under a card
+const CARD_PROMPT_CHARS: usize = 70;
+
+/// Silence before a card shows how long its agent has been idle
+const MIN_IDLE_SHOWN_SECS: i64 = 60;
+
+/// Completed turns before a card shows the iteration sparkline
+const MIN_SPARKLINE_TURNS: usize = 3;
+
+/// Bars for recent turn durations, scaled to the longest
+fn turn_sparkline(durations: impl Iterator<Item = i64> + Clone) -> String {
+    let max = durations.clone().max().unwrap_or(1).max(1);
+    durations
+        .map(|d| {
+            let level = (d * 8 / max).clamp(0, 7) as usize;
+            ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'][level]
+        })
+        .collect()
+}
+
+/// Turn length for the card, e.g. "45s" or "3m12s"
+fn turn_length(secs: i64) -> String {
+    if secs < 60 {
+        format!("{secs}s")
+    } else if secs < 3600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
 /// Render agents grouped by team with tree hierarchy
 pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
-    let teams = app.state.agents_by_team();
+    let filter = app.agent_filter();
+    let teams = app.state.agents_grouped(&filter);
+    let by_team = app.state.group_by == GroupBy::Team;
+    let title = if by_team {
+        " Teams ".to_string()
+    } else {
+        format!(" By {} ", app.state.group_by.label())
+    };
+    let now = crate::state::current_timestamp();
 
     // Get selected agent's pane_id for highlighting
     let selected_pane_id = app.state.selected_agent().map(|a| a.pane_id.as_str());
 
     if teams.is_empty() {
         let placeholder = Block::default()
-            .title(" Teams ")
+            .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors::BORDER))
+            .border_style(Style::default().fg(colors::border()))
             .border_type(ratatui::widgets::BorderType::Rounded);
-        f.render_widget(placeholder, area);
+        // Distinguish "filtered to nothing" from "no agents at all"
+        if (app.attention_only || !app.search_query.is_empty()) && !app.state.agents.is_empty() {
+            let hint = if app.search_query.is_empty() {
+                "No agents match (a: clear attention filter)"
+            } else {
+                "No agents match (Esc: clear search)"
+            };
+            let msg = Paragraph::new(hint)
+                .style(Style::default().fg(colors::idle()))
+                .alignment(Alignment::Center)
+                .block(placeholder);
+            f.render_widget(msg, area);
+        } else {
+            f.render_widget(placeholder, area);
+        }
         return;
     }
 
     let mut items: Vec<ListItem> = Vec::new();
+    // Index of the selected agent's line, so the list scrolls to keep it visible
+    let mut selected_item: Option<usize> = None;
 
     for (team_name, agents) in &teams {
         // Team header
-        let team_icon = if team_name == "Independent" {
+        let team_icon = if !by_team {
+            "\u{25c6}" // ◆
+        } else if team_name == "Independent" {
             "\u{1f464}" // 👤
         } else {
             "\u{1f465}" // 👥
@@ -41,25 +99,58 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
             .state
             .fs_task_lists
             .get(team_name.as_str())
+            .filter(|_| by_team)
             .map(|list| list.tasks.iter().filter(|t| t.status == "pending").count())
             .filter(|&n| n > 0)
             .map(|n| format!(" | {} pending", n))
             .unwrap_or_default();
 
-        let header = format!(
-            "{} {} ({} agent{}){}",
-            team_icon,
-            team_name,
-            agents.len(),
-            if agents.len() == 1 { "" } else { "s" },
-            task_suffix
-        );
+        let collapsed = app.state.collapsed_teams.contains(team_name.as_str());
+        let header = if collapsed {
+            // Summary stands in for the hidden agents
+            let attention = agents
+                .iter()
+                .filter(|a| matches!(a.status, Status::Attention(_)))
+                .count();
+            format!(
+                "\u{25b8} {} {} ({} agent{}, {} need{} attention){}", // ▸
+                team_icon,
+                team_name,
+                agents.len(),
+                if agents.len() == 1 { "" } else { "s" },
+                attention,
+                if attention == 1 { "s" } else { "" },
+                task_suffix
+            )
+        } else {
+            format!(
+                "{} {} ({} agent{}){}",
+                team_icon,
+                team_name,
+                agents.len(),
+                if agents.len() == 1 { "" } else { "s" },
+                task_suffix
+            )
+        };
+        let mut header_style = Style::default()
+            .fg(colors::highlight())
+            .add_modifier(Modifier::BOLD);
+        // A hidden selection is shown on its team header
+        if collapsed
+            && agents
+                .iter()
+                .any(|a| selected_pane_id == Some(a.pane_id.as_str()))
+        {
+            header_style = header_style.add_modifier(Modifier::REVERSED);
+            selected_item = Some(items.len());
+        }
         items.push(ListItem::new(Line::from(vec![Span::styled(
             header,
-            Style::default()
-                .fg(colors::HIGHLIGHT)
-                .add_modifier(Modifier::BOLD),
+            header_style,
         )])));
+        if collapsed {
+            continue;
+        }
 
         // Agent entries with tree glyphs
         let agent_count = agents.len();
@@ -71,13 +162,27 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 "\u{251c}\u{2500}"
             }; // └─ or ├─
 
-            let (icon, color) = match &agent.status {
-                Status::Attention(_) => ("\u{1f514}", colors::ATTENTION), // 🔔
-                Status::Working => ("\u{1f916}", colors::WORKING),        // 🤖
-                Status::Compacting => ("\u{1f504}", colors::COMPACTING),  // 🔄
+            // Hidden columns fold into Working
+            let status = if app.state.display_column(&agent.status) == StatusColumn::Working {
+                &Status::Working
+            } else {
+                &agent.status
             };
 
-            let status_str = match &agent.status {
+            let (icon, color) = match status {
+                Status::Attention(_) => ("\u{1f514}", colors::attention()), // 🔔
+                Status::Working => ("\u{1f916}", colors::working()),        // 🤖
+                Status::Compacting => ("\u{1f504}", colors::compacting()),  // 🔄
+            };
+            // Gone: hooks went quiet, kept greyed out until the grace period ends
+            let (icon, color) = if agent.is_gone() {
+                ("\u{1f4a4}", colors::idle()) // 💤
+            } else {
+                (icon, color)
+            };
+
+            let status_str = match status {
+                _ if agent.is_gone() => "Gone",
                 Status::Attention(AttentionType::Permission) => "Permission",
                 Status::Attention(AttentionType::Input) => "Input",
                 Status::Attention(AttentionType::Notification) => {
@@ -101,11 +206,52 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 ""
             };
 
+            let pin_prefix = if app.state.pinned.contains(&agent.pane_id) {
+                "\u{1f4cc} " // 📌
+            } else {
+                ""
+            };
+
             // Prefer team_agent_name, fall back to pane_id
             let display_name = agent.team_agent_name.as_deref().unwrap_or(&agent.pane_id);
 
-            let tool_info = agent.tool_display();
-            let elapsed = agent.elapsed_display();
+            // Git branch (worktree agents are otherwise indistinguishable),
+            // then ahead/behind its upstream: "⎇ feat ↑2 ↓0"
+            let branch_tag = agent
+                .git_branch
+                .as_deref()
+                .map(|b| {
+                    // Char-aware: branch names may be non-ASCII
+                    let short: String = if b.chars().count() > 24 {
+                        b.chars().take(23).chain(std::iter::once('…')).collect()
+                    } else {
+                        b.to_string()
+                    };
+                    let upstream = agent
+                        .upstream
+                        .map(|(ahead, behind)| format!(" \u{2191}{ahead} \u{2193}{behind}"))
+                        .unwrap_or_default();
+                    format!(" \u{2387} {short}{upstream}") // ⎇
+                })
+                .unwrap_or_default();
+
+            // Stuck badge goes in front of the tool name
+            let tool_info = if agent.stuck {
+                format!("STUCK {}", agent.tool_display())
+            } else {
+                agent.tool_display()
+            };
+            let elapsed = format_timestamp(agent.start_time, app.time_format);
+            // Long-lived agents that went quiet: "3h 05m idle 12m"
+            let idle_secs = agent.idle_secs(now);
+            let idle = if idle_secs >= MIN_IDLE_SHOWN_SECS && !agent.is_gone() {
+                format!(
+                    " idle {}",
+                    format_timestamp_at(agent.last_update, now, TimeFormat::Compact)
+                )
+            } else {
+                String::new()
+            };
 
             // Model name (shorten for display)
             let model_tag = agent.model.as_deref().map(|m| {
@@ -120,13 +266,19 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 short
             });
 
-            // Context usage indicator
+            // Context usage indicator (nothing for agents that never reported context)
             let ctx_tag = agent
-                .context_usage_percent
-                .map(|pct| format!("ctx:{:.0}%", pct));
+                .context_remaining()
+                .map(|left| format!("ctx:{:.0}%", 100.0 - left));
 
             let is_selected = selected_pane_id == Some(agent.pane_id.as_str());
-            let select_prefix = if is_selected { "\u{25b6} " } else { "  " }; // ▶ or spaces
+            let is_marked = app.state.selected_agents.contains(&agent.pane_id);
+            let select_prefix = match (is_selected, is_marked) {
+                (true, true) => "\u{25b6}\u{2022}", // ▶•
+                (true, false) => "\u{25b6} ",       // ▶
+                (false, true) => " \u{2022}",       // •
+                (false, false) => "  ",
+            };
 
             // Build optional tags string
             let mut tags = String::new();
@@ -139,6 +291,16 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 }
                 tags.push_str(c);
             }
+            // Running cost estimate once the agent has burned any context
+            if agent.tokens_accrued > 0 {
+                if !tags.is_empty() {
+                    tags.push(' ');
+                }
+                match app.state.agent_cost(agent) {
+                    cost @ Some(_) => tags.push_str(&format_cost(cost)),
+                    None => tags.push_str("$n/a"),
+                }
+            }
             if let Some(ref effort) = agent.effort_level {
                 if !tags.is_empty() {
                     tags.push(' ');
@@ -158,39 +320,142 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 format!(" [{}]", tags)
             };
 
-            let line = format!(
-                "{}{} {}{} {} ({}){} {} {}",
-                select_prefix,
-                glyph,
-                lead_prefix,
-                icon,
-                display_name,
-                status_str,
-                tags_display,
-                tool_info,
-                elapsed
+            let compact = app.density == Density::Compact;
+            // The card's name slot: project when compact, agent name otherwise
+            let head = format!(
+                "{}{} {}{}{} ",
+                select_prefix, glyph, pin_prefix, lead_prefix, icon
             );
-
-            // Context burn warning: override color when usage > 80%
-            let ctx_warning = agent.context_usage_percent.is_some_and(|pct| pct > 80.0);
-            let effective_color = if ctx_warning {
-                colors::COMPACTING // Yellow warning for high context burn
+            let (name, name_field, tail) = if compact {
+                (
+                    agent.project.as_str(),
+                    SearchField::Project,
+                    format!(
+                        " {} ({}){} {}",
+                        display_name,
+                        status_str,
+                        if agent.stuck { " STUCK" } else { "" },
+                        elapsed
+                    ),
+                )
             } else {
-                color
+                let field = if agent.team_agent_name.is_some() {
+                    SearchField::AgentName
+                } else {
+                    SearchField::PaneId
+                };
+                (
+                    display_name,
+                    field,
+                    format!(
+                        "{} ({}){} {} {}{}",
+                        branch_tag, status_str, tags_display, tool_info, elapsed, idle
+                    ),
+                )
+            };
+            // Search matches in the name slot are underlined
+            let matched: Vec<usize> = filter
+                .hit(agent)
+                .filter(|hit| hit.field == name_field)
+                .map(|hit| hit.positions)
+                .unwrap_or_default();
+
+            // Context burn warning: override color as remaining context runs low;
+            // a stuck tool outranks it since it needs a human right now
+            let effective_color = match app.state.context_level(agent) {
+                _ if agent.is_gone() => color,
+                _ if agent.stuck => colors::stuck(),
+                ContextLevel::Critical => colors::error(),
+                ContextLevel::Warn => colors::compacting(), // Yellow
+                ContextLevel::Normal => color,
             };
 
             let style = if is_selected {
                 Style::default()
                     .fg(effective_color)
                     .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else if agent.is_gone() {
+                Style::default()
+                    .fg(effective_color)
+                    .add_modifier(Modifier::DIM)
             } else {
                 Style::default().fg(effective_color)
             };
 
-            items.push(ListItem::new(Line::from(vec![Span::styled(line, style)])));
+            if is_selected {
+                selected_item = Some(items.len());
+            }
+            let mut spans = vec![Span::styled(head, style)];
+            if matched.is_empty() {
+                spans.push(Span::styled(name.to_string(), style));
+            } else {
+                let hit_style = style.add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
+                for (i, c) in name.chars().enumerate() {
+                    let style = if matched.contains(&i) {
+                        hit_style
+                    } else {
+                        style
+                    };
+                    spans.push(Span::styled(c.to_string(), style));
+                }
+            }
+            // Non-default permission mode; bypassing every prompt is alarming
+            if let Some(mode) = agent.notable_permission_mode() {
+                let badge_style = if agent.bypasses_permissions() {
+                    style.fg(colors::error()).add_modifier(Modifier::BOLD)
+                } else {
+                    style
+                };
+                let badge = if agent.bypasses_permissions() {
+                    " \u{26a0} BYPASS".to_string() // ⚠
+                } else {
+                    format!(" <{mode}>")
+                };
+                spans.push(Span::styled(badge, badge_style));
+            }
+            // Interrupted tool: sorted to the top of its group, flagged in red
+            if agent.failed_attention() && !agent.is_gone() {
+                spans.push(Span::styled(
+                    " FAILED",
+                    style.fg(colors::error()).add_modifier(Modifier::BOLD),
+                ));
+            }
+            spans.push(Span::styled(tail, style));
+            items.push(ListItem::new(Line::from(spans)));
+            if compact {
+                continue;
+            }
 
             let continuation = if is_last { "   " } else { "\u{2502}  " }; // │ or space
 
+            // Show what the agent was last asked to do
+            if let Some(ref prompt) = agent.last_prompt {
+                let mut clipped: String = prompt.chars().take(CARD_PROMPT_CHARS).collect();
+                if clipped.len() < prompt.len() {
+                    clipped.push('\u{2026}'); // …
+                }
+                let prompt_line = format!("  {}  \u{203a} {}", continuation, clipped); // ›
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    prompt_line,
+                    Style::default().fg(colors::idle()),
+                )])));
+            }
+
+            // The user's own annotation, kept whatever the agent does
+            if let Some(ref note) = agent.note {
+                let mut clipped: String = note.chars().take(CARD_PROMPT_CHARS).collect();
+                if clipped.len() < note.len() {
+                    clipped.push('\u{2026}'); // …
+                }
+                let note_line = format!("  {}  \u{270e} {}", continuation, clipped); // ✎
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    note_line,
+                    Style::default()
+                        .fg(colors::highlight())
+                        .add_modifier(Modifier::ITALIC),
+                )])));
+            }
+
             // Show error message when last tool failed
             if agent.last_tool_failed {
                 let error_msg = if agent.failed_tool_interrupt {
@@ -221,10 +486,74 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 };
                 items.push(ListItem::new(Line::from(vec![Span::styled(
                     error_msg,
-                    Style::default().fg(Color::Red),
+                    Style::default().fg(colors::error()),
                 )])));
             }
 
+            // Show recent iteration durations once the agent is looping
+            if agent.turn_durations.len() >= MIN_SPARKLINE_TURNS {
+                let trend = agent
+                    .turn_trend()
+                    .map(|t| format!(" {}", t.arrow()))
+                    .unwrap_or_default();
+                let last = agent.turn_durations.back().copied().unwrap_or_default();
+                let turns_line = format!(
+                    "  {}  \u{27f3} {}{} last {}", // ⟳
+                    continuation,
+                    turn_sparkline(agent.turn_durations.iter().copied()),
+                    trend,
+                    turn_length(last)
+                );
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    turns_line,
+                    Style::default().fg(colors::idle()),
+                )])));
+            }
+
+            // Show compaction progress estimate
+            if matches!(status, Status::Compacting) {
+                if let Some(progress) =
+                    agent.compaction_progress(app.state.typical_compaction_secs())
+                {
+                    let trigger = agent
+                        .last_compact_trigger
+                        .as_deref()
+                        .map(|t| format!(" ({t})"))
+                        .unwrap_or_default();
+                    let compact_line =
+                        format!("  {}  \u{23f3} {}{}", continuation, progress, trigger); // ⏳
+                    items.push(ListItem::new(Line::from(vec![Span::styled(
+                        compact_line,
+                        Style::default().fg(colors::compacting()),
+                    )])));
+                }
+            }
+
+            // Show what a pending permission prompt asks to run
+            if let Some(ref prompt) = agent.pending_permission {
+                let prompt_line = format!("  {}  \u{1f512} {}", continuation, prompt); // 🔒
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    prompt_line,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                )])));
+            }
+
+            // Show pending AskUserQuestion with numbered options (1-9 answers)
+            if let Some(ref question) = agent.pending_question {
+                let question_line = format!("  {}  \u{2753} {}", continuation, question); // ❓
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    question_line,
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                )])));
+                for (i, option) in agent.pending_options.iter().enumerate() {
+                    let option_line = format!("  {}     {}. {}", continuation, i + 1, option);
+                    items.push(ListItem::new(Line::from(vec![Span::styled(
+                        option_line,
+                        Style::default().fg(color),
+                    )])));
+                }
+            }
+
             // Show stop_hook_active indicator (Claude continues after Stop)
             if agent.stop_hook_active
                 && matches!(agent.status, Status::Attention(AttentionType::Waiting))
@@ -232,19 +561,17 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 let hook_line = format!("  {}  \u{1f517} stop hook active", continuation); // 🔗
                 items.push(ListItem::new(Line::from(vec![Span::styled(
                     hook_line,
-                    Style::default().fg(colors::WORKING),
+                    Style::default().fg(colors::working()),
                 )])));
             }
 
-            // Show session metadata line (session_source + permission_mode + cwd + files + subagents)
+            // Show session metadata line (session_source + cwd + files + subagents;
+            // a non-default permission mode is badged in the header instead)
             {
                 let mut meta_parts: Vec<String> = Vec::new();
                 if let Some(ref src) = agent.session_source {
                     meta_parts.push(src.clone());
                 }
-                if let Some(ref mode) = agent.permission_mode {
-                    meta_parts.push(format!("mode:{}", mode));
-                }
                 // Show shortened cwd (~/path instead of /Users/name/path)
                 if let Some(ref cwd) = agent.cwd {
                     let short_cwd = shorten_path(cwd);
@@ -290,32 +617,32 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                         format!("  {}  {}", continuation, meta_parts.join(" \u{2502} ")); // │ separator
                     items.push(ListItem::new(Line::from(vec![Span::styled(
                         meta_line,
-                        Style::default().fg(colors::IDLE),
+                        Style::default().fg(colors::idle()),
                     )])));
                 }
             }
 
-            // Show running subagent descriptions (truncated)
-            {
-                let running_subagents: Vec<_> = agent
-                    .subagents
-                    .iter()
-                    .filter(|s| s.status == "running")
-                    .collect();
-                for sub in running_subagents {
-                    if !sub.description.is_empty() && sub.description != "subagent" {
-                        let desc = if sub.description.len() > 40 {
-                            format!("{}...", &sub.description[..37])
-                        } else {
-                            sub.description.clone()
-                        };
-                        let sub_line = format!("  {}  \u{2192} {}", continuation, desc); // → prefix
-                        items.push(ListItem::new(Line::from(vec![Span::styled(
-                            sub_line,
-                            Style::default().fg(colors::IDLE),
-                        )])));
+            // Show running subagents as a tree (children under their spawner)
+            for row in agent.running_subagent_tree() {
+                let sub = row.subagent;
+                let label = if !sub.description.is_empty() && sub.description != "subagent" {
+                    if sub.description.len() > 40 {
+                        format!("{}...", &sub.description[..37])
+                    } else {
+                        sub.description.clone()
                     }
-                }
+                } else {
+                    sub.subagent_type
+                        .clone()
+                        .unwrap_or_else(|| "subagent".to_string())
+                };
+                // ↑ marks a subagent whose spawner already finished
+                let orphan = if row.orphan { "\u{2191} " } else { "" };
+                let sub_line = format!("  {}  {}{}{}", continuation, row.connector, orphan, label);
+                items.push(ListItem::new(Line::from(vec![Span::styled(
+                    sub_line,
+                    Style::default().fg(colors::idle()),
+                )])));
             }
 
             // Show current_task_subject indented below agent when present
@@ -323,7 +650,7 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
                 let task_line = format!("  {}  \u{1f4cb} {}", continuation, task_subject); // 📋
                 items.push(ListItem::new(Line::from(vec![Span::styled(
                     task_line,
-                    Style::default().fg(colors::IDLE),
+                    Style::default().fg(colors::idle()),
                 )])));
             }
         }
@@ -355,23 +682,26 @@ pub fn render_team_view(f: &mut Frame, area: ratatui::layout::Rect, app: &App) {
 
             items.push(ListItem::new(Line::from(vec![Span::styled(
                 bar,
-                Style::default().fg(colors::WORKING),
+                Style::default().fg(colors::working()),
             )])));
         }
 
         // Spacing between teams
-        items.push(ListItem::new(""));
+        if app.density == Density::Comfortable {
+            items.push(ListItem::new(""));
+        }
     }
 
     let list = List::new(items).block(
         Block::default()
-            .title(" Teams ")
+            .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(colors::BORDER))
+            .border_style(Style::default().fg(colors::border()))
             .border_type(ratatui::widgets::BorderType::Rounded),
     );
 
-    f.render_widget(list, area);
+    let mut list_state = ListState::default().with_selected(selected_item);
+    f.render_stateful_widget(list, area, &mut list_state);
 }
 
 /// Shorten a path for display: replace home dir with ~
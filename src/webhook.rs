@@ -0,0 +1,225 @@
+//! Outbound webhooks on agent status transitions (`[[webhooks]]`)
+//!
+//! A transition into a configured status is POSTed as JSON from a tokio
+//! task, so a slow or unreachable endpoint never blocks the UI. Each delivery
+//! is retried with exponential backoff; deliveries that still fail are
+//! counted and exported as `rehoboam_webhook_failures_total`.
+//!
+//! Webhook URLs often carry a secret in the path or query (Slack, Discord),
+//! so logs name only the host.
+
+use crate::config::{WebhookConfig, WebhookTrigger};
+use crate::state::{current_timestamp, AttentionType, Status, StatusTransition};
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Attempts per delivery before it counts as failed
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Wait before the first retry, doubled for each one after
+const FIRST_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Give up on a single request after this long
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JSON body POSTed to each webhook
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WebhookPayload {
+    pub pane_id: String,
+    pub project: String,
+    /// Status before the transition (None for a newly registered agent)
+    pub from: Option<&'static str>,
+    pub to: &'static str,
+    /// Tool, notification type or hook name that caused the transition
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+/// Webhook name for a status: the attention type, "working" or "compacting"
+pub fn status_name(status: &Status) -> &'static str {
+    match status {
+        Status::Attention(AttentionType::Permission) => "permission",
+        Status::Attention(AttentionType::Input) => "input",
+        Status::Attention(AttentionType::Notification) => "notification",
+        Status::Attention(AttentionType::Waiting) => "waiting",
+        Status::Working => "working",
+        Status::Compacting => "compacting",
+    }
+}
+
+/// Whether entering `status` fires a webhook configured with `trigger`
+fn triggers(trigger: WebhookTrigger, status: &Status) -> bool {
+    match trigger {
+        WebhookTrigger::Attention => matches!(status, Status::Attention(_)),
+        WebhookTrigger::Permission => *status == Status::Attention(AttentionType::Permission),
+        WebhookTrigger::Input => *status == Status::Attention(AttentionType::Input),
+        WebhookTrigger::Notification => *status == Status::Attention(AttentionType::Notification),
+        WebhookTrigger::Waiting => *status == Status::Attention(AttentionType::Waiting),
+        WebhookTrigger::Working => *status == Status::Working,
+        WebhookTrigger::Compacting => *status == Status::Compacting,
+    }
+}
+
+/// Shared count of deliveries that failed every attempt
+#[derive(Debug, Clone, Default)]
+pub struct WebhookFailures(Arc<AtomicU64>);
+
+impl WebhookFailures {
+    /// Failed deliveries since startup
+    pub fn total(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn record(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Configured webhooks and the client that delivers them
+#[derive(Debug, Default)]
+pub struct Webhooks {
+    hooks: Vec<WebhookConfig>,
+    /// Built only when webhooks are configured
+    client: Option<reqwest::Client>,
+    pub failures: WebhookFailures,
+}
+
+impl Webhooks {
+    pub fn new(hooks: Vec<WebhookConfig>) -> Self {
+        let client = if hooks.is_empty() {
+            None
+        } else {
+            match reqwest::Client::builder().timeout(REQUEST_TIMEOUT).build() {
+                Ok(client) => Some(client),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to build webhook client, webhooks disabled");
+                    None
+                }
+            }
+        };
+        Self {
+            hooks,
+            client,
+            failures: WebhookFailures::default(),
+        }
+    }
+
+    /// URLs of the webhooks that fire on entering `status`
+    fn urls_for<'a>(&'a self, status: &'a Status) -> impl Iterator<Item = &'a str> + 'a {
+        self.hooks
+            .iter()
+            .filter(|hook| hook.on.iter().any(|&trigger| triggers(trigger, status)))
+            .map(|hook| hook.url.as_str())
+    }
+
+    /// POST `transition` to every webhook it triggers, in the background
+    pub fn dispatch(&self, pane_id: &str, project: &str, transition: &StatusTransition) {
+        let Some(client) = self.client.as_ref() else {
+            return;
+        };
+        let urls: Vec<String> = self.urls_for(&transition.to).map(String::from).collect();
+        if urls.is_empty() {
+            return;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            tracing::warn!("No async runtime, webhook not sent");
+            return;
+        };
+        let payload = WebhookPayload {
+            pane_id: pane_id.to_string(),
+            project: project.to_string(),
+            from: transition.from.as_ref().map(status_name),
+            to: status_name(&transition.to),
+            reason: transition.reason.clone(),
+            timestamp: current_timestamp(),
+        };
+        for url in urls {
+            runtime.spawn(deliver(
+                client.clone(),
+                url,
+                payload.clone(),
+                self.failures.clone(),
+            ));
+        }
+    }
+}
+
+/// Host of a webhook URL for logs; the path and query may hold a token
+fn log_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(String::from))
+        .unwrap_or_else(|| "<invalid url>".to_string())
+}
+
+/// POST `payload` to `url`, retrying with backoff until a 2xx response
+async fn deliver(
+    client: reqwest::Client,
+    url: String,
+    payload: WebhookPayload,
+    failures: WebhookFailures,
+) {
+    let host = log_host(&url);
+    let mut backoff = FIRST_BACKOFF;
+    for attempt in 1..=MAX_ATTEMPTS {
+        let error = match client.post(&url).json(&payload).send().await {
+            Ok(response) if response.status().is_success() => {
+                tracing::debug!(host = %host, pane_id = %payload.pane_id, "Webhook delivered");
+                return;
+            }
+            Ok(response) => format!("HTTP {}", response.status()),
+            Err(e) => e.without_url().to_string(),
+        };
+        if attempt == MAX_ATTEMPTS {
+            tracing::warn!(host = %host, error = %error, attempts = attempt, "Webhook failed");
+            failures.record();
+            return;
+        }
+        tracing::debug!(host = %host, error = %error, attempt, "Webhook failed, retrying");
+        tokio::time::sleep(backoff).await;
+        backoff *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_webhooks_match_entered_status() {
+        let config: crate::config::RehoboamConfig = toml::from_str(
+            "[[webhooks]]\nurl = \"http://a\"\n\n\
+             [[webhooks]]\nurl = \"http://b\"\non = [\"attention\", \"compacting\"]\n",
+        )
+        .unwrap();
+        let webhooks = Webhooks::new(config.webhooks);
+        let urls = |status: Status| {
+            webhooks
+                .urls_for(&status)
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            urls(Status::Attention(AttentionType::Permission)),
+            ["http://a", "http://b"]
+        );
+        assert_eq!(
+            urls(Status::Attention(AttentionType::Waiting)),
+            ["http://b"]
+        );
+        assert_eq!(urls(Status::Compacting), ["http://b"]);
+        assert!(urls(Status::Working).is_empty());
+    }
+
+    #[test]
+    fn test_log_host_hides_path_and_query() {
+        assert_eq!(
+            log_host("https://hooks.slack.com/services/T000/B000/secret?token=x"),
+            "hooks.slack.com"
+        );
+        assert_eq!(log_host("not a url"), "<invalid url>");
+    }
+}